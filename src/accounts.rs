@@ -2,7 +2,7 @@
 
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 use crate::resolve;
@@ -49,6 +49,12 @@ pub struct OwnerConfig {
     pub github_user: String,
     #[serde(default)]
     pub name: String,
+    /// More addresses or display names that are you (old addresses, nicknames)
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Your chat ids by platform (`slack = "U024BE7LH"`, `telegram = "user123"`)
+    #[serde(default)]
+    pub handles: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,6 +65,12 @@ pub struct WatchConfig {
     pub notify: bool,
     #[serde(default)]
     pub auto_upgrade: bool,
+    /// Seconds between social metrics refreshes (0 = disabled)
+    #[serde(default)]
+    pub social_stats_interval: u64,
+    /// Report threads newly due for a follow-up (`corky followups`) each cycle
+    #[serde(default)]
+    pub followups: bool,
 }
 
 fn default_poll_interval() -> u64 {
@@ -71,6 +83,8 @@ impl Default for WatchConfig {
             poll_interval: 300,
             notify: false,
             auto_upgrade: false,
+            social_stats_interval: 0,
+            followups: false,
         }
     }
 }
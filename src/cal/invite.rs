@@ -0,0 +1,651 @@
+//! Meeting invitations — iCalendar parts rendered into conversations, and
+//! iTIP REPLY messages for `corky cal rsvp`.
+//!
+//! Sync renders each VEVENT of a `text/calendar` part as a plain-text block
+//! appended to the message body:
+//!
+//! ```text
+//! [Invitation] Budget review
+//! When: 2026-03-10T14:00:00+01:00 to 2026-03-10T15:00:00+01:00
+//! Where: Room 4
+//! Organizer: Alice <alice@example.com>
+//! UID: 040000008200E00074C5B7101A82E008@example.com
+//! ```
+//!
+//! `rsvp` reads the block back, so no raw message is needed to answer.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use clap::ValueEnum;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use super::create;
+use crate::accounts::{get_default_account, load_accounts};
+use crate::config::corky_config;
+use crate::contact::from_conversation::find_conversation;
+use crate::draft::new::create_in;
+use crate::draft::update_draft_status;
+use crate::mailbox::followups::message_id;
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::types::Thread;
+use crate::util::slugify;
+
+static BLOCK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\[(Invitation|Update|Cancelled|Reply|Event)\] (.*)$").unwrap());
+static FIELD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(When|Where|Organizer|Response|UID|Sequence): (.*)$").unwrap());
+static MAILBOX_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(.*?)\s*<([^<>]+)>$").unwrap());
+
+/// Answer to an invitation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Rsvp {
+    Accept,
+    Decline,
+    Tentative,
+}
+
+impl Rsvp {
+    /// iCalendar PARTSTAT value.
+    pub fn partstat(self) -> &'static str {
+        match self {
+            Rsvp::Accept => "ACCEPTED",
+            Rsvp::Decline => "DECLINED",
+            Rsvp::Tentative => "TENTATIVE",
+        }
+    }
+
+    /// Subject prefix, as calendar clients write it.
+    fn label(self) -> &'static str {
+        match self {
+            Rsvp::Accept => "Accepted",
+            Rsvp::Decline => "Declined",
+            Rsvp::Tentative => "Tentative",
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            Rsvp::Accept => "accepted",
+            Rsvp::Decline => "declined",
+            Rsvp::Tentative => "tentatively accepted",
+        }
+    }
+}
+
+/// One VEVENT of a calendar part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Invite {
+    /// iTIP METHOD of the enclosing calendar (REQUEST, CANCEL, REPLY, ...)
+    pub method: String,
+    pub uid: String,
+    pub sequence: u32,
+    pub summary: String,
+    /// RFC 3339 datetime, or YYYY-MM-DD for all-day events
+    pub start: String,
+    /// Same form as `start`; empty if the event has none
+    pub end: String,
+    pub location: String,
+    /// `Name <addr>` or `addr`
+    pub organizer: String,
+    /// For REPLY: who answered and how, e.g. `Bob <bob@example.com> accepted`
+    pub response: String,
+}
+
+// ---------------------------------------------------------------------------
+// iCalendar parsing
+// ---------------------------------------------------------------------------
+
+/// Unfold continuation lines (RFC 5545 §3.1).
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if let Some(rest) = line.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+    lines
+}
+
+/// One content line: `NAME;PARAM=x;PARAM="a:b":VALUE`.
+struct Property {
+    /// Uppercased
+    name: String,
+    /// Uppercased keys, unquoted values
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+/// Split `NAME;PARAM=x;PARAM="a:b":VALUE` into its name, params and value.
+fn split_property(line: &str) -> Option<Property> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => return Some(i),
+            _ => {}
+        }
+        None
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.to_ascii_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+    Some(Property { name, params, value: value.to_string() })
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+}
+
+/// Undo TEXT escaping (`\n`, `\,`, `\;`, `\\`).
+fn unescape(value: &str) -> String {
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// A DATE or DATE-TIME value as YYYY-MM-DD or RFC 3339.
+///
+/// UTC (`Z`) and TZID times keep their offset; floating times and unknown
+/// TZIDs (e.g. Windows zone names) are taken as local time.
+fn parse_ics_time(value: &str, params: &[(String, String)]) -> Option<String> {
+    let value = value.trim();
+    if param(params, "VALUE") == Some("DATE") || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(date.format("%Y-%m-%d").to_string());
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Utc.from_utc_datetime(&naive).to_rfc3339());
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    if let Some(tz) = param(params, "TZID").and_then(|id| id.parse::<Tz>().ok()) {
+        return tz.from_local_datetime(&naive).earliest().map(|t| t.fixed_offset().to_rfc3339());
+    }
+    Local.from_local_datetime(&naive).earliest().map(|t| t.fixed_offset().to_rfc3339())
+}
+
+/// `Name <addr>` from a CAL-ADDRESS property.
+fn cal_address(value: &str, params: &[(String, String)]) -> String {
+    let value = value.trim();
+    let addr = value
+        .strip_prefix("mailto:")
+        .or_else(|| value.strip_prefix("MAILTO:"))
+        .unwrap_or(value);
+    match param(params, "CN").filter(|cn| !cn.is_empty() && *cn != addr) {
+        Some(cn) => format!("{} <{}>", cn, addr),
+        None => addr.to_string(),
+    }
+}
+
+/// Apply one VEVENT property to `ev`.
+fn set_property(ev: &mut Invite, name: &str, params: &[(String, String)], value: &str) {
+    match name {
+        "UID" => ev.uid = value.trim().to_string(),
+        "SEQUENCE" => ev.sequence = value.trim().parse().unwrap_or(0),
+        "SUMMARY" => ev.summary = unescape(value).trim().to_string(),
+        "LOCATION" => ev.location = unescape(value).trim().to_string(),
+        "DTSTART" => ev.start = parse_ics_time(value, params).unwrap_or_default(),
+        "DTEND" => ev.end = parse_ics_time(value, params).unwrap_or_default(),
+        "ORGANIZER" => ev.organizer = cal_address(value, params),
+        "ATTENDEE" => {
+            if let Some(partstat) = param(params, "PARTSTAT") {
+                if ev.response.is_empty() && partstat != "NEEDS-ACTION" {
+                    let who = cal_address(value, params);
+                    ev.response = format!("{} {}", who, partstat.to_ascii_lowercase());
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Every VEVENT in an iCalendar document.
+pub fn parse(ics: &str) -> Vec<Invite> {
+    let mut method = String::new();
+    let mut events = Vec::new();
+    let mut current: Option<Invite> = None;
+    let mut nested = 0usize; // VALARM etc. inside a VEVENT
+    for line in unfold(ics) {
+        let Some(Property { name, params, value }) = split_property(&line) else {
+            continue;
+        };
+        let Some(ev) = current.as_mut() else {
+            if name == "METHOD" {
+                method = value.trim().to_ascii_uppercase();
+            } else if name == "BEGIN" && value.eq_ignore_ascii_case("VEVENT") {
+                current = Some(Invite { method: method.clone(), ..Default::default() });
+            }
+            continue;
+        };
+        match name.as_str() {
+            "BEGIN" => nested += 1,
+            "END" if nested > 0 => nested -= 1,
+            "END" => events.extend(current.take()),
+            _ if nested > 0 => {}
+            _ => set_property(ev, &name, &params, &value),
+        }
+    }
+    events
+}
+
+/// The METHOD of an iCalendar document, if it has one.
+pub fn method(ics: &str) -> Option<String> {
+    unfold(ics)
+        .iter()
+        .take_while(|l| !l.eq_ignore_ascii_case("BEGIN:VEVENT"))
+        .find_map(|l| split_property(l).filter(|p| p.name == "METHOD"))
+        .map(|p| p.value.trim().to_ascii_uppercase())
+        .filter(|m| !m.is_empty())
+}
+
+// ---------------------------------------------------------------------------
+// Conversation blocks
+// ---------------------------------------------------------------------------
+
+impl Invite {
+    fn heading(&self) -> &'static str {
+        match self.method.as_str() {
+            "REQUEST" if self.sequence > 0 => "Update",
+            "REQUEST" => "Invitation",
+            "CANCEL" => "Cancelled",
+            "REPLY" => "Reply",
+            _ => "Event",
+        }
+    }
+
+    /// The plain-text block written into the conversation.
+    pub fn render(&self) -> String {
+        let summary = if self.summary.is_empty() { "(no title)" } else { self.summary.as_str() };
+        let mut lines = vec![format!("[{}] {}", self.heading(), summary)];
+        if !self.start.is_empty() {
+            let when = if self.end.is_empty() || self.end == self.start {
+                self.start.clone()
+            } else {
+                format!("{} to {}", self.start, self.end)
+            };
+            lines.push(format!("When: {}", when));
+        }
+        if !self.location.is_empty() {
+            lines.push(format!("Where: {}", self.location.replace('\n', ", ")));
+        }
+        if !self.organizer.is_empty() {
+            lines.push(format!("Organizer: {}", self.organizer));
+        }
+        if !self.response.is_empty() {
+            lines.push(format!("Response: {}", self.response));
+        }
+        if !self.uid.is_empty() {
+            lines.push(format!("UID: {}", self.uid));
+        }
+        if self.sequence > 0 {
+            lines.push(format!("Sequence: {}", self.sequence));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Append a block for each event in `calendars` (raw iCalendar parts) to
+/// `body`, skipping repeats of the same event (inline part + .ics attachment).
+pub fn append_blocks(body: String, calendars: &[String]) -> String {
+    let mut seen: Vec<(String, String, u32)> = Vec::new();
+    let mut blocks = Vec::new();
+    for invite in calendars.iter().map(String::as_str).flat_map(parse) {
+        let key = (invite.uid.clone(), invite.method.clone(), invite.sequence);
+        if !invite.uid.is_empty() && seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        blocks.push(invite.render());
+    }
+    if blocks.is_empty() {
+        return body;
+    }
+    let body = body.trim_end();
+    let blocks = blocks.join("\n\n");
+    if body.is_empty() { blocks } else { format!("{}\n\n{}", body, blocks) }
+}
+
+/// Read rendered blocks back out of a message body.
+pub fn parse_blocks(body: &str) -> Vec<Invite> {
+    let mut invites = Vec::new();
+    let mut current: Option<Invite> = None;
+    for line in body.lines() {
+        if let Some(cap) = BLOCK_RE.captures(line) {
+            invites.extend(current.take());
+            let method = match &cap[1] {
+                "Invitation" | "Update" => "REQUEST",
+                "Cancelled" => "CANCEL",
+                "Reply" => "REPLY",
+                _ => "PUBLISH",
+            };
+            current = Some(Invite {
+                method: method.to_string(),
+                summary: cap[2].trim().to_string(),
+                ..Default::default()
+            });
+            continue;
+        }
+        let Some(invite) = current.as_mut() else {
+            continue;
+        };
+        let Some(cap) = FIELD_RE.captures(line) else {
+            invites.extend(current.take());
+            continue;
+        };
+        let value = cap[2].trim().to_string();
+        match &cap[1] {
+            "When" => match value.split_once(" to ") {
+                Some((start, end)) => {
+                    invite.start = start.to_string();
+                    invite.end = end.to_string();
+                }
+                None => invite.start = value,
+            },
+            "Where" => invite.location = value,
+            "Organizer" => invite.organizer = value,
+            "Response" => invite.response = value,
+            "UID" => invite.uid = value,
+            "Sequence" => invite.sequence = value.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+    invites.extend(current);
+    invites
+}
+
+// ---------------------------------------------------------------------------
+// iTIP REPLY
+// ---------------------------------------------------------------------------
+
+/// Escape a TEXT value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Quote a parameter value if it contains `:`, `;` or `,`.
+fn param_value(value: &str) -> String {
+    let value = value.replace('"', "");
+    if value.contains([':', ';', ',']) { format!("\"{}\"", value) } else { value }
+}
+
+/// Fold a content line at 75 octets.
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+/// `;VALUE=DATE:YYYYMMDD` or `:YYYYMMDDTHHMMSSZ` for a rendered time.
+fn ics_time(value: &str) -> Option<String> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(format!(";VALUE=DATE:{}", date.format("%Y%m%d")));
+    }
+    let time = DateTime::parse_from_rfc3339(value).ok()?;
+    Some(format!(":{}", time.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ")))
+}
+
+/// `;CN=Name:mailto:addr` for `Name <addr>` or `addr`.
+fn ics_address(mailbox: &str) -> String {
+    match MAILBOX_RE.captures(mailbox.trim()) {
+        Some(cap) if !cap[1].trim().is_empty() => {
+            format!(";CN={}:mailto:{}", param_value(cap[1].trim().trim_matches('"')), &cap[2])
+        }
+        Some(cap) => format!(":mailto:{}", &cap[2]),
+        None => format!(":mailto:{}", mailbox.trim()),
+    }
+}
+
+impl Invite {
+    /// A METHOD:REPLY calendar answering this invite as `attendee` (`Name <addr>`).
+    pub fn reply(&self, attendee: &str, answer: Rsvp, now: DateTime<Utc>) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "PRODID:-//corky//cal rsvp//EN".to_string(),
+            "VERSION:2.0".to_string(),
+            "METHOD:REPLY".to_string(),
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}", self.uid),
+            format!("SEQUENCE:{}", self.sequence),
+            format!("DTSTAMP:{}", now.format("%Y%m%dT%H%M%SZ")),
+        ];
+        if let Some(start) = ics_time(&self.start) {
+            lines.push(format!("DTSTART{}", start));
+        }
+        if let Some(end) = ics_time(&self.end) {
+            lines.push(format!("DTEND{}", end));
+        }
+        if !self.summary.is_empty() {
+            lines.push(format!("SUMMARY:{}", escape(&self.summary)));
+        }
+        lines.push(format!("ORGANIZER{}", ics_address(&self.organizer)));
+        let attendee = ics_address(attendee);
+        lines.push(format!("ATTENDEE;PARTSTAT={}{}", answer.partstat(), attendee));
+        lines.push("END:VEVENT".to_string());
+        lines.push("END:VCALENDAR".to_string());
+        lines.iter().map(String::as_str).map(fold).collect::<Vec<_>>().join("\r\n") + "\r\n"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// corky cal rsvp
+// ---------------------------------------------------------------------------
+
+/// The conversation and its last invitation, with the index of the message
+/// carrying it.
+fn latest_invite(path: &Path) -> Result<(Thread, Invite, usize)> {
+    let text = std::fs::read_to_string(path)?;
+    let thread = parse_thread_markdown(&text)
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse conversation: {}", path.display()))?;
+    let mut latest: Option<(Invite, usize)> = None;
+    for (i, msg) in thread.messages.iter().enumerate() {
+        for invite in parse_blocks(&msg.body) {
+            if invite.method == "REQUEST" || invite.method == "CANCEL" {
+                latest = Some((invite, i));
+            }
+        }
+    }
+    let Some((invite, i)) = latest else {
+        bail!("No meeting invitation found in {}", path.display());
+    };
+    if invite.method == "CANCEL" {
+        bail!("'{}' was cancelled by the organizer", invite.summary);
+    }
+    if invite.uid.is_empty() || invite.organizer.is_empty() {
+        bail!("Invitation '{}' has no UID or organizer to reply to", invite.summary);
+    }
+    Ok((thread, invite, i))
+}
+
+/// The account to reply from — `account`, else the thread's account, else
+/// the default — and its `Name <addr>` for the ATTENDEE line.
+fn reply_account(account: Option<&str>, thread_account: Option<&str>) -> Result<(String, String)> {
+    let accounts = load_accounts(None)?;
+    let (name, acct) = match account {
+        Some(name) => {
+            let acct = accounts.get(name).ok_or_else(|| anyhow::anyhow!("Unknown account '{}'", name))?;
+            (name.to_string(), acct.clone())
+        }
+        None => match thread_account.and_then(|n| accounts.get(n).map(|a| (n.to_string(), a.clone()))) {
+            Some(found) => found,
+            None => get_default_account(&accounts)?,
+        },
+    };
+    let owner = corky_config::try_load_config(None)
+        .and_then(|cfg| cfg.owner)
+        .map(|o| o.name)
+        .unwrap_or_default();
+    let mailbox = if owner.is_empty() { acct.user.clone() } else { format!("{} <{}>", owner, acct.user) };
+    Ok((name, mailbox))
+}
+
+/// corky cal rsvp CONVERSATION accept|decline|tentative [--send] [--add]
+pub fn run_rsvp(
+    conversation: &str,
+    answer: Rsvp,
+    account: Option<&str>,
+    send: bool,
+    add: bool,
+    cal_account: Option<&str>,
+) -> Result<()> {
+    if add && answer == Rsvp::Decline {
+        bail!("--add only applies to accept or tentative");
+    }
+    let path = find_conversation(conversation)?;
+    let (thread, invite, index) = latest_invite(&path)?;
+    let (account_name, me) = reply_account(account, thread.accounts.first().map(String::as_str))?;
+    let author = corky_config::try_load_config(None)
+        .and_then(|cfg| cfg.owner)
+        .map(|o| o.name)
+        .unwrap_or_default();
+
+    // The REPLY rides along as a draft attachment, next to the draft itself
+    let conversations_dir = path.parent().unwrap_or(Path::new("."));
+    let drafts_dir = conversations_dir.parent().unwrap_or(conversations_dir).join("drafts");
+    std::fs::create_dir_all(&drafts_dir)?;
+    let ics_path: PathBuf = drafts_dir.join(format!("rsvp-{}.ics", slugify(&invite.uid)));
+    std::fs::write(&ics_path, invite.reply(&me, answer, Utc::now()))?;
+
+    let subject = format!("{}: {}", answer.label(), invite.summary);
+    let in_reply_to = thread.messages.get(index).and_then(message_id);
+    let draft = create_in(
+        &drafts_dir,
+        &subject,
+        &invite.organizer,
+        None,
+        Some(&account_name),
+        None,
+        in_reply_to.as_deref(),
+        &author,
+        &[ics_path.display().to_string()],
+    )?;
+    let who = if author.is_empty() { me.as_str() } else { author.as_str() };
+    let mut content = std::fs::read_to_string(&draft)?;
+    content.push('\n');
+    content.push_str(&format!("{} has {} this invitation.\n", who, answer.verb()));
+    std::fs::write(&draft, content)?;
+    // Complete as scaffolded: ready for review, not a work in progress
+    update_draft_status(&draft, "review")?;
+
+    if send {
+        update_draft_status(&draft, "approved")?;
+        crate::draft::run(&draft, true)?;
+    } else {
+        println!("{}", draft.display());
+        eprintln!("Review, then send with: corky draft push {} --send", draft.display());
+    }
+
+    if add {
+        let description = format!("Organizer: {}", invite.organizer);
+        let end = if invite.end.is_empty() { &invite.start } else { &invite.end };
+        let location = Some(invite.location.as_str()).filter(|l| !l.is_empty());
+        create::run(&invite.summary, &invite.start, end, Some(description.as_str()), location, cal_account)?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REQUEST: &str = "BEGIN:VCALENDAR\r\nMETHOD:REQUEST\r\nBEGIN:VTIMEZONE\r\nTZID:Europe/Berlin\r\nEND:VTIMEZONE\r\nBEGIN:VEVENT\r\nUID:abc-123@example.com\r\nSEQUENCE:0\r\nSUMMARY:Budget review\\, Q3\r\nDTSTART;TZID=Europe/Berlin:20260310T140000\r\nDTEND;TZID=Europe/Berlin:20260310T150000\r\nLOCATION:Room 4\r\nORGANIZER;CN=\"Alice Example\":mailto:alice@example.com\r\nATTENDEE;PARTSTAT=NEEDS-ACTION;CN=Test User:mailto:me@\r\n example.com\r\nBEGIN:VALARM\r\nSUMMARY:Reminder\r\nEND:VALARM\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_request() {
+        let events = parse(REQUEST);
+        assert_eq!(events.len(), 1);
+        let ev = &events[0];
+        assert_eq!(ev.method, "REQUEST");
+        assert_eq!(ev.uid, "abc-123@example.com");
+        assert_eq!(ev.summary, "Budget review, Q3");
+        assert_eq!(ev.start, "2026-03-10T14:00:00+01:00");
+        assert_eq!(ev.end, "2026-03-10T15:00:00+01:00");
+        assert_eq!(ev.location, "Room 4");
+        assert_eq!(ev.organizer, "Alice Example <alice@example.com>");
+        assert!(ev.response.is_empty());
+        assert_eq!(method(REQUEST).as_deref(), Some("REQUEST"));
+    }
+
+    #[test]
+    fn test_parse_all_day_and_utc() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:x\nDTSTART;VALUE=DATE:20260401\nDTEND;VALUE=DATE:20260402\nEND:VEVENT\nBEGIN:VEVENT\nUID:y\nDTSTART:20260401T090000Z\nEND:VEVENT\nEND:VCALENDAR\n";
+        let events = parse(ics);
+        assert_eq!(events[0].start, "2026-04-01");
+        assert_eq!(events[0].end, "2026-04-02");
+        assert_eq!(events[1].start, "2026-04-01T09:00:00+00:00");
+        assert_eq!(method(ics), None);
+    }
+
+    #[test]
+    fn test_render_roundtrip() {
+        let ev = &parse(REQUEST)[0];
+        let body = append_blocks("See you there.\n".to_string(), &[REQUEST.to_string(), REQUEST.to_string()]);
+        assert!(body.starts_with("See you there.\n\n[Invitation] Budget review, Q3\nWhen: 2026-03-10T14:00:00+01:00 to "));
+        assert_eq!(body.matches("[Invitation]").count(), 1);
+        assert_eq!(parse_blocks(&body), vec![ev.clone()]);
+        assert_eq!(append_blocks("Hi".to_string(), &[]), "Hi");
+    }
+
+    #[test]
+    fn test_reply() {
+        let ev = &parse(REQUEST)[0];
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap();
+        let reply = ev.reply("Test User <me@example.com>", Rsvp::Tentative, now);
+        assert!(reply.contains("\r\nMETHOD:REPLY\r\n"));
+        assert!(reply.contains("\r\nUID:abc-123@example.com\r\nSEQUENCE:0\r\nDTSTAMP:20260301T080000Z\r\n"));
+        assert!(reply.contains("\r\nDTSTART:20260310T130000Z\r\n"));
+        assert!(reply.contains("\r\nSUMMARY:Budget review\\, Q3\r\n"));
+        assert!(reply.contains("\r\nORGANIZER;CN=Alice Example:mailto:alice@example.com\r\n"));
+        assert!(reply.contains("\r\nATTENDEE;PARTSTAT=TENTATIVE;CN=Test User:mailto:me@example.com\r\n"));
+        // The reply parses as a REPLY carrying the answer
+        let parsed = &parse(&reply)[0];
+        assert_eq!(parsed.method, "REPLY");
+        assert_eq!(parsed.response, "Test User <me@example.com> tentative");
+    }
+
+    #[test]
+    fn test_fold_long_lines() {
+        let folded = fold(&format!("SUMMARY:{}", "é".repeat(60)));
+        assert!(folded.split("\r\n").all(|l| l.len() <= 75));
+        assert_eq!(unfold(&folded).concat(), format!("SUMMARY:{}", "é".repeat(60)));
+    }
+}
@@ -2,8 +2,10 @@
 
 use anyhow::{bail, Result};
 use chrono::Utc;
+use serde::Serialize;
 
 use super::auth;
+use crate::output::{emit, OutputFormat, Report};
 
 const CALENDAR_API: &str = "https://www.googleapis.com/calendar/v3";
 
@@ -103,26 +105,79 @@ pub fn fetch_events(
     Ok(list.items)
 }
 
-/// List upcoming events to stdout.
-pub fn run(limit: usize, query: Option<&str>, account: Option<&str>) -> Result<()> {
-    let token = auth::get_access_token(account)?;
-    let events = fetch_events(&token, query, limit)?;
-    if events.is_empty() {
-        if let Some(q) = query {
-            println!("No upcoming events matching '{}'.", q);
-        } else {
-            println!("No upcoming events.");
+/// An upcoming event as reported by `corky cal list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct EventEntry {
+    pub id: String,
+    pub summary: String,
+    /// RFC 3339 start time, or YYYY-MM-DD for all-day events
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub all_day: bool,
+    pub status: String,
+    pub html_link: String,
+    /// Human-readable start (table column)
+    #[serde(skip)]
+    pub start_display: String,
+}
+
+impl From<&CalendarEvent> for EventEntry {
+    fn from(event: &CalendarEvent) -> Self {
+        let pick = |dt: &EventDateTime| dt.date_time.clone().or_else(|| dt.date.clone());
+        EventEntry {
+            id: event.id.clone(),
+            summary: event.summary.clone(),
+            start: pick(&event.start),
+            end: pick(&event.end),
+            all_day: event.start.date_time.is_none() && event.start.date.is_some(),
+            status: event.status.clone(),
+            html_link: event.html_link.clone(),
+            start_display: event.start.display(),
         }
-        return Ok(());
     }
-    for event in &events {
-        println!(
-            "  {} | {} | id:{}",
-            event.start.display(),
-            event.summary,
-            event.id,
-        );
+}
+
+/// Result of `corky cal list` (JSON: array of [`EventEntry`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct EventListReport {
+    pub events: Vec<EventEntry>,
+    #[serde(skip)]
+    pub query: Option<String>,
+}
+
+impl Report for EventListReport {
+    fn render_table(&self) -> String {
+        if self.events.is_empty() {
+            return match self.query {
+                Some(ref q) => format!("No upcoming events matching '{}'.\n", q),
+                None => "No upcoming events.\n".to_string(),
+            };
+        }
+        let mut out = String::new();
+        for event in &self.events {
+            out.push_str(&format!(
+                "  {} | {} | id:{}\n",
+                event.start_display, event.summary, event.id,
+            ));
+        }
+        out.push_str(&format!("\n{} event(s).\n", self.events.len()));
+        out
     }
-    println!("\n{} event(s).", events.len());
-    Ok(())
+}
+
+/// List upcoming events to stdout.
+pub fn run(
+    limit: usize,
+    query: Option<&str>,
+    account: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let token = auth::get_access_token(account)?;
+    let events = fetch_events(&token, query, limit)?;
+    let report = EventListReport {
+        events: events.iter().map(EventEntry::from).collect(),
+        query: query.map(|q| q.to_string()),
+    };
+    emit(format, &report)
 }
@@ -1,7 +1,9 @@
-//! Google Calendar management — list, delete, and create events.
+//! Google Calendar management — list, delete, and create events, and answer
+//! meeting invitations found in conversations.
 
 pub mod auth;
 pub mod check;
 pub mod create;
 pub mod delete;
+pub mod invite;
 pub mod list;
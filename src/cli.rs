@@ -1,6 +1,8 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::output::OutputFormat;
+
 #[derive(Parser)]
 #[command(name = "corky", version, about = "Sync email threads from IMAP to Markdown, draft replies, manage mailboxes", disable_help_subcommand = true)]
 pub struct Cli {
@@ -8,6 +10,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub mailbox: Option<String>,
 
+    /// Output format for listing commands: table, json or jsonl
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -143,17 +149,120 @@ pub enum Commands {
         filter: Option<String>,
     },
 
+    /// Full-text search across conversations and drafts
+    Search {
+        /// Query: terms, "phrases", from:, to:, label:, account:, before:, after:, has:attachment
+        #[arg(required = true, num_args = 1..)]
+        query: Vec<String>,
+
+        /// Maximum number of results
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        /// Rebuild the index from scratch
+        #[arg(long)]
+        rebuild: bool,
+    },
+
+    /// Export conversations to mbox or .eml files, with a manifest
+    Export {
+        /// Output mbox file (--type mbox) or directory (--type eml)
+        out: PathBuf,
+
+        /// Export file type
+        #[arg(long = "type", value_enum, default_value = "mbox")]
+        export_type: crate::export::ExportFormat,
+
+        /// Only threads with this label (repeatable)
+        #[arg(long = "label")]
+        labels: Vec<String>,
+
+        /// Only threads involving this contact's addresses
+        #[arg(long)]
+        contact: Option<String>,
+
+        /// Only messages on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only messages on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only threads matching this search query (corky search syntax)
+        #[arg(long)]
+        search: Option<String>,
+
+        /// Scope: "." for root only, mailbox name, or omit for all
+        #[arg(long)]
+        scope: Option<String>,
+    },
+
+    /// Regenerate conversation Markdown from the raw message store
+    Reprocess {
+        /// Scope: "." for root only, mailbox name, or omit for all
+        scope: Option<String>,
+
+        /// Report what would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Run a Model Context Protocol server over stdio
+    Mcp {
+        /// Serve only this shared mailbox, gated by its permissions
+        #[arg(long)]
+        scope: Option<String>,
+
+        /// Principal to look up in [mailboxes.NAME.permissions]
+        #[arg(long = "as", default_value = "agent")]
+        principal: String,
+    },
+
+    /// Serve a local HTTP/JSON API (threads, drafts, contacts, schedule, sync)
+    Serve {
+        /// Address to bind (non-loopback addresses expose the API to the network)
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port to listen on
+        #[arg(long, default_value_t = crate::serve::DEFAULT_PORT)]
+        port: u16,
+
+        /// API token (default: $CORKY_SERVE_TOKEN, else a generated token)
+        #[arg(long)]
+        token: Option<String>,
+    },
+
     /// Find threads awaiting a reply
     #[command(alias = "find-unanswered")]
     Unanswered {
         /// Scope: "." for root only, mailbox name, or omit for all
         scope: Option<String>,
 
-        /// Name to match as 'your' messages
+        /// Extra name or address to count as yours (besides accounts, [owner] name and aliases)
         #[arg(long = "from")]
         from_name: Option<String>,
     },
 
+    /// Find threads awaiting their reply to your last message
+    Followups {
+        /// Scope: "." for root only, mailbox name, or omit for all
+        scope: Option<String>,
+
+        /// Default wait before a thread is due ("36h", "5d", "2w"); overrides [followups] after
+        #[arg(long)]
+        after: Option<String>,
+
+        /// Extra name or address to count as yours (besides accounts, [owner] name and aliases)
+        #[arg(long = "from")]
+        from_name: Option<String>,
+
+        /// Scaffold a nudge draft for each thread (reuses an existing one)
+        #[arg(long)]
+        draft: bool,
+    },
+
     /// Validate draft markdown files
     #[command(hide = true)]
     ValidateDraft {
@@ -182,6 +291,10 @@ pub enum Commands {
     #[command(subcommand)]
     Youtube(YoutubeCommands),
 
+    /// Cross-platform social post commands (stats, validate)
+    #[command(subcommand)]
+    Social(SocialCommands),
+
     /// Scheduled publishing commands
     #[command(subcommand)]
     Schedule(ScheduleCommands),
@@ -194,6 +307,10 @@ pub enum Commands {
     #[command(subcommand)]
     Label(LabelCommands),
 
+    /// Triage conversations: done, snoozed, or waiting on someone
+    #[command(subcommand)]
+    Thread(ThreadCommands),
+
     /// Google Calendar management
     #[command(subcommand)]
     Cal(CalCommands),
@@ -260,6 +377,70 @@ pub enum ContactCommands {
 
     /// Sync CLAUDE.md files between root contacts/ and mailbox contacts/
     Sync,
+
+    /// Rename a contact (config, directories in root and mailboxes, sync state)
+    Rename {
+        /// Current contact name
+        old: String,
+
+        /// New contact name
+        new: String,
+    },
+
+    /// Merge one contact into another (FROM is removed, its old name kept as an alias)
+    Merge {
+        /// Contact to merge away
+        from: String,
+
+        /// Contact to keep
+        into: String,
+    },
+
+    /// Rank frequent correspondents who aren't contacts yet, and create them
+    Discover {
+        /// Maximum candidates to list
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        /// Minimum messages exchanged (from + to) to be listed
+        #[arg(long, default_value = "2")]
+        min_messages: usize,
+
+        /// Create a candidate: RANK, suggested NAME or EMAIL, optionally =NEW-NAME (repeatable)
+        #[arg(long)]
+        create: Vec<String>,
+
+        /// Create every listed candidate
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Import contacts from a vCard (.vcf) file
+    Import {
+        /// Path to a .vcf file (one or more cards)
+        file: PathBuf,
+
+        /// Show what would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Export contacts as vCard 3.0
+    Export {
+        /// Output .vcf file (default: stdout)
+        out: Option<PathBuf>,
+
+        /// Only export this contact (repeatable)
+        #[arg(long = "contact")]
+        contacts: Vec<String>,
+    },
+
+    /// Two-way sync with the [carddav] address book
+    Carddav {
+        /// Show what would change without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -305,6 +486,91 @@ pub enum SyncCommands {
         #[arg(long, default_value = "sms")]
         account: String,
     },
+
+    /// Sync Slack conversations via the Web API ([slack] in .corky.toml)
+    Slack {
+        /// Ignore saved cursors and fetch full history
+        #[arg(long)]
+        full: bool,
+        /// Channel name or ID to sync (repeatable; overrides [slack] channels)
+        #[arg(long = "channel")]
+        channels: Vec<String>,
+    },
+
+    /// Import WhatsApp "Export chat" .txt/.zip files
+    WhatsappImport {
+        /// Path to a chat .txt, an export .zip, or a directory of them
+        path: PathBuf,
+        /// Label for imported conversations
+        #[arg(long, default_value = "whatsapp")]
+        label: String,
+        /// Account name for imported conversations
+        #[arg(long, default_value = "whatsapp")]
+        account: String,
+        /// Chat name (default: from the file name); sets the wa: thread ID
+        #[arg(long)]
+        chat: Option<String>,
+        /// Timestamp field order (default: inferred from the file)
+        #[arg(long, value_enum, default_value = "auto")]
+        date_order: crate::sync::whatsapp_import::DateOrder,
+    },
+
+    /// Import DiscordChatExporter JSON (a channel .json or a directory)
+    DiscordImport {
+        /// Path to a channel export .json, or a directory of them
+        path: PathBuf,
+        /// Label prefix for imported conversations ({label}:{channel})
+        #[arg(long, default_value = "discord")]
+        label: String,
+        /// Account name for imported conversations
+        #[arg(long, default_value = "discord")]
+        account: String,
+    },
+
+    /// Import Signal messages (signal-cli JSON lines or Signal Desktop JSON)
+    SignalImport {
+        /// Path to the export file
+        path: PathBuf,
+        /// Label for imported conversations
+        #[arg(long, default_value = "signal")]
+        label: String,
+        /// Account name for imported conversations
+        #[arg(long, default_value = "signal")]
+        account: String,
+    },
+
+    /// Import an mbox archive (Google Takeout, Thunderbird, ...)
+    MboxImport {
+        /// Path to the mbox file
+        path: PathBuf,
+        /// Label for imported conversations
+        #[arg(long, default_value = "mbox")]
+        label: String,
+        /// Account name for imported conversations
+        #[arg(long, default_value = "mbox")]
+        account: String,
+    },
+
+    /// Import a Maildir (cur/ and new/, plus Maildir++ subfolders)
+    MaildirImport {
+        /// Path to the Maildir
+        path: PathBuf,
+        /// Label for imported conversations
+        #[arg(long, default_value = "maildir")]
+        label: String,
+        /// Account name for imported conversations
+        #[arg(long, default_value = "maildir")]
+        account: String,
+    },
+
+    /// Import comments on published LinkedIn/YouTube posts
+    SocialComments {
+        /// Draft files (default: all published drafts in social/)
+        files: Vec<PathBuf>,
+        /// Label for imported conversations
+        #[arg(long, default_value = "social")]
+        label: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -341,6 +607,10 @@ pub enum DraftCommands {
         /// Attach a file (can be repeated)
         #[arg(long = "attach")]
         attachments: Vec<String>,
+
+        /// Set scheduled_at: HH:MM or "YYYY-MM-DD HH:MM" in the recipient's timezone, or RFC 3339
+        #[arg(long)]
+        send_at: Option<String>,
     },
     /// Validate draft markdown files
     Validate {
@@ -444,7 +714,7 @@ pub enum MailboxCommands {
         /// Scope: "." for root only, mailbox name, or omit for all
         scope: Option<String>,
 
-        /// Name to match as 'your' messages
+        /// Extra name or address to count as yours (besides accounts, [owner] name and aliases)
         #[arg(long = "from")]
         from_name: Option<String>,
     },
@@ -471,6 +741,9 @@ pub enum LinkedinCommands {
         /// Profile name in .corky.toml [profiles] to update with URN
         #[arg(long)]
         profile: Option<String>,
+        /// Also request organization admin scopes (post as company pages)
+        #[arg(long)]
+        org: bool,
     },
 
     /// Create a new LinkedIn draft
@@ -482,6 +755,10 @@ pub enum LinkedinCommands {
         #[arg(long)]
         author: Option<String>,
 
+        /// Organization profile name to post as (company page)
+        #[arg(long)]
+        org: Option<String>,
+
         /// Post visibility (public, connections)
         #[arg(long, default_value = "public")]
         visibility: String,
@@ -509,12 +786,18 @@ pub enum LinkedinCommands {
         body: Option<String>,
     },
 
+    /// Delete a published LinkedIn post and mark the draft retracted
+    Delete {
+        /// Path to the published draft file
+        file: PathBuf,
+    },
+
     /// Validate profiles in .corky.toml
     Check,
 
     /// List LinkedIn drafts
     List {
-        /// Filter by status: draft, ready, published
+        /// Filter by status: draft, ready, published, retracted
         #[arg(long)]
         status: Option<String>,
     },
@@ -571,17 +854,38 @@ pub enum YoutubeCommands {
         file: PathBuf,
     },
 
+    /// Delete a published YouTube video and mark the draft retracted
+    Delete {
+        /// Path to the draft file (must have post_id in frontmatter)
+        file: PathBuf,
+    },
+
     /// Validate profiles in .corky.toml
     Check,
 
     /// List YouTube drafts
     List {
-        /// Filter by status: draft, ready, published
+        /// Filter by status: draft, ready, published, retracted
         #[arg(long)]
         status: Option<String>,
     },
 }
 
+#[derive(Subcommand)]
+pub enum SocialCommands {
+    /// Fetch engagement metrics for published drafts and print a summary
+    Stats {
+        /// Draft files (default: all published drafts in social/)
+        files: Vec<PathBuf>,
+    },
+
+    /// Check drafts against platform limits and media rules before publishing
+    Validate {
+        /// Draft files (default: all unpublished drafts in social/)
+        files: Vec<PathBuf>,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ScheduleCommands {
     /// Process due scheduled items (publish/send)
@@ -704,9 +1008,9 @@ pub enum DocCommands {
         /// Path to the markdown file
         file: PathBuf,
 
-        /// Output format: pdf (default) or docx
-        #[arg(long, default_value = "pdf", value_parser = ["pdf", "docx"])]
-        format: String,
+        /// Document type: pdf (default) or docx
+        #[arg(long = "to", default_value = "pdf", value_parser = ["pdf", "docx"])]
+        to: String,
 
         /// Template name (looks up templates/<name>.css in data dir)
         #[arg(long)]
@@ -748,6 +1052,47 @@ pub enum LabelCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum ThreadCommands {
+    /// Mark a conversation done (hidden until a new message arrives)
+    Done {
+        /// Conversation slug or path to its .md file
+        conversation: String,
+    },
+
+    /// Snooze a conversation until a date
+    Snooze {
+        /// Conversation slug or path to its .md file
+        conversation: String,
+
+        /// Date (YYYY-MM-DD) or span from now ("3d", "2w")
+        #[arg(long)]
+        until: String,
+    },
+
+    /// Mark a conversation as waiting on someone or something
+    Wait {
+        /// Conversation slug or path to its .md file
+        conversation: String,
+
+        /// Who or what it is waiting on
+        #[arg(long)]
+        on: Option<String>,
+    },
+
+    /// Clear a conversation's triage state
+    Reopen {
+        /// Conversation slug or path to its .md file
+        conversation: String,
+    },
+
+    /// List triaged conversations
+    List {
+        /// Scope: "." for root only, mailbox name, or omit for all
+        scope: Option<String>,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum SkillCommands {
     /// Install the skill definition to .claude/skills/corky/SKILL.md
@@ -824,6 +1169,31 @@ pub enum CalCommands {
         account: Option<String>,
     },
 
+    /// Answer a meeting invitation in a conversation with an iTIP REPLY
+    Rsvp {
+        /// Conversation slug or path
+        conversation: String,
+
+        /// Your answer
+        response: crate::cal::invite::Rsvp,
+
+        /// Email account to reply from (default: the thread's account)
+        #[arg(long)]
+        account: Option<String>,
+
+        /// Send the reply now instead of leaving a draft to review
+        #[arg(long)]
+        send: bool,
+
+        /// Also add the event to Google Calendar
+        #[arg(long)]
+        add: bool,
+
+        /// Calendar account for --add (must match the one used for auth)
+        #[arg(long)]
+        cal_account: Option<String>,
+    },
+
     /// Check availability in a time range
     Check {
         /// Range start (RFC 3339, e.g. 2026-03-10T09:00:00-05:00)
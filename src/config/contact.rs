@@ -1,13 +1,15 @@
 //! Contact configuration — parse [contacts.*] from .corky.toml.
 
 use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use crate::resolve;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct Contact {
     #[serde(default)]
     pub emails: Vec<String>,
@@ -17,6 +19,90 @@ pub struct Contact {
     /// Aliases for matching sender names that don't slugify to the directory name.
     #[serde(default)]
     pub aliases: Vec<String>,
+    /// Display name ("Alice Smith"); vCard FN
+    #[serde(default)]
+    pub full_name: String,
+    #[serde(default)]
+    pub phones: Vec<String>,
+    #[serde(default)]
+    pub organization: String,
+    /// vCard UID, linking this contact to address book cards (import, export, CardDAV)
+    #[serde(default)]
+    pub uid: String,
+    /// Job title; vCard TITLE
+    #[serde(default)]
+    pub title: String,
+    /// IANA time zone ("Europe/Berlin"), used when scheduling drafts to them
+    #[serde(default)]
+    pub timezone: String,
+    /// Preferred language for drafts (BCP 47 tag: "de", "pt-BR")
+    #[serde(default)]
+    pub language: String,
+    /// Name of a [profiles] entry holding their social handles
+    #[serde(default)]
+    pub profile: String,
+    /// How long to wait for their reply before `corky followups` lists a thread ("2d")
+    #[serde(default)]
+    pub followup_after: String,
+    /// Other handles by platform (`github = "alice"`)
+    #[serde(default)]
+    pub handles: BTreeMap<String, String>,
+    /// Important dates as "YYYY-MM-DD" or "MM-DD" (`birthday = "04-12"`); vCard BDAY is `birthday`
+    #[serde(default)]
+    pub dates: BTreeMap<String, String>,
+}
+
+impl Contact {
+    /// The parsed `timezone`, if set and a known IANA name.
+    pub fn tz(&self) -> Option<Tz> {
+        self.timezone.parse().ok()
+    }
+
+    /// Problems with the typed fields: unknown time zone, malformed dates.
+    pub fn field_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !self.timezone.is_empty() && self.tz().is_none() {
+            errors.push(format!("unknown timezone '{}' (expected an IANA name like Europe/Berlin)", self.timezone));
+        }
+        if !self.followup_after.is_empty() && crate::util::parse_span(&self.followup_after).is_none() {
+            errors.push(format!("followup_after '{}' is not a span like 36h, 5d or 2w", self.followup_after));
+        }
+        for (label, date) in &self.dates {
+            if parse_date(date).is_none() {
+                errors.push(format!("date '{}' = '{}' is not YYYY-MM-DD or MM-DD", label, date));
+            }
+        }
+        errors
+    }
+}
+
+/// Parse a contact date: "YYYY-MM-DD" → (Some(year), month, day), "MM-DD" → (None, month, day).
+pub fn parse_date(date: &str) -> Option<(Option<i32>, u32, u32)> {
+    let date = date.trim();
+    if let Ok(full) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return Some((Some(full.year()), full.month(), full.day()));
+    }
+    let (month, day) = date.split_once('-')?;
+    let (month, day): (u32, u32) = (month.parse().ok()?, day.parse().ok()?);
+    // 2000 is a leap year, so 02-29 is accepted
+    NaiveDate::from_ymd_opt(2000, month, day)?;
+    Some((None, month, day))
+}
+
+/// The next occurrence of a contact date on or after `today`. Feb 29 falls
+/// on Feb 28 in other years.
+pub fn next_occurrence(date: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let (_, month, day) = parse_date(date)?;
+    let on = |year: i32| NaiveDate::from_ymd_opt(year, month, day).or_else(|| NaiveDate::from_ymd_opt(year, month, day - 1));
+    let this_year = on(today.year())?;
+    if this_year >= today { Some(this_year) } else { on(today.year() + 1) }
+}
+
+/// The contact that has `email` among its emails (case-insensitive).
+pub fn find_by_email<'a>(contacts: &'a BTreeMap<String, Contact>, email: &str) -> Option<(&'a String, &'a Contact)> {
+    contacts
+        .iter()
+        .find(|(_, c)| c.emails.iter().any(|e| e.eq_ignore_ascii_case(email)))
 }
 
 /// Load contacts from [contacts.*] in .corky.toml and return {name: Contact} mapping.
@@ -80,6 +166,9 @@ pub fn save_contact(
 
     // Build contact table
     let mut table = toml_edit::Table::new();
+    if !contact.full_name.is_empty() {
+        table.insert("full_name", toml_edit::value(contact.full_name.as_str()));
+    }
     if !contact.emails.is_empty() {
         let mut arr = toml_edit::Array::new();
         for e in &contact.emails {
@@ -101,6 +190,39 @@ pub fn save_contact(
         }
         table.insert("aliases", toml_edit::value(arr));
     }
+    if !contact.phones.is_empty() {
+        let mut arr = toml_edit::Array::new();
+        for p in &contact.phones {
+            arr.push(p.as_str());
+        }
+        table.insert("phones", toml_edit::value(arr));
+    }
+    if !contact.organization.is_empty() {
+        table.insert("organization", toml_edit::value(contact.organization.as_str()));
+    }
+    if !contact.uid.is_empty() {
+        table.insert("uid", toml_edit::value(contact.uid.as_str()));
+    }
+    for (key, value) in [
+        ("title", &contact.title),
+        ("timezone", &contact.timezone),
+        ("language", &contact.language),
+        ("profile", &contact.profile),
+        ("followup_after", &contact.followup_after),
+    ] {
+        if !value.is_empty() {
+            table.insert(key, toml_edit::value(value.as_str()));
+        }
+    }
+    for (key, map) in [("handles", &contact.handles), ("dates", &contact.dates)] {
+        if !map.is_empty() {
+            let mut inline = toml_edit::InlineTable::new();
+            for (k, v) in map {
+                inline.insert(k, v.as_str().into());
+            }
+            table.insert(key, toml_edit::value(inline));
+        }
+    }
     contacts.insert(name, toml_edit::Item::Table(table));
 
     std::fs::write(&path, doc.to_string())?;
@@ -37,6 +37,14 @@ pub struct CorkyConfig {
     pub transcription: Option<TranscriptionConfig>,
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
+    #[serde(default)]
+    pub sync: Option<SyncConfig>,
+    #[serde(default)]
+    pub slack: Option<SlackConfig>,
+    #[serde(default)]
+    pub carddav: Option<CardDavConfig>,
+    #[serde(default)]
+    pub followups: Option<FollowupsConfig>,
 }
 
 /// Gmail API config + filter rules (lives in .corky.toml under [gmail]).
@@ -106,6 +114,97 @@ pub struct MailboxPermissions {
     pub send: bool,
 }
 
+/// Sync options (lives in .corky.toml under [sync]).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SyncConfig {
+    /// Keep each message's raw RFC822 source in raw/ (for `corky reprocess`)
+    #[serde(default)]
+    pub keep_raw: bool,
+}
+
+/// Live Slack sync via the Web API (lives in .corky.toml under [slack]).
+///
+/// Token resolution: inline `token` > `token_cmd` > `CORKY_SLACK_TOKEN`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackConfig {
+    /// User (xoxp-) or bot (xoxb-) token
+    #[serde(default)]
+    pub token: String,
+    #[serde(default)]
+    pub token_cmd: String,
+    /// Channel names or IDs to sync. Empty = every conversation the token is a member of.
+    #[serde(default)]
+    pub channels: Vec<String>,
+    /// Conversation types passed to conversations.list
+    #[serde(default = "default_slack_types")]
+    pub types: String,
+    /// Label prefix; threads are labeled `{label}:{channel}`
+    #[serde(default = "default_slack_name")]
+    pub label: String,
+    /// Account name for conversation metadata and sync state
+    #[serde(default = "default_slack_name")]
+    pub account: String,
+    /// How long (days) a thread keeps being checked for new replies
+    #[serde(default = "default_reply_window_days")]
+    pub reply_window_days: u32,
+}
+
+impl Default for SlackConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            token_cmd: String::new(),
+            channels: Vec::new(),
+            types: default_slack_types(),
+            label: default_slack_name(),
+            account: default_slack_name(),
+            reply_window_days: default_reply_window_days(),
+        }
+    }
+}
+
+fn default_slack_types() -> String {
+    "public_channel,private_channel".to_string()
+}
+
+fn default_slack_name() -> String {
+    "slack".to_string()
+}
+
+fn default_reply_window_days() -> u32 {
+    14
+}
+
+/// CardDAV address book for `corky contact carddav` (lives in .corky.toml under [carddav]).
+///
+/// Password resolution: inline `password` > `password_cmd` > `CORKY_CARDDAV_PASSWORD`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CardDavConfig {
+    /// Address book collection URL, e.g. `https://dav.example.com/addressbooks/me/default/`
+    pub url: String,
+    /// Username for HTTP Basic auth. Empty = no auth.
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub password: String,
+    #[serde(default)]
+    pub password_cmd: String,
+}
+
+/// Thresholds for `corky followups` (lives in .corky.toml under [followups]).
+///
+/// Spans are "36h", "5d" or "2w". A contact's `followup_after` wins over
+/// `labels`, which win over `after`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FollowupsConfig {
+    /// Default wait for their reply (empty = 5d)
+    #[serde(default)]
+    pub after: String,
+    /// Per-label waits, e.g. `clients = "2d"`
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+}
+
 /// Transcription config for `corky transcribe`.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TranscriptionConfig {
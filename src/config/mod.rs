@@ -1,3 +1,4 @@
 pub mod contact;
 pub mod corky_config;
+pub mod owner;
 pub mod topic;
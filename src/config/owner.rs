@@ -0,0 +1,179 @@
+//! Who the owner is, for deciding which messages are "mine".
+//!
+//! Every account's `user`, `[owner] name` and `[owner] aliases` (more
+//! addresses or display names) identify the owner. A sender with an address
+//! is matched by address; one without (chat imports) by display name,
+//! including [`ME`], which importers write for the owner's own messages:
+//! SMS sent-type, Signal outgoing, and Slack/Telegram/Discord senders whose id
+//! is in `[owner] handles`.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+use super::corky_config::{self, CorkyConfig};
+
+/// Sender name importers record for the owner's own chat messages.
+pub const ME: &str = "Me";
+
+static ADDR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([^<>@\s]+@[^<>\s]+)>").unwrap());
+
+/// Display names compare case-insensitively, ignoring quotes and extra spaces.
+fn name_key(name: &str) -> String {
+    name.trim_matches(|c: char| c == '"' || c == '\'' || c.is_whitespace())
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Split a sender into (display name, lowercased address).
+fn split_sender(sender: &str) -> (String, Option<String>) {
+    let sender = sender.trim();
+    if let Some(cap) = ADDR_RE.captures(sender) {
+        let name = sender[..cap.get(0).unwrap().start()].to_string();
+        return (name, Some(cap[1].to_lowercase()));
+    }
+    if sender.contains('@') && !sender.contains(char::is_whitespace) {
+        return (String::new(), Some(sender.to_lowercase()));
+    }
+    (sender.to_string(), None)
+}
+
+/// The owner's addresses and display names.
+#[derive(Debug, Clone, Default)]
+pub struct Identity {
+    emails: BTreeSet<String>,
+    names: BTreeSet<String>,
+}
+
+impl Identity {
+    /// From `config` plus `extra` aliases (e.g. a `--from` value).
+    pub fn new(config: Option<&CorkyConfig>, extra: &[&str]) -> Self {
+        let mut identity = Identity::default();
+        if let Some(config) = config {
+            for account in config.accounts.values() {
+                identity.add(&account.user);
+            }
+            if let Some(owner) = &config.owner {
+                identity.add(&owner.name);
+                for alias in &owner.aliases {
+                    identity.add(alias);
+                }
+            }
+        }
+        for alias in extra {
+            identity.add(alias);
+        }
+        identity
+    }
+
+    /// From `.corky.toml` plus an optional `--from` value.
+    pub fn load(extra: Option<&str>) -> Self {
+        let config = corky_config::try_load_config(None);
+        Identity::new(config.as_ref(), extra.as_slice())
+    }
+
+    /// Add an address (`me@x.com`, `Me <me@x.com>`) or a display name.
+    pub fn add(&mut self, alias: &str) {
+        match split_sender(alias) {
+            (_, Some(addr)) => {
+                self.emails.insert(addr);
+            }
+            (name, None) => {
+                let key = name_key(&name);
+                if !key.is_empty() {
+                    self.names.insert(key);
+                }
+            }
+        }
+    }
+
+    /// Whether any address or name is known.
+    pub fn is_configured(&self) -> bool {
+        !self.emails.is_empty() || !self.names.is_empty()
+    }
+
+    /// Whether a `## Sender` header (`Name <addr>`, `addr` or a chat name) is the owner.
+    ///
+    /// Senders with an address are compared by address once any is known;
+    /// otherwise by display name. [`ME`] always matches.
+    pub fn is_me(&self, sender: &str) -> bool {
+        let (name, addr) = split_sender(sender);
+        if let Some(addr) = addr {
+            if !self.emails.is_empty() {
+                return self.emails.contains(&addr);
+            }
+        }
+        let key = name_key(&name);
+        !key.is_empty() && (key == name_key(ME) || self.names.contains(&key))
+    }
+}
+
+/// The owner's chat id on `platform` (`slack`, `telegram`, `discord`) from `[owner] handles`.
+pub fn handle(platform: &str) -> Option<String> {
+    corky_config::try_load_config(None)?
+        .owner?
+        .handles
+        .get(platform)
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CorkyConfig {
+        toml::from_str(
+            r#"
+[owner]
+github_user = "me"
+name = "Test User"
+aliases = ["old@example.org", "T. User"]
+
+[accounts.work]
+user = "me@work.com"
+
+[accounts.home]
+user = "Me@Example.com"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_me_by_address() {
+        let me = Identity::new(Some(&config()), &[]);
+        assert!(me.is_me("Test User <me@example.com>"));
+        assert!(me.is_me("\"Someone Else\" <ME@work.com>"));
+        assert!(me.is_me("old@example.org"));
+        // Same display name, different address
+        assert!(!me.is_me("Test User <test.user@other.com>"));
+        assert!(!me.is_me("Alice <alice@example.com>"));
+    }
+
+    #[test]
+    fn test_is_me_by_name() {
+        let me = Identity::new(Some(&config()), &["Testy"]);
+        assert!(me.is_me("Test User"));
+        assert!(me.is_me("  t.  user "));
+        assert!(me.is_me("Me"));
+        assert!(me.is_me("Testy"));
+        assert!(!me.is_me("Test"));
+        assert!(!me.is_me("Alice"));
+        assert!(!me.is_me(""));
+    }
+
+    #[test]
+    fn test_names_match_addresses_without_accounts() {
+        let me = Identity::new(None, &["Test User"]);
+        assert!(me.is_configured());
+        assert!(me.is_me("Test User <me@example.com>"));
+        assert!(!Identity::new(None, &[]).is_configured());
+    }
+}
@@ -1,6 +1,7 @@
 //! Add a new contact: scaffold mail/contacts/{name}/ with AGENTS.md.
 
 use anyhow::Result;
+use std::path::PathBuf;
 
 use crate::config::contact::{load_contacts, save_contact, Contact};
 use crate::resolve;
@@ -121,6 +122,21 @@ professional
     )
 }
 
+/// Create contacts/{name}/ with AGENTS.md and a CLAUDE.md symlink.
+pub fn create_contact_dir(name: &str, agents_md_content: &str) -> Result<PathBuf> {
+    let contact_dir = resolve::contacts_dir().join(name);
+    if contact_dir.exists() {
+        anyhow::bail!("Directory {} already exists", contact_dir.display());
+    }
+    std::fs::create_dir_all(&contact_dir)?;
+    std::fs::write(contact_dir.join("AGENTS.md"), agents_md_content)?;
+
+    #[cfg(unix)]
+    std::os::unix::fs::symlink("AGENTS.md", contact_dir.join("CLAUDE.md"))?;
+
+    Ok(contact_dir)
+}
+
 /// Create a contact with the default AGENTS.md template.
 pub fn run(name: &str, emails: &[String]) -> Result<()> {
     run_with_agents_md(name, emails, &default_agents_md(name))
@@ -134,19 +150,8 @@ pub fn run_with_agents_md(name: &str, emails: &[String], agents_md_content: &str
         anyhow::bail!("Contact '{}' already exists in .corky.toml", name);
     }
 
-    let contact_dir = resolve::contacts_dir().join(name);
-    if contact_dir.exists() {
-        anyhow::bail!("Directory {} already exists", contact_dir.display());
-    }
-
     // 1. Create contact directory with AGENTS.md + CLAUDE.md symlink
-    std::fs::create_dir_all(&contact_dir)?;
-    let agents_md = contact_dir.join("AGENTS.md");
-    std::fs::write(&agents_md, agents_md_content)?;
-
-    #[cfg(unix)]
-    std::os::unix::fs::symlink("AGENTS.md", contact_dir.join("CLAUDE.md"))?;
-
+    let contact_dir = create_contact_dir(name, agents_md_content)?;
     println!("Created {}/AGENTS.md", contact_dir.display());
 
     // 2. Update .corky.toml
@@ -0,0 +1,460 @@
+//! CardDAV contact sync: `corky contact carddav`.
+//!
+//! Three-way reconcile between [contacts] and one address book collection,
+//! using the ETag and contact hash recorded at the last sync:
+//!
+//! - changed on one side only → copied to the other
+//! - changed on both sides → merged (new emails/phones/aliases unioned,
+//!   local values win) and pushed
+//! - deleted on the server → unlinked; the local contact is kept and not
+//!   uploaded again
+//! - deleted locally → deleted on the server, unless it changed there
+//! - new cards → matched to a contact (UID, email, phone, name) or created
+//! - other contacts with no card → uploaded as `{uid}.vcf`
+
+use anyhow::{bail, Context, Result};
+use base64::Engine;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeMap;
+
+use super::sync::content_hash;
+use super::vcard::{self, Card};
+use crate::config::contact::{load_contacts, save_contact, Contact};
+use crate::config::corky_config::{self, CardDavConfig};
+use crate::sync::types::{CardDavCardState, CardDavSyncState};
+
+/// Counts reported by a CardDAV sync.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CardDavStats {
+    /// Remote changes applied to existing contacts
+    pub pulled: usize,
+    /// Local changes written to existing cards
+    pub pushed: usize,
+    /// Both sides changed; merged and pushed
+    pub merged: usize,
+    /// Contacts created from new cards
+    pub created_local: usize,
+    /// Cards created for contacts that had none
+    pub created_remote: usize,
+    /// Cards deleted because their contact was removed
+    pub deleted_remote: usize,
+    /// Links dropped because the card was deleted on the server
+    pub unlinked: usize,
+}
+
+/// Resolve the password: inline > `password_cmd` > `CORKY_CARDDAV_PASSWORD`.
+pub fn resolve_password(config: &CardDavConfig) -> Result<String> {
+    crate::util::resolve_secret(
+        &config.password,
+        &config.password_cmd,
+        "[carddav] has no password or password_cmd, and CORKY_CARDDAV_PASSWORD is not set",
+    )
+    .or_else(|e| std::env::var("CORKY_CARDDAV_PASSWORD").map_err(|_| e))
+}
+
+/// HTTP Basic `Authorization` header value, or None when no user is set.
+pub fn auth_header(config: &CardDavConfig) -> Result<Option<String>> {
+    if config.user.is_empty() {
+        return Ok(None);
+    }
+    let password = resolve_password(config)?;
+    let token = base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", config.user, password));
+    Ok(Some(format!("Basic {}", token)))
+}
+
+// ---------------------------------------------------------------------------
+// HTTP
+// ---------------------------------------------------------------------------
+
+struct Client<'a> {
+    /// Collection URL, with a trailing slash
+    url: String,
+    auth: Option<&'a str>,
+}
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop><d:getetag/><d:resourcetype/></d:prop></d:propfind>"#;
+
+static RESPONSE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<(?:[\w-]+:)?response\b[^>]*>(.*?)</(?:[\w-]+:)?response>").unwrap());
+static HREF_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<(?:[\w-]+:)?href\b[^>]*>(.*?)</(?:[\w-]+:)?href>").unwrap());
+static ETAG_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<(?:[\w-]+:)?getetag\b[^>]*>(.*?)</(?:[\w-]+:)?getetag>").unwrap());
+static COLLECTION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<(?:[\w-]+:)?collection\b").unwrap());
+
+fn xml_unescape(s: &str) -> String {
+    s.trim()
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Card hrefs → ETags from a PROPFIND multistatus body. Collections are skipped.
+pub fn parse_multistatus(xml: &str) -> BTreeMap<String, String> {
+    RESPONSE_RE
+        .captures_iter(xml)
+        .filter(|c| !COLLECTION_RE.is_match(&c[1]))
+        .filter_map(|c| {
+            let href = xml_unescape(&HREF_RE.captures(&c[1])?[1]);
+            let etag = ETAG_RE.captures(&c[1]).map(|e| xml_unescape(&e[1])).unwrap_or_default();
+            Some((href, etag))
+        })
+        .collect()
+}
+
+impl Client<'_> {
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let req = ureq::request(method, url);
+        match self.auth {
+            Some(auth) => req.set("Authorization", auth),
+            None => req,
+        }
+    }
+
+    /// Absolute URL for an href (server-relative path or full URL).
+    fn resolve(&self, href: &str) -> String {
+        if href.starts_with("http://") || href.starts_with("https://") {
+            return href.to_string();
+        }
+        match self.url.find("://").and_then(|i| self.url[i + 3..].find('/').map(|j| i + 3 + j)) {
+            Some(root) if href.starts_with('/') => format!("{}{}", &self.url[..root], href),
+            _ => format!("{}{}", self.url, href.trim_start_matches('/')),
+        }
+    }
+
+    /// Server-relative href for a new card in the collection.
+    fn new_href(&self, uid: &str) -> String {
+        let file: String = uid
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || "-_.@".contains(c) { c } else { '-' })
+            .collect();
+        let path = match self.url.find("://").and_then(|i| self.url[i + 3..].find('/').map(|j| i + 3 + j)) {
+            Some(root) => &self.url[root..],
+            None => "/",
+        };
+        format!("{}{}.vcf", path, file)
+    }
+
+    fn list(&self) -> Result<BTreeMap<String, String>> {
+        let resp = self
+            .request("PROPFIND", &self.url)
+            .set("Depth", "1")
+            .set("Content-Type", "application/xml; charset=utf-8")
+            .send_string(PROPFIND_BODY)
+            .map_err(|e| http_error("PROPFIND", &self.url, e))?;
+        Ok(parse_multistatus(&resp.into_string()?))
+    }
+
+    fn get(&self, href: &str) -> Result<Card> {
+        let url = self.resolve(href);
+        let body = self
+            .request("GET", &url)
+            .call()
+            .map_err(|e| http_error("GET", &url, e))?
+            .into_string()?;
+        vcard::parse_vcards(&body)
+            .into_iter()
+            .next()
+            .with_context(|| format!("{} is not a vCard", url))
+    }
+
+    /// PUT a card. `etag` = Some(tag) updates with If-Match; None creates with If-None-Match: *.
+    fn put(&self, href: &str, card: &Card, etag: Option<&str>) -> Result<()> {
+        let url = self.resolve(href);
+        let req = self.request("PUT", &url).set("Content-Type", "text/vcard; charset=utf-8");
+        let req = match etag {
+            Some(tag) if !tag.is_empty() => req.set("If-Match", tag),
+            Some(_) => req,
+            None => req.set("If-None-Match", "*"),
+        };
+        req.send_string(&vcard::render_vcard(card))
+            .map_err(|e| http_error("PUT", &url, e))?;
+        Ok(())
+    }
+
+    fn delete(&self, href: &str, etag: &str) -> Result<()> {
+        let url = self.resolve(href);
+        let req = self.request("DELETE", &url);
+        let req = if etag.is_empty() { req } else { req.set("If-Match", etag) };
+        req.call().map_err(|e| http_error("DELETE", &url, e))?;
+        Ok(())
+    }
+}
+
+fn http_error(method: &str, url: &str, err: ureq::Error) -> anyhow::Error {
+    match err {
+        ureq::Error::Status(412, _) => {
+            anyhow::anyhow!("{} {}: card changed on the server during sync; run again", method, url)
+        }
+        ureq::Error::Status(code, resp) => {
+            anyhow::anyhow!("{} {}: HTTP {} {}", method, url, code, resp.status_text())
+        }
+        other => anyhow::anyhow!("{} {}: {}", method, url, other),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Reconcile
+// ---------------------------------------------------------------------------
+
+fn contact_hash(name: &str, contact: &Contact) -> String {
+    content_hash(&vcard::render_vcard(&Card::from_contact(name, contact)))
+}
+
+/// Give a contact linked to `card` the card's UID, so later pushes keep it.
+fn adopt_uid(contact: &mut Contact, card: &Card) {
+    if !card.uid.is_empty() {
+        contact.uid = card.uid.clone();
+    }
+}
+
+/// Reconcile `contacts` with the address book at `url`.
+///
+/// `contacts` and `state` are updated in place; the caller persists them.
+/// With `dry_run`, nothing is written to the server.
+pub fn sync_at(
+    url: &str,
+    auth: Option<&str>,
+    contacts: &mut BTreeMap<String, Contact>,
+    state: &mut CardDavSyncState,
+    dry_run: bool,
+) -> Result<CardDavStats> {
+    let client = Client {
+        url: if url.ends_with('/') { url.to_string() } else { format!("{}/", url) },
+        auth,
+    };
+    let mut remote = client.list()?;
+    let mut stats = CardDavStats::default();
+    let mut wrote = false;
+
+    // 1. Cards linked at the last sync
+    let known: Vec<(String, CardDavCardState)> =
+        state.cards.iter().map(|(h, s)| (h.clone(), s.clone())).collect();
+    for (href, link) in known {
+        let name = link.contact.clone();
+        let Some(etag) = remote.get(&href) else {
+            println!("  {}: card deleted on the server; contact kept, unlinked", name);
+            state.cards.remove(&href);
+            state.unlinked.push(name);
+            stats.unlinked += 1;
+            continue;
+        };
+        let remote_changed = *etag != link.etag;
+        let Some(contact) = contacts.get_mut(&name) else {
+            if remote_changed {
+                // Removed here but edited there: the edit wins, restore it
+                let card = client.get(&href)?;
+                let name = vcard::new_contact_name(&card, contacts);
+                let mut contact = Contact::default();
+                vcard::apply_card(&mut contact, &card);
+                println!("  Restored {} (changed on the server)", name);
+                contacts.insert(name.clone(), contact);
+                state.cards.insert(href.clone(), CardDavCardState { contact: name, ..link });
+                stats.created_local += 1;
+            } else {
+                println!("  Deleting card for removed contact {}", name);
+                if !dry_run {
+                    client.delete(&href, etag)?;
+                }
+                state.cards.remove(&href);
+                // Gone (or going) from the server: not a new card for step 2
+                remote.remove(&href);
+                stats.deleted_remote += 1;
+            }
+            continue;
+        };
+        let local_changed = contact_hash(&name, contact) != link.hash;
+        match (remote_changed, local_changed) {
+            (false, false) => {}
+            (true, false) => {
+                let card = client.get(&href)?;
+                vcard::apply_card(contact, &card);
+                println!("  Pulled {}", name);
+                stats.pulled += 1;
+            }
+            (false, true) => {
+                println!("  Pushed {}", name);
+                if !dry_run {
+                    client.put(&href, &Card::from_contact(&name, contact), Some(etag))?;
+                    wrote = true;
+                }
+                stats.pushed += 1;
+            }
+            (true, true) => {
+                let card = client.get(&href)?;
+                vcard::merge_card(contact, &card);
+                adopt_uid(contact, &card);
+                println!("  Merged {}", name);
+                if !dry_run {
+                    client.put(&href, &Card::from_contact(&name, contact), Some(etag))?;
+                    wrote = true;
+                }
+                stats.merged += 1;
+            }
+        }
+    }
+
+    // 2. Cards not linked yet
+    for (href, etag) in &remote {
+        if state.cards.contains_key(href) {
+            continue;
+        }
+        let card = client.get(href)?;
+        if card.is_empty() {
+            continue;
+        }
+        let linked: Vec<&String> = state.cards.values().map(|l| &l.contact).collect();
+        let candidates = contacts.iter().filter(|(name, _)| !linked.contains(name));
+        let name = match vcard::find_match(candidates, &card) {
+            Some(name) => {
+                let contact = contacts.get_mut(&name).expect("matched contact");
+                vcard::merge_card(contact, &card);
+                adopt_uid(contact, &card);
+                let merged = Card::from_contact(&name, contact);
+                if vcard::render_vcard(&merged) != vcard::render_vcard(&card) {
+                    println!("  Linked {} (pushed local details)", name);
+                    if !dry_run {
+                        client.put(href, &merged, Some(etag))?;
+                        wrote = true;
+                    }
+                } else {
+                    println!("  Linked {}", name);
+                }
+                name
+            }
+            None => {
+                let name = vcard::new_contact_name(&card, contacts);
+                let mut contact = Contact::default();
+                vcard::apply_card(&mut contact, &card);
+                println!("  Created {}", name);
+                contacts.insert(name.clone(), contact);
+                stats.created_local += 1;
+                name
+            }
+        };
+        state.unlinked.retain(|n| *n != name);
+        state.cards.insert(
+            href.clone(),
+            CardDavCardState { contact: name, etag: etag.clone(), hash: String::new() },
+        );
+    }
+
+    // 3. Contacts with no card
+    let linked: Vec<String> = state.cards.values().map(|l| l.contact.clone()).collect();
+    for (name, contact) in contacts.iter_mut() {
+        if linked.contains(name) || state.unlinked.contains(name) {
+            continue;
+        }
+        if contact.uid.is_empty() {
+            contact.uid = vcard::default_uid(name);
+        }
+        let href = client.new_href(&contact.uid);
+        println!("  Uploading {}", name);
+        if !dry_run {
+            client.put(&href, &Card::from_contact(name, contact), None)?;
+            wrote = true;
+        }
+        state.cards.insert(href, CardDavCardState { contact: name.clone(), ..Default::default() });
+        stats.created_remote += 1;
+    }
+
+    // 4. Record what both sides now hold
+    let remote = if wrote { client.list()? } else { remote };
+    for (href, link) in state.cards.iter_mut() {
+        link.etag = remote.get(href).cloned().unwrap_or_default();
+        if let Some(contact) = contacts.get(&link.contact) {
+            link.hash = contact_hash(&link.contact, contact);
+        }
+    }
+    Ok(stats)
+}
+
+/// corky contact carddav [--dry-run]
+pub fn run(dry_run: bool) -> Result<()> {
+    let config = corky_config::load_config(None)?
+        .carddav
+        .context("No [carddav] section in .corky.toml.\nAdd one with the address book url (and user/password_cmd).")?;
+    if config.url.is_empty() {
+        bail!("[carddav] url is empty");
+    }
+    let auth = auth_header(&config)?;
+
+    let mut contacts = load_contacts(None)?;
+    let before = contacts.clone();
+    let mut state = crate::sync::load_state()?;
+    let mut dav_state = state.carddav.remove(&config.url).unwrap_or_default();
+
+    println!("CardDAV sync: {}{}", config.url, if dry_run { " (dry run)" } else { "" });
+    let result = sync_at(&config.url, auth.as_deref(), &mut contacts, &mut dav_state, dry_run);
+
+    if !dry_run {
+        // Persist even on failure: links made before the error stay consistent
+        let changed: Vec<(&String, &Contact)> =
+            contacts.iter().filter(|(name, c)| before.get(*name) != Some(*c)).collect();
+        for (name, contact) in changed {
+            if before.contains_key(name) {
+                save_contact(name, contact, None)?;
+            } else {
+                vcard::create_contact(name, contact)?;
+            }
+        }
+        state.carddav.insert(config.url.clone(), dav_state);
+        crate::sync::save_state(&state)?;
+    }
+
+    let stats = result?;
+    println!(
+        "CardDAV sync {}: {} pulled, {} pushed, {} merged, {} created locally, {} created on server, {} deleted on server, {} unlinked.",
+        if dry_run { "dry run" } else { "complete" },
+        stats.pulled,
+        stats.pushed,
+        stats.merged,
+        stats.created_local,
+        stats.created_remote,
+        stats.deleted_remote,
+        stats.unlinked
+    );
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:">
+  <d:response><d:href>/ab/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat></d:response>
+  <d:response><d:href>/ab/a.vcf</d:href><d:propstat><d:prop><d:getetag>&quot;1&quot;</d:getetag><d:resourcetype/></d:prop></d:propstat></d:response>
+  <response xmlns="DAV:"><href>/ab/b%20c.vcf</href><propstat><prop><getetag>"2"</getetag></prop></propstat></response>
+</d:multistatus>"#;
+        let cards = parse_multistatus(xml);
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards["/ab/a.vcf"], "\"1\"");
+        assert_eq!(cards["/ab/b%20c.vcf"], "\"2\"");
+    }
+
+    #[test]
+    fn test_client_hrefs() {
+        let client = Client { url: "https://dav.example.com/addressbooks/me/default/".into(), auth: None };
+        assert_eq!(client.resolve("/addressbooks/me/default/a.vcf"), "https://dav.example.com/addressbooks/me/default/a.vcf");
+        assert_eq!(client.resolve("a.vcf"), "https://dav.example.com/addressbooks/me/default/a.vcf");
+        assert_eq!(client.new_href("corky-alice smith"), "/addressbooks/me/default/corky-alice-smith.vcf");
+    }
+
+    #[test]
+    fn test_auth_header() {
+        let config = CardDavConfig { user: "me".into(), password: "secret".into(), ..Default::default() };
+        assert_eq!(auth_header(&config).unwrap().as_deref(), Some("Basic bWU6c2VjcmV0"));
+        assert_eq!(auth_header(&CardDavConfig::default()).unwrap(), None);
+    }
+}
@@ -0,0 +1,406 @@
+//! `corky contact discover` — rank frequent correspondents who aren't contacts yet.
+//!
+//! Scans root and mailbox conversations, tallies every non-owner address
+//! seen in From/To/CC, and scores each one:
+//!
+//! ```text
+//! score = (messages from + messages to) × (1 + reply ratio) × 0.5^(days since last seen / 90)
+//! ```
+//!
+//! where the reply ratio is the share of their threads the owner wrote in.
+//! Accepted candidates are created like `contact add --from`, with an
+//! enriched AGENTS.md (recent subjects, co-participants, email domain).
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use super::add;
+use super::from_conversation::{extract_display_name, load_owner_emails};
+use crate::config::contact::{load_contacts, Contact};
+use crate::output::{emit, OutputFormat, Report};
+use crate::resolve;
+use crate::sync::imap_sync::parse_msg_date;
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::types::Thread;
+use crate::util::slugify;
+
+static BRACKETED_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([^<>\s]+@[^<>\s]+)>").unwrap());
+static BARE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+").unwrap());
+
+/// Local parts of automated senders, never proposed.
+const AUTOMATED: &[&str] = &[
+    "noreply",
+    "no-reply",
+    "donotreply",
+    "do-not-reply",
+    "mailer-daemon",
+    "postmaster",
+    "notifications",
+    "notification",
+    "bounce",
+];
+
+/// Half-life of the recency weight, in days.
+const RECENCY_HALF_LIFE_DAYS: f64 = 90.0;
+
+/// A correspondent not yet in [contacts].
+#[derive(Debug, Clone, Serialize)]
+pub struct Candidate {
+    /// 1-based position in the ranking (use with `--create`)
+    pub rank: usize,
+    pub suggested_name: String,
+    pub display_name: String,
+    pub emails: Vec<String>,
+    /// Messages they sent
+    pub messages_from: usize,
+    /// Owner messages addressed to them (To/CC)
+    pub messages_to: usize,
+    pub threads: usize,
+    /// Share of their threads the owner wrote in (0–1)
+    pub reply_ratio: f64,
+    /// RFC 2822 date of the newest message they were on
+    pub last_seen: Option<String>,
+    pub score: f64,
+    /// Recent thread subjects, newest first (AGENTS.md topics)
+    #[serde(skip)]
+    pub topics: Vec<String>,
+    /// Other non-owner participants on their threads, most shared first
+    #[serde(skip)]
+    pub co_participants: Vec<String>,
+}
+
+/// Result of `corky contact discover` (JSON: array of [`Candidate`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct DiscoverReport {
+    pub candidates: Vec<Candidate>,
+}
+
+impl Report for DiscoverReport {
+    fn render_table(&self) -> String {
+        if self.candidates.is_empty() {
+            return "No new correspondents found.\n".to_string();
+        }
+        let mut out = String::new();
+        for c in &self.candidates {
+            let last = c
+                .last_seen
+                .as_deref()
+                .map(|d| parse_msg_date(d).format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            out.push_str(&format!(
+                "{:>3}. {}  {} <{}>\n     {} message(s) ({} from, {} to), {} thread(s), {:.0}% replied, last {}, score {:.2}\n",
+                c.rank,
+                c.suggested_name,
+                c.display_name,
+                c.emails.join(">, <"),
+                c.messages_from + c.messages_to,
+                c.messages_from,
+                c.messages_to,
+                c.threads,
+                c.reply_ratio * 100.0,
+                last,
+                c.score
+            ));
+        }
+        out.push_str("\nCreate with: corky contact discover --create RANK|NAME|EMAIL[=NEW-NAME] ... (or --all)\n");
+        out
+    }
+}
+
+/// Addresses in a header field as (display name, lowercased email).
+pub(crate) fn addresses(field: &str) -> Vec<(String, String)> {
+    let mut found: Vec<(String, String)> = BRACKETED_RE
+        .captures_iter(field)
+        .map(|c| (extract_display_name(field, &c[1]), c[1].to_lowercase()))
+        .collect();
+    let rest = BRACKETED_RE.replace_all(field, "");
+    for m in BARE_RE.find_iter(&rest) {
+        let email = m.as_str().to_lowercase();
+        if !found.iter().any(|(_, e)| *e == email) {
+            found.push((extract_display_name(&rest, m.as_str()), email));
+        }
+    }
+    found
+}
+
+fn is_automated(email: &str) -> bool {
+    let local = email.split('@').next().unwrap_or(email);
+    AUTOMATED.iter().any(|a| local == *a || local.starts_with(&format!("{}+", a)) || local.starts_with(&format!("{}-", a)))
+}
+
+#[derive(Default)]
+struct Tally {
+    display_name: String,
+    from: usize,
+    to: usize,
+    threads: BTreeSet<String>,
+    replied: BTreeSet<String>,
+    last: Option<(DateTime<Utc>, String)>,
+    subjects: Vec<(DateTime<Utc>, String)>,
+    co: BTreeMap<String, usize>,
+}
+
+impl Tally {
+    fn seen(&mut self, display_name: &str, email: &str, date: DateTime<Utc>, raw_date: &str) {
+        let fallback = email.split('@').next().unwrap_or(email);
+        if self.display_name.is_empty() || (self.display_name == fallback && display_name != fallback) {
+            self.display_name = display_name.to_string();
+        }
+        if self.last.as_ref().is_none_or(|(d, _)| date > *d) {
+            self.last = Some((date, raw_date.to_string()));
+        }
+    }
+}
+
+/// Rank correspondents in `threads` who aren't owners or known contacts.
+///
+/// Threads are deduplicated by ID (routed threads also live in mailboxes).
+pub fn rank(
+    threads: &[Thread],
+    owner_emails: &[String],
+    contacts: &BTreeMap<String, Contact>,
+    min_messages: usize,
+    now: DateTime<Utc>,
+) -> Vec<Candidate> {
+    let is_owner = |email: &str| owner_emails.iter().any(|o| o.eq_ignore_ascii_case(email));
+    let mut tallies: BTreeMap<String, Tally> = BTreeMap::new();
+    let mut seen_threads = HashSet::new();
+
+    for thread in threads {
+        if !seen_threads.insert(thread.id.clone()) {
+            continue;
+        }
+        let owner_wrote = thread.messages.iter().any(|m| addresses(&m.from).iter().any(|(_, e)| is_owner(e)));
+        let mut participants: BTreeSet<String> = BTreeSet::new();
+        let mut thread_last = DateTime::<Utc>::default();
+
+        for msg in &thread.messages {
+            let date = parse_msg_date(&msg.date);
+            thread_last = thread_last.max(date);
+            let senders = addresses(&msg.from);
+            let from_owner = senders.iter().any(|(_, e)| is_owner(e));
+            for (display, email) in senders {
+                if is_owner(&email) {
+                    continue;
+                }
+                let tally = tallies.entry(email.clone()).or_default();
+                tally.from += 1;
+                tally.seen(&display, &email, date, &msg.date);
+                participants.insert(email);
+            }
+            let mut recipients = addresses(&msg.to);
+            recipients.extend(addresses(&msg.cc));
+            for (display, email) in recipients {
+                if is_owner(&email) {
+                    continue;
+                }
+                let tally = tallies.entry(email.clone()).or_default();
+                if from_owner {
+                    tally.to += 1;
+                }
+                tally.seen(&display, &email, date, &msg.date);
+                participants.insert(email);
+            }
+        }
+
+        for email in &participants {
+            let tally = tallies.get_mut(email).expect("tallied participant");
+            tally.threads.insert(thread.id.clone());
+            if owner_wrote {
+                tally.replied.insert(thread.id.clone());
+            }
+            tally.subjects.push((thread_last, thread.subject.clone()));
+            for other in participants.iter().filter(|o| *o != email) {
+                *tally.co.entry(other.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    let known_emails: HashSet<String> =
+        contacts.values().flat_map(|c| c.emails.iter().map(|e| e.to_lowercase())).collect();
+    let known_names: HashSet<String> = contacts
+        .iter()
+        .flat_map(|(name, c)| std::iter::once(name.clone()).chain(c.aliases.iter().map(|a| slugify(a))))
+        .collect();
+    let display_names: BTreeMap<String, String> =
+        tallies.iter().map(|(email, t)| (email.clone(), t.display_name.clone())).collect();
+
+    let mut candidates: Vec<Candidate> = tallies
+        .into_iter()
+        .filter(|(email, t)| {
+            t.from + t.to >= min_messages
+                && !is_automated(email)
+                && !known_emails.contains(email)
+                && !known_names.contains(&slugify(&t.display_name))
+        })
+        .map(|(email, mut t)| {
+            let reply_ratio = if t.threads.is_empty() {
+                0.0
+            } else {
+                t.replied.len() as f64 / t.threads.len() as f64
+            };
+            let days = t.last.as_ref().map(|(d, _)| (now - *d).num_days().max(0) as f64).unwrap_or(f64::MAX);
+            let score = (t.from + t.to) as f64 * (1.0 + reply_ratio) * 0.5f64.powf(days / RECENCY_HALF_LIFE_DAYS);
+
+            t.subjects.sort_by_key(|s| std::cmp::Reverse(s.0));
+            let mut topics: Vec<String> = Vec::new();
+            for (_, subject) in t.subjects {
+                if !subject.is_empty() && !topics.contains(&subject) && topics.len() < 5 {
+                    topics.push(subject);
+                }
+            }
+            let mut co: Vec<(String, usize)> = t.co.into_iter().collect();
+            co.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let co_participants = co
+                .into_iter()
+                .filter(|(other, _)| !is_automated(other))
+                .take(5)
+                .map(|(other, _)| slugify(&display_names[&other]))
+                .collect();
+
+            Candidate {
+                rank: 0,
+                suggested_name: slugify(&t.display_name),
+                display_name: t.display_name,
+                emails: vec![email],
+                messages_from: t.from,
+                messages_to: t.to,
+                threads: t.threads.len(),
+                reply_ratio: (reply_ratio * 100.0).round() / 100.0,
+                last_seen: t.last.map(|(_, raw)| raw),
+                score: (score * 100.0).round() / 100.0,
+                topics,
+                co_participants,
+            }
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| (b.messages_from + b.messages_to).cmp(&(a.messages_from + a.messages_to)))
+            .then_with(|| a.suggested_name.cmp(&b.suggested_name))
+    });
+
+    // Suggested names must not collide with contacts or each other
+    let mut taken: HashSet<String> = contacts.keys().cloned().collect();
+    for (i, c) in candidates.iter_mut().enumerate() {
+        c.rank = i + 1;
+        let base = c.suggested_name.clone();
+        let mut n = 2;
+        while taken.contains(&c.suggested_name) || resolve::contacts_dir().join(&c.suggested_name).exists() {
+            c.suggested_name = format!("{}-{}", base, n);
+            n += 1;
+        }
+        taken.insert(c.suggested_name.clone());
+    }
+    candidates
+}
+
+/// Every conversation in the root and in mailboxes/*/conversations/.
+fn load_threads() -> Vec<Thread> {
+    let mut dirs = vec![resolve::conversations_dir()];
+    if let Ok(entries) = std::fs::read_dir(resolve::mailboxes_base_dir()) {
+        let mut mailboxes: Vec<_> = entries.flatten().map(|e| e.path().join("conversations")).collect();
+        mailboxes.sort();
+        dirs.extend(mailboxes);
+    }
+    let mut threads = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|x| x == "md"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            if let Some(thread) = std::fs::read_to_string(&path).ok().and_then(|t| parse_thread_markdown(&t)) {
+                threads.push(thread);
+            }
+        }
+    }
+    threads
+}
+
+/// Resolve a `--create` selector (rank, suggested name or email, optionally
+/// `=NEW-NAME`) to a candidate and the contact name to create.
+fn select<'a>(candidates: &'a [Candidate], selector: &str) -> Result<(&'a Candidate, String)> {
+    let (key, rename) = match selector.split_once('=') {
+        Some((key, name)) => (key.trim(), Some(name.trim())),
+        None => (selector.trim(), None),
+    };
+    let found = candidates.iter().find(|c| {
+        key.parse::<usize>().is_ok_and(|r| r == c.rank)
+            || c.suggested_name == key
+            || c.emails.iter().any(|e| e.eq_ignore_ascii_case(key))
+    });
+    match found {
+        Some(c) => Ok((c, rename.map(str::to_string).unwrap_or_else(|| c.suggested_name.clone()))),
+        None => anyhow::bail!("No discovered correspondent matches '{}'", key),
+    }
+}
+
+/// corky contact discover [--limit N] [--min-messages N] [--create SEL]... [--all]
+pub fn run(limit: usize, min_messages: usize, create: &[String], all: bool, format: OutputFormat) -> Result<()> {
+    let contacts = load_contacts(None)?;
+    let candidates = rank(&load_threads(), &load_owner_emails(), &contacts, min_messages, Utc::now());
+    let shown = &candidates[..candidates.len().min(limit)];
+
+    if create.is_empty() && !all {
+        return emit(format, &DiscoverReport { candidates: shown.to_vec() });
+    }
+
+    let mut selected: Vec<(&Candidate, String)> = Vec::new();
+    if all {
+        selected.extend(shown.iter().map(|c| (c, c.suggested_name.clone())));
+    }
+    for selector in create {
+        let (candidate, name) = select(&candidates, selector)?;
+        selected.retain(|(c, _)| c.rank != candidate.rank);
+        selected.push((candidate, name));
+    }
+
+    let mut created = 0;
+    for (candidate, name) in selected {
+        let domain = candidate.emails.first().and_then(|e| e.split('@').nth(1));
+        let agents_md = add::enriched_agents_md(&name, &candidate.topics, &candidate.co_participants, domain);
+        match add::run_with_agents_md(&name, &candidate.emails, &agents_md) {
+            Ok(()) => created += 1,
+            Err(e) => eprintln!("Skipped {}: {}", name, e),
+        }
+    }
+    println!("Created {} contact(s).", created);
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_addresses_bracketed_and_bare() {
+        let found = addresses("Alice Smith <Alice@Example.com>, bob@example.com");
+        assert_eq!(found[0], ("Alice Smith".to_string(), "alice@example.com".to_string()));
+        assert_eq!(found[1], ("bob".to_string(), "bob@example.com".to_string()));
+    }
+
+    #[test]
+    fn test_is_automated() {
+        assert!(is_automated("noreply@github.com"));
+        assert!(is_automated("notifications+abc@github.com"));
+        assert!(!is_automated("alice@example.com"));
+        assert!(!is_automated("noreplyhero@example.com"));
+    }
+}
@@ -90,8 +90,12 @@ pub fn run(slug: &str, name: Option<&str>) -> Result<()> {
     add::run_with_agents_md(&contact_name, &emails, &agents_md)
 }
 
-/// Find a conversation file by slug, searching root then mailboxes.
-fn find_conversation(slug: &str) -> Result<std::path::PathBuf> {
+/// Find a conversation file by slug (or path), searching root then mailboxes.
+pub(crate) fn find_conversation(slug: &str) -> Result<std::path::PathBuf> {
+    if slug.ends_with(".md") && std::path::Path::new(slug).is_file() {
+        return Ok(std::path::PathBuf::from(slug));
+    }
+    let slug = slug.strip_suffix(".md").unwrap_or(slug);
     let data_dir = resolve::data_dir();
     let filename = format!("{}.md", slug);
 
@@ -123,7 +127,7 @@ fn find_conversation(slug: &str) -> Result<std::path::PathBuf> {
 }
 
 /// Load owner email addresses from .corky.toml accounts.
-fn load_owner_emails() -> Vec<String> {
+pub(crate) fn load_owner_emails() -> Vec<String> {
     let config = match corky_config::try_load_config(None) {
         Some(c) => c,
         None => return Vec::new(),
@@ -189,7 +193,7 @@ fn extract_from_field(
 }
 
 /// Extract display name from a "Name <email>" string.
-fn extract_display_name(field: &str, email: &str) -> String {
+pub(crate) fn extract_display_name(field: &str, email: &str) -> String {
     // Try to find "Name <email>" pattern near this email
     let pattern = format!("<{}>", email);
     if let Some(pos) = field.find(&pattern) {
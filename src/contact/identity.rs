@@ -0,0 +1,274 @@
+//! Contact identifiers beyond email: phone numbers and chat handles.
+//!
+//! Importers identify people differently — SMS by phone number
+//! (`sms:{phone}` thread ids), Signal by number or service id, Telegram,
+//! WhatsApp, Discord and Slack by display name and chat id. [`ContactIndex`]
+//! maps all of these back to `[contacts.*]` entries via `emails`, `phones`
+//! and `handles.{platform}`, for the manifest and contact sync eligibility.
+
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+use super::discover::addresses;
+use crate::config::contact::Contact;
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::types::Thread;
+use crate::util::slugify;
+
+static ANGLE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// Fewer digits than this is a short code or extension, never matched by suffix.
+const MIN_PHONE_DIGITS: usize = 7;
+
+/// Thread id prefix → `handles` key, for the chat importers.
+const PLATFORMS: &[(&str, &str)] = &[
+    ("tg", "telegram"),
+    ("wa", "whatsapp"),
+    ("signal", "signal"),
+    ("discord", "discord"),
+    ("slack", "slack"),
+];
+
+/// Digits (and a leading +) only, for comparing phone numbers.
+pub fn normalize_phone(raw: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in raw.trim().chars().enumerate() {
+        if c.is_ascii_digit() || (i == 0 && c == '+') {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Whether two phone numbers are the same line: equal once normalized, or
+/// one is a national number (no `+`) ending the other ("555 0100" vs "+1 555 0100").
+pub fn phones_match(a: &str, b: &str) -> bool {
+    let (a, b) = (normalize_phone(a), normalize_phone(b));
+    let (da, db) = (a.trim_start_matches('+'), b.trim_start_matches('+'));
+    if da.len() < MIN_PHONE_DIGITS || db.len() < MIN_PHONE_DIGITS {
+        return false;
+    }
+    if a == b {
+        return true;
+    }
+    if a.starts_with('+') && b.starts_with('+') {
+        return false;
+    }
+    let (short, long) = if da.len() <= db.len() { (da, db) } else { (db, da) };
+    long.ends_with(short)
+}
+
+/// `s` if it reads as a phone number rather than a name.
+fn as_phone(s: &str) -> Option<&str> {
+    let s = s.trim();
+    let phone_chars = s.chars().all(|c| c.is_ascii_digit() || " +-().".contains(c));
+    let digits = s.chars().filter(char::is_ascii_digit).count();
+    (phone_chars && digits >= MIN_PHONE_DIGITS).then_some(s)
+}
+
+/// Handles compare case-insensitively, with or without a leading `@`.
+fn handle_key(handle: &str) -> String {
+    handle.trim().trim_start_matches('@').to_lowercase()
+}
+
+/// The `handles` key for a chat thread id (`tg:123` → `telegram`) and the rest of the id.
+fn platform_of(thread_id: &str) -> Option<(&'static str, &str)> {
+    let (prefix, rest) = thread_id.split_once(':')?;
+    PLATFORMS.iter().find(|(p, _)| *p == prefix).map(|(_, platform)| (*platform, rest))
+}
+
+/// Lookup from emails, phone numbers and chat handles to contact names.
+#[derive(Debug, Default)]
+pub struct ContactIndex {
+    emails: HashMap<String, String>,
+    phones: Vec<(String, String)>,
+    handles: HashMap<(String, String), String>,
+}
+
+impl ContactIndex {
+    pub fn new(contacts: &BTreeMap<String, Contact>) -> Self {
+        let mut index = ContactIndex::default();
+        for (name, contact) in contacts {
+            for email in &contact.emails {
+                index.emails.insert(email.to_lowercase(), name.clone());
+            }
+            for phone in &contact.phones {
+                index.phones.push((phone.clone(), name.clone()));
+            }
+            for (platform, handle) in &contact.handles {
+                index.handles.insert((platform.to_lowercase(), handle_key(handle)), name.clone());
+            }
+        }
+        index
+    }
+
+    pub fn by_email(&self, email: &str) -> Option<&str> {
+        self.emails.get(&email.trim().to_lowercase()).map(String::as_str)
+    }
+
+    pub fn by_phone(&self, phone: &str) -> Option<&str> {
+        self.phones.iter().find(|(p, _)| phones_match(p, phone)).map(|(_, name)| name.as_str())
+    }
+
+    /// A handle on `platform`, also matching chat ids slugified from it (`wa:alice-smith`).
+    pub fn by_handle(&self, platform: &str, handle: &str) -> Option<&str> {
+        let key = handle_key(handle);
+        if let Some(name) = self.handles.get(&(platform.to_string(), key.clone())) {
+            return Some(name.as_str());
+        }
+        self.handles
+            .iter()
+            .find(|((p, h), _)| p == platform && slugify(h) == key)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Contacts in `thread`, in order of appearance: the thread id (phone or
+    /// chat id), then each message's From/To/CC by email, phone number, or
+    /// handle on the thread's chat platform.
+    pub fn thread_contacts(&self, thread: &Thread) -> Vec<String> {
+        let mut found: Vec<String> = Vec::new();
+        let mut add = |name: Option<&str>| {
+            if let Some(name) = name {
+                if !found.iter().any(|f| f == name) {
+                    found.push(name.to_string());
+                }
+            }
+        };
+
+        let platform = platform_of(&thread.id);
+        match (thread.id.strip_prefix("sms:"), platform) {
+            (Some(phone), _) => add(self.by_phone(phone)),
+            (None, Some((platform, rest))) => {
+                let rest = rest.strip_prefix("group:").unwrap_or(rest);
+                add(as_phone(rest).and_then(|p| self.by_phone(p)).or_else(|| self.by_handle(platform, rest)));
+            }
+            _ => {}
+        }
+
+        for msg in &thread.messages {
+            for field in [&msg.from, &msg.to, &msg.cc] {
+                for (_, email) in addresses(field) {
+                    add(self.by_email(&email));
+                }
+                for part in ANGLE_RE.replace_all(field, "").split(',') {
+                    if !addresses(part).is_empty() {
+                        continue;
+                    }
+                    if let Some(phone) = as_phone(part) {
+                        add(self.by_phone(phone));
+                    } else if let Some((platform, _)) = platform {
+                        let part = part.trim();
+                        if !part.is_empty() {
+                            add(self.by_handle(platform, part));
+                        }
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Every contact appearing in the conversations under `conversations_dir`.
+pub fn conversation_contacts(conversations_dir: &Path, index: &ContactIndex) -> Result<BTreeSet<String>> {
+    let mut found = BTreeSet::new();
+    if !conversations_dir.is_dir() {
+        return Ok(found);
+    }
+    for entry in std::fs::read_dir(conversations_dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+        let text = std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        if let Some(thread) = parse_thread_markdown(&text) {
+            found.extend(index.thread_contacts(&thread));
+        }
+    }
+    Ok(found)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::types::Message;
+
+    fn contacts() -> BTreeMap<String, Contact> {
+        let mut contacts = BTreeMap::new();
+        contacts.insert(
+            "alice".to_string(),
+            Contact {
+                emails: vec!["alice@example.com".into()],
+                phones: vec!["(555) 010-0100".into()],
+                handles: BTreeMap::from([("telegram".into(), "@AliceS".into()), ("whatsapp".into(), "Alice Smith".into())]),
+                ..Default::default()
+            },
+        );
+        contacts.insert(
+            "bob".to_string(),
+            Contact { phones: vec!["+44 7911 123456".into()], ..Default::default() },
+        );
+        contacts
+    }
+
+    fn thread(id: &str, messages: &[(&str, &str)]) -> Thread {
+        Thread {
+            id: id.to_string(),
+            subject: "Chat".to_string(),
+            labels: vec![],
+            accounts: vec![],
+            last_date: String::new(),
+            messages: messages
+                .iter()
+                .map(|(from, to)| Message {
+                    id: String::new(),
+                    thread_id: id.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    cc: String::new(),
+                    date: String::new(),
+                    subject: "Chat".to_string(),
+                    body: String::new(),
+                    raw: String::new(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_phones_match() {
+        assert!(phones_match("+1 (555) 010-0100", "+15550100100"));
+        assert!(phones_match("555-010-0100", "+1 555 010 0100"));
+        assert!(!phones_match("+44 5550100100", "+1 5550100100"));
+        assert!(!phones_match("0100", "+1 555 010 0100"));
+        assert_eq!(normalize_phone(" +1 (555) 123-4567"), "+15551234567");
+    }
+
+    #[test]
+    fn test_thread_contacts_by_importer() {
+        let index = ContactIndex::new(&contacts());
+        let sms = thread("sms:+15550100100", &[("Me", "+1 555-010-0100"), ("Ally", "")]);
+        assert_eq!(index.thread_contacts(&sms), vec!["alice"]);
+        let signal = thread("signal:group:g1", &[("+447911123456", ""), ("Me", "")]);
+        assert_eq!(index.thread_contacts(&signal), vec!["bob"]);
+        let telegram = thread("tg:42", &[("alices", ""), ("Bob", "")]);
+        assert_eq!(index.thread_contacts(&telegram), vec!["alice"]);
+        let whatsapp = thread("wa:alice-smith", &[("Me", "")]);
+        assert_eq!(index.thread_contacts(&whatsapp), vec!["alice"]);
+        let email = thread("abc", &[("Alice <ALICE@example.com>", "me@example.com"), ("AliceS", "")]);
+        assert_eq!(index.thread_contacts(&email), vec!["alice"]);
+        // Bare addresses, as well as Name <addr>
+        let bare = thread("def", &[("Me <me@example.com>", "carol@example.com, ALICE@example.com")]);
+        assert_eq!(index.thread_contacts(&bare), vec!["alice"]);
+        // Handles only count on their own platform
+        assert!(index.thread_contacts(&thread("discord:1", &[("AliceS", "")])).is_empty());
+    }
+}
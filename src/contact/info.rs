@@ -1,46 +1,355 @@
-//! Display aggregated contact info: config, AGENTS.md, matching threads.
+//! Display aggregated contact info: config, AGENTS.md, matching threads,
+//! and relationship stats computed from those threads' conversation files.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 
-use crate::config::contact;
+use super::discover::addresses;
+use super::from_conversation::load_owner_emails;
+use crate::config::contact::Contact;
+use crate::config::{contact, topic};
+use crate::output::{emit, OutputFormat, Report};
 use crate::resolve;
+use crate::social::profiles::ProfilesFile;
+use crate::sync::imap_sync::parse_msg_date;
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::types::Thread;
 
-/// Show contact info and thread history.
+/// Months shown in the table timeline (JSON has all of them).
+const TIMELINE_MONTHS: usize = 12;
+
+/// A thread mentioning the contact, from a manifest.toml.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactThread {
+    /// "root" or the mailbox name
+    pub scope: String,
+    pub slug: String,
+    pub subject: String,
+    /// RFC 2822 `last_updated` from the manifest
+    pub last_updated: String,
+}
+
+/// Result of `corky contact info` (JSON: object).
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactInfo {
+    pub name: String,
+    pub emails: Vec<String>,
+    pub details: ContactDetails,
+    /// Contents of contacts/{name}/AGENTS.md, if present
+    pub agents_md: Option<String>,
+    /// Matching threads, newest first
+    pub threads: Vec<ContactThread>,
+    pub last_activity: Option<String>,
+    pub stats: RelationshipStats,
+}
+
+/// Typed fields from `[contacts.{name}]`, resolved for display.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ContactDetails {
+    pub full_name: String,
+    pub organization: String,
+    pub title: String,
+    pub phones: Vec<String>,
+    pub timezone: String,
+    /// Their current local time ("Sat 14:05"), when `timezone` is known
+    pub local_time: Option<String>,
+    pub language: String,
+    pub profile: String,
+    /// Platform → handle: the linked profile's platforms, then `handles`
+    pub handles: BTreeMap<String, String>,
+    /// Soonest first
+    pub dates: Vec<UpcomingDate>,
+    /// Invalid timezone or dates, unknown profile
+    pub warnings: Vec<String>,
+}
+
+/// An important date and its next occurrence.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct UpcomingDate {
+    pub label: String,
+    /// As configured: "YYYY-MM-DD" or "MM-DD"
+    pub date: String,
+    /// "YYYY-MM-DD"
+    pub next: String,
+    pub days_until: i64,
+}
+
+/// Messages exchanged in one calendar month.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct MonthActivity {
+    /// "YYYY-MM" (UTC)
+    pub month: String,
+    /// Owner messages addressed to the contact
+    pub sent: usize,
+    /// Messages from the contact
+    pub received: usize,
+}
+
+/// A thread whose last message is from the contact.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OwedReply {
+    pub scope: String,
+    pub slug: String,
+    pub subject: String,
+    /// RFC 2822 date of their last message
+    pub since: String,
+    pub days_waiting: i64,
+}
+
+/// A `[topics.*]` entry that names the contact or matches their threads.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TopicMatch {
+    pub name: String,
+    /// Listed in the topic's `contacts`
+    pub linked: bool,
+    /// Threads whose subject contains one of the topic's keywords
+    pub threads: usize,
+}
+
+/// Relationship analytics over the contact's threads.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct RelationshipStats {
+    /// RFC 2822 date of the earliest message exchanged
+    pub first_contact: Option<String>,
+    pub messages_sent: usize,
+    pub messages_received: usize,
+    /// Oldest month first; months without messages are omitted
+    pub timeline: Vec<MonthActivity>,
+    /// Median hours from their message to the owner's reply
+    pub my_median_reply_hours: Option<f64>,
+    /// Median hours from the owner's message to their reply
+    pub their_median_reply_hours: Option<f64>,
+    /// Threads awaiting the owner's reply, longest waiting first
+    pub owed_replies: Vec<OwedReply>,
+    pub topics: Vec<TopicMatch>,
+}
+
+impl Report for ContactInfo {
+    fn render_table(&self) -> String {
+        let mut out = format!("Contact: {}\n\n", self.name);
+        if self.emails.is_empty() {
+            out.push_str("  Emails: (none)\n");
+        } else {
+            out.push_str(&format!("  Emails: {}\n", self.emails.join(", ")));
+        }
+        out.push_str(&self.details.render());
+        out.push('\n');
+
+        if let Some(ref content) = self.agents_md {
+            out.push_str("--- AGENTS.md ---\n");
+            out.push_str(&format!("{}\n\n", content.trim()));
+        }
+
+        if self.threads.is_empty() {
+            out.push_str("--- Threads ---\n");
+            out.push_str("  No matching threads found in manifest.\n");
+        } else {
+            out.push_str(&format!("--- Threads ({}) ---\n", self.threads.len()));
+            for t in &self.threads {
+                // Truncate date to just the date portion for display
+                let short_date = t.last_updated.split(' ').take(4).collect::<Vec<_>>().join(" ");
+                let scope_prefix = if t.scope == "root" {
+                    String::new()
+                } else {
+                    format!("[{}] ", t.scope)
+                };
+                out.push_str(&format!(
+                    "  {}  {:<30} {}{}\n",
+                    short_date, t.slug, scope_prefix, t.subject
+                ));
+            }
+        }
+        out.push('\n');
+
+        if let Some(ref last) = self.last_activity {
+            out.push_str(&format!("Last activity: {}\n", last));
+        }
+        out.push_str(&self.stats.render());
+        out
+    }
+}
+
+impl ContactDetails {
+    fn render(&self) -> String {
+        let mut out = String::new();
+        let mut line = |label: &str, value: String| {
+            if !value.is_empty() {
+                out.push_str(&format!("  {}: {}\n", label, value));
+            }
+        };
+        line("Name", self.full_name.clone());
+        let org = match (self.organization.is_empty(), self.title.is_empty()) {
+            (false, false) => format!("{}, {}", self.title, self.organization),
+            _ => format!("{}{}", self.title, self.organization),
+        };
+        line("Organization", org);
+        line("Phones", self.phones.join(", "));
+        let timezone = match &self.local_time {
+            Some(now) => format!("{} (now {})", self.timezone, now),
+            None => self.timezone.clone(),
+        };
+        line("Timezone", timezone);
+        line("Language", self.language.clone());
+        let handles: Vec<String> = self.handles.iter().map(|(p, h)| format!("{} {}", p, h)).collect();
+        line("Handles", handles.join(", "));
+        let dates: Vec<String> = self
+            .dates
+            .iter()
+            .map(|d| match d.days_until {
+                0 => format!("{} {} (today)", d.label, d.date),
+                n => format!("{} {} (in {} days)", d.label, d.date, n),
+            })
+            .collect();
+        line("Dates", dates.join(", "));
+        for warning in &self.warnings {
+            out.push_str(&format!("  Warning: {}\n", warning));
+        }
+        out
+    }
+}
+
+/// Resolve a contact's typed fields: local time, profile handles, next dates.
+pub fn details(contact: &Contact, profiles: &ProfilesFile, now: DateTime<Utc>) -> ContactDetails {
+    let mut warnings = contact.field_errors();
+    let mut handles = BTreeMap::new();
+    if !contact.profile.is_empty() {
+        match profiles.profiles.get(&contact.profile) {
+            Some(profile) => {
+                for (platform, entry) in profile.platform_entries() {
+                    handles.insert(platform.as_str().to_string(), entry.handle.clone());
+                }
+            }
+            None => warnings.push(format!("profile '{}' not found in [profiles]", contact.profile)),
+        }
+    }
+    handles.extend(contact.handles.clone());
+
+    let local_time = contact.tz().map(|tz| now.with_timezone(&tz).format("%a %H:%M").to_string());
+    let today = contact.tz().map_or(now.date_naive(), |tz| now.with_timezone(&tz).date_naive());
+    let mut dates: Vec<UpcomingDate> = contact
+        .dates
+        .iter()
+        .filter_map(|(label, date)| {
+            let next = contact::next_occurrence(date, today)?;
+            Some(UpcomingDate {
+                label: label.clone(),
+                date: date.clone(),
+                next: next.format("%Y-%m-%d").to_string(),
+                days_until: (next - today).num_days(),
+            })
+        })
+        .collect();
+    dates.sort_by(|a, b| a.days_until.cmp(&b.days_until).then(a.label.cmp(&b.label)));
+
+    ContactDetails {
+        full_name: contact.full_name.clone(),
+        organization: contact.organization.clone(),
+        title: contact.title.clone(),
+        phones: contact.phones.clone(),
+        timezone: contact.timezone.clone(),
+        local_time,
+        language: contact.language.clone(),
+        profile: contact.profile.clone(),
+        handles,
+        dates,
+        warnings,
+    }
+}
+
+/// Hours as "45m", "5.5h" or "3.2d".
+fn format_hours(hours: Option<f64>) -> String {
+    match hours {
+        None => "-".to_string(),
+        Some(h) if h < 1.0 => format!("{:.0}m", h * 60.0),
+        Some(h) if h < 48.0 => format!("{:.1}h", h),
+        Some(h) => format!("{:.1}d", h / 24.0),
+    }
+}
+
+impl RelationshipStats {
+    fn render(&self) -> String {
+        let mut out = String::new();
+        if let Some(ref first) = self.first_contact {
+            out.push_str(&format!("First contact: {}\n", first));
+        }
+        out.push_str(&format!(
+            "Messages: {} sent, {} received\n",
+            self.messages_sent, self.messages_received
+        ));
+        out.push_str(&format!(
+            "Median reply time: you {}, them {}\n",
+            format_hours(self.my_median_reply_hours),
+            format_hours(self.their_median_reply_hours)
+        ));
+
+        if !self.timeline.is_empty() {
+            out.push_str("\n--- Timeline (sent / received) ---\n");
+            let skipped = self.timeline.len().saturating_sub(TIMELINE_MONTHS);
+            if skipped > 0 {
+                out.push_str(&format!("  ({} earlier month(s) in --format json)\n", skipped));
+            }
+            for m in &self.timeline[skipped..] {
+                out.push_str(&format!("  {}  {:>3} / {:<3}\n", m.month, m.sent, m.received));
+            }
+        }
+
+        if !self.owed_replies.is_empty() {
+            out.push_str(&format!("\n--- Awaiting your reply ({}) ---\n", self.owed_replies.len()));
+            for o in &self.owed_replies {
+                let scope_prefix = if o.scope == "root" {
+                    String::new()
+                } else {
+                    format!("[{}] ", o.scope)
+                };
+                out.push_str(&format!(
+                    "  {:>3}d  {:<30} {}{}\n",
+                    o.days_waiting, o.slug, scope_prefix, o.subject
+                ));
+            }
+        }
+
+        if !self.topics.is_empty() {
+            let topics: Vec<String> = self
+                .topics
+                .iter()
+                .map(|t| match (t.linked, t.threads) {
+                    (true, 0) => t.name.clone(),
+                    _ => format!("{} ({} thread(s))", t.name, t.threads),
+                })
+                .collect();
+            out.push_str(&format!("\nTopics: {}\n", topics.join(", ")));
+        }
+        out
+    }
+}
+
+/// Gather contact info and thread history.
 ///
 /// Algorithm:
 /// 1. Load contact from config — bail if not found.
-/// 2. Print config section (emails).
-/// 3. Print AGENTS.md if it exists.
-/// 4. Scan manifest.toml files for matching threads.
-/// 5. Print thread list sorted by last_updated descending.
-/// 6. Print summary (thread count, last activity).
-pub fn run(name: &str) -> Result<()> {
+/// 2. Read AGENTS.md if it exists.
+/// 3. Scan manifest.toml files for matching threads.
+/// 4. Sort threads by last_updated descending.
+/// 5. Compute relationship stats from the threads' conversation files,
+///    and resolve the typed fields (local time, profile handles, dates).
+pub fn collect(name: &str) -> Result<ContactInfo> {
     // 1. Load contact
     let contacts = contact::load_contacts(None)?;
     let contact = contacts
         .get(name)
         .ok_or_else(|| anyhow::anyhow!("Contact '{}' not found in .corky.toml", name))?;
 
-    // 2. Print config
-    println!("Contact: {}", name);
-    println!();
-    if contact.emails.is_empty() {
-        println!("  Emails: (none)");
-    } else {
-        println!("  Emails: {}", contact.emails.join(", "));
-    }
-    println!();
-
-    // 3. Print AGENTS.md
+    // 2. AGENTS.md
     let agents_path = resolve::contacts_dir().join(name).join("AGENTS.md");
-    if agents_path.exists() {
-        let content = std::fs::read_to_string(&agents_path)?;
-        println!("--- AGENTS.md ---");
-        println!("{}", content.trim());
-        println!();
-    }
+    let agents_md = if agents_path.exists() {
+        Some(std::fs::read_to_string(&agents_path)?)
+    } else {
+        None
+    };
 
-    // 4. Scan manifests for matching threads
+    // 3. Scan manifests for matching threads
     let data_dir = resolve::data_dir();
     let mut all_threads: Vec<(String, String, String, String)> = Vec::new(); // (scope, date, slug, subject)
 
@@ -66,37 +375,172 @@ pub fn run(name: &str) -> Result<()> {
         }
     }
 
-    // 5. Print thread list
-    if all_threads.is_empty() {
-        println!("--- Threads ---");
-        println!("  No matching threads found in manifest.");
+    // 4. Sort by date descending
+    all_threads.sort_by(|a, b| b.1.cmp(&a.1));
+    let threads: Vec<ContactThread> = all_threads
+        .into_iter()
+        .map(|(scope, last_updated, slug, subject)| ContactThread {
+            scope,
+            slug,
+            subject,
+            last_updated,
+        })
+        .collect();
+    let last_activity = threads.first().map(|t| t.last_updated.clone());
+
+    // 5. Relationship stats
+    let stats = relationship_stats(name, &contact.emails, &threads)?;
+    let profiles = ProfilesFile::load().unwrap_or_default();
+
+    Ok(ContactInfo {
+        name: name.to_string(),
+        emails: contact.emails.clone(),
+        details: details(contact, &profiles, Utc::now()),
+        agents_md,
+        threads,
+        last_activity,
+        stats,
+    })
+}
+
+/// Load the conversation files behind `threads` and compute stats.
+///
+/// A thread routed to several scopes is counted once (by thread ID).
+pub fn relationship_stats(name: &str, emails: &[String], threads: &[ContactThread]) -> Result<RelationshipStats> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut loaded: Vec<(&ContactThread, Thread)> = Vec::new();
+    for t in threads {
+        let dir = if t.scope == "root" {
+            resolve::conversations_dir()
+        } else {
+            resolve::mailboxes_base_dir().join(&t.scope).join("conversations")
+        };
+        let Ok(text) = std::fs::read_to_string(dir.join(format!("{}.md", t.slug))) else {
+            continue;
+        };
+        if let Some(thread) = parse_thread_markdown(&text) {
+            if seen.insert(thread.id.clone()) {
+                loaded.push((t, thread));
+            }
+        }
+    }
+    let topics = topic::load_topics(None)?;
+    Ok(compute_stats(name, emails, &load_owner_emails(), &loaded, &topics, Utc::now()))
+}
+
+fn median_hours(mut durations: Vec<chrono::Duration>) -> Option<f64> {
+    if durations.is_empty() {
+        return None;
+    }
+    durations.sort();
+    let mid = durations.len() / 2;
+    let seconds = if durations.len().is_multiple_of(2) {
+        (durations[mid - 1].num_seconds() + durations[mid].num_seconds()) as f64 / 2.0
     } else {
-        // Sort by date descending
-        all_threads.sort_by(|a, b| b.1.cmp(&a.1));
-
-        println!("--- Threads ({}) ---", all_threads.len());
-        for (scope, date, slug, subject) in &all_threads {
-            // Truncate date to just the date portion for display
-            let short_date = date.split(' ').take(4).collect::<Vec<_>>().join(" ");
-            let scope_prefix = if scope == "root" {
-                String::new()
+        durations[mid].num_seconds() as f64
+    };
+    Some((seconds / 360.0).round() / 10.0)
+}
+
+/// Relationship stats over parsed threads.
+///
+/// Sent = owner messages with the contact in To/CC; received = messages
+/// from the contact. Reply times pair the first unanswered message of one
+/// side with the other side's next message in the same thread. A thread is
+/// owed a reply when its last message is from the contact.
+fn compute_stats(
+    name: &str,
+    emails: &[String],
+    owner_emails: &[String],
+    threads: &[(&ContactThread, Thread)],
+    topics: &BTreeMap<String, topic::TopicConfig>,
+    now: DateTime<Utc>,
+) -> RelationshipStats {
+    let emails: Vec<String> = emails.iter().map(|e| e.to_lowercase()).collect();
+    let is_contact = |field: &str| addresses(field).iter().any(|(_, e)| emails.contains(e));
+    let is_owner = |field: &str| addresses(field).iter().any(|(_, e)| owner_emails.contains(e));
+
+    let mut stats = RelationshipStats::default();
+    let mut first: Option<(DateTime<Utc>, String)> = None;
+    let mut months: BTreeMap<String, MonthActivity> = BTreeMap::new();
+    let mut mine: Vec<chrono::Duration> = Vec::new();
+    let mut theirs: Vec<chrono::Duration> = Vec::new();
+
+    for (info, thread) in threads {
+        let mut messages: Vec<_> = thread.messages.iter().map(|m| (parse_msg_date(&m.date), m)).collect();
+        messages.sort_by_key(|(date, _)| *date);
+
+        let mut awaiting_me: Option<DateTime<Utc>> = None;
+        let mut awaiting_them: Option<DateTime<Utc>> = None;
+        for (date, msg) in &messages {
+            let received = is_contact(&msg.from);
+            let sent = !received && is_owner(&msg.from) && (is_contact(&msg.to) || is_contact(&msg.cc));
+            if !received && !sent {
+                continue;
+            }
+            if first.as_ref().is_none_or(|(d, _)| date < d) {
+                first = Some((*date, msg.date.clone()));
+            }
+            let month = months.entry(date.format("%Y-%m").to_string()).or_default();
+            if received {
+                month.received += 1;
+                stats.messages_received += 1;
+                if let Some(asked) = awaiting_them.take() {
+                    theirs.push(*date - asked);
+                }
+                awaiting_me.get_or_insert(*date);
             } else {
-                format!("[{}] ", scope)
-            };
-            println!("  {}  {:<30} {}{}", short_date, slug, scope_prefix, subject);
+                month.sent += 1;
+                stats.messages_sent += 1;
+                if let Some(asked) = awaiting_me.take() {
+                    mine.push(*date - asked);
+                }
+                awaiting_them.get_or_insert(*date);
+            }
+        }
+
+        if let Some((date, last)) = messages.last() {
+            if is_contact(&last.from) {
+                stats.owed_replies.push(OwedReply {
+                    scope: info.scope.clone(),
+                    slug: info.slug.clone(),
+                    subject: thread.subject.clone(),
+                    since: last.date.clone(),
+                    days_waiting: (now - *date).num_days().max(0),
+                });
+            }
         }
     }
-    println!();
 
-    // 6. Summary
-    if !all_threads.is_empty() {
-        println!(
-            "Last activity: {}",
-            all_threads.first().map(|t| t.1.as_str()).unwrap_or("unknown")
-        );
+    stats.first_contact = first.map(|(_, raw)| raw);
+    stats.timeline = months
+        .into_iter()
+        .map(|(month, activity)| MonthActivity { month, ..activity })
+        .collect();
+    stats.my_median_reply_hours = median_hours(mine);
+    stats.their_median_reply_hours = median_hours(theirs);
+    stats.owed_replies.sort_by(|a, b| b.days_waiting.cmp(&a.days_waiting).then(a.slug.cmp(&b.slug)));
+
+    for (topic_name, config) in topics {
+        let linked = config.contacts.iter().any(|c| c == name);
+        let keywords: Vec<String> = config.keywords.iter().map(|k| k.to_lowercase()).collect();
+        let matched = threads
+            .iter()
+            .filter(|(_, t)| {
+                let subject = t.subject.to_lowercase();
+                keywords.iter().any(|k| subject.contains(k.as_str()))
+            })
+            .count();
+        if linked || matched > 0 {
+            stats.topics.push(TopicMatch { name: topic_name.clone(), linked, threads: matched });
+        }
     }
+    stats
+}
 
-    Ok(())
+/// Show contact info and thread history.
+pub fn run(name: &str, format: OutputFormat) -> Result<()> {
+    emit(format, &collect(name)?)
 }
 
 /// Collect threads mentioning a contact from a manifest.toml file.
@@ -147,3 +591,141 @@ fn collect_threads_from_manifest(
 
     Ok(())
 }
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::types::Message;
+
+    fn thread(id: &str, subject: &str, messages: &[(&str, &str, &str)]) -> Thread {
+        Thread {
+            id: id.to_string(),
+            subject: subject.to_string(),
+            labels: vec![],
+            accounts: vec![],
+            messages: messages
+                .iter()
+                .map(|(from, to, date)| Message {
+                    id: String::new(),
+                    thread_id: id.to_string(),
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    cc: String::new(),
+                    date: date.to_string(),
+                    subject: subject.to_string(),
+                    body: String::new(),
+                    raw: String::new(),
+                })
+                .collect(),
+            last_date: String::new(),
+            ..Default::default()
+        }
+    }
+
+    fn entry(slug: &str) -> ContactThread {
+        ContactThread { scope: "root".into(), slug: slug.into(), subject: String::new(), last_updated: String::new() }
+    }
+
+    #[test]
+    fn test_compute_stats() {
+        let alice = "Alice <alice@example.com>";
+        let me = "Me <me@example.com>";
+        let budget = thread("t1", "Q3 budget", &[
+            (alice, "me@example.com", "Mon, 02 Feb 2026 09:00:00 +0000"),
+            (alice, "me@example.com", "Mon, 02 Feb 2026 10:00:00 +0000"),
+            (me, "alice@example.com", "Mon, 02 Feb 2026 13:00:00 +0000"),
+            (alice, "me@example.com", "Tue, 03 Feb 2026 13:00:00 +0000"),
+        ]);
+        let venue = thread("t2", "Venue", &[
+            (me, "alice@example.com", "Sun, 28 Dec 2025 10:00:00 +0000"),
+            ("Bob <bob@example.com>", "me@example.com", "Sun, 28 Dec 2025 11:00:00 +0000"),
+            (alice, "me@example.com", "Mon, 29 Dec 2025 12:00:00 +0000"),
+            (me, "bob@example.com", "Mon, 29 Dec 2025 13:00:00 +0000"),
+            (me, "alice@example.com", "Mon, 29 Dec 2025 15:00:00 +0000"),
+        ]);
+        let (e1, e2) = (entry("q3-budget"), entry("venue"));
+        let threads = vec![(&e1, budget), (&e2, venue)];
+        let mut topics = BTreeMap::new();
+        topics.insert("finance".to_string(), topic::TopicConfig { keywords: vec!["Budget".into()], ..Default::default() });
+        topics.insert("events".to_string(), topic::TopicConfig { contacts: vec!["alice".into()], ..Default::default() });
+        topics.insert("other".to_string(), topic::TopicConfig { keywords: vec!["launch".into()], ..Default::default() });
+        let now = DateTime::parse_from_rfc3339("2026-02-10T13:00:00Z").unwrap().with_timezone(&Utc);
+
+        let stats = compute_stats(
+            "alice",
+            &["Alice@example.com".to_string()],
+            &["me@example.com".to_string()],
+            &threads,
+            &topics,
+            now,
+        );
+        assert_eq!(stats.first_contact.as_deref(), Some("Sun, 28 Dec 2025 10:00:00 +0000"));
+        assert_eq!((stats.messages_sent, stats.messages_received), (3, 4));
+        assert_eq!(
+            stats.timeline,
+            vec![
+                MonthActivity { month: "2025-12".into(), sent: 2, received: 1 },
+                MonthActivity { month: "2026-02".into(), sent: 1, received: 3 },
+            ]
+        );
+        // Mine: 4h (from the first unanswered message) and 3h; theirs: 24h and 26h
+        assert_eq!(stats.my_median_reply_hours, Some(3.5));
+        assert_eq!(stats.their_median_reply_hours, Some(25.0));
+        assert_eq!(stats.owed_replies.len(), 1);
+        assert_eq!(stats.owed_replies[0].slug, "q3-budget");
+        assert_eq!(stats.owed_replies[0].days_waiting, 7);
+        assert_eq!(
+            stats.topics,
+            vec![
+                TopicMatch { name: "events".into(), linked: true, threads: 0 },
+                TopicMatch { name: "finance".into(), linked: false, threads: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_details_resolves_profile_dates_and_timezone() {
+        let profiles = ProfilesFile::parse("[alice.linkedin]\nhandle = \"alice-smith\"\n\n[alice.bluesky]\nhandle = \"alice.bsky.social\"\n").unwrap();
+        let mut contact = Contact {
+            timezone: "Asia/Tokyo".into(),
+            profile: "alice".into(),
+            ..Default::default()
+        };
+        contact.handles.insert("github".into(), "alice".into());
+        contact.handles.insert("bluesky".into(), "alice.example.com".into());
+        contact.dates.insert("birthday".into(), "04-12".into());
+        contact.dates.insert("anniversary".into(), "2019-02-03".into());
+        contact.dates.insert("bad".into(), "13-40".into());
+        // 20:00 UTC on Apr 11 is already Apr 12 in Tokyo
+        let now = DateTime::parse_from_rfc3339("2026-04-11T20:00:00Z").unwrap().with_timezone(&Utc);
+
+        let d = details(&contact, &profiles, now);
+        assert_eq!(d.local_time.as_deref(), Some("Sun 05:00"));
+        assert_eq!(d.handles["linkedin"], "alice-smith");
+        assert_eq!(d.handles["bluesky"], "alice.example.com");
+        assert_eq!(d.handles["github"], "alice");
+        let dates: Vec<(&str, i64)> = d.dates.iter().map(|u| (u.label.as_str(), u.days_until)).collect();
+        assert_eq!(dates, vec![("birthday", 0), ("anniversary", 297)]);
+        assert_eq!(d.dates[1].next, "2027-02-03");
+        assert_eq!(d.warnings, vec!["date 'bad' = '13-40' is not YYYY-MM-DD or MM-DD"]);
+
+        contact.profile = "nobody".into();
+        contact.timezone = "Mars/Olympus".into();
+        let d = details(&contact, &profiles, now);
+        assert_eq!(d.local_time, None);
+        assert!(d.warnings.iter().any(|w| w.contains("unknown timezone 'Mars/Olympus'")));
+        assert!(d.warnings.iter().any(|w| w.contains("profile 'nobody' not found")));
+    }
+
+    #[test]
+    fn test_format_hours() {
+        assert_eq!(format_hours(None), "-");
+        assert_eq!(format_hours(Some(0.5)), "30m");
+        assert_eq!(format_hours(Some(5.5)), "5.5h");
+        assert_eq!(format_hours(Some(72.0)), "3.0d");
+    }
+}
@@ -0,0 +1,455 @@
+//! `corky contact rename OLD NEW` and `corky contact merge FROM INTO`.
+//!
+//! Both rewrite `[contacts]` (and topic references), move `contacts/{name}/`
+//! in the root and in every mailbox, and carry the contact sync base hashes
+//! (`.sync-state.json`) over to the new name so the next `contact sync`
+//! doesn't see spurious changes. The old name is kept as an alias, so
+//! senders that slugify to it still match for contact sync eligibility.
+
+use anyhow::{bail, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::identity::normalize_phone;
+use super::sync::{content_hash, slugify_sender};
+use crate::config::contact::{load_contacts, save_contact, Contact};
+use crate::resolve;
+use crate::sync::types::{ContactSyncState, SyncState};
+
+static COMMENT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)<!--.*?-->").unwrap());
+
+/// The instruction file of a contact directory: AGENTS.md in the root
+/// (CLAUDE.md is a symlink to it), CLAUDE.md in mailboxes or older contacts.
+fn instructions_file(dir: &Path) -> PathBuf {
+    let agents_md = dir.join("AGENTS.md");
+    if agents_md.exists() { agents_md } else { dir.join("CLAUDE.md") }
+}
+
+/// Contact instruction contents for one name, before or after an operation.
+#[derive(Debug, Default)]
+struct Snapshot {
+    root: Option<String>,
+    /// Key = mailbox name
+    mailboxes: BTreeMap<String, String>,
+}
+
+fn mailbox_dirs() -> Vec<(String, PathBuf)> {
+    let mut dirs: Vec<(String, PathBuf)> = std::fs::read_dir(resolve::mailboxes_base_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+fn snapshot(name: &str) -> Snapshot {
+    let read = |dir: PathBuf| std::fs::read_to_string(instructions_file(&dir)).ok();
+    Snapshot {
+        root: read(resolve::contacts_dir().join(name)),
+        mailboxes: mailbox_dirs()
+            .into_iter()
+            .filter_map(|(mb, path)| read(path.join("contacts").join(name)).map(|c| (mb, c)))
+            .collect(),
+    }
+}
+
+/// Carry a base hash across a content rewrite. The base recorded which side
+/// was unchanged since the last sync; keep pointing at that side's new content.
+fn carry_base(
+    base: Option<&str>,
+    old_root: Option<&str>,
+    old_mb: Option<&str>,
+    after: &Snapshot,
+    mb: &str,
+) -> Option<String> {
+    let new_mb = after.mailboxes.get(mb)?;
+    if after.root.as_deref() == Some(new_mb.as_str()) {
+        return Some(content_hash(new_mb));
+    }
+    let base = base?;
+    if old_mb.is_some_and(|c| content_hash(c) == base) {
+        return Some(content_hash(new_mb));
+    }
+    if let (Some(old_root), Some(new_root)) = (old_root, &after.root) {
+        if content_hash(old_root) == base {
+            return Some(content_hash(new_root));
+        }
+    }
+    Some(base.to_string())
+}
+
+/// Point template lines that name the contact at the new name.
+fn retitle(content: &str, old: &str, new: &str) -> String {
+    content
+        .replace(&format!("# Contact: {}\n", old), &format!("# Contact: {}\n", new))
+        .replace(&format!("to or about {}.", old), &format!("to or about {}.", new))
+        .replace(&format!("Communication style for {}.", old), &format!("Communication style for {}.", new))
+}
+
+/// Split markdown into a preamble and `## ` sections (heading line, body).
+fn sections(content: &str) -> (String, Vec<(String, String)>) {
+    let mut preamble = String::new();
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in content.split_inclusive('\n') {
+        if line.starts_with("## ") {
+            sections.push((line.trim_end().to_string(), String::new()));
+        } else if let Some((_, body)) = sections.last_mut() {
+            body.push_str(line);
+        } else {
+            preamble.push_str(line);
+        }
+    }
+    (preamble, sections)
+}
+
+/// Section text without HTML comment placeholders.
+fn meaningful(body: &str) -> String {
+    COMMENT_RE.replace_all(body, "").trim().to_string()
+}
+
+/// Merge `from`'s AGENTS.md into `into`'s.
+///
+/// `into`'s preamble is kept. For each `## ` section of `from`: a section
+/// missing from `into` is appended; one that is only placeholder comments in
+/// `into` is replaced; otherwise lines `into` doesn't already have are added.
+pub fn merge_agents_md(into: &str, from: &str) -> String {
+    let (preamble, mut merged) = sections(into);
+    let (_, from_sections) = sections(from);
+    for (heading, from_body) in from_sections {
+        let from_text = meaningful(&from_body);
+        let existing = merged.iter_mut().find(|(h, _)| h.eq_ignore_ascii_case(&heading));
+        match existing {
+            None => merged.push((heading, from_body)),
+            Some(_) if from_text.is_empty() => {}
+            Some((_, body)) => {
+                let into_text = meaningful(body);
+                if into_text.is_empty() {
+                    *body = from_body;
+                    continue;
+                }
+                let known: Vec<&str> = into_text.lines().map(str::trim).collect();
+                let new_lines: Vec<&str> = from_text
+                    .lines()
+                    .filter(|l| !l.trim().is_empty() && !known.contains(&l.trim()))
+                    .collect();
+                if !new_lines.is_empty() {
+                    *body = format!("\n{}\n{}\n\n", body.trim(), new_lines.join("\n"));
+                }
+            }
+        }
+    }
+    let mut out = preamble;
+    for (heading, body) in merged {
+        if !out.is_empty() && !out.ends_with("\n\n") {
+            out.push('\n');
+        }
+        out.push_str(&heading);
+        out.push('\n');
+        out.push_str(&body);
+    }
+    format!("{}\n", out.trim_end())
+}
+
+fn check_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        bail!("Invalid contact name: '{}'", name);
+    }
+    Ok(())
+}
+
+fn add_alias(contact: &mut Contact, alias: &str) {
+    let slug = slugify_sender(alias);
+    if !slug.is_empty() && !contact.aliases.iter().any(|a| slugify_sender(a) == slug) {
+        contact.aliases.push(alias.to_string());
+    }
+}
+
+/// Remove [contacts.{name}] and point [topics.*].contacts at `replacement`.
+fn update_config_refs(name: &str, replacement: &str) -> Result<()> {
+    let path = resolve::corky_toml();
+    let content = std::fs::read_to_string(&path)?;
+    let mut doc = content.parse::<toml_edit::DocumentMut>()?;
+    if let Some(contacts) = doc.get_mut("contacts").and_then(|c| c.as_table_mut()) {
+        contacts.remove(name);
+    }
+    if let Some(topics) = doc.get_mut("topics").and_then(|t| t.as_table_mut()) {
+        for (_, topic) in topics.iter_mut() {
+            let Some(arr) = topic.get_mut("contacts").and_then(|c| c.as_array_mut()) else {
+                continue;
+            };
+            if !arr.iter().any(|v| v.as_str() == Some(name)) {
+                continue;
+            }
+            let has_replacement = arr.iter().any(|v| v.as_str() == Some(replacement));
+            let mut updated = toml_edit::Array::new();
+            for v in arr.iter() {
+                match v.as_str() {
+                    Some(n) if n == name && !has_replacement => updated.push(replacement),
+                    Some(n) if n == name => {}
+                    _ => updated.push(v.clone()),
+                }
+            }
+            *arr = updated;
+        }
+    }
+    std::fs::write(&path, doc.to_string())?;
+    Ok(())
+}
+
+/// Move `from` into `to`, merging into an existing `to` directory:
+/// instructions via [`merge_agents_md`], other files moved (renamed
+/// `{file}.from-{name}` on collision).
+fn move_dir(from: &Path, to: &Path, from_name: &str, to_name: &str) -> Result<()> {
+    if !from.is_dir() {
+        return Ok(());
+    }
+    if !to.exists() {
+        std::fs::rename(from, to)?;
+        let file = instructions_file(to);
+        if let Ok(content) = std::fs::read_to_string(&file) {
+            std::fs::write(&file, retitle(&content, from_name, to_name))?;
+        }
+        println!("Moved {} \u{2192} {}", from.display(), to.display());
+        return Ok(());
+    }
+
+    let into_file = instructions_file(to);
+    let from_file = instructions_file(from);
+    if let Ok(from_content) = std::fs::read_to_string(&from_file) {
+        let into_content = std::fs::read_to_string(&into_file).unwrap_or_default();
+        let from_content = retitle(&from_content, from_name, to_name);
+        std::fs::write(&into_file, merge_agents_md(&into_content, &from_content))?;
+    }
+    for entry in std::fs::read_dir(from)?.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name == "AGENTS.md" || file_name == "CLAUDE.md" {
+            continue;
+        }
+        let mut dest = to.join(&file_name);
+        if dest.exists() {
+            dest = to.join(format!("{}.from-{}", file_name, from_name));
+        }
+        std::fs::rename(entry.path(), dest)?;
+    }
+    std::fs::remove_dir_all(from)?;
+    println!("Merged {} into {}", from.display(), to.display());
+    Ok(())
+}
+
+/// Move the directories in the root and every mailbox.
+fn move_dirs(from_name: &str, to_name: &str) -> Result<()> {
+    let root = resolve::contacts_dir();
+    move_dir(&root.join(from_name), &root.join(to_name), from_name, to_name)?;
+    for (_, mb_path) in mailbox_dirs() {
+        let contacts = mb_path.join("contacts");
+        move_dir(&contacts.join(from_name), &contacts.join(to_name), from_name, to_name)?;
+    }
+    Ok(())
+}
+
+/// Re-key contact sync state from `from_name` to `to_name`.
+///
+/// For each mailbox, the base is carried from whichever name's directory
+/// was there: the target's if it had one, else the source's.
+fn update_state(
+    state: &mut SyncState,
+    from_name: &str,
+    to_name: &str,
+    before_from: &Snapshot,
+    before_to: &Snapshot,
+    after: &Snapshot,
+) {
+    let from_state = state.contacts.remove(from_name).unwrap_or_default();
+    let to_state = state.contacts.remove(to_name).unwrap_or_default();
+    let mut carried = ContactSyncState::default();
+    for mb in after.mailboxes.keys() {
+        let (base, before) = if before_to.mailboxes.contains_key(mb) {
+            (to_state.mailboxes.get(mb), before_to)
+        } else {
+            (from_state.mailboxes.get(mb), before_from)
+        };
+        let old_mb = before.mailboxes.get(mb).map(String::as_str);
+        if let Some(hash) = carry_base(base.map(String::as_str), before.root.as_deref(), old_mb, after, mb) {
+            carried.mailboxes.insert(mb.clone(), hash);
+        }
+    }
+    if !carried.mailboxes.is_empty() {
+        state.contacts.insert(to_name.to_string(), carried);
+    }
+}
+
+/// Point CardDAV links at a renamed contact. (Cards of a merged-away
+/// contact keep their link: with the contact gone, the next CardDAV sync
+/// deletes them from the server.)
+fn rename_carddav_links(state: &mut SyncState, old: &str, new: &str) {
+    for dav in state.carddav.values_mut() {
+        for link in dav.cards.values_mut() {
+            if link.contact == old {
+                link.contact = new.to_string();
+            }
+        }
+        for name in dav.unlinked.iter_mut() {
+            if name == old {
+                *name = new.to_string();
+            }
+        }
+    }
+}
+
+/// corky contact rename OLD NEW
+pub fn rename(old: &str, new: &str) -> Result<()> {
+    check_name(new)?;
+    let contacts = load_contacts(None)?;
+    let Some(contact) = contacts.get(old) else {
+        bail!("Contact '{}' not found in .corky.toml", old);
+    };
+    if contacts.contains_key(new) {
+        bail!("Contact '{}' already exists; use 'corky contact merge {} {}'", new, old, new);
+    }
+    let before_new = snapshot(new);
+    if before_new.root.is_some() || !before_new.mailboxes.is_empty() || resolve::contacts_dir().join(new).exists() {
+        bail!("Directory contacts/{} already exists", new);
+    }
+
+    let mut contact = contact.clone();
+    add_alias(&mut contact, old);
+    save_contact(new, &contact, None)?;
+    update_config_refs(old, new)?;
+
+    let before = snapshot(old);
+    move_dirs(old, new)?;
+
+    let mut state = crate::sync::load_state()?;
+    update_state(&mut state, old, new, &before, &before_new, &snapshot(new));
+    rename_carddav_links(&mut state, old, new);
+    crate::sync::save_state(&state)?;
+
+    println!("Done. Contact '{}' renamed to '{}'.", old, new);
+    Ok(())
+}
+
+/// corky contact merge FROM INTO — fold FROM into INTO and remove FROM.
+pub fn merge(from: &str, into: &str) -> Result<()> {
+    if from == into {
+        bail!("Cannot merge a contact into itself");
+    }
+    let contacts = load_contacts(None)?;
+    let (Some(source), Some(target)) = (contacts.get(from), contacts.get(into)) else {
+        let missing = if contacts.contains_key(from) { into } else { from };
+        bail!("Contact '{}' not found in .corky.toml", missing);
+    };
+
+    let mut merged = merge_contacts(target, source);
+    add_alias(&mut merged, from);
+    save_contact(into, &merged, None)?;
+    update_config_refs(from, into)?;
+
+    let (before_from, before_into) = (snapshot(from), snapshot(into));
+    move_dirs(from, into)?;
+
+    let mut state = crate::sync::load_state()?;
+    update_state(&mut state, from, into, &before_from, &before_into, &snapshot(into));
+    crate::sync::save_state(&state)?;
+
+    println!("Done. Contact '{}' merged into '{}'.", from, into);
+    Ok(())
+}
+
+/// Union of two contacts' lists and maps; `target`'s single values win.
+pub fn merge_contacts(target: &Contact, source: &Contact) -> Contact {
+    let mut merged = target.clone();
+    for email in &source.emails {
+        if !merged.emails.iter().any(|e| e.eq_ignore_ascii_case(email)) {
+            merged.emails.push(email.clone());
+        }
+    }
+    for alias in &source.aliases {
+        add_alias(&mut merged, alias);
+    }
+    if !merged.full_name.is_empty() && !source.full_name.is_empty() && source.full_name != merged.full_name {
+        add_alias(&mut merged, &source.full_name);
+    }
+    for mailbox in &source.shared_with {
+        if !merged.shared_with.contains(mailbox) {
+            merged.shared_with.push(mailbox.clone());
+        }
+    }
+    for phone in &source.phones {
+        if !merged.phones.iter().any(|p| normalize_phone(p) == normalize_phone(phone)) {
+            merged.phones.push(phone.clone());
+        }
+    }
+    for (field, value) in [
+        (&mut merged.full_name, &source.full_name),
+        (&mut merged.organization, &source.organization),
+        (&mut merged.uid, &source.uid),
+        (&mut merged.title, &source.title),
+        (&mut merged.timezone, &source.timezone),
+        (&mut merged.language, &source.language),
+        (&mut merged.profile, &source.profile),
+        (&mut merged.followup_after, &source.followup_after),
+    ] {
+        if field.is_empty() {
+            *field = value.clone();
+        }
+    }
+    for (map, from) in [(&mut merged.handles, &source.handles), (&mut merged.dates, &source.dates)] {
+        for (key, value) in from {
+            map.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+    merged
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_agents_md_sections() {
+        let into = "# Contact: alice\n\nIntro.\n\n## Relationship\n\n<!-- placeholder -->\n\n## Formality\n\nprofessional\n\n## Topics\n\n- Budget\n";
+        let from = "# Contact: alice-work\n\n## Relationship\n\nMet at RustConf.\n\n## Formality\n\nprofessional\n\n## Topics\n\n- Budget\n- Venue\n\n## Research\n\n- Email domain: acme.com\n";
+        let merged = merge_agents_md(into, from);
+        assert_eq!(
+            merged,
+            "# Contact: alice\n\nIntro.\n\n## Relationship\n\nMet at RustConf.\n\n## Formality\n\nprofessional\n\n## Topics\n\n- Budget\n- Venue\n\n## Research\n\n- Email domain: acme.com\n"
+        );
+    }
+
+    #[test]
+    fn test_retitle_template_lines() {
+        let content = super::super::add::default_agents_md("alice");
+        let renamed = retitle(&content, "alice", "alice-smith");
+        assert!(renamed.starts_with("# Contact: alice-smith\n"));
+        assert!(renamed.contains("to or about alice-smith."));
+        assert!(!renamed.contains(" alice."));
+    }
+
+    #[test]
+    fn test_merge_contacts() {
+        let target = Contact { emails: vec!["alice@example.com".into()], full_name: "Alice Smith".into(), ..Default::default() };
+        let source = Contact {
+            emails: vec!["ALICE@example.com".into(), "alice@work.com".into()],
+            aliases: vec!["Ali".into()],
+            full_name: "A. Smith".into(),
+            phones: vec!["+1 555 0001".into()],
+            ..Default::default()
+        };
+        let merged = merge_contacts(&target, &source);
+        assert_eq!(merged.emails, vec!["alice@example.com", "alice@work.com"]);
+        assert_eq!(merged.aliases, vec!["Ali", "A. Smith"]);
+        assert_eq!(merged.full_name, "Alice Smith");
+        assert_eq!(merged.phones, vec!["+1 555 0001"]);
+    }
+}
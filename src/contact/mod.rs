@@ -1,4 +1,9 @@
 pub mod add;
+pub mod carddav;
+pub mod discover;
 pub mod from_conversation;
+pub mod identity;
 pub mod info;
+pub mod merge;
 pub mod sync;
+pub mod vcard;
@@ -7,6 +7,8 @@
 //! - Conversation match: sender name in mailbox conversations slugifies to the contact name
 //! - Explicit sharing: `[contacts.{name}].shared_with` includes the mailbox name
 //! - Alias matching: `[contacts.{name}].aliases` provides alternative sender names
+//! - Identifier match: a mailbox conversation involves one of the contact's
+//!   `emails`, `phones` or `handles` (see `contact::identity`)
 //!
 //! Mailbox → root sync is always allowed.
 //! Resolution: 3-way merge via `.sync-state.json` content hashes. If only one
@@ -22,6 +24,7 @@ use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::identity::{conversation_contacts, ContactIndex};
 use crate::config::contact::Contact;
 use crate::resolve;
 use crate::sync::types::ContactSyncState;
@@ -44,7 +47,7 @@ static VIA_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+via\s+.+$").unwrap());
 static EMAIL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s*<[^>]+>").unwrap());
 
 /// FNV-1a hash of content, returned as a 16-char hex string.
-fn content_hash(content: &str) -> String {
+pub(crate) fn content_hash(content: &str) -> String {
     const FNV_OFFSET: u64 = 0xcbf29ce484222325;
     const FNV_PRIME: u64 = 0x00000100000001B3;
     let mut hash = FNV_OFFSET;
@@ -328,6 +331,14 @@ fn build_eligible_set(
         }
     }
 
+    // Match emails, phone numbers and chat handles (SMS, Signal, Telegram, ...)
+    let index = ContactIndex::new(contacts_config);
+    for name in conversation_contacts(&conversations_dir, &index)? {
+        if root_contacts.contains_key(&name) {
+            eligible.insert(name);
+        }
+    }
+
     // 2. Check explicit sharing
     for (name, config) in contacts_config {
         if config.shared_with.iter().any(|s| s == mb_name)
@@ -715,6 +726,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eligible_via_phone_number() {
+        let tmp = TempDir::new().unwrap();
+        let root_contacts = tmp.path().join("contacts");
+        let mailboxes = tmp.path().join("mailboxes");
+        let mb = mailboxes.join("alice");
+        let mb_contacts = mb.join("contacts");
+        std::fs::create_dir_all(mb.join("conversations")).unwrap();
+        std::fs::create_dir_all(&mb_contacts).unwrap();
+
+        // SMS import: the sender is a bare number that slugifies to nothing useful
+        create_contact(&root_contacts, "bob", "content");
+        std::fs::write(
+            mb.join("conversations/sms.md"),
+            "# +15550100100\n\n**Thread ID**: sms:+15550100100\n\n---\n\n## +15550100100 — Mon, 01 Jan 2024 00:00:00 +0000\n\nHi\n",
+        )
+        .unwrap();
+        let mut config = BTreeMap::new();
+        config.insert(
+            "bob".to_string(),
+            Contact { phones: vec!["555-010-0100".to_string()], ..Default::default() },
+        );
+
+        sync_no_state(&root_contacts, &mailboxes, &config).unwrap();
+        assert!(
+            mb_contacts.join("bob").join("CLAUDE.md").exists(),
+            "phone number should enable sync"
+        );
+    }
+
     // --- Sync direction tests ---
 
     #[test]
@@ -0,0 +1,630 @@
+//! vCard ↔ contacts: `corky contact import FILE.vcf` / `corky contact export`.
+//!
+//! Reads vCard 2.1, 3.0 and 4.0 (line folding, escapes, grouped properties,
+//! quoted-printable values) and writes vCard 3.0, the version every CardDAV
+//! server accepts. Only the fields `Contact` models are mapped:
+//!
+//! | vCard | Contact |
+//! |-------|---------|
+//! | `FN` (else `N`) | `full_name` |
+//! | `EMAIL` | `emails` |
+//! | `TEL` | `phones` |
+//! | `ORG` (first unit) | `organization` |
+//! | `TITLE` | `title` |
+//! | `BDAY` | `dates.birthday` |
+//! | `NICKNAME` | `aliases` |
+//! | `UID` | `uid` |
+
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::add::{create_contact_dir, default_agents_md};
+use super::identity::normalize_phone;
+use crate::config::contact::{load_contacts, save_contact, Contact};
+use crate::resolve;
+use crate::util::slugify;
+
+/// The mapped fields of one vCard.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Card {
+    pub uid: String,
+    pub full_name: String,
+    pub emails: Vec<String>,
+    pub phones: Vec<String>,
+    pub organization: String,
+    pub nicknames: Vec<String>,
+    pub title: String,
+    /// Contact date format: "YYYY-MM-DD" or "MM-DD"
+    pub birthday: String,
+}
+
+impl Card {
+    /// The card for a configured contact. Contacts without a UID get
+    /// [`default_uid`], and without a full name use their config name.
+    pub fn from_contact(name: &str, contact: &Contact) -> Card {
+        Card {
+            uid: if contact.uid.is_empty() { default_uid(name) } else { contact.uid.clone() },
+            full_name: if contact.full_name.is_empty() { name.to_string() } else { contact.full_name.clone() },
+            emails: contact.emails.clone(),
+            phones: contact.phones.clone(),
+            organization: contact.organization.clone(),
+            nicknames: contact.aliases.clone(),
+            title: contact.title.clone(),
+            birthday: contact.dates.get("birthday").cloned().unwrap_or_default(),
+        }
+    }
+
+    /// No name, email or phone: nothing to make a contact from.
+    pub fn is_empty(&self) -> bool {
+        self.full_name.is_empty() && self.emails.is_empty() && self.phones.is_empty()
+    }
+}
+
+/// A vCard BDAY ("1985-04-12", "19850412", "--0412", "--04-12", with an
+/// optional time part) in contact date format. Empty if unrecognized.
+fn parse_bday(value: &str) -> String {
+    let date = value.trim().split('T').next().unwrap_or("");
+    let digits: String = date.chars().filter(|c| c.is_ascii_digit()).collect();
+    let parsed = if date.starts_with("--") && digits.len() == 4 {
+        format!("{}-{}", &digits[..2], &digits[2..])
+    } else if digits.len() == 8 {
+        format!("{}-{}-{}", &digits[..4], &digits[4..6], &digits[6..])
+    } else {
+        return String::new();
+    };
+    if crate::config::contact::parse_date(&parsed).is_some() { parsed } else { String::new() }
+}
+
+/// Stable UID for a contact that has none yet.
+pub fn default_uid(name: &str) -> String {
+    format!("corky-{}", name)
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// Split a property value on unescaped `sep`, undoing vCard escapes.
+fn split_unescaped(value: &str, sep: Option<char>) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') | Some('N') => parts.last_mut().unwrap().push('\n'),
+                Some(other) => parts.last_mut().unwrap().push(other),
+                None => {}
+            },
+            c if Some(c) == sep => parts.push(String::new()),
+            c => parts.last_mut().unwrap().push(c),
+        }
+    }
+    parts
+}
+
+fn unescape(value: &str) -> String {
+    split_unescaped(value, None).remove(0)
+}
+
+/// Decode a quoted-printable value (vCard 2.1), assuming UTF-8.
+fn decode_quoted_printable(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'=' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+            if let Ok(b) = u8::from_str_radix(hex, 16) {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Unfold physical lines into logical property lines.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.lines() {
+        let line = raw.trim_end_matches('\r');
+        let last_is_soft_qp = lines.last().is_some_and(|l| {
+            l.ends_with('=') && l.split(':').next().unwrap_or("").to_ascii_uppercase().contains("QUOTED-PRINTABLE")
+        });
+        if last_is_soft_qp {
+            let last = lines.last_mut().unwrap();
+            last.pop();
+            last.push_str(line.trim_start());
+        } else if (line.starts_with(' ') || line.starts_with('\t')) && !lines.is_empty() {
+            lines.last_mut().unwrap().push_str(&line[1..]);
+        } else if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+    lines
+}
+
+/// Split "GROUP.NAME;PARAMS:VALUE" into (NAME, params, value).
+fn split_property(line: &str) -> Option<(String, String, String)> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            in_quotes = !in_quotes;
+            None
+        }
+        ':' if !in_quotes => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let (name, params) = head.split_once(';').unwrap_or((head, ""));
+    let name = name.rsplit('.').next().unwrap_or(name).to_ascii_uppercase();
+    Some((name, params.to_ascii_uppercase(), value.to_string()))
+}
+
+/// Parse every `BEGIN:VCARD` … `END:VCARD` block in `text`.
+pub fn parse_vcards(text: &str) -> Vec<Card> {
+    let mut cards = Vec::new();
+    let mut current: Option<Card> = None;
+    let mut structured_name = String::new();
+
+    for line in unfold(text) {
+        let Some((name, params, value)) = split_property(&line) else {
+            continue;
+        };
+        let value = if params.contains("QUOTED-PRINTABLE") {
+            decode_quoted_printable(&value)
+        } else {
+            value
+        };
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VCARD") => {
+                current = Some(Card::default());
+                structured_name.clear();
+            }
+            "END" if value.eq_ignore_ascii_case("VCARD") => {
+                if let Some(mut card) = current.take() {
+                    if card.full_name.is_empty() {
+                        card.full_name = structured_name.clone();
+                    }
+                    cards.push(card);
+                }
+            }
+            _ => {
+                let Some(card) = current.as_mut() else {
+                    continue;
+                };
+                match name.as_str() {
+                    "FN" => card.full_name = unescape(&value).trim().to_string(),
+                    "N" => {
+                        // family;given;additional;prefix;suffix
+                        let parts = split_unescaped(&value, Some(';'));
+                        let family = parts.first().map(|s| s.trim()).unwrap_or("");
+                        let given = parts.get(1).map(|s| s.trim()).unwrap_or("");
+                        structured_name = format!("{} {}", given, family).trim().to_string();
+                    }
+                    "EMAIL" => {
+                        let email = unescape(&value);
+                        let email = email.trim().trim_start_matches("mailto:");
+                        if !email.is_empty() {
+                            card.emails.push(email.to_string());
+                        }
+                    }
+                    "TEL" => {
+                        let phone = unescape(&value);
+                        let phone = phone.trim().trim_start_matches("tel:");
+                        if !phone.is_empty() {
+                            card.phones.push(phone.to_string());
+                        }
+                    }
+                    "ORG" => {
+                        card.organization = split_unescaped(&value, Some(';')).remove(0).trim().to_string();
+                    }
+                    "NICKNAME" => {
+                        card.nicknames.extend(
+                            split_unescaped(&value, Some(','))
+                                .into_iter()
+                                .map(|n| n.trim().to_string())
+                                .filter(|n| !n.is_empty()),
+                        );
+                    }
+                    "UID" => card.uid = unescape(&value).trim().to_string(),
+                    "TITLE" => card.title = unescape(&value).trim().to_string(),
+                    "BDAY" => card.birthday = parse_bday(&value),
+                    _ => {}
+                }
+            }
+        }
+    }
+    cards
+}
+
+// ---------------------------------------------------------------------------
+// Rendering
+// ---------------------------------------------------------------------------
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line at 75 octets (RFC 6350 §3.2), on char boundaries.
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
+
+/// Render a card as vCard 3.0 with CRLF line endings.
+pub fn render_vcard(card: &Card) -> String {
+    let (given, family) = match card.full_name.rsplit_once(' ') {
+        Some((given, family)) => (given, family),
+        None => (card.full_name.as_str(), ""),
+    };
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("UID:{}", escape(&card.uid)),
+        format!("FN:{}", escape(&card.full_name)),
+        format!("N:{};{};;;", escape(family), escape(given)),
+    ];
+    if !card.nicknames.is_empty() {
+        let nicknames: Vec<String> = card.nicknames.iter().map(|n| escape(n)).collect();
+        lines.push(format!("NICKNAME:{}", nicknames.join(",")));
+    }
+    if !card.organization.is_empty() {
+        lines.push(format!("ORG:{}", escape(&card.organization)));
+    }
+    if !card.title.is_empty() {
+        lines.push(format!("TITLE:{}", escape(&card.title)));
+    }
+    if !card.birthday.is_empty() {
+        // Year-less dates use the vCard 4 `--MM-DD` form, which 3.0 readers accept in practice
+        let bday = if card.birthday.len() == 5 { format!("--{}", card.birthday) } else { card.birthday.clone() };
+        lines.push(format!("BDAY:{}", bday));
+    }
+    for email in &card.emails {
+        lines.push(format!("EMAIL;TYPE=INTERNET:{}", escape(email)));
+    }
+    for phone in &card.phones {
+        lines.push(format!("TEL:{}", escape(phone)));
+    }
+    lines.push("END:VCARD".to_string());
+    lines.iter().map(|l| fold(l)).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Mapping onto contacts
+// ---------------------------------------------------------------------------
+
+/// Replace the contact's vCard-mapped fields with the card's.
+/// `shared_with` and fields the card cannot carry are kept.
+pub fn apply_card(contact: &mut Contact, card: &Card) {
+    contact.full_name = card.full_name.clone();
+    contact.emails = card.emails.clone();
+    contact.phones = card.phones.clone();
+    contact.organization = card.organization.clone();
+    contact.aliases = card.nicknames.clone();
+    contact.title = card.title.clone();
+    if card.birthday.is_empty() {
+        contact.dates.remove("birthday");
+    } else {
+        contact.dates.insert("birthday".to_string(), card.birthday.clone());
+    }
+    if !card.uid.is_empty() {
+        contact.uid = card.uid.clone();
+    }
+}
+
+/// Merge a card into a contact: fill empty fields and add new emails,
+/// phones and aliases. Existing values win. Returns true if anything changed.
+pub fn merge_card(contact: &mut Contact, card: &Card) -> bool {
+    let before = contact.clone();
+    for (field, value) in [
+        (&mut contact.full_name, &card.full_name),
+        (&mut contact.organization, &card.organization),
+        (&mut contact.uid, &card.uid),
+        (&mut contact.title, &card.title),
+    ] {
+        if field.is_empty() {
+            *field = value.clone();
+        }
+    }
+    if !card.birthday.is_empty() {
+        contact.dates.entry("birthday".to_string()).or_insert_with(|| card.birthday.clone());
+    }
+    for email in &card.emails {
+        if !contact.emails.iter().any(|e| e.eq_ignore_ascii_case(email)) {
+            contact.emails.push(email.clone());
+        }
+    }
+    for phone in &card.phones {
+        if !contact.phones.iter().any(|p| normalize_phone(p) == normalize_phone(phone)) {
+            contact.phones.push(phone.clone());
+        }
+    }
+    for nickname in &card.nicknames {
+        if !contact.aliases.contains(nickname) {
+            contact.aliases.push(nickname.clone());
+        }
+    }
+    *contact != before
+}
+
+/// Find the configured contact a card describes: same UID, then a shared
+/// email, then a shared phone number, then a matching name.
+pub fn find_match<'a>(
+    contacts: impl IntoIterator<Item = (&'a String, &'a Contact)> + Clone,
+    card: &Card,
+) -> Option<String> {
+    let by = |pred: &dyn Fn(&Contact, &str) -> bool| {
+        contacts.clone().into_iter().find(|(name, c)| pred(c, name)).map(|(name, _)| name.clone())
+    };
+    if !card.uid.is_empty() {
+        if let Some(name) = by(&|c, name| c.uid == card.uid || (c.uid.is_empty() && default_uid(name) == card.uid)) {
+            return Some(name);
+        }
+    }
+    if let Some(name) = by(&|c, _| c.emails.iter().any(|e| card.emails.iter().any(|ce| ce.eq_ignore_ascii_case(e)))) {
+        return Some(name);
+    }
+    let card_phones: Vec<String> = card.phones.iter().map(|p| normalize_phone(p)).filter(|p| !p.is_empty()).collect();
+    if let Some(name) = by(&|c, _| c.phones.iter().any(|p| card_phones.contains(&normalize_phone(p)))) {
+        return Some(name);
+    }
+    if card.full_name.is_empty() {
+        return None;
+    }
+    let slug = slugify(&card.full_name);
+    by(&|c, name| name == slug || c.full_name.eq_ignore_ascii_case(&card.full_name))
+}
+
+/// Config name for a new contact from `card`: the slugged full name (else
+/// email local part, else phone), made unique against `taken` and existing
+/// contact directories.
+pub fn new_contact_name(card: &Card, taken: &BTreeMap<String, Contact>) -> String {
+    let base = if !card.full_name.is_empty() {
+        slugify(&card.full_name)
+    } else if let Some(email) = card.emails.first() {
+        slugify(email.split('@').next().unwrap_or(email))
+    } else {
+        slugify(&normalize_phone(card.phones.first().map(String::as_str).unwrap_or("")))
+    };
+    let contacts_dir = resolve::contacts_dir();
+    let free = |name: &str| !taken.contains_key(name) && !contacts_dir.join(name).exists();
+    if free(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|name| free(name))
+        .expect("unbounded range")
+}
+
+/// Save a contact created from an address book: config entry plus a
+/// contacts/{name}/ directory with the default AGENTS.md.
+pub fn create_contact(name: &str, contact: &Contact) -> Result<()> {
+    if !resolve::contacts_dir().join(name).exists() {
+        create_contact_dir(name, &default_agents_md(name))?;
+    }
+    save_contact(name, contact, None)
+}
+
+// ---------------------------------------------------------------------------
+// Commands
+// ---------------------------------------------------------------------------
+
+/// Counts reported by `corky contact import`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportStats {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    /// Cards without a name, email or phone
+    pub skipped: usize,
+}
+
+/// corky contact import FILE.vcf [--dry-run]
+///
+/// Matching cards are merged into existing contacts (existing values win);
+/// the rest become new contacts.
+pub fn import(path: &Path, dry_run: bool) -> Result<ImportStats> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let cards = parse_vcards(&text);
+    if cards.is_empty() {
+        bail!("No vCards found in {}", path.display());
+    }
+
+    let mut contacts = load_contacts(None)?;
+    let mut stats = ImportStats::default();
+    for card in &cards {
+        if card.is_empty() {
+            stats.skipped += 1;
+            continue;
+        }
+        match find_match(&contacts, card) {
+            Some(name) => {
+                let mut contact = contacts[&name].clone();
+                if !merge_card(&mut contact, card) {
+                    stats.unchanged += 1;
+                    continue;
+                }
+                println!("  Updated {}", name);
+                if !dry_run {
+                    save_contact(&name, &contact, None)?;
+                }
+                contacts.insert(name, contact);
+                stats.updated += 1;
+            }
+            None => {
+                let name = new_contact_name(card, &contacts);
+                let mut contact = Contact::default();
+                apply_card(&mut contact, card);
+                println!("  Created {}", name);
+                if !dry_run {
+                    create_contact(&name, &contact)?;
+                }
+                contacts.insert(name, contact);
+                stats.created += 1;
+            }
+        }
+    }
+
+    println!(
+        "vCard import {}: {} created, {} updated, {} unchanged, {} skipped.",
+        if dry_run { "dry run" } else { "complete" },
+        stats.created,
+        stats.updated,
+        stats.unchanged,
+        stats.skipped
+    );
+    Ok(stats)
+}
+
+/// corky contact export [OUT] [--contact NAME]...
+///
+/// Writes vCard 3.0 to OUT, or to stdout when OUT is omitted.
+pub fn export(out: Option<&Path>, names: &[String]) -> Result<()> {
+    let contacts = load_contacts(None)?;
+    for name in names {
+        if !contacts.contains_key(name) {
+            bail!("Unknown contact: {}", name);
+        }
+    }
+    let vcf: String = contacts
+        .iter()
+        .filter(|(name, _)| names.is_empty() || names.contains(name))
+        .map(|(name, contact)| render_vcard(&Card::from_contact(name, contact)))
+        .collect();
+    let count = vcf.matches("BEGIN:VCARD").count();
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, &vcf).with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Exported {} contact(s) to {}", count, path.display());
+        }
+        None => print!("{}", vcf),
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_v3_folding_and_escapes() {
+        let text = "BEGIN:VCARD\r\nVERSION:3.0\r\nUID:abc-1\r\nFN:Alice Smith\r\nN:Smith;Alice;;;\r\nORG:Acme\\, Inc.;R&D\r\nitem1.EMAIL;TYPE=INTERNET,pref:alice@example.com\r\nEMAIL:alice@\r\n work.com\r\nTEL;TYPE=CELL:+1 (555) 010-0001\r\nNICKNAME:Al,Ali\r\nEND:VCARD\r\n";
+        let cards = parse_vcards(text);
+        assert_eq!(cards.len(), 1);
+        let card = &cards[0];
+        assert_eq!(card.uid, "abc-1");
+        assert_eq!(card.full_name, "Alice Smith");
+        assert_eq!(card.organization, "Acme, Inc.");
+        assert_eq!(card.emails, vec!["alice@example.com", "alice@work.com"]);
+        assert_eq!(card.phones, vec!["+1 (555) 010-0001"]);
+        assert_eq!(card.nicknames, vec!["Al", "Ali"]);
+    }
+
+    #[test]
+    fn test_parse_v21_quoted_printable_and_n_fallback() {
+        let text = "BEGIN:VCARD\nVERSION:2.1\nN;CHARSET=UTF-8;ENCODING=QUOTED-PRINTABLE:M=C3=BCller;J=\n=C3=BCrgen;;;\nTEL;CELL:555-0100\nEND:VCARD\n";
+        let cards = parse_vcards(text);
+        assert_eq!(cards[0].full_name, "Jürgen Müller");
+        assert_eq!(cards[0].phones, vec!["555-0100"]);
+    }
+
+    #[test]
+    fn test_parse_v4_uris_and_multiple_cards() {
+        let text = "BEGIN:VCARD\nVERSION:4.0\nFN:Bob\nTEL;VALUE=uri:tel:+15550002\nEMAIL:mailto:bob@example.com\nEND:VCARD\nBEGIN:VCARD\nVERSION:4.0\nFN:Carol\nEND:VCARD\n";
+        let cards = parse_vcards(text);
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].phones, vec!["+15550002"]);
+        assert_eq!(cards[0].emails, vec!["bob@example.com"]);
+        assert_eq!(cards[1].full_name, "Carol");
+    }
+
+    #[test]
+    fn test_render_roundtrip_and_folding() {
+        let card = Card {
+            uid: "corky-alice".into(),
+            full_name: "Alice Smith".into(),
+            emails: vec!["alice@example.com".into()],
+            phones: vec!["+15550001".into()],
+            organization: "Acme; Inc.".into(),
+            nicknames: vec!["Al".into()],
+            title: "CTO".into(),
+            birthday: "04-12".into(),
+        };
+        let vcf = render_vcard(&card);
+        assert!(vcf.contains("N:Smith;Alice;;;\r\n"));
+        assert!(vcf.contains("ORG:Acme\\; Inc.\r\n"));
+        assert!(vcf.contains("BDAY:--04-12\r\n"));
+        assert_eq!(parse_vcards(&vcf), vec![card]);
+
+        let long = Card { full_name: "é".repeat(60), ..Default::default() };
+        let vcf = render_vcard(&long);
+        assert!(vcf.split("\r\n").all(|l| l.len() <= 75));
+        assert_eq!(parse_vcards(&vcf)[0].full_name, long.full_name);
+    }
+
+    #[test]
+    fn test_parse_bday_forms() {
+        assert_eq!(parse_bday("1985-04-12"), "1985-04-12");
+        assert_eq!(parse_bday("19850412T000000Z"), "1985-04-12");
+        assert_eq!(parse_bday("--0412"), "04-12");
+        assert_eq!(parse_bday("--04-12"), "04-12");
+        assert_eq!(parse_bday("April 12"), "");
+    }
+
+    #[test]
+    fn test_merge_and_match() {
+        let mut contacts = BTreeMap::new();
+        contacts.insert(
+            "alice".to_string(),
+            Contact { emails: vec!["Alice@Example.com".into()], organization: "Local Org".into(), ..Default::default() },
+        );
+        contacts.insert("bob".to_string(), Contact { phones: vec!["+1 555 0002".into()], ..Default::default() });
+
+        let card = Card {
+            full_name: "Alice Smith".into(),
+            emails: vec!["alice@example.com".into(), "alice@work.com".into()],
+            organization: "Remote Org".into(),
+            ..Default::default()
+        };
+        assert_eq!(find_match(&contacts, &card).as_deref(), Some("alice"));
+        let phone_card = Card { phones: vec!["+15550002".into()], ..Default::default() };
+        assert_eq!(find_match(&contacts, &phone_card).as_deref(), Some("bob"));
+        let uid_card = Card { uid: "corky-bob".into(), ..Default::default() };
+        assert_eq!(find_match(&contacts, &uid_card).as_deref(), Some("bob"));
+        assert_eq!(find_match(&contacts, &Card { full_name: "Dana".into(), ..Default::default() }), None);
+
+        let alice = contacts.get_mut("alice").unwrap();
+        assert!(merge_card(alice, &card));
+        assert_eq!(alice.full_name, "Alice Smith");
+        assert_eq!(alice.organization, "Local Org");
+        assert_eq!(alice.emails, vec!["Alice@Example.com", "alice@work.com"]);
+        assert!(!merge_card(alice, &card));
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod migrate;
 pub mod new;
+pub mod send_at;
 
 use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
@@ -213,13 +214,34 @@ fn compose_email(
         }
     }
 
-    if attachment_paths.is_empty() {
+    // An iTIP calendar (e.g. from `cal rsvp`) goes inline as text/calendar
+    // alongside the text, which is where calendar clients look for it
+    let mut calendar = None;
+    let mut files = Vec::new();
+    for path_str in attachment_paths {
+        match calendar_part(Path::new(path_str))? {
+            Some(part) if calendar.is_none() => calendar = Some(part),
+            _ => files.push(path_str),
+        }
+    }
+
+    if calendar.is_none() && files.is_empty() {
         let email = builder.body(body.to_string())?;
         Ok(email)
     } else {
-        let mut multipart = MultiPart::mixed().singlepart(SinglePart::plain(body.to_string()));
+        let text = SinglePart::plain(body.to_string());
+        let mut multipart = match calendar {
+            Some(part) => {
+                let alternative = MultiPart::alternative().singlepart(text).singlepart(part);
+                if files.is_empty() {
+                    return Ok(builder.multipart(alternative)?);
+                }
+                MultiPart::mixed().multipart(alternative)
+            }
+            None => MultiPart::mixed().singlepart(text),
+        };
 
-        for path_str in attachment_paths {
+        for path_str in files {
             let path = Path::new(path_str);
             if !path.exists() {
                 bail!("Attachment not found: {}", path_str);
@@ -247,6 +269,21 @@ fn compose_email(
     }
 }
 
+/// A `text/calendar; method=...` part for an `.ics` attachment carrying an
+/// iTIP METHOD, or None for any other file.
+fn calendar_part(path: &Path) -> Result<Option<SinglePart>> {
+    if !path.extension().is_some_and(|e| e.eq_ignore_ascii_case("ics")) || !path.exists() {
+        return Ok(None);
+    }
+    let text = std::fs::read_to_string(path)?;
+    let Some(method) = crate::cal::invite::method(&text) else {
+        return Ok(None);
+    };
+    let content_type = ContentType::parse(&format!("text/calendar; charset=utf-8; method={}", method))
+        .map_err(|_| anyhow::anyhow!("Invalid calendar METHOD '{}' in {}", method, path.display()))?;
+    Ok(Some(SinglePart::builder().header(content_type).body(text)))
+}
+
 /// Push draft to IMAP drafts folder.
 fn push_to_drafts(
     email: &Message,
@@ -296,7 +333,7 @@ fn send_email(
 }
 
 /// Update the status field in a draft file (supports both YAML and legacy formats).
-fn update_draft_status(path: &Path, new_status: &str) -> Result<()> {
+pub(crate) fn update_draft_status(path: &Path, new_status: &str) -> Result<()> {
     let text = std::fs::read_to_string(path)?;
 
     if is_yaml_format(&text) {
@@ -591,4 +628,18 @@ mod tests {
         assert_eq!(subject, "Hello");
         assert!(body.contains("Body here"));
     }
+
+    #[test]
+    fn test_compose_email_inlines_itip_calendar() {
+        let dir = tempfile::tempdir().unwrap();
+        let ics = dir.path().join("rsvp.ics");
+        std::fs::write(&ics, "BEGIN:VCALENDAR\r\nMETHOD:REPLY\r\nBEGIN:VEVENT\r\nUID:x\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n").unwrap();
+        let mut meta = HashMap::new();
+        meta.insert("To".to_string(), "alice@example.com".to_string());
+        let email = compose_email(&meta, "Accepted: Lunch", "See you", "me@example.com", &[ics.display().to_string()]).unwrap();
+        let raw = String::from_utf8(email.formatted()).unwrap();
+        assert!(raw.contains("multipart/alternative"));
+        assert!(raw.contains("text/calendar; charset=utf-8; method=REPLY"));
+        assert!(!raw.contains("Content-Disposition: attachment"));
+    }
 }
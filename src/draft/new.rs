@@ -1,10 +1,11 @@
 //! Scaffold a new draft markdown file.
 
 use anyhow::Result;
-use chrono::Local;
-use std::path::PathBuf;
+use chrono::{Local, Utc};
+use std::path::{Path, PathBuf};
 
-use crate::config::corky_config;
+use super::send_at;
+use crate::config::{contact, corky_config};
 use crate::resolve;
 use crate::util;
 
@@ -19,12 +20,12 @@ pub fn run(
     in_reply_to: Option<&str>,
     mailbox: Option<&str>,
     attachments: &[String],
+    send_at: Option<&str>,
 ) -> Result<()> {
     let drafts_dir = match mailbox {
         Some(name) => resolve::mailbox_dir(name).join("drafts"),
         None => resolve::drafts_dir(),
     };
-    std::fs::create_dir_all(&drafts_dir)?;
 
     // Resolve author name from [owner] in .corky.toml
     let author = corky_config::try_load_config(None)
@@ -32,18 +33,62 @@ pub fn run(
         .map(|o| o.name)
         .unwrap_or_default();
 
+    let contacts = contact::load_contacts(None).unwrap_or_default();
+    let fields = [to, cc.unwrap_or("")];
+    let scheduled = send_at
+        .map(|spec| send_at::resolve(spec, &fields, &contacts, Utc::now()))
+        .transpose()?;
+
+    let path = create_in(
+        &drafts_dir, subject, to, cc, account, from, in_reply_to, &author, attachments,
+    )?;
+
+    // Recipient context from [contacts] goes to stderr; stdout is just the path
+    if let Some((at, zone)) = scheduled {
+        send_at::set_scheduled_at(&path, at)?;
+        eprintln!(
+            "scheduled_at: {} ({}); set status: scheduled once reviewed",
+            at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            zone
+        );
+        for warning in send_at::off_hours_warnings(&fields, &contacts, at) {
+            eprintln!("{}", warning);
+        }
+    }
+    for (name, c) in send_at::recipient_contacts(&fields, &contacts) {
+        if !c.language.is_empty() {
+            eprintln!("Note: {} prefers writing in '{}'", name, c.language);
+        }
+    }
+    println!("{}", path.display());
+    Ok(())
+}
+
+/// Write a new draft file into `drafts_dir` and return its path.
+#[allow(clippy::too_many_arguments)]
+pub fn create_in(
+    drafts_dir: &Path,
+    subject: &str,
+    to: &str,
+    cc: Option<&str>,
+    account: Option<&str>,
+    from: Option<&str>,
+    in_reply_to: Option<&str>,
+    author: &str,
+    attachments: &[String],
+) -> Result<PathBuf> {
+    std::fs::create_dir_all(drafts_dir)?;
     let date = Local::now().format("%Y-%m-%d").to_string();
     let slug = util::slugify(subject);
-    let path = unique_path(&drafts_dir, &date, &slug);
+    let path = unique_path(drafts_dir, &date, &slug);
 
-    let content = render(subject, to, cc, account, from, in_reply_to, &author, attachments);
+    let content = render(subject, to, cc, account, from, in_reply_to, author, attachments);
     std::fs::write(&path, content)?;
-    println!("{}", path.display());
-    Ok(())
+    Ok(path)
 }
 
 /// Find a unique filename, appending -2, -3, etc. on collision.
-fn unique_path(dir: &std::path::Path, date: &str, slug: &str) -> PathBuf {
+fn unique_path(dir: &Path, date: &str, slug: &str) -> PathBuf {
     let base = dir.join(format!("{}-{}.md", date, slug));
     if !base.exists() {
         return base;
@@ -0,0 +1,179 @@
+//! Recipient-aware scheduling: `draft new --send-at` reads wall-clock times
+//! in the recipient's `[contacts.*].timezone`, and draft validation warns
+//! when a scheduled send lands outside their working hours.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::config::contact::{find_by_email, Contact};
+use crate::contact::discover::addresses;
+
+/// Local hours considered polite for a scheduled send: [start, end).
+const WORK_HOURS: (u32, u32) = (8, 20);
+
+/// Contacts among the recipients of `fields` (To, CC), in order, deduplicated.
+pub fn recipient_contacts<'a>(fields: &[&str], contacts: &'a BTreeMap<String, Contact>) -> Vec<(&'a String, &'a Contact)> {
+    let mut found: Vec<(&String, &Contact)> = Vec::new();
+    for field in fields {
+        for (_, email) in addresses(field) {
+            if let Some((name, contact)) = find_by_email(contacts, &email) {
+                if !found.iter().any(|(n, _)| *n == name) {
+                    found.push((name, contact));
+                }
+            }
+        }
+    }
+    found
+}
+
+/// Parse `spec` as a time in `zone`: RFC 3339 (absolute), "YYYY-MM-DD HH:MM",
+/// or "HH:MM" (the next such time after `now`).
+fn wall_clock<Z: TimeZone>(spec: &str, zone: &Z, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let spec = spec.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(spec) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let local = if let Ok(at) = NaiveDateTime::parse_from_str(spec, "%Y-%m-%d %H:%M") {
+        at
+    } else if let Ok(time) = NaiveTime::parse_from_str(spec, "%H:%M") {
+        let today = now.with_timezone(zone).date_naive().and_time(time);
+        match zone.from_local_datetime(&today).earliest() {
+            Some(at) if at.with_timezone(&Utc) > now => today,
+            _ => today + chrono::Duration::days(1),
+        }
+    } else {
+        bail!("Invalid --send-at '{}': expected HH:MM, 'YYYY-MM-DD HH:MM' or RFC 3339", spec);
+    };
+    match zone.from_local_datetime(&local).earliest() {
+        Some(at) => Ok(at.with_timezone(&Utc)),
+        None => bail!("--send-at '{}' does not exist in that time zone (DST gap)", spec),
+    }
+}
+
+/// Resolve `--send-at` against the first recipient contact with a
+/// timezone, else the local zone. Returns the instant and the zone used.
+pub fn resolve(spec: &str, fields: &[&str], contacts: &BTreeMap<String, Contact>, now: DateTime<Utc>) -> Result<(DateTime<Utc>, String)> {
+    let recipient = recipient_contacts(fields, contacts)
+        .into_iter()
+        .find_map(|(name, c)| c.tz().map(|tz| (name, tz)));
+    match recipient {
+        Some((name, tz)) => Ok((wall_clock(spec, &tz, now)?, format!("{} for {}", tz.name(), name))),
+        None => Ok((wall_clock(spec, &Local, now)?, "local time".to_string())),
+    }
+}
+
+/// Add or replace `scheduled_at` in a YAML-frontmatter draft.
+pub fn set_scheduled_at(path: &Path, at: DateTime<Utc>) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let Some(rest) = content.strip_prefix("---\n") else {
+        bail!("{} has no YAML frontmatter", path.display());
+    };
+    let Some(end) = rest.find("\n---") else {
+        bail!("{} has no closing frontmatter delimiter", path.display());
+    };
+    let mut lines: Vec<String> = rest[..end]
+        .lines()
+        .filter(|l| !l.starts_with("scheduled_at:"))
+        .map(str::to_string)
+        .collect();
+    lines.push(format!("scheduled_at: {}", at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)));
+    std::fs::write(path, format!("---\n{}{}", lines.join("\n"), &rest[end..]))?;
+    Ok(())
+}
+
+/// Warnings for recipients whose local time at `at` is outside working hours.
+pub fn off_hours_warnings(fields: &[&str], contacts: &BTreeMap<String, Contact>, at: DateTime<Utc>) -> Vec<String> {
+    recipient_contacts(fields, contacts)
+        .into_iter()
+        .filter_map(|(name, c)| {
+            let tz: Tz = c.tz()?;
+            let local = at.with_timezone(&tz);
+            (local.hour() < WORK_HOURS.0 || local.hour() >= WORK_HOURS.1).then(|| {
+                format!(
+                    "Warning: scheduled_at is {} for {} ({}), outside {:02}:00-{:02}:00",
+                    local.format("%a %H:%M"),
+                    name,
+                    tz.name(),
+                    WORK_HOURS.0,
+                    WORK_HOURS.1
+                )
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contacts() -> BTreeMap<String, Contact> {
+        let mut contacts = BTreeMap::new();
+        contacts.insert(
+            "bob".to_string(),
+            Contact { emails: vec!["bob@example.com".into()], ..Default::default() },
+        );
+        contacts.insert(
+            "kenji".to_string(),
+            Contact { emails: vec!["kenji@example.jp".into()], timezone: "Asia/Tokyo".into(), ..Default::default() },
+        );
+        contacts
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_resolve_uses_recipient_timezone() {
+        let now = utc("2026-03-02T10:00:00Z"); // 19:00 in Tokyo
+        let fields = ["Bob <bob@example.com>, Kenji <kenji@example.jp>"];
+        let (at, zone) = resolve("09:00", &fields, &contacts(), now).unwrap();
+        assert_eq!(at, utc("2026-03-03T00:00:00Z"));
+        assert_eq!(zone, "Asia/Tokyo for kenji");
+
+        let (at, _) = resolve("2026-03-05 14:30", &fields, &contacts(), now).unwrap();
+        assert_eq!(at, utc("2026-03-05T05:30:00Z"));
+        let (at, _) = resolve("2026-03-05T14:30:00+01:00", &fields, &contacts(), now).unwrap();
+        assert_eq!(at, utc("2026-03-05T13:30:00Z"));
+        assert!(resolve("tomorrow", &fields, &contacts(), now).is_err());
+    }
+
+    #[test]
+    fn test_wall_clock_today_or_tomorrow_and_dst_gap() {
+        let berlin: Tz = "Europe/Berlin".parse().unwrap();
+        let now = utc("2026-03-02T06:00:00Z"); // 07:00 in Berlin
+        assert_eq!(wall_clock("08:00", &berlin, now).unwrap(), utc("2026-03-02T07:00:00Z"));
+        assert_eq!(wall_clock("06:30", &berlin, now).unwrap(), utc("2026-03-03T05:30:00Z"));
+        assert!(wall_clock("2026-03-29 02:30", &berlin, now).is_err());
+    }
+
+    #[test]
+    fn test_off_hours_warnings() {
+        let fields = ["kenji@example.jp", "bob@example.com"];
+        assert!(off_hours_warnings(&fields, &contacts(), utc("2026-03-03T01:00:00Z")).is_empty());
+        let warnings = off_hours_warnings(&fields, &contacts(), utc("2026-03-03T15:00:00Z"));
+        assert_eq!(
+            warnings,
+            vec!["Warning: scheduled_at is Wed 00:00 for kenji (Asia/Tokyo), outside 08:00-20:00"]
+        );
+    }
+
+    #[test]
+    fn test_set_scheduled_at_replaces_existing() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("d.md");
+        std::fs::write(&path, "---\nto: a@b.com\nstatus: draft\nscheduled_at: 2020-01-01T00:00:00Z\n---\n\n# Hi\n").unwrap();
+        set_scheduled_at(&path, utc("2026-03-03T00:00:00Z")).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "---\nto: a@b.com\nstatus: draft\nscheduled_at: 2026-03-03T00:00:00Z\n---\n\n# Hi\n"
+        );
+    }
+}
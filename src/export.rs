@@ -0,0 +1,605 @@
+//! Export conversations to mbox or .eml files (`corky export`).
+//!
+//! Threads are selected by scope, label, contact, date range and search
+//! query. Messages whose raw source was retained (`[sync] keep_raw`) are
+//! exported byte-for-byte; the rest are rebuilt as RFC 5322 messages from
+//! their Markdown, with `Message-ID`, `In-Reply-To` and `References`
+//! synthesized deterministically, so an exported archive re-imports with
+//! `corky sync mbox-import` into the same threads.
+//!
+//! Every export writes a JSON manifest listing what was exported, where it
+//! came from and the selection used.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::contact;
+use crate::mailbox::find_unanswered::{resolve_dirs, Scope};
+use crate::resolve;
+use crate::search::index::{DocKind, SearchIndex};
+use crate::search::query::Query;
+use crate::sync::imap_sync::{header_value, parse_msg_date};
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::raw_store;
+use crate::sync::types::{Message, Thread};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// A single mbox file (mboxrd quoting)
+    #[default]
+    Mbox,
+    /// A directory of .eml files, one per message
+    Eml,
+}
+
+/// Which threads and messages to export.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Selection {
+    /// "." for root only, a mailbox name, or None for all
+    pub scope: Option<String>,
+    /// Thread must carry at least one of these labels (case-insensitive)
+    pub labels: Vec<String>,
+    /// Thread must involve one of this contact's addresses
+    pub contact: Option<String>,
+    /// Only messages on or after this date (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Only messages on or before this date (YYYY-MM-DD)
+    pub until: Option<String>,
+    /// Thread must match this `corky search` query
+    pub search: Option<String>,
+}
+
+/// One exported message.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestItem {
+    /// .eml file name, or the 1-based position in the mbox
+    pub location: String,
+    pub message_id: String,
+    /// Conversation file, relative to the data directory
+    pub source: String,
+    pub thread_id: String,
+    pub subject: String,
+    pub from: String,
+    pub date: String,
+    /// Exported from the retained raw source rather than rebuilt from Markdown
+    pub raw_headers: bool,
+    pub bytes: usize,
+}
+
+/// Written next to the export as JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportManifest {
+    pub exported_at: String,
+    pub format: ExportFormat,
+    pub selection: Selection,
+    pub threads: usize,
+    pub messages: usize,
+    pub items: Vec<ManifestItem>,
+}
+
+/// Path of the manifest for an export written to `out`.
+pub fn manifest_path(format: ExportFormat, out: &Path) -> PathBuf {
+    match format {
+        ExportFormat::Mbox => out.with_extension("manifest.json"),
+        ExportFormat::Eml => out.join("manifest.json"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Selection
+// ---------------------------------------------------------------------------
+
+fn parse_day(flag: &str, value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid {} date '{}' (expected YYYY-MM-DD)", flag, value))
+}
+
+/// Resolved filters, ready to apply.
+struct Filters {
+    labels: Vec<String>,
+    contact_emails: Vec<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    search_hits: Option<HashSet<String>>,
+}
+
+impl Filters {
+    fn new(data_dir: &Path, selection: &Selection) -> Result<Self> {
+        let contact_emails = match selection.contact {
+            None => vec![],
+            Some(ref name) => {
+                let contacts = contact::load_contacts(None)?;
+                let c = contacts
+                    .get(name)
+                    .ok_or_else(|| anyhow::anyhow!("Contact '{}' not found in .corky.toml", name))?;
+                if c.emails.is_empty() {
+                    bail!("Contact '{}' has no email addresses to match", name);
+                }
+                c.emails.iter().map(|e| e.to_lowercase()).collect()
+            }
+        };
+
+        let since = match selection.since {
+            Some(ref s) => Some(parse_day("--since", s)?.and_hms_opt(0, 0, 0).unwrap().and_utc()),
+            None => None,
+        };
+        let until = match selection.until {
+            Some(ref s) => Some(parse_day("--until", s)?.and_hms_opt(23, 59, 59).unwrap().and_utc()),
+            None => None,
+        };
+        if let (Some(a), Some(b)) = (since, until) {
+            if a > b {
+                bail!("--since is after --until");
+            }
+        }
+
+        let search_hits = match selection.search {
+            None => None,
+            Some(ref q) => {
+                let query = Query::parse(q)?;
+                if query.is_empty() {
+                    bail!("Empty --search query");
+                }
+                let (index, _) = SearchIndex::open(data_dir, false)?;
+                let hits = crate::search::search(&index, data_dir, &query, usize::MAX);
+                Some(
+                    hits.into_iter()
+                        .filter(|h| h.kind == DocKind::Conversation)
+                        .map(|h| h.path)
+                        .collect(),
+                )
+            }
+        };
+
+        Ok(Filters {
+            labels: selection.labels.iter().map(|l| l.to_lowercase()).collect(),
+            contact_emails,
+            since,
+            until,
+            search_hits,
+        })
+    }
+
+    fn thread_matches(&self, rel: &str, thread: &Thread) -> bool {
+        if !self.labels.is_empty()
+            && !thread
+                .labels
+                .iter()
+                .any(|l| self.labels.contains(&l.to_lowercase()))
+        {
+            return false;
+        }
+        if !self.contact_emails.is_empty() {
+            let involved = thread.messages.iter().any(|m| {
+                let fields = format!("{} {} {}", m.from, m.to, m.cc).to_lowercase();
+                self.contact_emails.iter().any(|e| fields.contains(e.as_str()))
+            });
+            if !involved {
+                return false;
+            }
+        }
+        if let Some(ref hits) = self.search_hits {
+            if !hits.contains(rel) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn message_matches(&self, msg: &Message) -> bool {
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let date = parse_msg_date(&msg.date);
+        if date.timestamp() == 0 {
+            // Undated messages can't be shown to fall inside the range
+            return false;
+        }
+        self.since.is_none_or(|s| date >= s) && self.until.is_none_or(|u| date <= u)
+    }
+}
+
+/// A selected thread: its file (relative to the data dir) and the messages
+/// to export, in order.
+pub struct Selected {
+    pub source: String,
+    pub thread: Thread,
+    pub messages: Vec<Message>,
+}
+
+/// Threads and messages matching `selection`: root first, then mailboxes,
+/// each sorted by file name.
+pub fn select(data_dir: &Path, selection: &Selection) -> Result<Vec<Selected>> {
+    let filters = Filters::new(data_dir, selection)?;
+    let scope = Scope::from_arg(selection.scope.as_deref());
+
+    let mut selected = Vec::new();
+    for (_, dir) in resolve_dirs(&scope)? {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "md"))
+            .collect();
+        files.sort();
+
+        for path in files {
+            let text = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let Some(thread) = parse_thread_markdown(&text) else {
+                continue;
+            };
+            let rel = path
+                .strip_prefix(data_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if !filters.thread_matches(&rel, &thread) {
+                continue;
+            }
+            let messages: Vec<Message> = thread
+                .messages
+                .iter()
+                .filter(|m| filters.message_matches(m))
+                .cloned()
+                .collect();
+            if messages.is_empty() {
+                continue;
+            }
+            selected.push(Selected {
+                source: rel,
+                thread,
+                messages,
+            });
+        }
+    }
+    Ok(selected)
+}
+
+// ---------------------------------------------------------------------------
+// RFC 5322 rendering
+// ---------------------------------------------------------------------------
+
+/// FNV-1a hash as 16 hex chars.
+fn fnv_hex(content: &str) -> String {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x00000100000001B3;
+    let mut hash = FNV_OFFSET;
+    for byte in content.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Stable Message-ID for a message rebuilt from Markdown.
+pub fn synthetic_message_id(thread_id: &str, msg: &Message) -> String {
+    format!(
+        "<{}@corky.invalid>",
+        fnv_hex(&format!("{}\n{}\n{}", thread_id, msg.from, msg.date))
+    )
+}
+
+/// RFC 2047 Q-encode a header fragment if it isn't plain ASCII.
+fn encode_word(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+    let mut out = String::from("=?UTF-8?Q?");
+    for b in text.bytes() {
+        match b {
+            b' ' => out.push('_'),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'-' | b'!' | b'*' | b'+' | b'/' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("={:02X}", b)),
+        }
+    }
+    out.push_str("?=");
+    out
+}
+
+/// Encode an address header value: display names are encoded, addresses kept.
+fn encode_addresses(value: &str) -> String {
+    value
+        .split(',')
+        .map(|addr| {
+            let addr = addr.trim();
+            match addr.rfind('<') {
+                Some(i) => {
+                    let name = addr[..i].trim().trim_matches('"');
+                    if name.is_empty() {
+                        addr[i..].to_string()
+                    } else if name.is_ascii() {
+                        format!("\"{}\" {}", name.replace('"', ""), &addr[i..])
+                    } else {
+                        format!("{} {}", encode_word(name), &addr[i..])
+                    }
+                }
+                None => encode_word(addr),
+            }
+        })
+        .filter(|a| !a.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Bare address for the mbox `From ` line.
+fn envelope_sender(from: &str) -> String {
+    let addr = match (from.rfind('<'), from.rfind('>')) {
+        (Some(a), Some(b)) if a < b => &from[a + 1..b],
+        _ => from.trim(),
+    };
+    if addr.contains('@') && !addr.contains(char::is_whitespace) {
+        addr.to_string()
+    } else {
+        "MAILER-DAEMON".to_string()
+    }
+}
+
+/// Build an RFC 5322 message (LF line endings) for `msg` in `thread`.
+/// `parents` are the Message-IDs of earlier messages in the thread.
+pub fn render_message(thread: &Thread, msg: &Message, message_id: &str, parents: &[String]) -> String {
+    let mut headers = vec![
+        format!("Date: {}", msg.date),
+        format!("From: {}", encode_addresses(&msg.from)),
+    ];
+    if !msg.to.is_empty() {
+        headers.push(format!("To: {}", encode_addresses(&msg.to)));
+    }
+    if !msg.cc.is_empty() {
+        headers.push(format!("Cc: {}", encode_addresses(&msg.cc)));
+    }
+    headers.push(format!("Subject: {}", encode_word(&thread.subject)));
+    headers.push(format!("Message-ID: {}", message_id));
+    if let Some(parent) = parents.last() {
+        headers.push(format!("In-Reply-To: {}", parent));
+        headers.push(format!("References: {}", parents.join(" ")));
+    }
+    headers.push("MIME-Version: 1.0".to_string());
+    headers.push("Content-Type: text/plain; charset=utf-8".to_string());
+    headers.push("Content-Transfer-Encoding: 8bit".to_string());
+    headers.push(format!("X-Corky-Thread-ID: {}", thread.id));
+    if !thread.labels.is_empty() {
+        headers.push(format!("X-Corky-Labels: {}", thread.labels.join(", ")));
+    }
+    if !thread.accounts.is_empty() {
+        headers.push(format!("X-Corky-Accounts: {}", thread.accounts.join(", ")));
+    }
+
+    let mut out = headers.join("\n");
+    out.push_str("\n\n");
+    out.push_str(msg.body.trim_end());
+    out.push('\n');
+    out
+}
+
+/// mboxrd-quote body lines: any `>*From ` gains one `>`.
+fn mbox_quote(message: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(message.len());
+    for line in message.split_inclusive(|&b| b == b'\n') {
+        let depth = line.iter().take_while(|&&b| b == b'>').count();
+        if line[depth..].starts_with(b"From ") {
+            out.push(b'>');
+        }
+        out.extend_from_slice(line);
+    }
+    out
+}
+
+/// The retained raw source of `msg` and its Message-ID, if the raw store has it.
+fn raw_source(store: &Path, msg: &Message) -> Option<(Vec<u8>, String)> {
+    if msg.raw.is_empty() {
+        return None;
+    }
+    let raw = raw_store::get(store, &msg.raw).ok()?;
+    let parsed = mailparse::parse_mail(&raw).ok()?;
+    let message_id = header_value(&parsed, "Message-ID")
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())?;
+    Some((raw, message_id))
+}
+
+// ---------------------------------------------------------------------------
+// Writing
+// ---------------------------------------------------------------------------
+
+/// Export `selection` from `data_dir` to `out`. Refuses to overwrite an
+/// existing mbox file or a non-empty .eml directory.
+pub fn export(data_dir: &Path, selection: &Selection, format: ExportFormat, out: &Path) -> Result<ExportManifest> {
+    match format {
+        ExportFormat::Mbox if out.exists() => {
+            bail!("{} already exists; choose a new output path", out.display())
+        }
+        ExportFormat::Eml if out.is_file() => bail!("{} is a file, expected a directory", out.display()),
+        ExportFormat::Eml
+            if std::fs::read_dir(out).is_ok_and(|mut d| d.next().is_some()) =>
+        {
+            bail!("{} is not empty; choose a new output directory", out.display())
+        }
+        _ => {}
+    }
+
+    let threads = select(data_dir, selection)?;
+    let store = data_dir.join(raw_store::RAW_DIR);
+
+    let mut mbox = match format {
+        ExportFormat::Mbox => {
+            if let Some(parent) = out.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            Some(std::io::BufWriter::new(
+                std::fs::File::create(out)
+                    .with_context(|| format!("Failed to create {}", out.display()))?,
+            ))
+        }
+        ExportFormat::Eml => {
+            std::fs::create_dir_all(out)
+                .with_context(|| format!("Failed to create {}", out.display()))?;
+            None
+        }
+    };
+
+    let mut items = Vec::new();
+    for sel in &threads {
+        let slug = Path::new(&sel.source)
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        // Parents come from the whole thread, so date-filtered exports still
+        // reference the messages they reply to
+        let mut parents: Vec<String> = Vec::new();
+        for msg in &sel.thread.messages {
+            let raw = raw_source(&store, msg);
+            let message_id = match raw {
+                Some((_, ref id)) => id.clone(),
+                None => synthetic_message_id(&sel.thread.id, msg),
+            };
+            let wanted = sel
+                .messages
+                .iter()
+                .any(|m| m.from == msg.from && m.date == msg.date);
+            if wanted {
+                let raw_headers = raw.is_some();
+                let message = match raw {
+                    Some((bytes, _)) => bytes,
+                    None => render_message(&sel.thread, msg, &message_id, &parents).into_bytes(),
+                };
+                let n = items.len() + 1;
+                let (location, bytes) = match mbox {
+                    Some(ref mut w) => {
+                        let date = parse_msg_date(&msg.date);
+                        let mut quoted = mbox_quote(&message);
+                        if !quoted.ends_with(b"\n") {
+                            quoted.push(b'\n');
+                        }
+                        writeln!(
+                            w,
+                            "From {} {}",
+                            envelope_sender(&msg.from),
+                            date.format("%a %b %e %H:%M:%S %Y")
+                        )?;
+                        w.write_all(&quoted)?;
+                        w.write_all(b"\n")?;
+                        (n.to_string(), quoted.len())
+                    }
+                    None => {
+                        let name = format!("{:05}-{}.eml", n, slug);
+                        let data = if raw_headers {
+                            message
+                        } else {
+                            String::from_utf8_lossy(&message).replace('\n', "\r\n").into_bytes()
+                        };
+                        std::fs::write(out.join(&name), &data)?;
+                        (name, data.len())
+                    }
+                };
+                items.push(ManifestItem {
+                    location,
+                    message_id: message_id.clone(),
+                    source: sel.source.clone(),
+                    thread_id: sel.thread.id.clone(),
+                    subject: sel.thread.subject.clone(),
+                    from: msg.from.clone(),
+                    date: msg.date.clone(),
+                    raw_headers,
+                    bytes,
+                });
+            }
+            parents.push(message_id);
+        }
+    }
+    if let Some(mut w) = mbox {
+        w.flush()?;
+    }
+
+    let manifest = ExportManifest {
+        exported_at: Utc::now().to_rfc3339(),
+        format,
+        selection: selection.clone(),
+        threads: threads.len(),
+        messages: items.len(),
+        items,
+    };
+    std::fs::write(
+        manifest_path(format, out),
+        serde_json::to_string_pretty(&manifest)? + "\n",
+    )?;
+    Ok(manifest)
+}
+
+/// corky export OUT [--format mbox|eml] [--label L]... [--contact NAME]
+///   [--since DATE] [--until DATE] [--search QUERY] [--scope SCOPE]
+pub fn run(selection: &Selection, format: ExportFormat, out: &Path) -> Result<()> {
+    let manifest = export(&resolve::data_dir(), selection, format, out)?;
+    if manifest.messages == 0 {
+        println!("No messages matched; wrote an empty export to {}", out.display());
+    } else {
+        println!(
+            "Exported {} message(s) from {} thread(s) to {}",
+            manifest.messages,
+            manifest.threads,
+            out.display()
+        );
+    }
+    println!("Manifest: {}", manifest_path(format, out).display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(from: &str, body: &str) -> Message {
+        Message {
+            id: String::new(),
+            thread_id: "t".to_string(),
+            from: from.to_string(),
+            to: "Bob <bob@example.com>".to_string(),
+            cc: String::new(),
+            date: "Mon, 10 Feb 2025 10:00:00 +0000".to_string(),
+            subject: "Hi".to_string(),
+            body: body.to_string(),
+            raw: String::new(),
+        }
+    }
+
+    #[test]
+    fn render_encodes_non_ascii_and_threads() {
+        let thread = Thread {
+            id: "café plans".to_string(),
+            subject: "Café plans".to_string(),
+            labels: vec!["inbox".to_string()],
+            ..Default::default()
+        };
+        let m = msg("José Díaz <jose@example.com>", "Hola");
+        let out = render_message(&thread, &m, "<b@x>", &["<a@x>".to_string()]);
+        assert!(out.contains("From: =?UTF-8?Q?Jos=C3=A9_D=C3=ADaz?= <jose@example.com>\n"));
+        assert!(out.contains("Subject: =?UTF-8?Q?Caf=C3=A9_plans?=\n"));
+        assert!(out.contains("In-Reply-To: <a@x>\nReferences: <a@x>\n"));
+        assert!(out.ends_with("\n\nHola\n"));
+    }
+
+    #[test]
+    fn mbox_quoting_and_envelope() {
+        assert_eq!(mbox_quote(b"a\nFrom here\n>From x\n"), b"a\n>From here\n>>From x\n");
+        assert_eq!(envelope_sender("Alice <alice@example.com>"), "alice@example.com");
+        assert_eq!(envelope_sender("Alice"), "MAILER-DAEMON");
+    }
+
+    #[test]
+    fn synthetic_ids_are_stable() {
+        let m = msg("a@example.com", "x");
+        assert_eq!(synthetic_message_id("t", &m), synthetic_message_id("t", &m));
+        assert_ne!(synthetic_message_id("t", &m), synthetic_message_id("u", &m));
+    }
+}
@@ -10,20 +10,37 @@ const COMMANDS: &[(&str, &str)] = &[
     ("sync account NAME", "Sync one account"),
     ("sync routes", "Apply routing to existing conversations"),
     ("sync mailbox [NAME]", "Push/pull shared mailboxes"),
+    ("sync slack [--channel C]", "Incremental Slack sync via the Web API"),
     ("sync-auth", "Gmail OAuth setup"),
     ("list-folders [ACCOUNT]", "List IMAP folders for an account"),
-    ("draft new SUBJECT --to EMAIL", "Scaffold a new draft file"),
+    ("draft new SUBJECT --to EMAIL [--send-at HH:MM]", "Scaffold a new draft file (send time in the recipient's timezone)"),
     ("draft validate [FILE|SCOPE...]", "Validate draft markdown files"),
     ("draft push FILE [--send]", "Save draft to email"),
     ("add-label LABEL --account NAME", "Add a label to an account's sync config"),
     ("contact add NAME --email EMAIL", "Add a contact with context docs"),
     ("contact add --from SLUG", "Create contact from a conversation"),
-    ("contact info NAME", "Show contact info and thread history"),
+    ("contact info NAME", "Show contact info, thread history and relationship stats"),
+    ("contact discover [--create RANK|NAME|EMAIL] [--all]", "Rank frequent correspondents who aren't contacts yet"),
+    ("contact rename OLD NEW", "Rename a contact everywhere, keeping the old name as an alias"),
+    ("contact merge FROM INTO", "Merge a duplicate contact into another"),
+    ("contact import FILE.vcf [--dry-run]", "Import contacts from a vCard file"),
+    ("contact export [OUT] [--contact NAME]", "Export contacts as vCard"),
+    ("contact carddav [--dry-run]", "Two-way sync with a CardDAV address book"),
     ("watch [--interval N]", "Poll IMAP and sync on an interval"),
     ("unanswered [SCOPE] [--from NAME]", "Find threads awaiting a reply"),
+    ("followups [SCOPE] [--after 5d] [--draft]", "Find threads awaiting their reply; scaffold nudge drafts"),
+    ("thread done|snooze|wait|reopen CONVERSATION", "Triage a thread; hidden from unanswered until it resurfaces"),
+    ("thread list [SCOPE]", "List done, snoozed and waiting threads"),
+    ("cal rsvp CONVERSATION accept|decline|tentative [--send] [--add]", "Answer a meeting invitation with an iTIP reply draft"),
+    ("search QUERY [--limit N] [--rebuild]", "Full-text search across conversations and drafts"),
+    ("export OUT [--type mbox|eml] [--label L] [--contact NAME] [--since D] [--until D] [--search Q]", "Export threads to mbox or .eml with a manifest"),
+    ("reprocess [SCOPE] [--dry-run]", "Regenerate Markdown from the raw message store"),
+    ("mcp [--scope MAILBOX] [--as PRINCIPAL]", "Run an MCP server over stdio for agents"),
+    ("serve [--port N] [--token TOKEN]", "Local HTTP/JSON API with SSE sync events"),
     ("audit-docs", "Audit instruction files"),
     ("migrate", "Migrate from accounts.toml to .corky.toml"),
     ("help", "Show this reference"),
+    ("--format table|json|jsonl", "Output format for listing commands"),
 ];
 
 const MAILBOX_COMMANDS: &[(&str, &str)] = &[
@@ -6,10 +6,13 @@ pub mod config;
 pub mod contact;
 pub mod doc;
 pub mod draft;
+pub mod export;
 pub mod init;
 pub mod filter;
 pub mod label;
 pub mod mailbox;
+pub mod mcp;
+pub mod output;
 pub mod resolve;
 pub mod skill;
 pub mod sync;
@@ -18,6 +21,8 @@ pub mod watch;
 pub mod help;
 pub mod audit_docs;
 pub mod schedule;
+pub mod search;
+pub mod serve;
 pub mod social;
 pub mod topics;
 pub mod transcribe;
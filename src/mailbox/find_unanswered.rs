@@ -1,16 +1,25 @@
 //! Find threads where the last message is not from the owner.
+//!
+//! "The owner" is an [`Identity`]: account addresses, `[owner] name` and
+//! `[owner] aliases`, so replies from any account or alias count.
 
 use anyhow::{bail, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Serialize;
 use std::path::PathBuf;
 
+use super::triage;
+use crate::config::owner::Identity;
+use crate::output::{emit, OutputFormat, Report};
 use crate::resolve;
+use crate::sync::imap_sync::parse_msg_date;
+use crate::sync::markdown::parse_thread_markdown;
 
 static SENDER_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?m)^## (.+?) \u{2014}").unwrap());
 static DATE_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"\*\*Last updated\*\*:\s*(\S+)").unwrap());
+    Lazy::new(|| Regex::new(r"\*\*Last updated\*\*:[ \t]*(.+)").unwrap());
 static LABELS_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\*\*Labels?\*\*:\s*(.+)").unwrap());
 
@@ -45,7 +54,7 @@ fn last_sender(text: &str) -> String {
 fn thread_date(text: &str) -> String {
     DATE_RE
         .captures(text)
-        .map(|cap| cap[1].to_string())
+        .map(|cap| cap[1].trim().to_string())
         .unwrap_or_default()
 }
 
@@ -56,11 +65,12 @@ fn thread_labels(text: &str) -> String {
         .unwrap_or_default()
 }
 
-/// Scan a conversations directory and return unanswered threads.
+/// Scan a conversations directory and return unanswered threads, skipping
+/// those triaged away (done, snoozed, waiting) that haven't resurfaced.
 /// Each entry: (date, labels, filename, sender).
 fn scan_dir(
     dir: &std::path::Path,
-    from_lower: &str,
+    me: &Identity,
 ) -> Result<Vec<(String, String, String, String)>> {
     let mut results = Vec::new();
     if !dir.is_dir() {
@@ -70,11 +80,17 @@ fn scan_dir(
     let mut md_files = Vec::new();
     collect_md_files(dir, &mut md_files)?;
     md_files.sort();
+    let now = chrono::Utc::now();
 
     for thread_file in &md_files {
         let text = std::fs::read_to_string(thread_file)?;
         let sender = last_sender(&text);
-        if !sender.is_empty() && !sender.to_lowercase().contains(from_lower) {
+        if !sender.is_empty() && !me.is_me(&sender) {
+            if text.contains("**State**:")
+                && parse_thread_markdown(&text).is_some_and(|t| triage::is_hidden(&t, me, now))
+            {
+                continue;
+            }
             let labels = {
                 let l = thread_labels(&text);
                 if l.is_empty() {
@@ -107,7 +123,7 @@ fn scan_dir(
 }
 
 /// Build list of (group_label, conversations_dir) pairs based on scope.
-fn resolve_dirs(scope: &Scope) -> Result<Vec<(String, PathBuf)>> {
+pub(crate) fn resolve_dirs(scope: &Scope) -> Result<Vec<(String, PathBuf)>> {
     let data = resolve::data_dir();
     let root_convos = data.join("conversations");
     let mailboxes_base = resolve::mailboxes_base_dir();
@@ -149,50 +165,103 @@ fn resolve_dirs(scope: &Scope) -> Result<Vec<(String, PathBuf)>> {
     Ok(dirs)
 }
 
-/// corky unanswered [SCOPE] [--from NAME]
-pub fn run(scope: Scope, from_name: &str) -> Result<()> {
-    let dirs = resolve_dirs(&scope)?;
+/// A thread whose last message is not from the owner.
+#[derive(Debug, Clone, Serialize)]
+pub struct UnansweredThread {
+    /// "Root" or the mailbox name
+    pub scope: String,
+    /// Conversation filename
+    pub file: String,
+    pub labels: Vec<String>,
+    /// Sender of the last message
+    pub last_from: String,
+    /// `**Last updated**` value (or "unknown")
+    pub last_updated: String,
+}
 
-    if dirs.is_empty() {
-        eprintln!("No conversations directories found.");
-        std::process::exit(1);
+/// Result of `corky unanswered` (JSON: array of [`UnansweredThread`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct UnansweredReport {
+    pub threads: Vec<UnansweredThread>,
+    /// Whether multiple scopes were scanned (groups table output)
+    #[serde(skip)]
+    pub multi: bool,
+}
+
+impl Report for UnansweredReport {
+    fn render_table(&self) -> String {
+        let mut out = String::new();
+        if self.threads.is_empty() {
+            out.push_str("No unanswered threads found.\n");
+            return out;
+        }
+        let mut scopes: Vec<&str> = Vec::new();
+        for t in &self.threads {
+            if !scopes.contains(&t.scope.as_str()) {
+                scopes.push(&t.scope);
+            }
+        }
+        for scope in scopes {
+            let threads: Vec<_> = self.threads.iter().filter(|t| t.scope == scope).collect();
+            if self.multi {
+                out.push_str(&format!("{} ({} unanswered):\n\n", scope, threads.len()));
+            } else {
+                out.push_str(&format!("Unanswered threads ({}):\n\n", threads.len()));
+            }
+            for t in threads {
+                out.push_str(&format!("  [{}] {}\n", t.labels.join(", "), t.file));
+                out.push_str(&format!("           Last from: {} ({})\n\n", t.last_from, t.last_updated));
+            }
+        }
+        out
     }
+}
 
-    let from_lower = from_name.to_lowercase();
-    let multi = dirs.len() > 1;
+/// Collect unanswered threads for a scope, newest first within each scope.
+pub fn collect(scope: &Scope, me: &Identity) -> Result<UnansweredReport> {
+    collect_dirs(&resolve_dirs(scope)?, me)
+}
 
-    let mut total = 0usize;
+/// Collect unanswered threads from explicit (group label, conversations dir) pairs.
+pub fn collect_dirs(dirs: &[(String, PathBuf)], me: &Identity) -> Result<UnansweredReport> {
+    let mut threads = Vec::new();
 
-    for (label, dir) in &dirs {
-        let mut unanswered = scan_dir(dir, &from_lower)?;
-        if unanswered.is_empty() {
-            continue;
-        }
+    for (label, dir) in dirs {
+        let mut unanswered = scan_dir(dir, me)?;
         // Sort by date descending (newest first)
-        unanswered.sort_by(|a, b| b.0.cmp(&a.0));
-        total += unanswered.len();
-
-        if multi {
-            println!("{} ({} unanswered):\n", label, unanswered.len());
-        } else {
-            println!("Unanswered threads ({}):\n", unanswered.len());
-        }
-
-        for (date, labels, filename, sender) in &unanswered {
-            println!("  [{}] {}", labels, filename);
-            println!("           Last from: {} ({})", sender, date);
-            println!();
+        unanswered.sort_by_key(|t| std::cmp::Reverse(parse_msg_date(&t.0)));
+        for (date, labels, filename, sender) in unanswered {
+            threads.push(UnansweredThread {
+                scope: label.clone(),
+                file: filename,
+                labels: labels
+                    .split(',')
+                    .map(|l| l.trim().to_string())
+                    .filter(|l| !l.is_empty())
+                    .collect(),
+                last_from: sender,
+                last_updated: date,
+            });
         }
     }
 
-    if total == 0 {
-        println!("No unanswered threads found.");
-    }
+    Ok(UnansweredReport {
+        threads,
+        multi: dirs.len() > 1,
+    })
+}
 
-    Ok(())
+/// corky unanswered [SCOPE] [--from NAME]
+pub fn run(scope: Scope, me: &Identity, format: OutputFormat) -> Result<()> {
+    if resolve_dirs(&scope)?.is_empty() {
+        eprintln!("No conversations directories found.");
+        std::process::exit(1);
+    }
+    emit(format, &collect(&scope, me)?)
 }
 
-fn collect_md_files(dir: &std::path::Path, out: &mut Vec<PathBuf>) -> Result<()> {
+pub(crate) fn collect_md_files(dir: &std::path::Path, out: &mut Vec<PathBuf>) -> Result<()> {
     if !dir.is_dir() {
         return Ok(());
     }
@@ -0,0 +1,422 @@
+//! Find threads awaiting *their* reply: the owner sent the last message and
+//! nobody has answered within the follow-up threshold.
+//!
+//! The inverse of `find_unanswered`. Thresholds come from `--after` (else
+//! `[followups] after`, else 5 days), `[followups] labels`, and each
+//! contact's `followup_after`; the most specific one applies.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use super::find_unanswered::{collect_md_files, resolve_dirs, Scope};
+use super::triage;
+use crate::config::contact::{self, Contact};
+use crate::config::corky_config;
+use crate::config::owner::Identity;
+use crate::contact::identity::ContactIndex;
+use crate::draft::new::create_in;
+use crate::output::{emit, OutputFormat, Report};
+use crate::sync::imap_sync::{header_value, parse_msg_date};
+use crate::sync::markdown::parse_thread_markdown;
+use crate::sync::raw_store;
+use crate::sync::types::{Message, Thread, ThreadStatus};
+use crate::util::parse_span;
+
+/// Threshold when neither `--after` nor `[followups] after` is set.
+pub const DEFAULT_AFTER: &str = "5d";
+
+/// Follow-up thresholds, most specific first: contact, label, default.
+#[derive(Debug, Clone)]
+pub struct Thresholds {
+    default: (Duration, String),
+    labels: HashMap<String, (Duration, String)>,
+    contacts: BTreeMap<String, (Duration, String)>,
+}
+
+fn span(spec: &str, what: &str) -> Result<(Duration, String)> {
+    match parse_span(spec) {
+        Some(d) => Ok((d, spec.trim().to_string())),
+        None => bail!("Invalid {} '{}': expected a span like 36h, 5d or 2w", what, spec),
+    }
+}
+
+impl Thresholds {
+    /// Build from `--after`, `[followups]` and `[contacts.*].followup_after`.
+    pub fn new(
+        after: Option<&str>,
+        config: &corky_config::FollowupsConfig,
+        contacts: &BTreeMap<String, Contact>,
+    ) -> Result<Self> {
+        let default = match after {
+            Some(spec) => span(spec, "--after")?,
+            None if !config.after.is_empty() => span(&config.after, "[followups] after")?,
+            None => span(DEFAULT_AFTER, "default")?,
+        };
+        let mut labels = HashMap::new();
+        for (label, spec) in &config.labels {
+            labels.insert(label.clone(), span(spec, &format!("[followups.labels] {}", label))?);
+        }
+        let mut by_contact = BTreeMap::new();
+        for (name, c) in contacts {
+            if !c.followup_after.is_empty() {
+                by_contact.insert(name.clone(), span(&c.followup_after, &format!("[contacts.{}] followup_after", name))?);
+            }
+        }
+        Ok(Thresholds { default, labels, contacts: by_contact })
+    }
+
+    /// The threshold for a thread: the shortest among its contacts' own,
+    /// else the shortest among its labels', else the default.
+    pub fn for_thread(&self, labels: &[String], contacts: &[String]) -> &(Duration, String) {
+        let by_contact = contacts.iter().filter_map(|c| self.contacts.get(c)).min_by_key(|(d, _)| *d);
+        let by_label = labels.iter().filter_map(|l| self.labels.get(l)).min_by_key(|(d, _)| *d);
+        by_contact.or(by_label).unwrap_or(&self.default)
+    }
+}
+
+/// A thread whose last message is from the owner and has gone unanswered.
+#[derive(Debug, Clone, Serialize)]
+pub struct Followup {
+    /// "Root" or the mailbox name
+    pub scope: String,
+    /// Conversation file, relative to the scope's conversations/
+    pub file: String,
+    pub subject: String,
+    pub labels: Vec<String>,
+    /// Contacts on the thread (by email, phone or handle)
+    pub contacts: Vec<String>,
+    /// Recipients of the owner's last message
+    pub to: String,
+    /// Date of the owner's last message (RFC 2822)
+    pub last_sent: String,
+    pub days_waiting: i64,
+    /// The threshold that applied ("5d")
+    pub after: String,
+    /// Nudge draft for this thread (created or already present), with `--draft`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<String>,
+}
+
+/// Result of `corky followups` (JSON: array of [`Followup`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct FollowupReport {
+    pub threads: Vec<Followup>,
+    /// Whether multiple scopes were scanned (groups table output)
+    #[serde(skip)]
+    pub multi: bool,
+}
+
+impl Report for FollowupReport {
+    fn render_table(&self) -> String {
+        let mut out = String::new();
+        if self.threads.is_empty() {
+            out.push_str("No threads awaiting a follow-up.\n");
+            return out;
+        }
+        let mut scopes: Vec<&str> = Vec::new();
+        for t in &self.threads {
+            if !scopes.contains(&t.scope.as_str()) {
+                scopes.push(&t.scope);
+            }
+        }
+        for scope in scopes {
+            let threads: Vec<_> = self.threads.iter().filter(|t| t.scope == scope).collect();
+            if self.multi {
+                out.push_str(&format!("{} ({} awaiting their reply):\n\n", scope, threads.len()));
+            } else {
+                out.push_str(&format!("Awaiting their reply ({}):\n\n", threads.len()));
+            }
+            for t in threads {
+                out.push_str(&format!("  [{}] {}\n", t.labels.join(", "), t.file));
+                let who = if t.contacts.is_empty() { t.to.clone() } else { t.contacts.join(", ") };
+                out.push_str(&format!(
+                    "           {}d since you wrote to {} (after {})\n",
+                    t.days_waiting, who, t.after
+                ));
+                if let Some(draft) = &t.draft {
+                    out.push_str(&format!("           Nudge draft: {}\n", draft));
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+/// The owner's last message in `thread`, if the thread ends with one.
+fn last_from_owner<'a>(thread: &'a Thread, me: &Identity) -> Option<&'a Message> {
+    let last = thread.messages.last()?;
+    me.is_me(&last.from).then_some(last)
+}
+
+/// Threads due for a follow-up in one conversations directory, oldest first.
+fn scan_dir(
+    scope: &str,
+    dir: &Path,
+    me: &Identity,
+    thresholds: &Thresholds,
+    index: &ContactIndex,
+    now: DateTime<Utc>,
+) -> Result<Vec<(DateTime<Utc>, Followup)>> {
+    let mut results = Vec::new();
+    let mut md_files = Vec::new();
+    collect_md_files(dir, &mut md_files)?;
+    md_files.sort();
+
+    for path in &md_files {
+        let Some(thread) = parse_thread_markdown(&std::fs::read_to_string(path)?) else {
+            continue;
+        };
+        let Some(last) = last_from_owner(&thread, me) else {
+            continue;
+        };
+        // Waiting threads still count: that is what a follow-up is for
+        if thread.state.status != ThreadStatus::Waiting && triage::is_hidden(&thread, me, now) {
+            continue;
+        }
+        let sent = [&last.date, &thread.last_date]
+            .into_iter()
+            .map(|d| parse_msg_date(d.as_str()))
+            .find(|d| d.timestamp() != 0);
+        let Some(sent) = sent else {
+            continue;
+        };
+        let contacts = index.thread_contacts(&thread);
+        let (threshold, after) = thresholds.for_thread(&thread.labels, &contacts);
+        if now - sent < *threshold {
+            continue;
+        }
+        results.push((
+            sent,
+            Followup {
+                scope: scope.to_string(),
+                file: path.strip_prefix(dir).unwrap_or(path).to_string_lossy().to_string(),
+                subject: thread.subject.clone(),
+                labels: thread.labels.clone(),
+                contacts,
+                to: last.to.clone(),
+                last_sent: last.date.clone(),
+                days_waiting: (now - sent).num_days(),
+                after: after.clone(),
+                draft: None,
+            },
+        ));
+    }
+    results.sort_by_key(|(sent, _)| *sent);
+    Ok(results)
+}
+
+/// Collect threads due for a follow-up from explicit (group label, conversations dir) pairs.
+pub fn collect_dirs(
+    dirs: &[(String, PathBuf)],
+    me: &Identity,
+    thresholds: &Thresholds,
+    contacts: &BTreeMap<String, Contact>,
+    now: DateTime<Utc>,
+) -> Result<FollowupReport> {
+    let index = ContactIndex::new(contacts);
+    let mut threads = Vec::new();
+    for (label, dir) in dirs {
+        threads.extend(
+            scan_dir(label, dir, me, thresholds, &index, now)?
+                .into_iter()
+                .map(|(_, f)| f),
+        );
+    }
+    Ok(FollowupReport { threads, multi: dirs.len() > 1 })
+}
+
+/// Collect threads due for a follow-up for a scope, using the configured thresholds.
+pub fn collect(scope: &Scope, me: &Identity, after: Option<&str>) -> Result<FollowupReport> {
+    let config = corky_config::try_load_config(None)
+        .and_then(|c| c.followups)
+        .unwrap_or_default();
+    let contacts = contact::load_contacts(None).unwrap_or_default();
+    let thresholds = Thresholds::new(after, &config, &contacts)?;
+    collect_dirs(&resolve_dirs(scope)?, me, &thresholds, &contacts, Utc::now())
+}
+
+// ---------------------------------------------------------------------------
+// Nudge drafts
+// ---------------------------------------------------------------------------
+
+/// "Re: {subject}", without doubling an existing prefix.
+fn reply_subject(subject: &str) -> String {
+    if subject.to_lowercase().starts_with("re:") {
+        subject.to_string()
+    } else {
+        format!("Re: {}", subject)
+    }
+}
+
+/// An existing draft in `drafts_dir` with the heading `# {subject}`.
+fn existing_draft(drafts_dir: &Path, subject: &str) -> Result<Option<PathBuf>> {
+    let heading = format!("# {}", subject);
+    let mut files = Vec::new();
+    collect_md_files(drafts_dir, &mut files)?;
+    files.sort();
+    for file in files {
+        if std::fs::read_to_string(&file)?.lines().any(|l| l.trim_end() == heading) {
+            return Ok(Some(file));
+        }
+    }
+    Ok(None)
+}
+
+/// Message-ID of `msg` from the raw store, for `in_reply_to`.
+pub(crate) fn message_id(msg: &Message) -> Option<String> {
+    if msg.raw.is_empty() {
+        return None;
+    }
+    let raw = raw_store::get(&raw_store::store_dir(), &msg.raw).ok()?;
+    let parsed = mailparse::parse_mail(&raw).ok()?;
+    header_value(&parsed, "Message-ID")
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Body of a nudge draft: a short follow-up line above the quoted last message.
+fn nudge_body(last: &Message) -> String {
+    let mut body = String::from("Just following up on my message below. Any update?\n\n");
+    body.push_str(&format!("> On {}, {} wrote:\n>\n", last.date, last.from));
+    for line in last.body.lines() {
+        if line.is_empty() {
+            body.push_str(">\n");
+        } else {
+            body.push_str(&format!("> {}\n", line));
+        }
+    }
+    body
+}
+
+/// Scaffold a nudge draft for `followup` next to its conversations, unless
+/// one already exists. Returns the draft path.
+fn scaffold_draft(conversations_dir: &Path, followup: &Followup, author: &str) -> Result<Option<PathBuf>> {
+    let drafts_dir = conversations_dir
+        .parent()
+        .unwrap_or(conversations_dir)
+        .join("drafts");
+    let subject = reply_subject(&followup.subject);
+    if let Some(path) = existing_draft(&drafts_dir, &subject)? {
+        return Ok(Some(path));
+    }
+    let text = std::fs::read_to_string(conversations_dir.join(&followup.file))?;
+    let Some(thread) = parse_thread_markdown(&text) else {
+        return Ok(None);
+    };
+    let Some(last) = thread.messages.last() else {
+        return Ok(None);
+    };
+    if last.to.trim().is_empty() {
+        eprintln!("  {}: no recipients on your last message; no draft created", followup.file);
+        return Ok(None);
+    }
+    let cc = Some(last.cc.as_str()).filter(|cc| !cc.trim().is_empty());
+    let in_reply_to = message_id(last);
+    let path = create_in(
+        &drafts_dir,
+        &subject,
+        &last.to,
+        cc,
+        thread.accounts.first().map(String::as_str),
+        None,
+        in_reply_to.as_deref(),
+        author,
+        &[],
+    )?;
+    let mut content = std::fs::read_to_string(&path)?;
+    content.push('\n');
+    content.push_str(&nudge_body(last));
+    std::fs::write(&path, content)?;
+    Ok(Some(path))
+}
+
+/// corky followups [SCOPE] [--after SPAN] [--from NAME] [--draft]
+pub fn run(scope: Scope, me: &Identity, after: Option<&str>, draft: bool, format: OutputFormat) -> Result<()> {
+    let dirs = resolve_dirs(&scope)?;
+    if dirs.is_empty() {
+        eprintln!("No conversations directories found.");
+        std::process::exit(1);
+    }
+    let mut report = collect(&scope, me, after)?;
+    if draft {
+        let author = corky_config::try_load_config(None)
+            .and_then(|cfg| cfg.owner)
+            .map(|o| o.name)
+            .unwrap_or_default();
+        for followup in &mut report.threads {
+            let Some((_, dir)) = dirs.iter().find(|(label, _)| *label == followup.scope) else {
+                continue;
+            };
+            followup.draft = scaffold_draft(dir, followup, &author)?.map(|p| p.display().to_string());
+        }
+    }
+    emit(format, &report)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholds() -> Thresholds {
+        let config = corky_config::FollowupsConfig {
+            after: "7d".into(),
+            labels: HashMap::from([("clients".into(), "2d".into()), ("vendors".into(), "3d".into())]),
+        };
+        let mut contacts = BTreeMap::new();
+        contacts.insert("alice".to_string(), Contact { followup_after: "36h".into(), ..Default::default() });
+        contacts.insert("bob".to_string(), Contact { followup_after: "1w".into(), ..Default::default() });
+        Thresholds::new(None, &config, &contacts).unwrap()
+    }
+
+    #[test]
+    fn test_threshold_precedence() {
+        let t = thresholds();
+        let strings = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(t.for_thread(&[], &[]).1, "7d");
+        assert_eq!(t.for_thread(&strings(&["vendors", "clients"]), &[]).1, "2d");
+        // A contact's own threshold wins over labels, even when longer
+        assert_eq!(t.for_thread(&strings(&["clients"]), &strings(&["bob"])).1, "1w");
+        assert_eq!(t.for_thread(&[], &strings(&["bob", "alice"])).1, "36h");
+        assert_eq!(t.for_thread(&[], &strings(&["carol"])).1, "7d");
+    }
+
+    #[test]
+    fn test_after_overrides_config_default_and_rejects_bad_spans() {
+        let config = corky_config::FollowupsConfig { after: "7d".into(), ..Default::default() };
+        let t = Thresholds::new(Some("3d"), &config, &BTreeMap::new()).unwrap();
+        assert_eq!(t.for_thread(&[], &[]).0, Duration::days(3));
+        let err = Thresholds::new(Some("soon"), &config, &BTreeMap::new()).unwrap_err();
+        assert!(err.to_string().contains("Invalid --after 'soon'"));
+    }
+
+    #[test]
+    fn test_nudge_body_quotes_last_message() {
+        let last = Message {
+            id: String::new(),
+            thread_id: String::new(),
+            from: "Me <me@example.com>".into(),
+            to: "alice@example.com".into(),
+            cc: String::new(),
+            date: "Mon, 2 Mar 2026 10:00:00 +0000".into(),
+            subject: "Budget".into(),
+            body: "Any news?\n\nThanks".into(),
+            raw: String::new(),
+        };
+        assert_eq!(reply_subject("Budget"), "Re: Budget");
+        assert_eq!(reply_subject("RE: Budget"), "RE: Budget");
+        assert_eq!(
+            nudge_body(&last),
+            "Just following up on my message below. Any update?\n\n\
+             > On Mon, 2 Mar 2026 10:00:00 +0000, Me <me@example.com> wrote:\n>\n> Any news?\n>\n> Thanks\n"
+        );
+    }
+}
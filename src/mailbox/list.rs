@@ -1,23 +1,47 @@
 //! List registered mailboxes from app config.
 
 use anyhow::Result;
+use serde::Serialize;
 
 use crate::app_config;
+use crate::output::{emit, OutputFormat, Report};
 
-pub fn run() -> Result<()> {
-    let mailboxes = app_config::list_mailboxes()?;
+/// A registered mailbox.
+#[derive(Debug, Clone, Serialize)]
+pub struct MailboxEntry {
+    pub name: String,
+    pub path: String,
+    pub default: bool,
+}
 
-    if mailboxes.is_empty() {
-        println!("No mailboxes configured.");
-        println!("Run 'corky init --user EMAIL' to create one.");
-        return Ok(());
-    }
+/// Result of `corky mailbox list` (JSON: array of [`MailboxEntry`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct MailboxListReport(pub Vec<MailboxEntry>);
 
-    println!("corky mailboxes\n");
-    let name_w = mailboxes.iter().map(|(n, _, _)| n.len()).max().unwrap_or(0);
-    for (name, path, is_default) in &mailboxes {
-        let marker = if *is_default { " (default)" } else { "" };
-        println!("  {:<width$}  {}{}", name, path, marker, width = name_w);
+impl Report for MailboxListReport {
+    fn render_table(&self) -> String {
+        if self.0.is_empty() {
+            return "No mailboxes configured.\nRun 'corky init --user EMAIL' to create one.\n"
+                .to_string();
+        }
+        let mut out = String::from("corky mailboxes\n\n");
+        let name_w = self.0.iter().map(|m| m.name.len()).max().unwrap_or(0);
+        for m in &self.0 {
+            let marker = if m.default { " (default)" } else { "" };
+            out.push_str(&format!("  {:<width$}  {}{}\n", m.name, m.path, marker, width = name_w));
+        }
+        out
     }
-    Ok(())
+}
+
+pub fn run(format: OutputFormat) -> Result<()> {
+    let mailboxes = app_config::list_mailboxes()?;
+    let report = MailboxListReport(
+        mailboxes
+            .into_iter()
+            .map(|(name, path, default)| MailboxEntry { name, path, default })
+            .collect(),
+    );
+    emit(format, &report)
 }
@@ -1,9 +1,11 @@
 pub mod add;
 pub mod find_unanswered;
+pub mod followups;
 pub mod list;
 pub mod remove;
 pub mod rename;
 pub mod reset;
 pub mod sync;
 pub mod templates;
+pub mod triage;
 pub mod validate_draft;
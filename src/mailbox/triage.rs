@@ -0,0 +1,342 @@
+//! Per-thread triage state: done, snoozed until a date, or waiting on someone.
+//!
+//! State lives in the thread file's header (`**State**`, `**Snoozed until**`,
+//! `**Waiting on**`, `**State since**`), so it survives sync and reprocess.
+//! Triaged threads drop out of `unanswered` until a snooze expires or someone
+//! else's message dated after `**State since**` arrives; `watch` then clears
+//! the state and reports the thread as resurfaced.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::find_unanswered::{collect_md_files, resolve_dirs, Scope};
+use crate::config::owner::Identity;
+use crate::contact::from_conversation::find_conversation;
+use crate::output::{emit, OutputFormat, Report};
+use crate::sync::imap_sync::{parse_msg_date, set_mtime};
+use crate::sync::markdown::{parse_thread_markdown, thread_to_markdown};
+use crate::sync::types::{Thread, ThreadState, ThreadStatus};
+use crate::util::parse_span;
+
+/// Why a triaged thread should come back, if it should.
+///
+/// The owner's own messages don't count as new.
+pub fn resurface_reason(thread: &Thread, me: &Identity, now: DateTime<Utc>) -> Option<&'static str> {
+    let state = &thread.state;
+    if state.status == ThreadStatus::Open {
+        return None;
+    }
+    if !state.since.is_empty() {
+        let since = parse_msg_date(&state.since);
+        if thread
+            .messages
+            .iter()
+            .any(|m| !me.is_me(&m.from) && parse_msg_date(&m.date) > since)
+        {
+            return Some("new message");
+        }
+    }
+    if state.status == ThreadStatus::Snoozed {
+        let until = NaiveDate::parse_from_str(&state.until, "%Y-%m-%d").ok();
+        if until.is_none_or(|d| d <= now.with_timezone(&Local).date_naive()) {
+            return Some("snooze expired");
+        }
+    }
+    None
+}
+
+/// Whether a triaged thread stays hidden right now.
+pub fn is_hidden(thread: &Thread, me: &Identity, now: DateTime<Utc>) -> bool {
+    thread.state.status != ThreadStatus::Open && resurface_reason(thread, me, now).is_none()
+}
+
+/// `--until`: a date, or a span from now ("3d", "2w").
+fn parse_until(s: &str, now: DateTime<Local>) -> Result<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d") {
+        return Ok(date);
+    }
+    match parse_span(s) {
+        Some(span) => Ok((now + span).date_naive()),
+        None => bail!("Invalid --until '{}': expected YYYY-MM-DD or a span like 3d or 2w", s),
+    }
+}
+
+/// Replace the triage state of a thread file; [`ThreadState::default`] reopens it.
+pub fn set_state(path: &Path, state: ThreadState) -> Result<Thread> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let Some(mut thread) = parse_thread_markdown(&text) else {
+        bail!("{} is not a conversation file", path.display());
+    };
+    thread.state = state;
+    std::fs::write(path, thread_to_markdown(&thread))?;
+    let _ = set_mtime(path, &thread.last_date);
+    crate::search::index::note_changed(path);
+    Ok(thread)
+}
+
+fn triaged(status: ThreadStatus, until: String, waiting_on: String) -> ThreadState {
+    ThreadState { status, until, waiting_on, since: Utc::now().to_rfc2822() }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+/// corky thread done CONVERSATION
+pub fn run_done(conversation: &str) -> Result<()> {
+    let path = find_conversation(conversation)?;
+    set_state(&path, triaged(ThreadStatus::Done, String::new(), String::new()))?;
+    println!("Marked {} done", file_name(&path));
+    Ok(())
+}
+
+/// corky thread snooze CONVERSATION --until DATE|SPAN
+pub fn run_snooze(conversation: &str, until: &str) -> Result<()> {
+    let until = parse_until(until, Local::now())?;
+    let path = find_conversation(conversation)?;
+    set_state(&path, triaged(ThreadStatus::Snoozed, until.format("%Y-%m-%d").to_string(), String::new()))?;
+    println!("Snoozed {} until {}", file_name(&path), until.format("%Y-%m-%d"));
+    Ok(())
+}
+
+/// corky thread wait CONVERSATION [--on WHO]
+pub fn run_wait(conversation: &str, on: Option<&str>) -> Result<()> {
+    let path = find_conversation(conversation)?;
+    let on = on.map(str::trim).unwrap_or_default();
+    set_state(&path, triaged(ThreadStatus::Waiting, String::new(), on.to_string()))?;
+    if on.is_empty() {
+        println!("Marked {} waiting", file_name(&path));
+    } else {
+        println!("Marked {} waiting on {}", file_name(&path), on);
+    }
+    Ok(())
+}
+
+/// corky thread reopen CONVERSATION
+pub fn run_reopen(conversation: &str) -> Result<()> {
+    let path = find_conversation(conversation)?;
+    set_state(&path, ThreadState::default())?;
+    println!("Reopened {}", file_name(&path));
+    Ok(())
+}
+
+/// Triaged threads in one conversations directory, in file order.
+fn scan_dir(dir: &Path) -> Result<Vec<(PathBuf, Thread)>> {
+    let mut results = Vec::new();
+    let mut md_files = Vec::new();
+    collect_md_files(dir, &mut md_files)?;
+    md_files.sort();
+    for path in md_files {
+        let text = std::fs::read_to_string(&path)?;
+        if !text.contains("**State**:") {
+            continue;
+        }
+        if let Some(thread) = parse_thread_markdown(&text).filter(|t| t.state.status != ThreadStatus::Open) {
+            results.push((path, thread));
+        }
+    }
+    Ok(results)
+}
+
+/// A triaged thread, as listed by `corky thread list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TriagedThread {
+    /// "Root" or the mailbox name
+    pub scope: String,
+    /// Conversation filename
+    pub file: String,
+    pub subject: String,
+    pub status: ThreadStatus,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub until: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub waiting_on: String,
+    pub since: String,
+}
+
+/// Result of `corky thread list` (JSON: array of [`TriagedThread`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct TriageReport {
+    pub threads: Vec<TriagedThread>,
+    /// Whether multiple scopes were scanned (groups table output)
+    #[serde(skip)]
+    pub multi: bool,
+}
+
+impl Report for TriageReport {
+    fn render_table(&self) -> String {
+        let mut out = String::new();
+        if self.threads.is_empty() {
+            out.push_str("No triaged threads.\n");
+            return out;
+        }
+        let mut scopes: Vec<&str> = Vec::new();
+        for t in &self.threads {
+            if !scopes.contains(&t.scope.as_str()) {
+                scopes.push(&t.scope);
+            }
+        }
+        for scope in scopes {
+            let threads: Vec<_> = self.threads.iter().filter(|t| t.scope == scope).collect();
+            if self.multi {
+                out.push_str(&format!("{} ({} triaged):\n\n", scope, threads.len()));
+            } else {
+                out.push_str(&format!("Triaged threads ({}):\n\n", threads.len()));
+            }
+            for t in threads {
+                let detail = match t.status {
+                    ThreadStatus::Snoozed => format!("snoozed until {}", t.until),
+                    ThreadStatus::Waiting if !t.waiting_on.is_empty() => format!("waiting on {}", t.waiting_on),
+                    ThreadStatus::Waiting => "waiting".to_string(),
+                    _ => "done".to_string(),
+                };
+                out.push_str(&format!("  [{}] {}\n", detail, t.file));
+                out.push_str(&format!("           {}\n\n", t.subject));
+            }
+        }
+        out
+    }
+}
+
+/// Collect triaged threads for a scope.
+pub fn collect(scope: &Scope) -> Result<TriageReport> {
+    let dirs = resolve_dirs(scope)?;
+    let mut threads = Vec::new();
+    for (label, dir) in &dirs {
+        for (path, thread) in scan_dir(dir)? {
+            threads.push(TriagedThread {
+                scope: label.clone(),
+                file: file_name(&path),
+                subject: thread.subject,
+                status: thread.state.status,
+                until: thread.state.until,
+                waiting_on: thread.state.waiting_on,
+                since: thread.state.since,
+            });
+        }
+    }
+    Ok(TriageReport { threads, multi: dirs.len() > 1 })
+}
+
+/// corky thread list [SCOPE]
+pub fn run_list(scope: Scope, format: OutputFormat) -> Result<()> {
+    emit(format, &collect(&scope)?)
+}
+
+/// A thread whose triage state [`resurface`] cleared.
+#[derive(Debug, Clone)]
+pub struct Resurfaced {
+    pub scope: String,
+    pub file: String,
+    pub subject: String,
+    pub reason: &'static str,
+}
+
+/// Reopen triaged threads whose snooze expired or that got a new message.
+pub fn resurface_dirs(dirs: &[(String, PathBuf)], me: &Identity, now: DateTime<Utc>) -> Result<Vec<Resurfaced>> {
+    let mut resurfaced = Vec::new();
+    for (label, dir) in dirs {
+        for (path, thread) in scan_dir(dir)? {
+            let Some(reason) = resurface_reason(&thread, me, now) else {
+                continue;
+            };
+            set_state(&path, ThreadState::default())?;
+            resurfaced.push(Resurfaced {
+                scope: label.clone(),
+                file: file_name(&path),
+                subject: thread.subject,
+                reason,
+            });
+        }
+    }
+    Ok(resurfaced)
+}
+
+/// [`resurface_dirs`] over a scope.
+pub fn resurface(scope: &Scope, me: &Identity) -> Result<Vec<Resurfaced>> {
+    resurface_dirs(&resolve_dirs(scope)?, me, Utc::now())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::types::Message;
+    use chrono::{Duration, TimeZone};
+
+    fn thread(state: ThreadState, messages: &[(&str, &str)]) -> Thread {
+        Thread {
+            id: "t".to_string(),
+            subject: "Plans".to_string(),
+            messages: messages
+                .iter()
+                .map(|(from, date)| Message {
+                    id: String::new(),
+                    thread_id: "t".to_string(),
+                    from: from.to_string(),
+                    to: String::new(),
+                    cc: String::new(),
+                    date: date.to_string(),
+                    subject: "Plans".to_string(),
+                    body: String::new(),
+                    raw: String::new(),
+                })
+                .collect(),
+            state,
+            ..Default::default()
+        }
+    }
+
+    fn state(status: ThreadStatus, until: &str) -> ThreadState {
+        ThreadState {
+            status,
+            until: until.to_string(),
+            waiting_on: String::new(),
+            since: "Mon, 10 Feb 2025 12:00:00 +0000".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resurface_on_new_message_from_others() {
+        let now = Utc.with_ymd_and_hms(2025, 2, 11, 9, 0, 0).unwrap();
+        let early = ("Alice <alice@example.com>", "Mon, 10 Feb 2025 10:00:00 +0000");
+        let mine = ("Test User <me@example.com>", "Mon, 10 Feb 2025 13:00:00 +0000");
+        let theirs = ("Alice <alice@example.com>", "Mon, 10 Feb 2025 14:00:00 +0000");
+        let me = Identity::new(None, &["me@example.com"]);
+
+        let done = thread(state(ThreadStatus::Done, ""), &[early]);
+        assert!(is_hidden(&done, &me, now));
+        let replied = thread(state(ThreadStatus::Waiting, ""), &[early, mine]);
+        assert!(is_hidden(&replied, &me, now));
+        assert_eq!(resurface_reason(&replied, &Identity::default(), now), Some("new message"));
+        let answered = thread(state(ThreadStatus::Done, ""), &[early, mine, theirs]);
+        assert_eq!(resurface_reason(&answered, &me, now), Some("new message"));
+        assert!(!is_hidden(&thread(ThreadState::default(), &[early]), &me, now));
+    }
+
+    #[test]
+    fn test_snooze_expiry() {
+        let now = Utc.with_ymd_and_hms(2025, 2, 20, 12, 0, 0).unwrap();
+        let msg = ("Alice <alice@example.com>", "Mon, 10 Feb 2025 10:00:00 +0000");
+        let me = Identity::default();
+        assert!(is_hidden(&thread(state(ThreadStatus::Snoozed, "2025-03-01"), &[msg]), &me, now));
+        let expired = thread(state(ThreadStatus::Snoozed, "2025-02-15"), &[msg]);
+        assert_eq!(resurface_reason(&expired, &me, now), Some("snooze expired"));
+        let unreadable = thread(state(ThreadStatus::Snoozed, "someday"), &[msg]);
+        assert_eq!(resurface_reason(&unreadable, &me, now), Some("snooze expired"));
+    }
+
+    #[test]
+    fn test_parse_until() {
+        let now = Local.with_ymd_and_hms(2025, 2, 10, 9, 0, 0).unwrap();
+        assert_eq!(parse_until("2025-03-01", now).unwrap(), NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+        assert_eq!(parse_until("3d", now).unwrap(), (now + Duration::days(3)).date_naive());
+        assert!(parse_until("soon", now).unwrap_err().to_string().contains("Invalid --until 'soon'"));
+    }
+}
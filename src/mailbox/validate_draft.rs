@@ -96,6 +96,13 @@ fn validate_yaml_draft(text: &str) -> Vec<String> {
         issues.push("Warning: empty body after subject heading".to_string());
     }
 
+    // Scheduled sends outside recipients' working hours ([contacts.*].timezone)
+    if let Some(at) = meta.scheduled_at {
+        let contacts = crate::config::contact::load_contacts(None).unwrap_or_default();
+        let fields = [meta.to.as_str(), meta.cc.as_deref().unwrap_or("")];
+        issues.extend(draft::send_at::off_hours_warnings(&fields, &contacts, at));
+    }
+
     issues
 }
 
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 
-use corky::cli::{CalCommands, Cli, Commands, ContactCommands, DocCommands, DraftCommands, FilterCommands, LabelCommands, LinkedinCommands, MailboxCommands, ScheduleCommands, SkillCommands, SlackCommands, SyncCommands, TopicCommands, YoutubeCommands};
+use corky::cli::{CalCommands, Cli, Commands, ContactCommands, DocCommands, DraftCommands, FilterCommands, LabelCommands, LinkedinCommands, MailboxCommands, ScheduleCommands, SkillCommands, SlackCommands, SocialCommands, SyncCommands, ThreadCommands, TopicCommands, YoutubeCommands};
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -24,6 +24,7 @@ fn main() -> Result<()> {
         corky::upgrade::warn_if_outdated();
     }
 
+    let format = cli.format;
     match cli.command {
         Commands::Init {
             path,
@@ -56,11 +57,43 @@ fn main() -> Result<()> {
             Some(SyncCommands::Mailbox { name }) => corky::mailbox::sync::run(name.as_deref()),
             Some(SyncCommands::TelegramImport { path, label, account }) => {
                 let out_dir = corky::resolve::conversations_dir();
-                corky::sync::telegram_import::run(&path, &label, &out_dir, &account)
+                corky::sync::telegram_import::run(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
             }
             Some(SyncCommands::SmsImport { path, label, account }) => {
                 let out_dir = corky::resolve::conversations_dir();
-                corky::sync::sms_import::run(&path, &label, &out_dir, &account)
+                corky::sync::sms_import::run(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::Slack { full, channels }) => corky::sync::slack_sync::run(full, &channels),
+            Some(SyncCommands::WhatsappImport { path, label, account, chat, date_order }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::sync::whatsapp_import::run(&path, &label, &out_dir, &account, chat.as_deref(), date_order)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::DiscordImport { path, label, account }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::sync::discord_import::run(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::SignalImport { path, label, account }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::sync::signal_import::run(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::MboxImport { path, label, account }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::sync::mail_import::run_mbox(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::MaildirImport { path, label, account }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::sync::mail_import::run_maildir(&path, &label, &out_dir, &account)?;
+                corky::sync::manifest::generate_manifest(&out_dir)
+            }
+            Some(SyncCommands::SocialComments { files, label }) => {
+                let out_dir = corky::resolve::conversations_dir();
+                corky::social::comments::run(&files, &label, &out_dir)
             }
         },
         Commands::SyncAuth => corky::sync::auth::run(),
@@ -78,8 +111,20 @@ fn main() -> Result<()> {
                     corky::contact::add::run(&name, &emails)
                 }
             }
-            ContactCommands::Info { name } => corky::contact::info::run(&name),
+            ContactCommands::Info { name } => corky::contact::info::run(&name, format),
             ContactCommands::Sync => corky::contact::sync::run(),
+            ContactCommands::Rename { old, new } => corky::contact::merge::rename(&old, &new),
+            ContactCommands::Merge { from, into } => corky::contact::merge::merge(&from, &into),
+            ContactCommands::Discover { limit, min_messages, create, all } => {
+                corky::contact::discover::run(limit, min_messages, &create, all, format)
+            }
+            ContactCommands::Import { file, dry_run } => {
+                corky::contact::vcard::import(&file, dry_run).map(|_| ())
+            }
+            ContactCommands::Export { out, contacts } => {
+                corky::contact::vcard::export(out.as_deref(), &contacts)
+            }
+            ContactCommands::Carddav { dry_run } => corky::contact::carddav::run(dry_run),
         },
         Commands::ContactAdd {
             name,
@@ -96,14 +141,31 @@ fn main() -> Result<()> {
         Commands::AuditDocs => corky::audit_docs::run(),
         Commands::Help { filter } => corky::help::run(filter.as_deref()),
         Commands::Unanswered { scope, from_name } => {
-            let from = resolve_from_name(from_name)?;
+            let me = resolve_identity(from_name)?;
             let scope = corky::mailbox::find_unanswered::Scope::from_arg(scope.as_deref());
-            corky::mailbox::find_unanswered::run(scope, &from)
+            corky::mailbox::find_unanswered::run(scope, &me, format)
         }
+        Commands::Followups { scope, after, from_name, draft } => {
+            let me = resolve_identity(from_name)?;
+            let scope = corky::mailbox::find_unanswered::Scope::from_arg(scope.as_deref());
+            corky::mailbox::followups::run(scope, &me, after.as_deref(), draft, format)
+        }
+        Commands::Search { query, limit, rebuild } => {
+            corky::search::run(&query.join(" "), limit, rebuild, format)
+        }
+        Commands::Export { out, export_type, labels, contact, since, until, search, scope } => {
+            let selection = corky::export::Selection { scope, labels, contact, since, until, search };
+            corky::export::run(&selection, export_type, &out)
+        }
+        Commands::Reprocess { scope, dry_run } => {
+            corky::sync::reprocess::run(scope.as_deref(), dry_run)
+        }
+        Commands::Mcp { scope, principal } => corky::mcp::run(scope.as_deref(), &principal),
+        Commands::Serve { host, port, token } => corky::serve::run(&host, port, token.as_deref()),
         Commands::ValidateDraft { files } => corky::mailbox::validate_draft::run(&files),
         Commands::Draft(cmd) => run_draft_command(cmd),
         Commands::Mailbox(cmd) => match cmd {
-            MailboxCommands::List => corky::mailbox::list::run(),
+            MailboxCommands::List => corky::mailbox::list::run(format),
             MailboxCommands::Add {
                 name,
                 labels,
@@ -139,26 +201,28 @@ fn main() -> Result<()> {
                 corky::mailbox::reset::run(name.as_deref(), no_sync)
             }
             MailboxCommands::Unanswered { scope, from_name } => {
-                let from = resolve_from_name(from_name)?;
+                let me = resolve_identity(from_name)?;
                 let scope =
                     corky::mailbox::find_unanswered::Scope::from_arg(scope.as_deref());
-                corky::mailbox::find_unanswered::run(scope, &from)
+                corky::mailbox::find_unanswered::run(scope, &me, format)
             }
             MailboxCommands::Draft(cmd) => run_draft_command(cmd),
         },
         Commands::Linkedin(cmd) => match cmd {
-            LinkedinCommands::Auth { profile } => {
-                corky::social::run_auth("linkedin", profile.as_deref())
+            LinkedinCommands::Auth { profile, org } => {
+                corky::social::run_auth("linkedin", profile.as_deref(), org)
             }
             LinkedinCommands::Draft {
                 body,
                 author,
+                org,
                 visibility,
                 tags,
             } => corky::social::run_draft(
                 "linkedin",
                 body.as_deref(),
                 author.as_deref(),
+                org.as_deref(),
                 &visibility,
                 &tags,
             ),
@@ -166,15 +230,16 @@ fn main() -> Result<()> {
             LinkedinCommands::Edit { file, body } => {
                 corky::social::run_edit(&file, body.as_deref())
             }
+            LinkedinCommands::Delete { file } => corky::social::run_delete("linkedin", &file),
             LinkedinCommands::Check => corky::social::run_check(),
-            LinkedinCommands::List { status } => corky::social::run_list(status.as_deref()),
+            LinkedinCommands::List { status } => corky::social::run_list(status.as_deref(), format),
             LinkedinCommands::RenameAuthor { old, new } => {
                 corky::social::run_rename_author(&old, &new)
             }
         },
         Commands::Youtube(cmd) => match cmd {
             YoutubeCommands::Auth { profile } => {
-                corky::social::run_auth("youtube", profile.as_deref())
+                corky::social::run_auth("youtube", profile.as_deref(), false)
             }
             YoutubeCommands::Draft {
                 body,
@@ -185,24 +250,30 @@ fn main() -> Result<()> {
                 "youtube",
                 body.as_deref(),
                 author.as_deref(),
+                None,
                 &visibility,
                 &tags,
             ),
             YoutubeCommands::Publish { file, dry_run } => corky::social::run_publish(&file, dry_run),
             YoutubeCommands::Edit { file } => corky::social::run_youtube_edit(&file),
+            YoutubeCommands::Delete { file } => corky::social::run_delete("youtube", &file),
             YoutubeCommands::Check => corky::social::run_check(),
-            YoutubeCommands::List { status } => corky::social::run_list(status.as_deref()),
+            YoutubeCommands::List { status } => corky::social::run_list(status.as_deref(), format),
+        },
+        Commands::Social(cmd) => match cmd {
+            SocialCommands::Stats { files } => corky::social::stats::run(&files),
+            SocialCommands::Validate { files } => corky::social::validate::run(&files),
         },
         Commands::Schedule(cmd) => match cmd {
             ScheduleCommands::Run { dry_run } => corky::schedule::run(dry_run),
-            ScheduleCommands::List => corky::schedule::list(),
+            ScheduleCommands::List => corky::schedule::list(format),
         },
         Commands::Topics(cmd) => match cmd {
-            TopicCommands::List { verbose } => corky::topics::run_list(verbose),
+            TopicCommands::List { verbose } => corky::topics::run_list(verbose, format),
             TopicCommands::Add { name, keywords, description } => {
                 corky::topics::run_add(&name, &keywords, description.as_deref())
             }
-            TopicCommands::Info { name } => corky::topics::run_info(&name),
+            TopicCommands::Info { name } => corky::topics::run_info(&name, format),
             TopicCommands::Suggest { limit, mailbox } => {
                 corky::topics::run_suggest(limit, mailbox.as_deref())
             }
@@ -218,12 +289,26 @@ fn main() -> Result<()> {
                 corky::label::clear::run(&label, account.as_deref(), search.as_deref(), dry_run)
             }
         },
+        Commands::Thread(cmd) => match cmd {
+            ThreadCommands::Done { conversation } => corky::mailbox::triage::run_done(&conversation),
+            ThreadCommands::Snooze { conversation, until } => {
+                corky::mailbox::triage::run_snooze(&conversation, &until)
+            }
+            ThreadCommands::Wait { conversation, on } => {
+                corky::mailbox::triage::run_wait(&conversation, on.as_deref())
+            }
+            ThreadCommands::Reopen { conversation } => corky::mailbox::triage::run_reopen(&conversation),
+            ThreadCommands::List { scope } => {
+                let scope = corky::mailbox::find_unanswered::Scope::from_arg(scope.as_deref());
+                corky::mailbox::triage::run_list(scope, format)
+            }
+        },
         Commands::Cal(cmd) => match cmd {
             CalCommands::Auth { account } => {
                 corky::cal::auth::run_auth(account.as_deref())
             }
             CalCommands::List { limit, query, account } => {
-                corky::cal::list::run(limit, query.as_deref(), account.as_deref())
+                corky::cal::list::run(limit, query.as_deref(), account.as_deref(), format)
             }
             CalCommands::Delete { query, all, dry_run, account } => {
                 corky::cal::delete::run(&query, all, dry_run, account.as_deref())
@@ -234,6 +319,9 @@ fn main() -> Result<()> {
             CalCommands::Check { start, end, account } => {
                 corky::cal::check::run(&start, &end, account.as_deref())
             }
+            CalCommands::Rsvp { conversation, response, account, send, add, cal_account } => {
+                corky::cal::invite::run_rsvp(&conversation, response, account.as_deref(), send, add, cal_account.as_deref())
+            }
         },
         Commands::Filter(cmd) => match cmd {
             FilterCommands::Build { input, output } => {
@@ -254,8 +342,8 @@ fn main() -> Result<()> {
             }
         },
         Commands::Doc(cmd) => match cmd {
-            DocCommands::Build { file, format, template, output } => {
-                corky::doc::build::run(&file, &format, template.as_deref(), output.as_deref())
+            DocCommands::Build { file, to, template, output } => {
+                corky::doc::build::run(&file, &to, template.as_deref(), output.as_deref())
             }
             DocCommands::Upload { .. } => {
                 println!("corky doc upload is coming soon — requires Google Drive API OAuth.");
@@ -280,6 +368,7 @@ fn run_draft_command(cmd: DraftCommands) -> anyhow::Result<()> {
             in_reply_to,
             mailbox,
             attachments,
+            send_at,
         } => corky::draft::new::run(
             &subject,
             &to,
@@ -289,6 +378,7 @@ fn run_draft_command(cmd: DraftCommands) -> anyhow::Result<()> {
             in_reply_to.as_deref(),
             mailbox.as_deref(),
             &attachments,
+            send_at.as_deref(),
         ),
         DraftCommands::Validate { args } => {
             corky::mailbox::validate_draft::run_scoped(&args)
@@ -298,20 +388,14 @@ fn run_draft_command(cmd: DraftCommands) -> anyhow::Result<()> {
     }
 }
 
-/// Resolve the --from name: CLI flag > owner.name in .corky.toml > error.
-fn resolve_from_name(from_name: Option<String>) -> anyhow::Result<String> {
-    if let Some(name) = from_name {
-        return Ok(name);
-    }
-    if let Some(cfg) = corky::config::corky_config::try_load_config(None) {
-        if let Some(owner) = cfg.owner {
-            if !owner.name.is_empty() {
-                return Ok(owner.name);
-            }
-        }
+/// Who "you" are: account addresses, [owner] name and aliases, plus --from.
+fn resolve_identity(from_name: Option<String>) -> anyhow::Result<corky::config::owner::Identity> {
+    let me = corky::config::owner::Identity::load(from_name.as_deref());
+    if !me.is_configured() {
+        anyhow::bail!(
+            "No --from name provided and no [owner] name or accounts in .corky.toml.\n\
+             Use --from NAME or set name in [owner] section of .corky.toml."
+        );
     }
-    anyhow::bail!(
-        "No --from name provided and no [owner] name in .corky.toml.\n\
-         Use --from NAME or set name in [owner] section of .corky.toml."
-    )
+    Ok(me)
 }
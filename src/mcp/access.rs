@@ -0,0 +1,208 @@
+//! Permission gating for `corky mcp`.
+//!
+//! Unscoped servers act as the owner and may read and write everything in
+//! the data directory. A server scoped to a mailbox (`--scope NAME`) only
+//! sees `mailboxes/{name}/` and is limited by
+//! `[mailboxes.{name}.permissions.{principal}]` in `.corky.toml`:
+//!
+//! ```toml
+//! [mailboxes.alex.permissions.agent]
+//! read = ["conversations", "contacts"]   # or ["*"]
+//! write = ["drafts"]
+//! ```
+//!
+//! A `"*"` principal entry applies to principals without their own entry.
+//! A mailbox with no `permissions` table at all gets the shared-mailbox
+//! default: read everything, write drafts. Once the table exists, a
+//! principal listed in neither entry is denied everything.
+
+use anyhow::Result;
+
+use crate::config::corky_config::{MailboxConfig, MailboxPermissions};
+
+/// A part of the data directory that tools and resources touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Area {
+    Conversations,
+    Drafts,
+    Contacts,
+}
+
+impl Area {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Area::Conversations => "conversations",
+            Area::Drafts => "drafts",
+            Area::Contacts => "contacts",
+        }
+    }
+}
+
+impl std::fmt::Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Effective permissions for an MCP session.
+#[derive(Debug, Clone)]
+pub struct Access {
+    /// Mailbox the session is scoped to (`None` = owner, whole data dir)
+    pub mailbox: Option<String>,
+    pub principal: String,
+    read: Vec<String>,
+    write: Vec<String>,
+}
+
+fn allows(list: &[String], area: Area) -> bool {
+    list.iter().any(|a| a == "*" || a.eq_ignore_ascii_case(area.as_str()))
+}
+
+impl Access {
+    /// Owner access: everything allowed.
+    pub fn owner() -> Self {
+        Access {
+            mailbox: None,
+            principal: "owner".to_string(),
+            read: vec!["*".to_string()],
+            write: vec!["*".to_string()],
+        }
+    }
+
+    /// Access for `principal` in a shared mailbox.
+    pub fn for_mailbox(name: &str, config: Option<&MailboxConfig>, principal: &str) -> Self {
+        let (read, write) = match config.filter(|c| !c.permissions.is_empty()) {
+            None => (vec!["*".to_string()], vec![Area::Drafts.as_str().to_string()]),
+            Some(c) => match c.permissions.get(principal).or_else(|| c.permissions.get("*")) {
+                Some(MailboxPermissions { read, write, .. }) => (read.clone(), write.clone()),
+                None => (Vec::new(), Vec::new()),
+            },
+        };
+        Access {
+            mailbox: Some(name.to_string()),
+            principal: principal.to_string(),
+            read,
+            write,
+        }
+    }
+
+    pub fn can_read(&self, area: Area) -> bool {
+        allows(&self.read, area) || allows(&self.write, area)
+    }
+
+    pub fn can_write(&self, area: Area) -> bool {
+        allows(&self.write, area)
+    }
+
+    fn denied(&self, verb: &str, area: Area) -> anyhow::Error {
+        let scope = self
+            .mailbox
+            .as_deref()
+            .map(|m| format!(" in mailbox '{}'", m))
+            .unwrap_or_default();
+        anyhow::anyhow!(
+            "Permission denied: '{}' cannot {} {}{}",
+            self.principal,
+            verb,
+            area,
+            scope
+        )
+    }
+
+    pub fn check_read(&self, area: Area) -> Result<()> {
+        if !self.can_read(area) {
+            return Err(self.denied("read", area));
+        }
+        Ok(())
+    }
+
+    pub fn check_write(&self, area: Area) -> Result<()> {
+        if !self.can_write(area) {
+            return Err(self.denied("write", area));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config(entries: &[(&str, &[&str], &[&str])]) -> MailboxConfig {
+        let mut permissions = HashMap::new();
+        for (principal, read, write) in entries {
+            permissions.insert(
+                principal.to_string(),
+                MailboxPermissions {
+                    read: read.iter().map(|s| s.to_string()).collect(),
+                    write: write.iter().map(|s| s.to_string()).collect(),
+                    ..Default::default()
+                },
+            );
+        }
+        MailboxConfig {
+            permissions,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn owner_allows_everything() {
+        let a = Access::owner();
+        assert!(a.can_read(Area::Contacts));
+        assert!(a.can_write(Area::Drafts));
+        assert!(a.can_write(Area::Conversations));
+    }
+
+    #[test]
+    fn mailbox_default_reads_all_writes_drafts() {
+        let a = Access::for_mailbox("alex", None, "agent");
+        assert!(a.can_read(Area::Conversations));
+        assert!(a.can_read(Area::Contacts));
+        assert!(a.can_write(Area::Drafts));
+        assert!(!a.can_write(Area::Conversations));
+    }
+
+    #[test]
+    fn mailbox_principal_entry_and_wildcard() {
+        let cfg = config(&[
+            ("agent", &["conversations"], &[]),
+            ("*", &["*"], &["drafts"]),
+        ]);
+        let agent = Access::for_mailbox("alex", Some(&cfg), "agent");
+        assert!(agent.can_read(Area::Conversations));
+        assert!(!agent.can_read(Area::Contacts));
+        assert!(!agent.can_write(Area::Drafts));
+        let err = agent.check_write(Area::Drafts).unwrap_err().to_string();
+        assert_eq!(err, "Permission denied: 'agent' cannot write drafts in mailbox 'alex'");
+
+        let other = Access::for_mailbox("alex", Some(&cfg), "bot");
+        assert!(other.can_read(Area::Contacts));
+        assert!(other.can_write(Area::Drafts));
+    }
+
+    #[test]
+    fn mailbox_table_without_entry_denies() {
+        let cfg = config(&[("agent", &["*"], &["drafts"])]);
+        let other = Access::for_mailbox("alex", Some(&cfg), "anything");
+        assert!(!other.can_read(Area::Conversations));
+        assert!(!other.can_read(Area::Contacts));
+        assert!(!other.can_write(Area::Drafts));
+        let err = other.check_read(Area::Conversations).unwrap_err().to_string();
+        assert_eq!(err, "Permission denied: 'anything' cannot read conversations in mailbox 'alex'");
+
+        // A mailbox config without a permissions table keeps the default
+        let open = Access::for_mailbox("alex", Some(&config(&[])), "anything");
+        assert!(open.can_read(Area::Contacts));
+        assert!(open.can_write(Area::Drafts));
+    }
+
+    #[test]
+    fn write_implies_read() {
+        let cfg = config(&[("agent", &[], &["drafts"])]);
+        let a = Access::for_mailbox("alex", Some(&cfg), "agent");
+        assert!(a.can_read(Area::Drafts));
+        assert!(!a.can_read(Area::Conversations));
+    }
+}
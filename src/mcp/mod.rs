@@ -0,0 +1,260 @@
+//! Model Context Protocol server over stdio (`corky mcp`).
+//!
+//! Speaks newline-delimited JSON-RPC 2.0 on stdin/stdout. Diagnostics go to
+//! stderr so they never corrupt the protocol stream.
+
+pub mod access;
+pub mod tools;
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use self::access::{Access, Area};
+use crate::config::corky_config;
+use crate::resolve;
+
+/// MCP protocol revision implemented here.
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+const URI_PREFIX: &str = "corky://";
+
+/// An MCP session bound to a data directory and a permission scope.
+pub struct Server {
+    pub data_dir: PathBuf,
+    /// Root for tool paths: the data dir, or `mailboxes/{name}` when scoped
+    pub base: PathBuf,
+    pub access: Access,
+    /// `[owner] name`, used for unanswered detection and draft authorship
+    pub owner_name: Option<String>,
+}
+
+impl Server {
+    pub fn new(data_dir: PathBuf, access: Access, owner_name: Option<String>) -> Result<Self> {
+        let base = match access.mailbox {
+            Some(ref name) => {
+                let dir = data_dir.join("mailboxes").join(name);
+                if !dir.is_dir() {
+                    bail!("Mailbox '{}' not found at {}", name, dir.display());
+                }
+                dir
+            }
+            None => data_dir.clone(),
+        };
+        Ok(Server {
+            data_dir,
+            base,
+            access,
+            owner_name: owner_name.filter(|n| !n.is_empty()),
+        })
+    }
+
+    /// Prefix of index paths (relative to the data dir) visible in this scope.
+    pub fn rel_prefix(&self) -> String {
+        match self.access.mailbox {
+            Some(ref name) => format!("mailboxes/{}/", name),
+            None => String::new(),
+        }
+    }
+
+    /// Handle one JSON-RPC message. Returns `None` for notifications.
+    pub fn handle(&self, msg: &Value) -> Option<Value> {
+        let id = msg.get("id").cloned();
+        let method = msg.get("method").and_then(|m| m.as_str()).unwrap_or("");
+        let params = msg.get("params").cloned().unwrap_or(Value::Null);
+
+        let result = match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": { "tools": {}, "resources": {} },
+                "serverInfo": { "name": "corky", "version": env!("CARGO_PKG_VERSION") },
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+            "tools/call" => self.call_tool(&params),
+            "resources/list" => Ok(json!({ "resources": self.list_resources() })),
+            "resources/templates/list" => Ok(json!({ "resourceTemplates": resource_templates() })),
+            "resources/read" => self.read_resource(&params),
+            m if m.starts_with("notifications/") => return None,
+            m => Err((METHOD_NOT_FOUND, format!("Method not found: {}", m))),
+        };
+
+        // Requests without an id are notifications: never reply
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": code, "message": message },
+            }),
+        })
+    }
+
+    fn call_tool(&self, params: &Value) -> std::result::Result<Value, (i64, String)> {
+        let name = params
+            .get("name")
+            .and_then(|n| n.as_str())
+            .ok_or((INVALID_PARAMS, "Missing tool name".to_string()))?;
+        if !tools::definitions().iter().any(|t| t["name"] == name) {
+            return Err((INVALID_PARAMS, format!("Unknown tool: {}", name)));
+        }
+        let args = params.get("arguments").cloned().unwrap_or_else(|| json!({}));
+        // Tool failures are results, so the agent can see and react to them
+        let (text, is_error) = match tools::call(self, name, &args) {
+            Ok(text) => (text, false),
+            Err(e) => (e.to_string(), true),
+        };
+        Ok(json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": is_error,
+        }))
+    }
+
+    fn list_resources(&self) -> Vec<Value> {
+        let mut out = Vec::new();
+        if self.access.can_read(Area::Conversations) {
+            for file in sorted_entries(&self.base.join("conversations"), true) {
+                out.push(json!({
+                    "uri": format!("{}conversations/{}", URI_PREFIX, file),
+                    "name": file,
+                    "mimeType": "text/markdown",
+                }));
+            }
+        }
+        if self.access.can_read(Area::Contacts) {
+            for name in sorted_entries(&self.base.join("contacts"), false) {
+                out.push(json!({
+                    "uri": format!("{}contacts/{}", URI_PREFIX, name),
+                    "name": name,
+                    "description": format!("AGENTS.md for contact {}", name),
+                    "mimeType": "text/markdown",
+                }));
+            }
+        }
+        out
+    }
+
+    fn read_resource(&self, params: &Value) -> std::result::Result<Value, (i64, String)> {
+        let uri = params
+            .get("uri")
+            .and_then(|u| u.as_str())
+            .ok_or((INVALID_PARAMS, "Missing uri".to_string()))?;
+        let text = self
+            .resource_text(uri)
+            .map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+        Ok(json!({
+            "contents": [{ "uri": uri, "mimeType": "text/markdown", "text": text }],
+        }))
+    }
+
+    fn resource_text(&self, uri: &str) -> Result<String> {
+        let rel = uri
+            .strip_prefix(URI_PREFIX)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported resource URI: {}", uri))?;
+        let rel = match rel.strip_prefix("contacts/") {
+            Some(name) if !name.contains('/') => format!("contacts/{}/AGENTS.md", name),
+            _ => rel.to_string(),
+        };
+        let (path, area) = tools::resolve_path(&self.base, &rel)?;
+        if area == Area::Drafts {
+            bail!("Unsupported resource URI: {}", uri);
+        }
+        self.access.check_read(area)?;
+        std::fs::read_to_string(&path).map_err(|_| anyhow::anyhow!("Resource not found: {}", uri))
+    }
+
+    /// Serve newline-delimited JSON-RPC until `input` closes.
+    pub fn serve<R: BufRead, W: Write>(&self, input: R, mut output: W) -> Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let reply = match serde_json::from_str::<Value>(&line) {
+                Ok(msg) => self.handle(&msg),
+                Err(e) => Some(json!({
+                    "jsonrpc": "2.0",
+                    "id": Value::Null,
+                    "error": { "code": PARSE_ERROR, "message": format!("Parse error: {}", e) },
+                })),
+            };
+            if let Some(reply) = reply {
+                writeln!(output, "{}", reply)?;
+                output.flush()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn resource_templates() -> Value {
+    json!([
+        {
+            "uriTemplate": format!("{}conversations/{{file}}", URI_PREFIX),
+            "name": "Conversation thread",
+            "mimeType": "text/markdown",
+        },
+        {
+            "uriTemplate": format!("{}contacts/{{name}}", URI_PREFIX),
+            "name": "Contact AGENTS.md",
+            "mimeType": "text/markdown",
+        },
+    ])
+}
+
+/// Sorted `.md` file names (`files = true`) or subdirectory names in `dir`.
+fn sorted_entries(dir: &std::path::Path, files: bool) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| {
+            let p = e.path();
+            if files {
+                p.is_file() && p.extension().is_some_and(|x| x == "md")
+            } else {
+                p.is_dir()
+            }
+        })
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+/// corky mcp [--scope MAILBOX] [--as PRINCIPAL]
+pub fn run(scope: Option<&str>, principal: &str) -> Result<()> {
+    let data_dir = resolve::data_dir();
+    let config = corky_config::try_load_config(None);
+    let owner_name = config
+        .as_ref()
+        .and_then(|c| c.owner.as_ref())
+        .map(|o| o.name.clone());
+    let access = match scope {
+        Some(name) => Access::for_mailbox(
+            name,
+            config.as_ref().and_then(|c| c.mailboxes.get(name)),
+            principal,
+        ),
+        None => Access::owner(),
+    };
+
+    let server = Server::new(data_dir, access, owner_name)?;
+    eprintln!(
+        "corky mcp: serving {} on stdio{}",
+        server.base.display(),
+        scope
+            .map(|s| format!(" (mailbox '{}' as '{}')", s, principal))
+            .unwrap_or_default()
+    );
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    server.serve(stdin.lock(), stdout.lock())
+}
@@ -0,0 +1,318 @@
+//! MCP tool definitions and dispatch.
+//!
+//! Tool results are JSON text using the same result types as `--format json`
+//! (`UnansweredReport`, `ContactInfo`, `ScheduleReport`, `SearchHit`).
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use std::path::{Component, Path, PathBuf};
+
+use super::access::Area;
+use super::Server;
+use crate::config::contact as contact_config;
+use crate::config::owner::Identity;
+use crate::contact;
+use crate::draft;
+use crate::mailbox::find_unanswered;
+use crate::mailbox::validate_draft::validate_draft;
+use crate::schedule;
+use crate::search::{self, index::SearchIndex, query::Query};
+
+/// Tool definitions for `tools/list`.
+pub fn definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "search",
+            "description": "Full-text search across conversations and drafts. Supports terms, \"phrases\", from:, to:, label:, account:, before:/after:YYYY-MM-DD and has:attachment.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "Search query"},
+                    "limit": {"type": "integer", "description": "Maximum results (default 20)"}
+                },
+                "required": ["query"]
+            }
+        }),
+        json!({
+            "name": "read_conversation",
+            "description": "Read a conversation thread as markdown. Accepts a slug or a path such as conversations/{slug}.md.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": {"type": "string", "description": "Conversation slug or relative path"}
+                },
+                "required": ["path"]
+            }
+        }),
+        json!({
+            "name": "list_unanswered",
+            "description": "List threads whose last message is not from the owner.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "from": {"type": "string", "description": "Extra name or address to match as 'your' messages (besides account addresses, [owner] name and aliases)"}
+                }
+            }
+        }),
+        json!({
+            "name": "create_draft",
+            "description": "Create a new email draft (status: draft). Returns the draft path.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "subject": {"type": "string"},
+                    "to": {"type": "string"},
+                    "cc": {"type": "string"},
+                    "account": {"type": "string"},
+                    "from": {"type": "string"},
+                    "in_reply_to": {"type": "string"},
+                    "body": {"type": "string", "description": "Markdown body below the subject heading"},
+                    "send_at": {"type": "string", "description": "Set scheduled_at: HH:MM or 'YYYY-MM-DD HH:MM' in the recipient contact's timezone, or RFC 3339"}
+                },
+                "required": ["subject", "to"]
+            }
+        }),
+        json!({
+            "name": "validate_draft",
+            "description": "Validate a draft file. Returns errors and warnings; valid when there are no errors.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": {"type": "string", "description": "Draft path, e.g. drafts/2025-01-01-hello.md"}
+                },
+                "required": ["path"]
+            }
+        }),
+        json!({
+            "name": "contact_info",
+            "description": "Show a contact's emails, AGENTS.md context, thread history and relationship stats (timeline, reply times, owed replies, topics).",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "name": {"type": "string", "description": "Contact name"}
+                },
+                "required": ["name"]
+            }
+        }),
+        json!({
+            "name": "list_schedule",
+            "description": "List pending scheduled email and social drafts.",
+            "inputSchema": {"type": "object", "properties": {}}
+        }),
+    ]
+}
+
+fn str_arg<'a>(args: &'a Value, key: &str) -> Option<&'a str> {
+    args.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty())
+}
+
+fn required<'a>(args: &'a Value, key: &str) -> Result<&'a str> {
+    str_arg(args, key).ok_or_else(|| anyhow::anyhow!("Missing required argument: {}", key))
+}
+
+/// Resolve a relative path inside the server's base directory, rejecting
+/// absolute paths and `..`, and return it with the area it belongs to.
+pub fn resolve_path(base: &Path, rel: &str) -> Result<(PathBuf, Area)> {
+    let rel_path = Path::new(rel);
+    if rel_path
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        bail!("Invalid path '{}': must be relative to the data directory", rel);
+    }
+    let area = rel_path
+        .components()
+        .find_map(|c| match c.as_os_str().to_str() {
+            Some("conversations") => Some(Area::Conversations),
+            Some("drafts") => Some(Area::Drafts),
+            Some("contacts") => Some(Area::Contacts),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow::anyhow!("Path '{}' is not in conversations/, drafts/ or contacts/", rel))?;
+    Ok((base.join(rel_path), area))
+}
+
+/// Call a tool and return its text result.
+pub fn call(server: &Server, name: &str, args: &Value) -> Result<String> {
+    match name {
+        "search" => search_tool(server, args),
+        "read_conversation" => read_conversation(server, args),
+        "list_unanswered" => list_unanswered(server, args),
+        "create_draft" => create_draft(server, args),
+        "validate_draft" => validate(server, args),
+        "contact_info" => contact_info(server, args),
+        "list_schedule" => list_schedule(server),
+        other => bail!("Unknown tool: {}", other),
+    }
+}
+
+fn search_tool(server: &Server, args: &Value) -> Result<String> {
+    server.access.check_read(Area::Conversations)?;
+    let query = Query::parse(required(args, "query")?)?;
+    let limit = args.get("limit").and_then(|v| v.as_u64()).unwrap_or(20) as usize;
+
+    let (index, _) = SearchIndex::open(&server.data_dir, false)?;
+    let prefix = server.rel_prefix();
+    let drafts_ok = server.access.can_read(Area::Drafts);
+    let hits: Vec<_> = search::search(&index, &server.data_dir, &query, usize::MAX)
+        .into_iter()
+        .filter(|h| h.path.starts_with(&prefix))
+        .filter(|h| drafts_ok || h.kind != search::index::DocKind::Draft)
+        .map(|mut h| {
+            h.path = h.path[prefix.len()..].to_string();
+            h
+        })
+        .take(limit)
+        .collect();
+    Ok(serde_json::to_string_pretty(&hits)?)
+}
+
+fn read_conversation(server: &Server, args: &Value) -> Result<String> {
+    let arg = required(args, "path")?;
+    let rel = if arg.contains('/') {
+        arg.to_string()
+    } else {
+        format!("conversations/{}.md", arg.trim_end_matches(".md"))
+    };
+    let (path, area) = resolve_path(&server.base, &rel)?;
+    if area != Area::Conversations {
+        bail!("'{}' is not a conversation", rel);
+    }
+    server.access.check_read(area)?;
+    std::fs::read_to_string(&path).map_err(|_| anyhow::anyhow!("Conversation not found: {}", rel))
+}
+
+fn list_unanswered(server: &Server, args: &Value) -> Result<String> {
+    server.access.check_read(Area::Conversations)?;
+    let me = Identity::load(str_arg(args, "from").or(server.owner_name.as_deref()));
+    if !me.is_configured() {
+        bail!("No 'from' argument and no [owner] name or accounts in .corky.toml");
+    }
+
+    let mut dirs = vec![(
+        server.access.mailbox.clone().unwrap_or_else(|| "Root".to_string()),
+        server.base.join("conversations"),
+    )];
+    if server.access.mailbox.is_none() {
+        if let Ok(entries) = std::fs::read_dir(server.data_dir.join("mailboxes")) {
+            let mut entries: Vec<_> = entries.flatten().filter(|e| e.path().is_dir()).collect();
+            entries.sort_by_key(|e| e.file_name());
+            for entry in entries {
+                dirs.push((
+                    entry.file_name().to_string_lossy().to_string(),
+                    entry.path().join("conversations"),
+                ));
+            }
+        }
+    }
+    let report = find_unanswered::collect_dirs(&dirs, &me)?;
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+fn create_draft(server: &Server, args: &Value) -> Result<String> {
+    server.access.check_write(Area::Drafts)?;
+    let drafts_dir = server.base.join("drafts");
+    let contacts = contact_config::load_contacts(None).unwrap_or_default();
+    let fields = [required(args, "to")?, str_arg(args, "cc").unwrap_or("")];
+    let scheduled = str_arg(args, "send_at")
+        .map(|spec| draft::send_at::resolve(spec, &fields, &contacts, chrono::Utc::now()))
+        .transpose()?;
+    let path = draft::new::create_in(
+        &drafts_dir,
+        required(args, "subject")?,
+        required(args, "to")?,
+        str_arg(args, "cc"),
+        str_arg(args, "account"),
+        str_arg(args, "from"),
+        str_arg(args, "in_reply_to"),
+        server.owner_name.as_deref().unwrap_or(""),
+        &[],
+    )?;
+    if let Some(body) = str_arg(args, "body") {
+        let mut content = std::fs::read_to_string(&path)?;
+        content.push_str(body.trim_end());
+        content.push('\n');
+        std::fs::write(&path, content)?;
+    }
+    let rel = path.strip_prefix(&server.base).unwrap_or(&path);
+    if let Some((at, zone)) = scheduled {
+        draft::send_at::set_scheduled_at(&path, at)?;
+        return Ok(json!({
+            "path": rel.to_string_lossy(),
+            "scheduled_at": at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            "zone": zone,
+            "warnings": draft::send_at::off_hours_warnings(&fields, &contacts, at),
+        })
+        .to_string());
+    }
+    Ok(json!({ "path": rel.to_string_lossy() }).to_string())
+}
+
+fn validate(server: &Server, args: &Value) -> Result<String> {
+    let rel = required(args, "path")?;
+    let (path, area) = resolve_path(&server.base, rel)?;
+    if area != Area::Drafts {
+        bail!("'{}' is not a draft", rel);
+    }
+    server.access.check_read(area)?;
+    let (warnings, errors): (Vec<String>, Vec<String>) = validate_draft(&path)
+        .into_iter()
+        .partition(|i| i.starts_with("Warning:"));
+    Ok(serde_json::to_string_pretty(&json!({
+        "path": rel,
+        "valid": errors.is_empty(),
+        "errors": errors,
+        "warnings": warnings,
+    }))?)
+}
+
+fn contact_info(server: &Server, args: &Value) -> Result<String> {
+    server.access.check_read(Area::Contacts)?;
+    let name = required(args, "name")?;
+    let mut info = contact::info::collect(name)?;
+    if let Some(ref mailbox) = server.access.mailbox {
+        // Scoped: only contacts shared with this mailbox, and only its threads
+        let agents = server.base.join("contacts").join(name).join("AGENTS.md");
+        if !agents.exists() {
+            bail!("Contact '{}' is not shared with mailbox '{}'", name, mailbox);
+        }
+        info.agents_md = std::fs::read_to_string(&agents).ok();
+        info.threads.retain(|t| &t.scope == mailbox);
+        info.last_activity = info.threads.first().map(|t| t.last_updated.clone());
+        info.stats = contact::info::relationship_stats(name, &info.emails, &info.threads)?;
+    }
+    Ok(serde_json::to_string_pretty(&info)?)
+}
+
+fn list_schedule(server: &Server) -> Result<String> {
+    server.access.check_read(Area::Drafts)?;
+    let mut report = schedule::collect_pending()?;
+    if server.access.mailbox.is_some() {
+        report.0.retain(|item| Path::new(&item.path).starts_with(&server.base));
+    }
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_path_areas() {
+        let base = Path::new("/data");
+        let (p, a) = resolve_path(base, "conversations/x.md").unwrap();
+        assert_eq!(p, Path::new("/data/conversations/x.md"));
+        assert_eq!(a, Area::Conversations);
+        assert_eq!(resolve_path(base, "mailboxes/a/drafts/d.md").unwrap().1, Area::Drafts);
+        assert_eq!(resolve_path(base, "contacts/bob/AGENTS.md").unwrap().1, Area::Contacts);
+    }
+
+    #[test]
+    fn resolve_path_rejects_escapes() {
+        let base = Path::new("/data");
+        assert!(resolve_path(base, "../secret/conversations/x.md").is_err());
+        assert!(resolve_path(base, "/etc/passwd").is_err());
+        assert!(resolve_path(base, "conversations/../../x").is_err());
+        assert!(resolve_path(base, ".corky.toml").is_err());
+    }
+}
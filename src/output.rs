@@ -0,0 +1,110 @@
+//! Output formats for listing commands (`--format table|json|jsonl`).
+//!
+//! Listing commands build a result type implementing [`Report`] and hand it
+//! to [`emit`]. `table` is the human-readable text; `json` pretty-prints the
+//! serde shape (an array for listings, an object for single entities);
+//! `jsonl` prints one compact JSON object per line (one per array element).
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Table,
+    /// Pretty-printed JSON document
+    Json,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// A command result with a stable serde schema and a human rendering.
+pub trait Report: Serialize {
+    /// Human-readable output for `--format table` (including empty-result messages).
+    fn render_table(&self) -> String;
+}
+
+/// Render a report in the given format (with trailing newline, or empty).
+pub fn render<R: Report>(format: OutputFormat, report: &R) -> Result<String> {
+    Ok(match format {
+        OutputFormat::Table => report.render_table(),
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(report)?),
+        OutputFormat::Jsonl => match serde_json::to_value(report)? {
+            serde_json::Value::Array(items) => {
+                let mut out = String::new();
+                for item in items {
+                    out.push_str(&serde_json::to_string(&item)?);
+                    out.push('\n');
+                }
+                out
+            }
+            other => format!("{}\n", serde_json::to_string(&other)?),
+        },
+    })
+}
+
+/// Print a report to stdout in the given format.
+pub fn emit<R: Report>(format: OutputFormat, report: &R) -> Result<()> {
+    print!("{}", render(format, report)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(transparent)]
+    struct Names(Vec<Name>);
+
+    #[derive(Serialize)]
+    struct Name {
+        name: String,
+    }
+
+    impl Report for Names {
+        fn render_table(&self) -> String {
+            self.0.iter().map(|n| format!("  {}\n", n.name)).collect()
+        }
+    }
+
+    fn names() -> Names {
+        Names(vec![
+            Name { name: "a".into() },
+            Name { name: "b".into() },
+        ])
+    }
+
+    #[test]
+    fn render_table() {
+        assert_eq!(render(OutputFormat::Table, &names()).unwrap(), "  a\n  b\n");
+    }
+
+    #[test]
+    fn render_json_array() {
+        let out = render(OutputFormat::Json, &names()).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(v, serde_json::json!([{"name": "a"}, {"name": "b"}]));
+    }
+
+    #[test]
+    fn render_jsonl_one_per_line() {
+        let out = render(OutputFormat::Jsonl, &names()).unwrap();
+        assert_eq!(out, "{\"name\":\"a\"}\n{\"name\":\"b\"}\n");
+        assert_eq!(render(OutputFormat::Jsonl, &Names(vec![])).unwrap(), "");
+    }
+
+    #[test]
+    fn render_jsonl_object_single_line() {
+        let out = render(OutputFormat::Jsonl, &Name { name: "x".into() }).unwrap();
+        assert_eq!(out, "{\"name\":\"x\"}\n");
+    }
+
+    impl Report for Name {
+        fn render_table(&self) -> String {
+            format!("{}\n", self.name)
+        }
+    }
+}
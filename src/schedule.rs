@@ -5,8 +5,10 @@
 
 use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
+use crate::output::{emit, OutputFormat, Report};
 use crate::resolve;
 use crate::social::draft::SocialDraft;
 
@@ -86,7 +88,7 @@ pub fn scan_scheduled(now: DateTime<Utc>) -> Result<Vec<ScheduledItem>> {
 
 /// Scan a social/ directory for drafts with scheduled_at <= deadline.
 /// Accepts `draft` or `ready` status — setting `scheduled_at` implies readiness.
-/// Skips `published` items (already posted).
+/// Skips `published` and `retracted` items (already posted or deleted).
 fn scan_social_dir(
     dir: &Path,
     deadline: DateTime<Utc>,
@@ -97,8 +99,12 @@ fn scan_social_dir(
         if path.extension().map(|e| e == "md").unwrap_or(false) {
             if let Ok(content) = std::fs::read_to_string(&path) {
                 if let Ok(draft) = SocialDraft::parse(&content) {
-                    // Skip already-published items (prevents double-publish)
-                    if draft.meta.status == crate::social::draft::DraftStatus::Published {
+                    // Skip already-published or retracted items (prevents double-publish)
+                    if matches!(
+                        draft.meta.status,
+                        crate::social::draft::DraftStatus::Published
+                            | crate::social::draft::DraftStatus::Retracted
+                    ) {
                         continue;
                     }
                     if let Some(scheduled_at) = draft.meta.scheduled_at {
@@ -303,8 +309,46 @@ pub fn run(dry_run: bool) -> Result<()> {
     Ok(())
 }
 
-/// List all pending scheduled items (due and future).
-pub fn list() -> Result<()> {
+/// A pending scheduled item as reported by `corky schedule list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleEntry {
+    pub scheduled_at: DateTime<Utc>,
+    /// "social" or "email"
+    pub kind: String,
+    /// Platform (social) or subject (email)
+    pub label: String,
+    pub path: String,
+    /// Scheduled time has passed
+    pub due: bool,
+}
+
+/// Result of `corky schedule list` (JSON: array of [`ScheduleEntry`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct ScheduleReport(pub Vec<ScheduleEntry>);
+
+impl Report for ScheduleReport {
+    fn render_table(&self) -> String {
+        if self.0.is_empty() {
+            return "No scheduled items.\n".to_string();
+        }
+        let mut out = String::new();
+        for item in &self.0 {
+            let due = if item.due { " [DUE]" } else { "" };
+            out.push_str(&format!(
+                "  {} {} — {} ({}){due}\n",
+                item.scheduled_at.format("%Y-%m-%d %H:%M UTC"),
+                item.kind,
+                item.label,
+                item.path,
+            ));
+        }
+        out
+    }
+}
+
+/// Collect all pending scheduled items (due and future), soonest first.
+pub fn collect_pending() -> Result<ScheduleReport> {
     let now = Utc::now();
     // Use a far-future deadline to find all scheduled items (not just due ones)
     let far_future = now + chrono::Duration::days(365 * 10);
@@ -338,27 +382,23 @@ pub fn list() -> Result<()> {
 
     items.sort_by_key(|item| item.scheduled_at);
 
-    if items.is_empty() {
-        println!("No scheduled items.");
-        return Ok(());
-    }
-
-    for item in &items {
-        let due = if item.scheduled_at <= now {
-            " [DUE]"
-        } else {
-            ""
-        };
-        println!(
-            "  {} {} — {} ({}){due}",
-            item.scheduled_at.format("%Y-%m-%d %H:%M UTC"),
-            item.kind,
-            item.label,
-            item.path.display(),
-        );
-    }
+    Ok(ScheduleReport(
+        items
+            .into_iter()
+            .map(|item| ScheduleEntry {
+                due: item.scheduled_at <= now,
+                scheduled_at: item.scheduled_at,
+                kind: item.kind.to_string(),
+                label: item.label,
+                path: item.path.display().to_string(),
+            })
+            .collect(),
+    ))
+}
 
-    Ok(())
+/// List all pending scheduled items (due and future).
+pub fn list(format: OutputFormat) -> Result<()> {
+    emit(format, &collect_pending()?)
 }
 
 #[cfg(test)]
@@ -571,6 +611,24 @@ mod tests {
         assert!(items.is_empty());
     }
 
+    // S8c: Social draft with retracted status — skipped
+    #[test]
+    fn s8c_social_retracted_skipped() {
+        let tmp = TempDir::new().unwrap();
+        let social = tmp.path().join("social");
+        std::fs::create_dir_all(&social).unwrap();
+
+        let past = Utc::now() - Duration::minutes(5);
+        let content = make_social_draft(Some(past), "retracted");
+        std::fs::write(social.join("retracted.md"), &content).unwrap();
+
+        let now = Utc::now();
+        let deadline = now + Duration::seconds(GRACE_SECONDS);
+        let mut items = Vec::new();
+        scan_social_dir(&social, deadline, &mut items).unwrap();
+        assert!(items.is_empty());
+    }
+
     // S9: Non-md files ignored
     #[test]
     fn s9_non_md_ignored() {
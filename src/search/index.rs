@@ -0,0 +1,401 @@
+//! Inverted index over conversations and drafts (`.search-index.json`).
+//!
+//! Documents are keyed by their path relative to the data directory. The
+//! index is refreshed incrementally: files whose mtime/size changed are
+//! re-indexed, deleted files are dropped. Because sync resets a thread's
+//! mtime to its last message date, `merge_message_to_file` also records
+//! written files in `.search-index.pending` (see [`note_changed`]) so they
+//! are re-indexed even when mtime and size happen to match.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::query::tokenize;
+use crate::sync::imap_sync::parse_msg_date;
+use crate::sync::markdown::parse_thread_markdown;
+
+pub const INDEX_FILE: &str = ".search-index.json";
+pub const PENDING_FILE: &str = ".search-index.pending";
+const INDEX_VERSION: u32 = 2;
+
+/// Subject tokens count this many times towards term frequency.
+const SUBJECT_WEIGHT: u32 = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocKind {
+    #[default]
+    Conversation,
+    Draft,
+}
+
+impl std::fmt::Display for DocKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocKind::Conversation => write!(f, "conversation"),
+            DocKind::Draft => write!(f, "draft"),
+        }
+    }
+}
+
+/// Per-document metadata stored in the index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DocEntry {
+    pub kind: DocKind,
+    pub subject: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub accounts: Vec<String>,
+    /// Senders (conversation message authors, draft `from`/`author`)
+    #[serde(default)]
+    pub from: Vec<String>,
+    /// Recipients (To + CC)
+    #[serde(default)]
+    pub to: Vec<String>,
+    /// Last activity as a Unix timestamp (0 = unknown)
+    #[serde(default)]
+    pub date: i64,
+    #[serde(default)]
+    pub has_attachment: bool,
+    #[serde(default)]
+    pub mtime: i64,
+    #[serde(default)]
+    pub size: u64,
+    /// Distinct terms in this document (for removal from postings)
+    #[serde(default)]
+    pub terms: Vec<String>,
+}
+
+/// Counts from an incremental refresh.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RefreshStats {
+    pub indexed: usize,
+    pub removed: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub docs: BTreeMap<String, DocEntry>,
+    /// term → (doc id → term frequency)
+    #[serde(default)]
+    pub postings: HashMap<String, HashMap<String, u32>>,
+}
+
+/// Record that a conversation or draft file changed so the next search
+/// re-indexes it. No-op unless an index exists in one of the file's
+/// ancestor directories (the data dir is at most three levels up:
+/// `mailboxes/{name}/conversations/{file}`).
+pub fn note_changed(path: &Path) {
+    for dir in path.ancestors().skip(1).take(4) {
+        if dir.join(INDEX_FILE).exists() {
+            let Ok(rel) = path.strip_prefix(dir) else {
+                return;
+            };
+            if let Ok(mut f) = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(dir.join(PENDING_FILE))
+            {
+                let _ = writeln!(f, "{}", doc_id(rel));
+            }
+            return;
+        }
+    }
+}
+
+/// Doc ID for a path relative to the data dir (always `/`-separated).
+fn doc_id(rel: &Path) -> String {
+    rel.components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// List `.md` files directly inside `dir`.
+fn md_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().and_then(|e| e.to_str()) == Some("md"))
+        .collect()
+}
+
+/// All indexable files under `data_dir`: (doc id, path, kind).
+pub fn collect_documents(data_dir: &Path) -> Vec<(String, PathBuf, DocKind)> {
+    let mut roots = vec![
+        (data_dir.join("conversations"), DocKind::Conversation),
+        (data_dir.join("drafts"), DocKind::Draft),
+    ];
+    if let Ok(entries) = std::fs::read_dir(data_dir.join("mailboxes")) {
+        let mut mailboxes: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir())
+            .collect();
+        mailboxes.sort();
+        for mb in mailboxes {
+            roots.push((mb.join("conversations"), DocKind::Conversation));
+            roots.push((mb.join("drafts"), DocKind::Draft));
+        }
+    }
+
+    let mut docs = Vec::new();
+    for (root, kind) in roots {
+        for path in md_files(&root) {
+            if let Ok(rel) = path.strip_prefix(data_dir) {
+                docs.push((doc_id(rel), path.clone(), kind));
+            }
+        }
+    }
+    docs.sort_by(|a, b| a.0.cmp(&b.0));
+    docs
+}
+
+fn split_addresses(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect()
+}
+
+/// Whether `line` is an `[Attachment: NAME]` marker written by sync or a chat importer.
+fn is_attachment_line(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with("[Attachment: ") && line.ends_with(']')
+}
+
+/// Extract metadata and searchable text from a conversation file.
+fn conversation_doc(text: &str) -> (DocEntry, String) {
+    let mut doc = DocEntry {
+        kind: DocKind::Conversation,
+        ..Default::default()
+    };
+    let Some(thread) = parse_thread_markdown(text) else {
+        return (doc, text.to_string());
+    };
+
+    doc.subject = thread.subject.clone();
+    doc.labels = thread.labels.clone();
+    doc.accounts = thread.accounts.clone();
+    let mut body = String::new();
+    for msg in &thread.messages {
+        if !doc.from.contains(&msg.from) {
+            doc.from.push(msg.from.clone());
+        }
+        for addr in split_addresses(&msg.to).into_iter().chain(split_addresses(&msg.cc)) {
+            if !doc.to.contains(&addr) {
+                doc.to.push(addr);
+            }
+        }
+        body.push_str(&msg.from);
+        body.push('\n');
+        body.push_str(&msg.body);
+        body.push('\n');
+        if msg.body.lines().any(is_attachment_line) {
+            doc.has_attachment = true;
+        }
+    }
+    let last = if thread.last_date.is_empty() {
+        thread.messages.last().map(|m| m.date.clone()).unwrap_or_default()
+    } else {
+        thread.last_date.clone()
+    };
+    doc.date = parse_msg_date(&last).timestamp();
+    (doc, body)
+}
+
+/// Extract metadata and searchable text from a draft file.
+fn draft_doc(path: &Path, text: &str, mtime: i64) -> (DocEntry, String) {
+    let mut doc = DocEntry {
+        kind: DocKind::Draft,
+        date: mtime,
+        ..Default::default()
+    };
+    let (meta, subject, body) = match crate::draft::parse_draft(path) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("  Warning: {}: {}; indexed as plain text", path.display(), e);
+            return (doc, text.to_string());
+        }
+    };
+
+    doc.subject = subject;
+    for key in ["From", "Author"] {
+        if let Some(v) = meta.get(key).filter(|v| !v.is_empty()) {
+            doc.from.push(v.clone());
+        }
+    }
+    for key in ["To", "CC"] {
+        if let Some(v) = meta.get(key) {
+            doc.to.extend(split_addresses(v));
+        }
+    }
+    if let Some(account) = meta.get("Account").filter(|v| !v.is_empty()) {
+        doc.accounts.push(account.clone());
+    }
+    doc.has_attachment = crate::draft::parse_draft_yaml(text)
+        .map(|m| !m.attachments.is_empty())
+        .unwrap_or(false);
+    (doc, body)
+}
+
+fn file_stamp(path: &Path) -> (i64, u64) {
+    let Ok(meta) = path.metadata() else {
+        return (0, 0);
+    };
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    (mtime, meta.len())
+}
+
+impl SearchIndex {
+    /// Load the index from `data_dir`, or start empty if missing/outdated.
+    pub fn load(data_dir: &Path) -> Self {
+        std::fs::read_to_string(data_dir.join(INDEX_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str::<SearchIndex>(&s).ok())
+            .filter(|idx| idx.version == INDEX_VERSION)
+            .unwrap_or_else(|| SearchIndex {
+                version: INDEX_VERSION,
+                ..Default::default()
+            })
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<()> {
+        std::fs::write(data_dir.join(INDEX_FILE), serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Load, refresh and save the index for `data_dir`. With `rebuild`, the
+    /// existing index is discarded first.
+    pub fn open(data_dir: &Path, rebuild: bool) -> Result<(Self, RefreshStats)> {
+        let mut index = if rebuild {
+            SearchIndex {
+                version: INDEX_VERSION,
+                ..Default::default()
+            }
+        } else {
+            Self::load(data_dir)
+        };
+        let pending_path = data_dir.join(PENDING_FILE);
+        let pending: HashSet<String> = std::fs::read_to_string(&pending_path)
+            .unwrap_or_default()
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
+
+        let stats = index.refresh(data_dir, &pending);
+        index.save(data_dir)?;
+        if pending_path.exists() {
+            std::fs::remove_file(&pending_path)?;
+        }
+        Ok((index, stats))
+    }
+
+    /// Re-index new, changed and `pending` documents; drop deleted ones.
+    pub fn refresh(&mut self, data_dir: &Path, pending: &HashSet<String>) -> RefreshStats {
+        let mut stats = RefreshStats::default();
+        let current = collect_documents(data_dir);
+        let present: HashSet<&str> = current.iter().map(|(id, _, _)| id.as_str()).collect();
+
+        let stale: Vec<String> = self
+            .docs
+            .keys()
+            .filter(|id| !present.contains(id.as_str()))
+            .cloned()
+            .collect();
+        for id in stale {
+            self.remove(&id);
+            stats.removed += 1;
+        }
+
+        for (id, path, kind) in &current {
+            let (mtime, size) = file_stamp(path);
+            let unchanged = self
+                .docs
+                .get(id)
+                .is_some_and(|d| d.mtime == mtime && d.size == size);
+            if unchanged && !pending.contains(id) {
+                continue;
+            }
+            if self.index_file(id, path, *kind).is_ok() {
+                stats.indexed += 1;
+            }
+        }
+        stats
+    }
+
+    /// Remove a document and its postings.
+    pub fn remove(&mut self, id: &str) {
+        let Some(doc) = self.docs.remove(id) else {
+            return;
+        };
+        for term in &doc.terms {
+            if let Some(list) = self.postings.get_mut(term) {
+                list.remove(id);
+                if list.is_empty() {
+                    self.postings.remove(term);
+                }
+            }
+        }
+    }
+
+    /// (Re-)index a single file under the given doc ID.
+    pub fn index_file(&mut self, id: &str, path: &Path, kind: DocKind) -> Result<()> {
+        let text = std::fs::read_to_string(path)?;
+        let (mtime, size) = file_stamp(path);
+        let (mut doc, body) = match kind {
+            DocKind::Conversation => conversation_doc(&text),
+            DocKind::Draft => draft_doc(path, &text, mtime),
+        };
+        doc.mtime = mtime;
+        doc.size = size;
+
+        let mut freqs: HashMap<String, u32> = HashMap::new();
+        for token in tokenize(&doc.subject) {
+            *freqs.entry(token).or_default() += SUBJECT_WEIGHT;
+        }
+        for token in tokenize(&body) {
+            *freqs.entry(token).or_default() += 1;
+        }
+        let mut terms: Vec<String> = freqs.keys().cloned().collect();
+        terms.sort();
+        doc.terms = terms;
+
+        self.remove(id);
+        for (term, tf) in freqs {
+            self.postings.entry(term).or_default().insert(id.to_string(), tf);
+        }
+        self.docs.insert(id.to_string(), doc);
+        Ok(())
+    }
+
+    /// Number of documents containing `term`.
+    pub fn doc_freq(&self, term: &str) -> usize {
+        self.postings.get(term).map(|p| p.len()).unwrap_or(0)
+    }
+
+    /// Term frequency of `term` in document `id`.
+    pub fn term_freq(&self, term: &str, id: &str) -> u32 {
+        self.postings
+            .get(term)
+            .and_then(|p| p.get(id))
+            .copied()
+            .unwrap_or(0)
+    }
+}
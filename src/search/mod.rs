@@ -0,0 +1,230 @@
+//! Full-text search across conversations and drafts (`corky search`).
+
+pub mod index;
+pub mod query;
+
+use anyhow::{bail, Result};
+use chrono::DateTime;
+use serde::Serialize;
+use std::path::Path;
+
+use self::index::{DocKind, SearchIndex};
+use self::query::{normalize_phrase, Query};
+use crate::output::{emit, OutputFormat, Report};
+use crate::resolve;
+
+/// Maximum snippet length in characters.
+const SNIPPET_LEN: usize = 160;
+
+/// A ranked search result.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    /// Path relative to the data directory
+    pub path: String,
+    pub kind: DocKind,
+    pub subject: String,
+    /// Last activity as a Unix timestamp (0 = unknown)
+    pub date: i64,
+    pub score: f64,
+    pub snippet: String,
+}
+
+/// Lines that are thread/draft structure rather than content.
+fn is_structural(line: &str) -> bool {
+    line.starts_with("# ")
+        || line.starts_with("## ")
+        || line.starts_with("**")
+        || line == "---"
+        || line.is_empty()
+}
+
+/// Build a snippet: the first content line containing any needle, trimmed to
+/// a window around the match. Falls back to the first content line.
+pub fn snippet(text: &str, needles: &[String]) -> String {
+    let content = |line: &&str| !is_structural(line.trim());
+    let lines: Vec<&str> = text.lines().map(|l| l.trim()).filter(|l| content(l)).collect();
+
+    let hit = lines.iter().find_map(|line| {
+        let lower = line.to_lowercase();
+        needles
+            .iter()
+            .filter_map(|n| lower.find(n.as_str()))
+            .min()
+            .map(|byte| (*line, lower[..byte].chars().count()))
+    });
+    let Some((line, pos)) = hit.or_else(|| lines.first().map(|l| (*l, 0))) else {
+        return String::new();
+    };
+
+    let chars: Vec<char> = line.chars().collect();
+    if chars.len() <= SNIPPET_LEN {
+        return line.to_string();
+    }
+    let start = pos.saturating_sub(SNIPPET_LEN / 3).min(chars.len() - SNIPPET_LEN);
+    let end = start + SNIPPET_LEN;
+    let mut out: String = chars[start..end].iter().collect();
+    if start > 0 {
+        out = format!("…{}", out);
+    }
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+/// Run `query` against `index`. Text terms are ANDed and ranked by TF-IDF
+/// (subject matches weigh more); ties and filter-only queries sort newest first.
+pub fn search(index: &SearchIndex, data_dir: &Path, query: &Query, limit: usize) -> Vec<SearchHit> {
+    let tokens = query.required_tokens();
+    let total = index.docs.len().max(1) as f64;
+
+    // Candidates: docs containing every token (or all docs for filter-only queries)
+    let candidates: Vec<&String> = match tokens.first() {
+        None => index.docs.keys().collect(),
+        Some(first) => {
+            let Some(list) = index.postings.get(first) else {
+                return vec![];
+            };
+            let mut ids: Vec<&String> = list
+                .keys()
+                .filter(|id| tokens[1..].iter().all(|t| index.term_freq(t, id) > 0))
+                .collect();
+            ids.sort();
+            ids
+        }
+    };
+
+    let mut needles: Vec<String> = query.phrases.clone();
+    needles.extend(tokens.iter().cloned());
+
+    let mut hits = Vec::new();
+    for id in candidates {
+        let Some(doc) = index.docs.get(id) else {
+            continue;
+        };
+        if !query.matches_filters(doc) {
+            continue;
+        }
+        let text = std::fs::read_to_string(data_dir.join(id)).unwrap_or_default();
+        if !query.phrases.is_empty() {
+            let normalized = format!(" {} ", normalize_phrase(&text));
+            if !query
+                .phrases
+                .iter()
+                .all(|p| normalized.contains(&format!(" {} ", p)))
+            {
+                continue;
+            }
+        }
+
+        let score = tokens
+            .iter()
+            .map(|t| {
+                let tf = index.term_freq(t, id) as f64;
+                let idf = (1.0 + total / index.doc_freq(t).max(1) as f64).ln();
+                tf / (tf + 1.2) * idf
+            })
+            .sum();
+
+        hits.push(SearchHit {
+            path: id.clone(),
+            kind: doc.kind,
+            subject: doc.subject.clone(),
+            date: doc.date,
+            score,
+            snippet: snippet(&text, &needles),
+        });
+    }
+
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.date.cmp(&a.date))
+            .then(a.path.cmp(&b.path))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+/// Ranked hits for one query, as printed by `corky search`.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct SearchReport {
+    pub hits: Vec<SearchHit>,
+    #[serde(skip)]
+    query: String,
+}
+
+impl Report for SearchReport {
+    fn render_table(&self) -> String {
+        if self.hits.is_empty() {
+            return format!("No results for: {}\n", self.query);
+        }
+        let mut out = format!("{} result(s) for: {}\n\n", self.hits.len(), self.query);
+        for hit in &self.hits {
+            let date = DateTime::from_timestamp(hit.date, 0)
+                .filter(|_| hit.date > 0)
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            let subject = if hit.subject.is_empty() {
+                "(no subject)"
+            } else {
+                &hit.subject
+            };
+            out.push_str(&format!("  {}  [{}]  {}\n", date, hit.kind, subject));
+            out.push_str(&format!("    {}\n", hit.path));
+            if !hit.snippet.is_empty() {
+                out.push_str(&format!("    {}\n", hit.snippet));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// corky search QUERY [--limit N] [--rebuild]
+pub fn run(query_str: &str, limit: usize, rebuild: bool, format: OutputFormat) -> Result<()> {
+    let query = Query::parse(query_str)?;
+    if query.is_empty() {
+        bail!("Empty search query. Example: corky search from:alice \"project plan\"");
+    }
+
+    let data_dir = resolve::data_dir();
+    let (index, stats) = SearchIndex::open(&data_dir, rebuild)?;
+    if stats.indexed > 0 || stats.removed > 0 {
+        eprintln!(
+            "Indexed {} file(s), removed {} ({} total).",
+            stats.indexed,
+            stats.removed,
+            index.docs.len()
+        );
+    }
+
+    let report = SearchReport {
+        hits: search(&index, &data_dir, &query, limit),
+        query: query_str.to_string(),
+    };
+    emit(format, &report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snippet_skips_structure_and_finds_match() {
+        let text = "# Subject\n\n**Labels**: x\n\n---\n\n## Alice — date\n\nFirst line\nThe budget is due\n";
+        assert_eq!(snippet(text, &["budget".into()]), "The budget is due");
+        assert_eq!(snippet(text, &["nomatch".into()]), "First line");
+    }
+
+    #[test]
+    fn snippet_windows_long_lines() {
+        let line = format!("{} needle {}", "a ".repeat(200), "b ".repeat(200));
+        let s = snippet(&line, &["needle".into()]);
+        assert!(s.contains("needle"));
+        assert!(s.starts_with('…') && s.ends_with('…'));
+        assert_eq!(s.chars().count(), SNIPPET_LEN + 2);
+    }
+}
@@ -0,0 +1,267 @@
+//! Search query language.
+//!
+//! Whitespace-separated terms are ANDed together. Supported operators:
+//!
+//! - `"exact phrase"` — words must appear adjacent, in order
+//! - `from:NAME` / `to:NAME` — substring match on sender / recipients (To + CC)
+//! - `label:NAME` / `account:NAME` — exact (case-insensitive) thread label / account
+//! - `before:YYYY-MM-DD` / `after:YYYY-MM-DD` — last activity date
+//! - `has:attachment` — drafts with `attachments` in frontmatter, conversations
+//!   with an `[Attachment: NAME]` line
+//!
+//! Operator values may be quoted (`from:"Alice Smith"`). Unknown `key:value`
+//! pairs are treated as plain text.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::index::DocEntry;
+
+/// A parsed search query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    /// Free-text terms (normalized tokens)
+    pub terms: Vec<String>,
+    /// Quoted phrases (normalized, space-joined tokens)
+    pub phrases: Vec<String>,
+    pub from: Vec<String>,
+    pub to: Vec<String>,
+    pub labels: Vec<String>,
+    pub accounts: Vec<String>,
+    pub before: Option<DateTime<Utc>>,
+    pub after: Option<DateTime<Utc>>,
+    pub has_attachment: bool,
+}
+
+/// Split text into lowercase alphanumeric tokens (2–40 chars).
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| {
+            let n = t.chars().count();
+            (2..=40).contains(&n)
+        })
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// Normalize text for phrase matching: tokens joined by single spaces.
+pub fn normalize_phrase(text: &str) -> String {
+    tokenize(text).join(" ")
+}
+
+/// Split raw query input into (field, value, quoted) parts.
+fn split_parts(input: &str) -> Vec<(Option<String>, String, bool)> {
+    let mut parts = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        // Read an optional `field:` prefix followed by a bare or quoted value
+        let mut word = String::new();
+        let mut field = None;
+        let mut quoted = false;
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            if c == '"' {
+                chars.next();
+                quoted = true;
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                }
+                break;
+            }
+            if c == ':' && field.is_none() && !word.is_empty() {
+                chars.next();
+                field = Some(std::mem::take(&mut word).to_lowercase());
+                continue;
+            }
+            word.push(c);
+            chars.next();
+        }
+        parts.push((field, word, quoted));
+    }
+    parts
+}
+
+fn parse_date(field: &str, value: &str) -> Result<DateTime<Utc>> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        anyhow::anyhow!("Invalid date '{}' for {}: (expected YYYY-MM-DD)", value, field)
+    })?;
+    Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+impl Query {
+    /// Parse a query string.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut q = Query::default();
+        for (field, value, quoted) in split_parts(input) {
+            match field.as_deref() {
+                Some("from") => q.from.push(value.to_lowercase()),
+                Some("to") => q.to.push(value.to_lowercase()),
+                Some("label") => q.labels.push(value.to_lowercase()),
+                Some("account") => q.accounts.push(value.to_lowercase()),
+                Some("before") => q.before = Some(parse_date("before", &value)?),
+                Some("after") => q.after = Some(parse_date("after", &value)?),
+                Some("has") => {
+                    if value.eq_ignore_ascii_case("attachment") {
+                        q.has_attachment = true;
+                    } else {
+                        bail!("Unknown has: value '{}' (supported: attachment)", value);
+                    }
+                }
+                other => {
+                    // Unknown field: keep the whole `key:value` as text
+                    let text = match other {
+                        Some(f) => format!("{}:{}", f, value),
+                        None => value,
+                    };
+                    if quoted {
+                        let phrase = normalize_phrase(&text);
+                        if !phrase.is_empty() {
+                            q.phrases.push(phrase);
+                        }
+                    } else {
+                        q.terms.extend(tokenize(&text));
+                    }
+                }
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        q.terms.retain(|t| seen.insert(t.clone()));
+        Ok(q)
+    }
+
+    /// True if the query has no terms, phrases or filters.
+    pub fn is_empty(&self) -> bool {
+        *self == Query::default()
+    }
+
+    /// All tokens that must appear in a matching document (terms + phrase words).
+    pub fn required_tokens(&self) -> Vec<String> {
+        let mut tokens = self.terms.clone();
+        for phrase in &self.phrases {
+            tokens.extend(phrase.split(' ').map(|s| s.to_string()));
+        }
+        tokens.sort();
+        tokens.dedup();
+        tokens
+    }
+
+    /// Check the metadata filters (everything except text) against a document.
+    pub fn matches_filters(&self, doc: &DocEntry) -> bool {
+        let any_contains =
+            |haystack: &[String], needle: &str| haystack.iter().any(|h| h.to_lowercase().contains(needle));
+        let any_eq =
+            |haystack: &[String], needle: &str| haystack.iter().any(|h| h.eq_ignore_ascii_case(needle));
+
+        if !self.from.iter().all(|f| any_contains(&doc.from, f)) {
+            return false;
+        }
+        if !self.to.iter().all(|t| any_contains(&doc.to, t)) {
+            return false;
+        }
+        if !self.labels.iter().all(|l| any_eq(&doc.labels, l)) {
+            return false;
+        }
+        if !self.accounts.iter().all(|a| any_eq(&doc.accounts, a)) {
+            return false;
+        }
+        if self.has_attachment && !doc.has_attachment {
+            return false;
+        }
+        if self.before.is_some() || self.after.is_some() {
+            let Some(date) = DateTime::from_timestamp(doc.date, 0).filter(|_| doc.date > 0) else {
+                return false;
+            };
+            if self.before.is_some_and(|b| date >= b) {
+                return false;
+            }
+            if self.after.is_some_and(|a| date < a) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_lowercases_and_splits() {
+        assert_eq!(
+            tokenize("Hello, World! a alice@example.com"),
+            vec!["hello", "world", "alice", "example", "com"]
+        );
+    }
+
+    #[test]
+    fn parse_terms_and_phrases() {
+        let q = Query::parse(r#"budget "Quarterly Review" plan"#).unwrap();
+        assert_eq!(q.terms, vec!["budget", "plan"]);
+        assert_eq!(q.phrases, vec!["quarterly review"]);
+        assert_eq!(q.required_tokens(), vec!["budget", "plan", "quarterly", "review"]);
+    }
+
+    #[test]
+    fn parse_operators() {
+        let q = Query::parse(
+            r#"from:"Alice Smith" to:bob label:Work account:personal after:2025-01-01 before:2025-02-01 has:attachment"#,
+        )
+        .unwrap();
+        assert_eq!(q.from, vec!["alice smith"]);
+        assert_eq!(q.to, vec!["bob"]);
+        assert_eq!(q.labels, vec!["work"]);
+        assert_eq!(q.accounts, vec!["personal"]);
+        assert_eq!(q.after.unwrap().to_rfc3339(), "2025-01-01T00:00:00+00:00");
+        assert_eq!(q.before.unwrap().to_rfc3339(), "2025-02-01T00:00:00+00:00");
+        assert!(q.has_attachment);
+        assert!(q.terms.is_empty());
+    }
+
+    #[test]
+    fn parse_unknown_field_is_text() {
+        let q = Query::parse("https://example.com").unwrap();
+        assert_eq!(q.terms, vec!["https", "example", "com"]);
+    }
+
+    #[test]
+    fn parse_rejects_bad_values() {
+        assert!(Query::parse("before:yesterday").is_err());
+        assert!(Query::parse("has:pdf").is_err());
+    }
+
+    #[test]
+    fn empty_query() {
+        assert!(Query::parse("   ").unwrap().is_empty());
+        assert!(!Query::parse("label:x").unwrap().is_empty());
+    }
+
+    #[test]
+    fn filters_against_doc() {
+        let doc = DocEntry {
+            from: vec!["Alice Smith <alice@example.com>".into()],
+            to: vec!["bob@example.com".into()],
+            labels: vec!["Work".into()],
+            accounts: vec!["personal".into()],
+            date: DateTime::parse_from_rfc3339("2025-01-15T12:00:00Z").unwrap().timestamp(),
+            ..Default::default()
+        };
+        assert!(Query::parse("from:alice to:bob label:work").unwrap().matches_filters(&doc));
+        assert!(Query::parse("after:2025-01-15 before:2025-01-16").unwrap().matches_filters(&doc));
+        assert!(!Query::parse("after:2025-01-16").unwrap().matches_filters(&doc));
+        assert!(!Query::parse("from:carol").unwrap().matches_filters(&doc));
+        assert!(!Query::parse("account:work").unwrap().matches_filters(&doc));
+        assert!(!Query::parse("has:attachment").unwrap().matches_filters(&doc));
+    }
+}
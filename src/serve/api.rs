@@ -0,0 +1,534 @@
+//! REST handlers for `corky serve`.
+//!
+//! Handlers are plain functions from [`ApiRequest`] to [`ApiResponse`] so they
+//! can be exercised without a socket. Failures are [`ApiError`]s, whose variant
+//! picks the HTTP status. Response bodies reuse the `--format json`
+//! schemas (§5.32) where a CLI equivalent exists.
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::config::owner::Identity;
+use crate::contact;
+use crate::draft;
+use crate::mailbox::find_unanswered;
+use crate::mailbox::validate_draft::validate_draft;
+use crate::schedule;
+use crate::search::{self, index::SearchIndex, query::Query};
+use crate::sync::markdown::parse_thread_markdown;
+
+/// Shared server state.
+pub struct Api {
+    pub data_dir: PathBuf,
+    pub token: String,
+    /// `[owner] name`: default for unanswered detection and draft authorship
+    pub owner_name: Option<String>,
+    /// Set while a triggered sync is running
+    pub sync_running: Arc<AtomicBool>,
+}
+
+/// A parsed HTTP request.
+#[derive(Debug, Default)]
+pub struct ApiRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub authorization: Option<String>,
+    pub body: String,
+}
+
+impl ApiRequest {
+    /// Build from a method, a raw URL (`/path?query`) and a body.
+    pub fn new(method: &str, url: &str, authorization: Option<String>, body: String) -> Self {
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        ApiRequest {
+            method: method.to_uppercase(),
+            path: path.trim_end_matches('/').to_string(),
+            query: parse_query(query),
+            authorization,
+            body,
+        }
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.query.get(key).map(|s| s.as_str()).filter(|s| !s.is_empty())
+    }
+}
+
+#[derive(Debug)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl ApiResponse {
+    fn ok(body: Value) -> Self {
+        ApiResponse { status: 200, body }
+    }
+
+    pub fn error(status: u16, message: impl std::fmt::Display) -> Self {
+        ApiResponse {
+            status,
+            body: json!({ "error": message.to_string() }),
+        }
+    }
+}
+
+/// Why a handler failed; each variant maps to an HTTP status.
+#[derive(Debug)]
+pub enum ApiError {
+    /// `400`: malformed query, parameter or body
+    BadRequest(String),
+    /// `403`: a name reaching outside the data directory
+    Forbidden(String),
+    /// `404`: missing thread, draft, contact or mailbox
+    NotFound(String),
+    /// `500`: anything else (I/O, config)
+    Internal(anyhow::Error),
+}
+
+impl ApiError {
+    pub fn status(&self) -> u16 {
+        match self {
+            ApiError::BadRequest(_) => 400,
+            ApiError::Forbidden(_) => 403,
+            ApiError::NotFound(_) => 404,
+            ApiError::Internal(_) => 500,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::BadRequest(msg) | ApiError::Forbidden(msg) | ApiError::NotFound(msg) => write!(f, "{}", msg),
+            ApiError::Internal(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        ApiError::Internal(e)
+    }
+}
+
+impl From<std::io::Error> for ApiError {
+    fn from(e: std::io::Error) -> Self {
+        ApiError::Internal(e.into())
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(e: serde_json::Error) -> Self {
+        ApiError::Internal(e.into())
+    }
+}
+
+/// Decode `%XX` escapes (and `+` as space in query components).
+fn percent_decode(s: &str, plus_as_space: bool) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' if plus_as_space => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = |b: u8| (b as char).to_digit(16);
+                match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                    (Some(hi), Some(lo)) => {
+                        out.push((hi * 16 + lo) as u8);
+                        i += 2;
+                    }
+                    _ => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+pub fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(k, true), percent_decode(v, true))
+        })
+        .collect()
+}
+
+/// Constant-time token comparison.
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+impl Api {
+    /// True if the request carries the API token (`Authorization: Bearer`,
+    /// or `?token=` for EventSource clients that cannot set headers).
+    pub fn authorized(&self, req: &ApiRequest) -> bool {
+        let header = req
+            .authorization
+            .as_deref()
+            .and_then(|h| h.strip_prefix("Bearer "))
+            .map(|t| t.trim());
+        header
+            .or_else(|| req.param("token"))
+            .is_some_and(|t| token_matches(t, &self.token))
+    }
+
+    /// Authenticate and route a request (`/api/health` needs no token).
+    pub fn handle(&self, req: &ApiRequest) -> ApiResponse {
+        if req.path == "/api/health" {
+            return ApiResponse::ok(json!({ "status": "ok", "version": env!("CARGO_PKG_VERSION") }));
+        }
+        if !self.authorized(req) {
+            return ApiResponse::error(401, "Missing or invalid API token");
+        }
+
+        let decoded: Vec<String> = req
+            .path
+            .trim_start_matches('/')
+            .split('/')
+            .map(|seg| percent_decode(seg, false))
+            .collect();
+        let segments: Vec<&str> = decoded.iter().map(|s| s.as_str()).collect();
+        let result = match (req.method.as_str(), segments.as_slice()) {
+            ("GET", ["api", "threads"]) => self.list_threads(req),
+            ("GET", ["api", "threads", slug]) => self.get_thread(req, slug),
+            ("GET", ["api", "unanswered"]) => self.unanswered(req),
+            ("GET", ["api", "search"]) => self.search(req),
+            ("GET", ["api", "drafts"]) => self.list_drafts(req),
+            ("POST", ["api", "drafts"]) => self.create_draft(req),
+            ("GET", ["api", "drafts", file]) => self.get_draft(req, file),
+            ("POST", ["api", "drafts", file, "validate"]) => self.validate_draft(req, file),
+            ("GET", ["api", "contacts"]) => self.list_contacts(),
+            ("GET", ["api", "contacts", name]) => self.contact_info(name),
+            ("GET", ["api", "schedule"]) => self.schedule(),
+            ("POST", ["api", "sync"]) => return self.trigger_sync(),
+            (_, ["api", ..]) => return ApiResponse::error(404, format!("No route for {} {}", req.method, req.path)),
+            _ => return ApiResponse::error(404, "Not found"),
+        };
+        result.unwrap_or_else(|e| ApiResponse::error(e.status(), e))
+    }
+
+    /// Root of the data dir or of `?mailbox=NAME`.
+    fn base(&self, req: &ApiRequest) -> Result<PathBuf, ApiError> {
+        match req.param("mailbox") {
+            None => Ok(self.data_dir.clone()),
+            Some(name) => {
+                check_name(name)?;
+                let dir = self.data_dir.join("mailboxes").join(name);
+                if !dir.is_dir() {
+                    return Err(ApiError::NotFound(format!("Mailbox '{}' not found", name)));
+                }
+                Ok(dir)
+            }
+        }
+    }
+
+    fn list_threads(&self, req: &ApiRequest) -> Result<ApiResponse, ApiError> {
+        let dir = self.base(req)?.join("conversations");
+        let mut threads = Vec::new();
+        for slug in md_slugs(&dir) {
+            let Ok(text) = std::fs::read_to_string(dir.join(format!("{}.md", slug))) else {
+                continue;
+            };
+            let Some(thread) = parse_thread_markdown(&text) else {
+                continue;
+            };
+            threads.push(json!({
+                "slug": slug,
+                "subject": thread.subject,
+                "labels": thread.labels,
+                "accounts": thread.accounts,
+                "last_updated": thread.last_date,
+                "messages": thread.messages.len(),
+            }));
+        }
+        // Newest first
+        threads.sort_by_key(|t| {
+            std::cmp::Reverse(crate::sync::imap_sync::parse_msg_date(
+                t["last_updated"].as_str().unwrap_or(""),
+            ))
+        });
+        Ok(ApiResponse::ok(Value::Array(threads)))
+    }
+
+    fn get_thread(&self, req: &ApiRequest, slug: &str) -> Result<ApiResponse, ApiError> {
+        check_name(slug)?;
+        let slug = slug.trim_end_matches(".md");
+        let path = self.base(req)?.join("conversations").join(format!("{}.md", slug));
+        let text = std::fs::read_to_string(&path)
+            .map_err(|_| ApiError::NotFound(format!("Thread '{}' not found", slug)))?;
+        let thread = parse_thread_markdown(&text).unwrap_or_default();
+        Ok(ApiResponse::ok(json!({
+            "slug": slug,
+            "thread_id": thread.id,
+            "subject": thread.subject,
+            "labels": thread.labels,
+            "accounts": thread.accounts,
+            "last_updated": thread.last_date,
+            "messages": thread.messages.iter().map(|m| json!({
+                "from": m.from,
+                "to": m.to,
+                "cc": m.cc,
+                "date": m.date,
+                "body": m.body,
+            })).collect::<Vec<_>>(),
+            "markdown": text,
+        })))
+    }
+
+    fn unanswered(&self, req: &ApiRequest) -> Result<ApiResponse, ApiError> {
+        let me = Identity::load(req.param("from").or(self.owner_name.as_deref()));
+        if !me.is_configured() {
+            return Err(ApiError::BadRequest(
+                "No ?from= and no [owner] name or accounts in .corky.toml".to_string(),
+            ));
+        }
+        let dirs = match req.param("mailbox") {
+            Some(name) => vec![(name.to_string(), self.base(req)?.join("conversations"))],
+            None => {
+                let mut dirs = vec![("Root".to_string(), self.data_dir.join("conversations"))];
+                for name in subdirs(&self.data_dir.join("mailboxes")) {
+                    let conv = self.data_dir.join("mailboxes").join(&name).join("conversations");
+                    dirs.push((name, conv));
+                }
+                dirs
+            }
+        };
+        let report = find_unanswered::collect_dirs(&dirs, &me)?;
+        Ok(ApiResponse::ok(serde_json::to_value(&report)?))
+    }
+
+    fn search(&self, req: &ApiRequest) -> Result<ApiResponse, ApiError> {
+        let query = Query::parse(req.param("q").unwrap_or("")).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        if query.is_empty() {
+            return Err(ApiError::BadRequest("Missing search query: ?q=".to_string()));
+        }
+        let limit = match req.param("limit") {
+            Some(l) => l.parse().map_err(|_| ApiError::BadRequest(format!("Invalid limit: {}", l)))?,
+            None => 20,
+        };
+        let (index, _) = SearchIndex::open(&self.data_dir, false)?;
+        let hits = search::search(&index, &self.data_dir, &query, limit);
+        Ok(ApiResponse::ok(serde_json::to_value(&hits)?))
+    }
+
+    fn list_drafts(&self, req: &ApiRequest) -> Result<ApiResponse, ApiError> {
+        let dir = self.base(req)?.join("drafts");
+        let mut drafts = Vec::new();
+        for slug in md_slugs(&dir) {
+            let file = format!("{}.md", slug);
+            let Ok(text) = std::fs::read_to_string(dir.join(&file)) else {
+                continue;
+            };
+            let Some(meta) = draft::parse_draft_yaml(&text) else {
+                continue;
+            };
+            drafts.push(json!({
+                "file": file,
+                "subject": meta.subject,
+                "to": meta.to,
+                "status": meta.status,
+                "author": meta.author,
+                "scheduled_at": meta.scheduled_at,
+            }));
+        }
+        Ok(ApiResponse::ok(Value::Array(drafts)))
+    }
+
+    fn get_draft(&self, req: &ApiRequest, file: &str) -> Result<ApiResponse, ApiError> {
+        let path = self.draft_path(req, file)?;
+        let text = std::fs::read_to_string(&path)
+            .map_err(|_| ApiError::NotFound(format!("Draft '{}' not found", file)))?;
+        Ok(ApiResponse::ok(json!({
+            "file": file,
+            "meta": draft::parse_draft_yaml(&text),
+            "markdown": text,
+        })))
+    }
+
+    fn create_draft(&self, req: &ApiRequest) -> Result<ApiResponse, ApiError> {
+        #[derive(Deserialize)]
+        struct NewDraft {
+            subject: String,
+            to: String,
+            cc: Option<String>,
+            account: Option<String>,
+            from: Option<String>,
+            in_reply_to: Option<String>,
+            body: Option<String>,
+        }
+        let input: NewDraft = serde_json::from_str(&req.body)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid draft JSON: {}", e)))?;
+        if input.subject.trim().is_empty() || input.to.trim().is_empty() {
+            return Err(ApiError::BadRequest("Draft requires 'subject' and 'to'".to_string()));
+        }
+        let base = self.base(req)?;
+        let path = draft::new::create_in(
+            &base.join("drafts"),
+            &input.subject,
+            &input.to,
+            input.cc.as_deref(),
+            input.account.as_deref(),
+            input.from.as_deref(),
+            input.in_reply_to.as_deref(),
+            self.owner_name.as_deref().unwrap_or(""),
+            &[],
+        )?;
+        if let Some(body) = input.body.as_deref().filter(|b| !b.trim().is_empty()) {
+            let mut content = std::fs::read_to_string(&path)?;
+            content.push_str(body.trim_end());
+            content.push('\n');
+            std::fs::write(&path, content)?;
+        }
+        let file = path
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Ok(ApiResponse {
+            status: 201,
+            body: json!({ "file": file }),
+        })
+    }
+
+    fn validate_draft(&self, req: &ApiRequest, file: &str) -> Result<ApiResponse, ApiError> {
+        let path = self.draft_path(req, file)?;
+        let (warnings, errors): (Vec<String>, Vec<String>) = validate_draft(&path)
+            .into_iter()
+            .partition(|i| i.starts_with("Warning:"));
+        Ok(ApiResponse::ok(json!({
+            "file": file,
+            "valid": errors.is_empty(),
+            "errors": errors,
+            "warnings": warnings,
+        })))
+    }
+
+    fn draft_path(&self, req: &ApiRequest, file: &str) -> Result<PathBuf, ApiError> {
+        check_name(file)?;
+        Ok(self.base(req)?.join("drafts").join(file))
+    }
+
+    fn list_contacts(&self) -> Result<ApiResponse, ApiError> {
+        let contacts = crate::config::contact::load_contacts(None)?;
+        let list: Vec<Value> = contacts
+            .iter()
+            .map(|(name, c)| json!({ "name": name, "emails": c.emails }))
+            .collect();
+        Ok(ApiResponse::ok(Value::Array(list)))
+    }
+
+    fn contact_info(&self, name: &str) -> Result<ApiResponse, ApiError> {
+        check_name(name)?;
+        if !crate::config::contact::load_contacts(None)?.contains_key(name) {
+            return Err(ApiError::NotFound(format!("Contact '{}' not found", name)));
+        }
+        let info = contact::info::collect(name)?;
+        Ok(ApiResponse::ok(serde_json::to_value(&info)?))
+    }
+
+    fn schedule(&self) -> Result<ApiResponse, ApiError> {
+        let report = schedule::collect_pending()?;
+        Ok(ApiResponse::ok(serde_json::to_value(&report)?))
+    }
+
+    /// Start one sync cycle in the background; progress arrives on `/api/events`.
+    fn trigger_sync(&self) -> ApiResponse {
+        if self.sync_running.swap(true, Ordering::SeqCst) {
+            return ApiResponse::error(409, "A sync is already running");
+        }
+        let running = self.sync_running.clone();
+        std::thread::spawn(move || {
+            crate::watch::poll_once(false, "serve");
+            running.store(false, Ordering::SeqCst);
+        });
+        ApiResponse {
+            status: 202,
+            body: json!({ "status": "started" }),
+        }
+    }
+}
+
+/// Reject path separators and dot-segments in a single path component.
+fn check_name(name: &str) -> Result<(), ApiError> {
+    if name.is_empty() || name.contains('/') || name.contains('\\') || name.starts_with('.') {
+        return Err(ApiError::Forbidden(format!("Invalid name: '{}'", name)));
+    }
+    Ok(())
+}
+
+/// Sorted `.md` file stems in `dir`.
+fn md_slugs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut slugs: Vec<String> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|x| x == "md"))
+        .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .collect();
+    slugs.sort();
+    slugs
+}
+
+/// Sorted subdirectory names of `dir`.
+fn subdirs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_decoding() {
+        let q = parse_query("q=from%3Aalice+budget&limit=5&empty=");
+        assert_eq!(q["q"], "from:alice budget");
+        assert_eq!(q["limit"], "5");
+        assert_eq!(q["empty"], "");
+        assert_eq!(percent_decode("100%", true), "100%");
+        assert_eq!(percent_decode("%zz", true), "%zz");
+        assert_eq!(percent_decode("a+b%20c", false), "a+b c");
+    }
+
+    #[test]
+    fn token_comparison() {
+        assert!(token_matches("abc", "abc"));
+        assert!(!token_matches("abd", "abc"));
+        assert!(!token_matches("ab", "abc"));
+    }
+
+    #[test]
+    fn names_reject_traversal() {
+        assert!(check_name("hello.md").is_ok());
+        assert!(check_name("..").is_err());
+        assert!(check_name("../x").is_err());
+        assert!(check_name(".corky.toml").is_err());
+        assert!(check_name("").is_err());
+    }
+}
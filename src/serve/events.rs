@@ -0,0 +1,188 @@
+//! Sync event log shared by `corky watch` and `corky serve`.
+//!
+//! Each sync cycle appends JSON lines to `.sync-events.jsonl` in the data
+//! dir. `corky serve` tails the file and streams new lines to `/api/events`
+//! subscribers, so events reach the dashboard whichever process ran the sync.
+//! Each record carries a sequence number, one past the previous record's,
+//! which is its SSE event id; truncating the log keeps the numbers intact.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Event log file name, in the data directory.
+pub const EVENTS_FILE: &str = ".sync-events.jsonl";
+
+/// Once the log grows past this size it is truncated to its newest half.
+const MAX_LOG_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SyncEvent {
+    SyncStarted { accounts: usize },
+    AccountSynced { account: String },
+    AccountFailed { account: String, error: String },
+    SyncFinished { new_labels: usize },
+}
+
+/// One line of the event log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Monotonic sequence number (SSE event id); 0 for lines written before ids existed
+    #[serde(default)]
+    pub seq: u64,
+    pub ts: DateTime<Utc>,
+    /// "watch" or "serve"
+    pub source: String,
+    #[serde(flatten)]
+    pub event: SyncEvent,
+}
+
+pub fn events_file(data_dir: &Path) -> PathBuf {
+    data_dir.join(EVENTS_FILE)
+}
+
+fn seq_of(line: &str) -> Option<u64> {
+    serde_json::from_str::<serde_json::Value>(line).ok()?["seq"].as_u64()
+}
+
+/// Sequence number of the newest complete record in `text` (0 if none).
+fn newest_seq(text: &str) -> u64 {
+    text.lines().rev().find_map(seq_of).unwrap_or(0)
+}
+
+/// Append an event to the log, numbered one past the newest record.
+pub fn append(data_dir: &Path, source: &str, event: SyncEvent) -> Result<()> {
+    let path = events_file(data_dir);
+    let text = std::fs::read_to_string(&path).unwrap_or_default();
+    let seq = newest_seq(&text) + 1;
+    if text.len() as u64 > MAX_LOG_BYTES {
+        let lines: Vec<&str> = text.lines().collect();
+        let keep = lines[lines.len() / 2..].join("\n");
+        std::fs::write(&path, format!("{}\n", keep))?;
+    }
+    let record = EventRecord {
+        seq,
+        ts: Utc::now(),
+        source: source.to_string(),
+        event,
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    Ok(())
+}
+
+/// Best-effort [`append`] for the sync loop: failures are logged, never fatal.
+pub fn record(data_dir: &Path, source: &str, event: SyncEvent) {
+    if let Err(e) = append(data_dir, source, event) {
+        eprintln!("sync events: {}", e);
+    }
+}
+
+/// Complete records numbered after `after`, as (seq, line) pairs, plus the
+/// sequence number to resume from. A log that restarted below `after`
+/// (deleted or written by another data dir) resumes at its newest record.
+pub fn read_since(data_dir: &Path, after: u64) -> (Vec<(u64, String)>, u64) {
+    let Ok(text) = std::fs::read_to_string(events_file(data_dir)) else {
+        return (vec![], 0);
+    };
+    // Only complete lines; a partial trailing line is picked up next time
+    let complete = &text[..text.rfind('\n').map_or(0, |i| i + 1)];
+    let newest = newest_seq(complete);
+    if newest < after {
+        return (vec![], newest);
+    }
+
+    let out: Vec<(u64, String)> = complete
+        .lines()
+        .filter_map(|line| seq_of(line).map(|seq| (seq, line.to_string())))
+        .filter(|(seq, _)| *seq > after)
+        .collect();
+    (out, newest)
+}
+
+/// Sequence number of the newest record (where a new subscriber starts).
+pub fn last_seq(data_dir: &Path) -> u64 {
+    let text = std::fs::read_to_string(events_file(data_dir)).unwrap_or_default();
+    newest_seq(&text[..text.rfind('\n').map_or(0, |i| i + 1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_and_read_since() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        assert_eq!(read_since(dir, 0), (vec![], 0));
+
+        append(dir, "watch", SyncEvent::SyncStarted { accounts: 2 }).unwrap();
+        append(dir, "watch", SyncEvent::SyncFinished { new_labels: 1 }).unwrap();
+
+        let (lines, next) = read_since(dir, 0);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].0, 1);
+        assert_eq!(lines[1].0, 2);
+        assert_eq!(next, 2);
+        assert_eq!(last_seq(dir), 2);
+        let rec: EventRecord = serde_json::from_str(&lines[1].1).unwrap();
+        assert_eq!(rec.seq, 2);
+        assert_eq!(rec.event, SyncEvent::SyncFinished { new_labels: 1 });
+        assert_eq!(rec.source, "watch");
+        assert!(lines[0].1.contains("\"event\":\"sync_started\""));
+
+        let (lines, _) = read_since(dir, 1);
+        assert_eq!(lines.len(), 1);
+        assert!(read_since(dir, next).0.is_empty());
+    }
+
+    #[test]
+    fn partial_line_waits_and_restart_resets() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::write(events_file(dir), "{\"seq\":1}\n{\"seq\":2").unwrap();
+        let (lines, next) = read_since(dir, 0);
+        assert_eq!(lines, vec![(1, "{\"seq\":1}".to_string())]);
+        assert_eq!(next, 1);
+        assert_eq!(last_seq(dir), 1);
+
+        let (lines, next) = read_since(dir, 1000);
+        assert!(lines.is_empty());
+        assert_eq!(next, 1);
+    }
+
+    #[test]
+    fn truncation_keeps_event_ids() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let line = |seq: u64| {
+            let record = EventRecord {
+                seq,
+                ts: DateTime::from_timestamp(0, 0).unwrap(),
+                source: "watch".to_string(),
+                event: SyncEvent::AccountSynced { account: "x".repeat(200) },
+            };
+            serde_json::to_string(&record).unwrap()
+        };
+        let count = MAX_LOG_BYTES / 200 + 100;
+        let log: String = (1..=count).map(|seq| format!("{}\n", line(seq))).collect();
+        std::fs::write(events_file(dir), log).unwrap();
+
+        append(dir, "serve", SyncEvent::SyncFinished { new_labels: 0 }).unwrap();
+        assert!(std::fs::metadata(events_file(dir)).unwrap().len() < MAX_LOG_BYTES);
+        assert_eq!(last_seq(dir), count + 1);
+
+        // A subscriber a few events behind gets exactly the ones it missed
+        let (lines, next) = read_since(dir, count - 2);
+        let seqs: Vec<u64> = lines.iter().map(|(seq, _)| *seq).collect();
+        assert_eq!(seqs, vec![count - 1, count, count + 1]);
+        assert_eq!(lines[0].1, line(count - 1));
+        assert_eq!(next, count + 1);
+    }
+}
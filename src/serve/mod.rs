@@ -0,0 +1,276 @@
+//! Local HTTP/JSON API (`corky serve`).
+//!
+//! Binds to 127.0.0.1 by default and requires a bearer token on every route
+//! except `/api/health`. Each request is handled on its own thread; `/api/events`
+//! holds its connection open as a Server-Sent Events stream of the sync log.
+
+pub mod api;
+pub mod events;
+
+use anyhow::Result;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use self::api::{Api, ApiRequest, ApiResponse};
+use crate::config::corky_config;
+use crate::resolve;
+
+/// Default port for `corky serve`.
+pub const DEFAULT_PORT: u16 = 8765;
+
+/// Environment variable holding the API token.
+pub const TOKEN_ENV: &str = "CORKY_SERVE_TOKEN";
+
+/// Largest accepted request body.
+const MAX_BODY_BYTES: u64 = 1024 * 1024;
+
+/// How often the SSE stream polls the event log.
+const EVENT_POLL: Duration = Duration::from_millis(500);
+
+/// Keep-alive comment interval for idle SSE streams.
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Generate a random 32-hex-char API token.
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 16];
+    let from_os = std::fs::File::open("/dev/urandom")
+        .and_then(|mut f| f.read_exact(&mut bytes))
+        .is_ok();
+    if !from_os {
+        // Fallback: mix the process-random hasher keys with the clock
+        use std::hash::{BuildHasher, Hasher};
+        for chunk in bytes.chunks_mut(8) {
+            let mut h = std::collections::hash_map::RandomState::new().build_hasher();
+            h.write_u128(
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_nanos(),
+            );
+            chunk.copy_from_slice(&h.finish().to_le_bytes());
+        }
+    }
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn is_loopback(host: &str) -> bool {
+    host == "localhost"
+        || host
+            .trim_matches(|c| c == '[' || c == ']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Tails the event log and yields SSE frames, with keep-alive comments.
+struct EventStream {
+    data_dir: PathBuf,
+    after: u64,
+    last_sent: Instant,
+}
+
+impl EventStream {
+    fn new(data_dir: PathBuf, last_event_id: Option<u64>) -> Self {
+        // Event ids are sequence numbers, so resuming from `Last-Event-ID`
+        // starts at the next one
+        let after = last_event_id.unwrap_or_else(|| events::last_seq(&data_dir));
+        EventStream {
+            data_dir,
+            after,
+            last_sent: Instant::now(),
+        }
+    }
+
+    /// Block until there is something to send: new events or a keep-alive.
+    fn next_frame(&mut self) -> String {
+        loop {
+            std::thread::sleep(EVENT_POLL);
+            let (lines, next) = events::read_since(&self.data_dir, self.after);
+            self.after = next;
+            let mut frame = String::new();
+            for (id, line) in lines {
+                let name = serde_json::from_str::<serde_json::Value>(&line)
+                    .ok()
+                    .and_then(|v| v["event"].as_str().map(|s| s.to_string()))
+                    .unwrap_or_else(|| "message".to_string());
+                frame.push_str(&format!("id: {}\nevent: {}\ndata: {}\n\n", id, name, line));
+            }
+            if frame.is_empty() && self.last_sent.elapsed() >= KEEPALIVE {
+                frame.push_str(": keep-alive\n\n");
+            }
+            if !frame.is_empty() {
+                self.last_sent = Instant::now();
+                return frame;
+            }
+        }
+    }
+}
+
+/// Stream sync events until the client disconnects. Written straight to the
+/// connection: tiny_http's chunked encoder would buffer frames.
+fn stream_events(request: tiny_http::Request, data_dir: PathBuf, last_event_id: Option<u64>) {
+    let mut stream = EventStream::new(data_dir, last_event_id);
+    let mut writer = request.into_writer();
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\nretry: 3000\n\n";
+    let mut out = head.to_string();
+    loop {
+        if writer
+            .write_all(out.as_bytes())
+            .and_then(|_| writer.flush())
+            .is_err()
+        {
+            return;
+        }
+        out = stream.next_frame();
+    }
+}
+
+fn header(name: &str, value: &str) -> tiny_http::Header {
+    tiny_http::Header::from_bytes(name.as_bytes(), value.as_bytes())
+        .expect("static header is valid")
+}
+
+fn respond_json(request: tiny_http::Request, resp: ApiResponse) {
+    let body = serde_json::to_string_pretty(&resp.body).unwrap_or_default();
+    let response = tiny_http::Response::from_string(body)
+        .with_status_code(resp.status)
+        .with_header(header("Content-Type", "application/json"));
+    let _ = request.respond(response);
+}
+
+fn handle_request(api: &Api, mut request: tiny_http::Request) {
+    let find = |name: &'static str| {
+        request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv(name))
+            .map(|h| h.value.as_str().to_string())
+    };
+    let authorization = find("Authorization");
+    let last_event_id = find("Last-Event-ID").and_then(|v| v.trim().parse::<u64>().ok());
+
+    let mut body = String::new();
+    if request
+        .as_reader()
+        .take(MAX_BODY_BYTES)
+        .read_to_string(&mut body)
+        .is_err()
+    {
+        return respond_json(request, ApiResponse::error(400, "Request body must be UTF-8"));
+    }
+    let req = ApiRequest::new(
+        request.method().as_str(),
+        request.url(),
+        authorization,
+        body,
+    );
+
+    if req.method == "GET" && req.path == "/api/events" {
+        if !api.authorized(&req) {
+            return respond_json(request, ApiResponse::error(401, "Missing or invalid API token"));
+        }
+        return stream_events(request, api.data_dir.clone(), last_event_id);
+    }
+
+    let resp = api.handle(&req);
+    eprintln!("{} {} -> {}", req.method, req.path, resp.status);
+    respond_json(request, resp);
+}
+
+/// corky serve [--host HOST] [--port N] [--token TOKEN]
+pub fn run(host: &str, port: u16, token: Option<&str>) -> Result<()> {
+    let token = match token
+        .map(|t| t.to_string())
+        .or_else(|| std::env::var(TOKEN_ENV).ok())
+        .filter(|t| !t.trim().is_empty())
+    {
+        Some(t) => t,
+        None => {
+            let t = generate_token();
+            eprintln!("Generated API token (set {} or --token to fix it):", TOKEN_ENV);
+            println!("{}", t);
+            t
+        }
+    };
+    if !is_loopback(host) {
+        eprintln!(
+            "Warning: binding to {} exposes your mail to the network. Use a strong token.",
+            host
+        );
+    }
+
+    let owner_name = corky_config::try_load_config(None)
+        .and_then(|c| c.owner)
+        .map(|o| o.name)
+        .filter(|n| !n.is_empty());
+    let api = Arc::new(Api {
+        data_dir: resolve::data_dir(),
+        token,
+        owner_name,
+        sync_running: Arc::new(AtomicBool::new(false)),
+    });
+
+    let addr = format!("{}:{}", host, port);
+    let server = tiny_http::Server::http(&addr)
+        .map_err(|e| anyhow::anyhow!("Failed to bind {}: {}", addr, e))?;
+    eprintln!(
+        "corky serve: listening on http://{} (data: {})",
+        addr,
+        api.data_dir.display()
+    );
+
+    serve(server, api);
+    Ok(())
+}
+
+/// Handle requests from `server` until it shuts down, one thread per request.
+pub fn serve(server: tiny_http::Server, api: Arc<Api>) {
+    for request in server.incoming_requests() {
+        let api = api.clone();
+        std::thread::spawn(move || handle_request(&api, request));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_hex_and_distinct() {
+        let a = generate_token();
+        let b = generate_token();
+        assert_eq!(a.len(), 32);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn loopback_hosts() {
+        assert!(is_loopback("127.0.0.1"));
+        assert!(is_loopback("localhost"));
+        assert!(is_loopback("[::1]"));
+        assert!(!is_loopback("0.0.0.0"));
+        assert!(!is_loopback("192.168.1.5"));
+    }
+
+    #[test]
+    fn event_stream_frames_new_events() {
+        let tmp = tempfile::tempdir().unwrap();
+        events::append(tmp.path(), "watch", events::SyncEvent::SyncStarted { accounts: 1 }).unwrap();
+        let mut stream = EventStream::new(tmp.path().to_path_buf(), None);
+
+        // Only events written after subscribing
+        events::append(tmp.path(), "serve", events::SyncEvent::SyncFinished { new_labels: 2 }).unwrap();
+        let frame = stream.next_frame();
+        assert_eq!(frame.matches("\n\n").count(), 1);
+        assert!(frame.starts_with("id: 2\n"));
+        assert!(frame.contains("event: sync_finished\n"));
+        assert!(frame.contains("\"new_labels\":2"));
+
+        // Resuming from the first event's id replays the second
+        let mut resumed = EventStream::new(tmp.path().to_path_buf(), Some(1));
+        assert!(resumed.next_frame().contains("sync_finished"));
+    }
+}
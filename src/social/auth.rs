@@ -13,6 +13,16 @@ const CALLBACK_TIMEOUT_SECS: u64 = 120;
 /// LinkedIn OAuth scopes.
 const LINKEDIN_SCOPES: &[&str] = &["openid", "profile", "w_member_social"];
 
+/// Additional LinkedIn scopes for posting as an organization (company page admin).
+const LINKEDIN_ORG_SCOPES: &[&str] = &[
+    "w_organization_social",
+    "r_organization_social",
+    "rw_organization_admin",
+];
+
+/// Scope that authorizes posting on behalf of an organization.
+pub const LINKEDIN_ORG_POST_SCOPE: &str = "w_organization_social";
+
 /// YouTube OAuth scopes.
 const YOUTUBE_SCOPES: &[&str] = &[
     "https://www.googleapis.com/auth/youtube.upload",
@@ -117,14 +127,29 @@ fn generate_state() -> String {
     format!("{:x}", nonce)
 }
 
+/// OAuth scopes requested for a platform. `org` adds LinkedIn organization scopes.
+pub fn scopes(platform: Platform, org: bool) -> Vec<&'static str> {
+    match platform {
+        Platform::LinkedIn => {
+            let mut scopes = LINKEDIN_SCOPES.to_vec();
+            if org {
+                scopes.extend_from_slice(LINKEDIN_ORG_SCOPES);
+            }
+            scopes
+        }
+        Platform::Youtube => YOUTUBE_SCOPES.to_vec(),
+        _ => vec![],
+    }
+}
+
 /// Build the authorization URL for a platform.
-pub fn build_auth_url(platform: Platform) -> Result<(String, String)> {
+pub fn build_auth_url(platform: Platform, org: bool) -> Result<(String, String)> {
     let creds = resolve_credentials(platform)?;
     let state = generate_state();
 
     match platform {
         Platform::LinkedIn => {
-            let scopes = LINKEDIN_SCOPES.join("%20");
+            let scopes = scopes(platform, org).join("%20");
             let url = format!(
                 "https://www.linkedin.com/oauth/v2/authorization\
                  ?response_type=code\
@@ -211,7 +236,7 @@ pub fn parse_callback(query: &str) -> Result<(String, String)> {
 }
 
 /// Exchange authorization code for tokens.
-fn exchange_code(platform: Platform, code: &str) -> Result<StoredToken> {
+fn exchange_code(platform: Platform, code: &str, org: bool) -> Result<StoredToken> {
     let creds = resolve_credentials(platform)?;
 
     match platform {
@@ -247,7 +272,7 @@ fn exchange_code(platform: Platform, code: &str) -> Result<StoredToken> {
                 access_token,
                 refresh_token,
                 expires_at: Utc::now() + Duration::seconds(expires_in),
-                scopes: LINKEDIN_SCOPES.iter().map(|s| s.to_string()).collect(),
+                scopes: scopes(platform, org).iter().map(|s| s.to_string()).collect(),
                 platform: platform.to_string(),
             })
         }
@@ -292,8 +317,11 @@ fn exchange_code(platform: Platform, code: &str) -> Result<StoredToken> {
 }
 
 /// Run the full OAuth flow: open browser, wait for callback, exchange code, store token.
-pub fn run(platform: Platform, profile_name: Option<&str>) -> Result<()> {
-    let (auth_url, expected_state) = build_auth_url(platform)?;
+pub fn run(platform: Platform, profile_name: Option<&str>, org: bool) -> Result<()> {
+    if org && platform != Platform::LinkedIn {
+        bail!("--org is only supported for LinkedIn");
+    }
+    let (auth_url, expected_state) = build_auth_url(platform, org)?;
 
     println!("Opening browser for {} authorization...", platform);
     println!("If the browser doesn't open, visit:\n  {}\n", auth_url);
@@ -334,13 +362,16 @@ pub fn run(platform: Platform, profile_name: Option<&str>) -> Result<()> {
 
     // Exchange code for token
     println!("Exchanging authorization code...");
-    let token = exchange_code(platform, &code)?;
+    let token = exchange_code(platform, &code, org)?;
 
     // Get user URN / channel ID
     let urn = match platform {
         Platform::LinkedIn => {
             let urn = super::linkedin::get_user_urn(&token.access_token)?;
             println!("Authenticated as URN: {}", urn);
+            if org {
+                print_admin_orgs(&token.access_token);
+            }
             urn
         }
         Platform::Youtube => {
@@ -365,6 +396,27 @@ pub fn run(platform: Platform, profile_name: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// List organizations the member administers, with a profile snippet (best-effort).
+fn print_admin_orgs(access_token: &str) {
+    match super::linkedin::get_admin_orgs(access_token) {
+        Ok(orgs) if orgs.is_empty() => {
+            println!("No administered organizations found for this member.");
+        }
+        Ok(orgs) => {
+            println!("Administered organizations:");
+            for urn in &orgs {
+                println!("  {}", urn);
+            }
+            println!("\nAdd an organization profile to .corky.toml, e.g.:");
+            println!("  [profiles.<org-name>.linkedin]");
+            println!("  handle = \"<company-page-handle>\"");
+            println!("  urn = \"{}\"", orgs[0]);
+            println!("Then set `organization: <org-name>` in draft frontmatter.");
+        }
+        Err(e) => eprintln!("Could not list administered organizations: {}", e),
+    }
+}
+
 /// Update the URN in .corky.toml [profiles] section for a given profile/platform.
 ///
 /// Uses toml_edit for format-preserving writes.
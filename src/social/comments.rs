@@ -0,0 +1,279 @@
+//! Import comments on published social posts as conversations.
+//!
+//! Each published draft becomes one thread (`linkedin:{post_id}` /
+//! `youtube:{video_id}`) and each comment a message, merged with the same
+//! `merge_message_to_file` path the email and chat importers use.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+
+use super::draft::SocialDraft;
+use super::platform::Platform;
+use super::profiles::ProfilesFile;
+use super::{linkedin, youtube};
+use crate::resolve;
+use crate::sync::imap_sync::{build_label_routes, merge_message_to_file};
+use crate::sync::types::Message;
+use crate::util;
+
+/// A single comment on a published post.
+#[derive(Debug, Clone)]
+pub struct PostComment {
+    /// Platform comment ID
+    pub id: String,
+    /// Commenter URN (LinkedIn) or channel ID (YouTube)
+    pub author_id: String,
+    /// Display name, when the platform provides one
+    pub author_name: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Thread key for a published post's comment thread.
+pub fn thread_key(platform: Platform, post_id: &str) -> String {
+    format!("{}:{}", platform, post_id)
+}
+
+/// Thread subject for a draft's comments: title, else first line of the body.
+pub fn comment_subject(draft: &SocialDraft) -> String {
+    let base = draft
+        .meta
+        .title
+        .clone()
+        .unwrap_or_else(|| util::truncate_preview(&draft.body, 60));
+    format!("Comments: {}", base)
+}
+
+/// Resolve the sender name for a comment.
+///
+/// Comments by the post author map to `owner_name` (so `corky unanswered`
+/// treats them as our replies); known profiles map to their profile name.
+fn sender_name(
+    comment: &PostComment,
+    platform: Platform,
+    author_urn: &str,
+    owner_name: &str,
+    profiles: &ProfilesFile,
+) -> String {
+    if !author_urn.is_empty() && comment.author_id == author_urn {
+        return owner_name.to_string();
+    }
+    if let Some(name) = profiles.resolve_by_urn(&comment.author_id, platform) {
+        return name;
+    }
+    if !comment.author_name.is_empty() {
+        return comment.author_name.clone();
+    }
+    comment.author_id.clone()
+}
+
+/// Merge comments into the thread for one post. Returns the number of comments processed.
+#[allow(clippy::too_many_arguments)]
+pub fn merge_comments(
+    out_dirs: &[PathBuf],
+    label: &str,
+    platform: Platform,
+    post_id: &str,
+    subject: &str,
+    comments: &[PostComment],
+    author_urn: &str,
+    owner_name: &str,
+    profiles: &ProfilesFile,
+) -> Result<usize> {
+    let key = thread_key(platform, post_id);
+    for comment in comments {
+        let message = Message {
+            id: format!("{}:{}", platform, comment.id),
+            thread_id: key.clone(),
+            from: sender_name(comment, platform, author_urn, owner_name, profiles),
+            to: String::new(),
+            cc: String::new(),
+            date: comment.created_at.to_rfc2822(),
+            subject: subject.to_string(),
+            body: comment.text.clone(),
+            raw: String::new(),
+        };
+        for dir in out_dirs {
+            merge_message_to_file(dir, label, platform.as_str(), &message, &key)?;
+        }
+    }
+    Ok(comments.len())
+}
+
+/// Owner display name used as the sender for our own comments.
+fn owner_name(draft: &SocialDraft) -> String {
+    crate::config::corky_config::try_load_config(None)
+        .and_then(|cfg| cfg.owner)
+        .map(|o| o.name)
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| draft.meta.author.clone())
+}
+
+/// Fetch and merge comments for one published draft file.
+fn sync_file(path: &Path, label: &str, out_dir: &Path, profiles: &ProfilesFile) -> Result<usize> {
+    let content = std::fs::read_to_string(path)?;
+    let draft = SocialDraft::parse(&content)?;
+    let platform = draft.meta.platform;
+    let post_id = draft.meta.post_id.clone().ok_or_else(|| {
+        anyhow::anyhow!("Post has not been published yet — no post_id in frontmatter.")
+    })?;
+
+    let access_token = super::access_token_for(&draft.meta)?;
+    let comments = match platform {
+        Platform::LinkedIn => linkedin::get_comments(&access_token, &post_id)?,
+        Platform::Youtube => youtube::get_comments(&access_token, &post_id)?,
+        other => bail!("Comment sync for {} posts is not yet supported", other),
+    };
+    if comments.is_empty() {
+        return Ok(0);
+    }
+
+    // Base conversations/ plus any [routing] fan-out for the label
+    let mut out_dirs = vec![out_dir.to_path_buf()];
+    if let Some(dirs) = build_label_routes(platform.as_str()).get(label) {
+        out_dirs.extend(dirs.iter().cloned());
+    }
+
+    let author_urn = super::post_as_urn(&draft.meta, profiles).unwrap_or_default();
+    merge_comments(
+        &out_dirs,
+        label,
+        platform,
+        &post_id,
+        &comment_subject(&draft),
+        &comments,
+        &author_urn,
+        &owner_name(&draft),
+        profiles,
+    )
+}
+
+/// Run `corky sync social-comments [FILES]`: import comments on published
+/// drafts (all of `social/` when no files are given) into `out_dir`.
+pub fn run(files: &[PathBuf], label: &str, out_dir: &Path) -> Result<()> {
+    let paths = if files.is_empty() {
+        super::stats::published_drafts(&resolve::social_dir())?
+    } else {
+        files.to_vec()
+    };
+    if paths.is_empty() {
+        println!("No published social drafts found.");
+        return Ok(());
+    }
+
+    println!("Social comments sync: {} post(s)", paths.len());
+    let profiles = ProfilesFile::load()?;
+    let mut total = 0;
+    for path in &paths {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        match sync_file(path, label, out_dir, &profiles) {
+            Ok(n) => {
+                println!("  {} — {} comment(s)", name, n);
+                total += n;
+            }
+            Err(e) => eprintln!("  {}: {}", name, e),
+        }
+    }
+
+    println!("Social comments sync complete: {} comment(s) total.", total);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::markdown::parse_thread_markdown;
+    use tempfile::TempDir;
+
+    fn comment(id: &str, author_id: &str, author_name: &str, text: &str, ts: i64) -> PostComment {
+        PostComment {
+            id: id.to_string(),
+            author_id: author_id.to_string(),
+            author_name: author_name.to_string(),
+            text: text.to_string(),
+            created_at: DateTime::from_timestamp(ts, 0).unwrap(),
+        }
+    }
+
+    fn profiles() -> ProfilesFile {
+        ProfilesFile::parse(
+            r#"
+[alice.linkedin]
+handle = "alice"
+urn = "urn:li:person:alice"
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn thread_key_format() {
+        assert_eq!(thread_key(Platform::LinkedIn, "urn:li:share:1"), "linkedin:urn:li:share:1");
+        assert_eq!(thread_key(Platform::Youtube, "abc123"), "youtube:abc123");
+    }
+
+    #[test]
+    fn sender_name_resolution() {
+        let p = profiles();
+        let me = "urn:li:person:me";
+        let own = comment("1", me, "", "thanks!", 0);
+        let known = comment("2", "urn:li:person:alice", "", "nice", 0);
+        let named = comment("3", "UCxyz", "Bob", "great video", 0);
+        let anon = comment("4", "urn:li:person:zzz", "", "hm", 0);
+
+        assert_eq!(sender_name(&own, Platform::LinkedIn, me, "Brian", &p), "Brian");
+        assert_eq!(sender_name(&known, Platform::LinkedIn, me, "Brian", &p), "alice");
+        assert_eq!(sender_name(&named, Platform::Youtube, "", "Brian", &p), "Bob");
+        assert_eq!(sender_name(&anon, Platform::LinkedIn, me, "Brian", &p), "urn:li:person:zzz");
+    }
+
+    #[test]
+    fn merge_comments_writes_social_thread() {
+        let tmp = TempDir::new().unwrap();
+        let out = tmp.path().join("conversations");
+        let comments = vec![
+            comment("c1", "urn:li:person:alice", "", "Great post!", 1_700_000_000),
+            comment("c2", "urn:li:person:me", "", "Thanks Alice", 1_700_000_600),
+        ];
+
+        let n = merge_comments(
+            std::slice::from_ref(&out),
+            "social",
+            Platform::LinkedIn,
+            "urn:li:share:42",
+            "Comments: Hello world",
+            &comments,
+            "urn:li:person:me",
+            "Brian",
+            &profiles(),
+        )
+        .unwrap();
+        assert_eq!(n, 2);
+
+        // Re-running is idempotent (dedup by sender + date)
+        merge_comments(
+            std::slice::from_ref(&out),
+            "social",
+            Platform::LinkedIn,
+            "urn:li:share:42",
+            "Comments: Hello world",
+            &comments,
+            "urn:li:person:me",
+            "Brian",
+            &profiles(),
+        )
+        .unwrap();
+
+        let files: Vec<_> = std::fs::read_dir(&out).unwrap().flatten().collect();
+        assert_eq!(files.len(), 1);
+        let text = std::fs::read_to_string(files[0].path()).unwrap();
+        let thread = parse_thread_markdown(&text).unwrap();
+        assert_eq!(thread.id, "linkedin:urn:li:share:42");
+        assert_eq!(thread.labels, vec!["social"]);
+        assert_eq!(thread.accounts, vec!["linkedin"]);
+        assert_eq!(thread.messages.len(), 2);
+        assert_eq!(thread.messages[0].from, "alice");
+        assert_eq!(thread.messages[1].from, "Brian");
+    }
+}
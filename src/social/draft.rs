@@ -13,6 +13,7 @@ pub enum DraftStatus {
     Draft,
     Ready,
     Published,
+    Retracted,
 }
 
 impl DraftStatus {
@@ -21,6 +22,7 @@ impl DraftStatus {
             DraftStatus::Draft => "draft",
             DraftStatus::Ready => "ready",
             DraftStatus::Published => "published",
+            DraftStatus::Retracted => "retracted",
         }
     }
 }
@@ -39,7 +41,11 @@ impl std::str::FromStr for DraftStatus {
             "draft" => Ok(DraftStatus::Draft),
             "ready" => Ok(DraftStatus::Ready),
             "published" => Ok(DraftStatus::Published),
-            _ => bail!("Invalid status '{}'. Valid: draft, ready, published", s),
+            "retracted" => Ok(DraftStatus::Retracted),
+            _ => bail!(
+                "Invalid status '{}'. Valid: draft, ready, published, retracted",
+                s
+            ),
         }
     }
 }
@@ -49,6 +55,9 @@ impl std::str::FromStr for DraftStatus {
 pub struct SocialDraftMeta {
     pub platform: Platform,
     pub author: String,
+    /// Organization profile to post as (LinkedIn company page); `author`'s token is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
     #[serde(default = "default_visibility")]
     pub visibility: String,
     #[serde(default = "default_status")]
@@ -60,6 +69,8 @@ pub struct SocialDraftMeta {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub published_at: Option<DateTime<Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retracted_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub post_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub post_url: Option<String>,
@@ -71,6 +82,25 @@ pub struct SocialDraftMeta {
     pub captions: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<PostMetrics>,
+}
+
+/// Engagement metrics snapshot for a published post (written by `corky social stats`).
+///
+/// `shares` and `impressions` are `None` when the platform doesn't report them
+/// (or the token lacks analytics scope). For YouTube, `impressions` is the view count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PostMetrics {
+    #[serde(default)]
+    pub reactions: u64,
+    #[serde(default)]
+    pub comments: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shares: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impressions: Option<u64>,
+    pub fetched_at: DateTime<Utc>,
 }
 
 fn default_visibility() -> String {
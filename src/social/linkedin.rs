@@ -1,13 +1,17 @@
 //! LinkedIn API client (REST API).
 
 use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
 use serde_json::json;
 
+use super::comments::PostComment;
+use super::draft::PostMetrics;
+
 /// Maximum character count for a LinkedIn post.
-const MAX_BODY_LENGTH: usize = 3000;
+pub(crate) const MAX_BODY_LENGTH: usize = 3000;
 
 /// Maximum images in a multi-image carousel.
-const MAX_IMAGES: usize = 20;
+pub(crate) const MAX_IMAGES: usize = 20;
 
 /// Default LinkedIn API base URL.
 const API_BASE: &str = "https://api.linkedin.com";
@@ -45,8 +49,54 @@ pub fn get_user_urn_at(api_base: &str, access_token: &str) -> Result<String> {
     Ok(format!("urn:li:person:{}", sub))
 }
 
+/// List organizations the authenticated member administers (approved ADMINISTRATOR role).
+///
+/// Requires the `rw_organization_admin` scope (`corky linkedin auth --org`).
+pub fn get_admin_orgs(access_token: &str) -> Result<Vec<String>> {
+    get_admin_orgs_at(API_BASE, access_token)
+}
+
+/// List administered organizations with configurable API base URL (for testing).
+pub fn get_admin_orgs_at(api_base: &str, access_token: &str) -> Result<Vec<String>> {
+    let url = format!(
+        "{}/rest/organizationAcls?q=roleAssignee&role=ADMINISTRATOR&state=APPROVED",
+        api_base
+    );
+    let resp = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .set("LinkedIn-Version", "202601")
+        .set("X-Restli-Protocol-Version", "2.0.0")
+        .call();
+
+    let body: serde_json::Value = match resp {
+        Ok(r) => r.into_json()?,
+        Err(ureq::Error::Status(status, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            bail!(
+                "LinkedIn API error (HTTP {}): {}",
+                status,
+                body
+            );
+        }
+        Err(e) => bail!("LinkedIn API request failed: {}", e),
+    };
+
+    let mut orgs: Vec<String> = body["elements"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|el| el["organization"].as_str().map(|s| s.to_string()))
+        .collect();
+    orgs.sort();
+    orgs.dedup();
+    Ok(orgs)
+}
+
 /// Initialize an image upload and upload the binary data.
 /// Returns the image URN for use in post creation.
+///
+/// `author_urn` becomes the image owner — a person or organization URN, and
+/// must match the post author.
 pub fn upload_image(access_token: &str, author_urn: &str, image_bytes: &[u8]) -> Result<String> {
     upload_image_at(API_BASE, access_token, author_urn, image_bytes)
 }
@@ -107,6 +157,15 @@ pub fn upload_image_at(
     Ok(image_urn)
 }
 
+/// URL-encode a URN for use as a path segment (colons → %3A, commas → %2C).
+fn encode_urn(urn: &str) -> String {
+    urn.replace('%', "%25")
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+        .replace('(', "%28")
+        .replace(')', "%29")
+}
+
 /// Update an existing LinkedIn post's commentary via PARTIAL_UPDATE.
 ///
 /// Returns `Ok(())` on success (HTTP 204 No Content).
@@ -130,14 +189,7 @@ pub fn update_post_at(
         );
     }
 
-    // URL-encode the URN (colons → %3A, commas → %2C)
-    let encoded_urn = post_urn
-        .replace('%', "%25")
-        .replace(':', "%3A")
-        .replace(',', "%2C")
-        .replace('(', "%28")
-        .replace(')', "%29");
-    let url = format!("{}/rest/posts/{}", api_base, encoded_urn);
+    let url = format!("{}/rest/posts/{}", api_base, encode_urn(post_urn));
     let payload = serde_json::json!({
         "patch": {
             "$set": {
@@ -167,6 +219,185 @@ pub fn update_post_at(
     }
 }
 
+/// Delete a published LinkedIn post.
+///
+/// Returns `Ok(())` on success (HTTP 204 No Content).
+pub fn delete_post(access_token: &str, post_urn: &str) -> Result<()> {
+    delete_post_at(API_BASE, access_token, post_urn)
+}
+
+/// Delete a post with configurable API base URL (for testing).
+pub fn delete_post_at(api_base: &str, access_token: &str, post_urn: &str) -> Result<()> {
+    let url = format!("{}/rest/posts/{}", api_base, encode_urn(post_urn));
+
+    let resp = ureq::delete(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .set("X-RestLi-Method", "DELETE")
+        .set("LinkedIn-Version", "202601")
+        .call();
+
+    match resp {
+        Ok(_) => Ok(()),
+        Err(ureq::Error::Status(status, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            bail!(
+                "LinkedIn API error (HTTP {}): {}",
+                status,
+                body
+            );
+        }
+        Err(e) => bail!("LinkedIn API request failed: {}", e),
+    }
+}
+
+/// Fetch engagement metrics for a published post.
+///
+/// Reactions and comments come from `socialActions`. Shares and impressions come
+/// from `memberCreatorPostAnalytics`, which needs the `r_member_postAnalytics`
+/// scope — they are left as `None` when that call fails.
+pub fn get_post_metrics(access_token: &str, post_urn: &str) -> Result<PostMetrics> {
+    get_post_metrics_at(API_BASE, access_token, post_urn)
+}
+
+/// Fetch post metrics with configurable API base URL (for testing).
+pub fn get_post_metrics_at(
+    api_base: &str,
+    access_token: &str,
+    post_urn: &str,
+) -> Result<PostMetrics> {
+    let url = format!("{}/rest/socialActions/{}", api_base, encode_urn(post_urn));
+    let resp = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .set("LinkedIn-Version", "202601")
+        .set("X-Restli-Protocol-Version", "2.0.0")
+        .call();
+
+    let body: serde_json::Value = match resp {
+        Ok(r) => r.into_json()?,
+        Err(ureq::Error::Status(status, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            bail!(
+                "LinkedIn API error (HTTP {}): {}",
+                status,
+                body
+            );
+        }
+        Err(e) => bail!("LinkedIn API request failed: {}", e),
+    };
+
+    let reactions = body["likesSummary"]["totalLikes"].as_u64().unwrap_or(0);
+    let comments = body["commentsSummary"]["aggregatedTotalComments"]
+        .as_u64()
+        .or_else(|| body["commentsSummary"]["totalFirstLevelComments"].as_u64())
+        .unwrap_or(0);
+
+    Ok(PostMetrics {
+        reactions,
+        comments,
+        shares: post_analytics_at(api_base, access_token, post_urn, "RESHARE"),
+        impressions: post_analytics_at(api_base, access_token, post_urn, "IMPRESSION"),
+        fetched_at: Utc::now(),
+    })
+}
+
+/// Page size for comment listing.
+const COMMENTS_PAGE_SIZE: usize = 100;
+
+/// Fetch all comments on a published post (oldest first).
+pub fn get_comments(access_token: &str, post_urn: &str) -> Result<Vec<PostComment>> {
+    get_comments_at(API_BASE, access_token, post_urn)
+}
+
+/// Fetch post comments with configurable API base URL (for testing).
+pub fn get_comments_at(
+    api_base: &str,
+    access_token: &str,
+    post_urn: &str,
+) -> Result<Vec<PostComment>> {
+    let mut comments = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let url = format!(
+            "{}/rest/socialActions/{}/comments?start={}&count={}",
+            api_base,
+            encode_urn(post_urn),
+            start,
+            COMMENTS_PAGE_SIZE
+        );
+        let resp = ureq::get(&url)
+            .set("Authorization", &format!("Bearer {}", access_token))
+            .set("LinkedIn-Version", "202601")
+            .set("X-Restli-Protocol-Version", "2.0.0")
+            .call();
+
+        let body: serde_json::Value = match resp {
+            Ok(r) => r.into_json()?,
+            Err(ureq::Error::Status(status, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                bail!(
+                    "LinkedIn API error (HTTP {}): {}",
+                    status,
+                    body
+                );
+            }
+            Err(e) => bail!("LinkedIn API request failed: {}", e),
+        };
+
+        let elements = body["elements"].as_array().cloned().unwrap_or_default();
+        let page_len = elements.len();
+        for el in elements {
+            let id = el["id"]
+                .as_str()
+                .or_else(|| el["commentUrn"].as_str())
+                .unwrap_or_default()
+                .to_string();
+            let created_ms = el["created"]["time"].as_i64().unwrap_or(0);
+            comments.push(PostComment {
+                id,
+                author_id: el["actor"].as_str().unwrap_or_default().to_string(),
+                author_name: String::new(),
+                text: el["message"]["text"].as_str().unwrap_or_default().to_string(),
+                created_at: DateTime::from_timestamp_millis(created_ms).unwrap_or_default(),
+            });
+        }
+
+        if page_len < COMMENTS_PAGE_SIZE {
+            break;
+        }
+        start += page_len;
+    }
+
+    comments.sort_by_key(|c| c.created_at);
+    Ok(comments)
+}
+
+/// Query a single lifetime total from `memberCreatorPostAnalytics` (best-effort).
+fn post_analytics_at(
+    api_base: &str,
+    access_token: &str,
+    post_urn: &str,
+    query_type: &str,
+) -> Option<u64> {
+    // urn:li:share:123 → (share:urn%3Ali%3Ashare%3A123)
+    let kind = post_urn.strip_prefix("urn:li:")?.split(':').next()?;
+    let url = format!(
+        "{}/rest/memberCreatorPostAnalytics?q=entity&entity=({}:{})&queryType={}&aggregation=TOTAL",
+        api_base,
+        kind,
+        encode_urn(post_urn),
+        query_type
+    );
+    let resp = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .set("LinkedIn-Version", "202601")
+        .set("X-Restli-Protocol-Version", "2.0.0")
+        .call()
+        .ok()?;
+    let body: serde_json::Value = resp.into_json().ok()?;
+    body["elements"][0]["count"].as_u64()
+}
+
 /// Create a post on LinkedIn using the REST API.
 ///
 /// `image_urns` controls the post type:
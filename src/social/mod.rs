@@ -1,17 +1,22 @@
 //! Social media posting module.
 
 pub mod auth;
+pub mod comments;
 pub mod draft;
 pub mod linkedin;
 pub mod platform;
 pub mod profiles;
 pub mod publish;
+pub mod stats;
 pub mod token_store;
+pub mod validate;
 pub mod youtube;
 
 use anyhow::{bail, Result};
+use serde::Serialize;
 use std::path::Path;
 
+use crate::output::{emit, OutputFormat, Report};
 use crate::resolve;
 use crate::util;
 use draft::{DraftStatus, SocialDraft, SocialDraftMeta};
@@ -19,9 +24,11 @@ use platform::Platform;
 use profiles::ProfilesFile;
 
 /// Run the `social auth` command.
-pub fn run_auth(platform_str: &str, profile: Option<&str>) -> Result<()> {
+///
+/// `org` additionally requests LinkedIn organization admin scopes.
+pub fn run_auth(platform_str: &str, profile: Option<&str>, org: bool) -> Result<()> {
     let platform: Platform = platform_str.parse()?;
-    auth::run(platform, profile)
+    auth::run(platform, profile, org)
 }
 
 /// Run the `social draft` command: create a new social draft file.
@@ -29,10 +36,14 @@ pub fn run_draft(
     platform_str: &str,
     body: Option<&str>,
     author: Option<&str>,
+    organization: Option<&str>,
     visibility: &str,
     tags: &[String],
 ) -> Result<()> {
     let platform: Platform = platform_str.parse()?;
+    if organization.is_some() && platform != Platform::LinkedIn {
+        bail!("Posting as an organization is only supported on LinkedIn");
+    }
 
     let author = match author {
         Some(a) => a.to_string(),
@@ -57,17 +68,20 @@ pub fn run_draft(
     let meta = SocialDraftMeta {
         platform,
         author,
+        organization: organization.map(|o| o.to_string()),
         visibility: visibility.to_string(),
         status: DraftStatus::Draft,
         tags: tags.to_vec(),
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let body_text = body.unwrap_or("").to_string();
@@ -196,6 +210,78 @@ pub fn run_edit(file: &Path, body: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Resolve the URN a draft is posted as: the organization's URN when
+/// `organization` is set (LinkedIn company pages), otherwise the author's.
+pub(crate) fn post_as_urn(meta: &SocialDraftMeta, profiles: &ProfilesFile) -> Result<String> {
+    match &meta.organization {
+        Some(org) => {
+            if meta.platform != Platform::LinkedIn {
+                bail!("Posting as an organization is only supported on LinkedIn");
+            }
+            profiles.resolve_org_urn(org)
+        }
+        None => profiles.resolve_urn(&meta.author, meta.platform),
+    }
+}
+
+/// Resolve a draft's author → URN → valid access token.
+pub(crate) fn access_token_for(meta: &SocialDraftMeta) -> Result<String> {
+    let profiles = ProfilesFile::load()?;
+    let urn = profiles.resolve_urn(&meta.author, meta.platform)?;
+
+    let store = token_store::TokenStore::load()?;
+    let token = store.get_valid(&urn).ok_or_else(|| {
+        anyhow::anyhow!(
+            "No valid token for {} ({}).\nRun `corky {} auth` to authenticate.",
+            meta.author,
+            urn,
+            meta.platform,
+        )
+    })?;
+    Ok(token.access_token.clone())
+}
+
+/// Run the `linkedin delete` / `youtube delete` command: remove a published post
+/// from the platform and mark the draft as retracted.
+pub fn run_delete(platform_str: &str, file: &Path) -> Result<()> {
+    let expected: Platform = platform_str.parse()?;
+    let content = std::fs::read_to_string(file)?;
+    let mut draft = SocialDraft::parse(&content)?;
+
+    if draft.meta.platform != expected {
+        bail!(
+            "Draft is not a {} draft (platform: {})",
+            expected,
+            draft.meta.platform
+        );
+    }
+    if draft.meta.status == DraftStatus::Retracted {
+        bail!(
+            "Post has already been retracted.\nRetracted at: {}",
+            draft.meta.retracted_at.map(|t| t.to_string()).unwrap_or_default()
+        );
+    }
+
+    let post_id = draft.meta.post_id.clone().ok_or_else(|| {
+        anyhow::anyhow!("Post has not been published yet — no post_id in frontmatter.")
+    })?;
+
+    let access_token = access_token_for(&draft.meta)?;
+
+    match expected {
+        Platform::LinkedIn => linkedin::delete_post(&access_token, &post_id)?,
+        Platform::Youtube => youtube::delete_video(&access_token, &post_id)?,
+        other => bail!("Deleting {} posts is not yet supported", other),
+    }
+
+    draft.meta.status = DraftStatus::Retracted;
+    draft.meta.retracted_at = Some(chrono::Utc::now());
+    std::fs::write(file, draft.render()?)?;
+
+    println!("Deleted {} post {} (draft marked retracted)", expected, post_id);
+    Ok(())
+}
+
 /// Run the `social check` command: validate profiles in .corky.toml (or profiles.toml fallback).
 pub fn run_check() -> Result<()> {
     let profiles = match ProfilesFile::load() {
@@ -234,12 +320,45 @@ pub fn run_check() -> Result<()> {
     Ok(())
 }
 
-/// Run the `social list` command: list social drafts.
-pub fn run_list(status_filter: Option<&str>) -> Result<()> {
+/// A social draft as reported by `corky linkedin list` / `corky youtube list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SocialDraftEntry {
+    pub file: String,
+    pub status: DraftStatus,
+    pub platform: Platform,
+    pub author: String,
+    pub scheduled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub post_url: Option<String>,
+    /// First 60 characters of the body
+    pub preview: String,
+}
+
+/// Result of the `social list` command (JSON: array of [`SocialDraftEntry`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct SocialListReport(pub Vec<SocialDraftEntry>);
+
+impl Report for SocialListReport {
+    fn render_table(&self) -> String {
+        if self.0.is_empty() {
+            return "No social drafts found.\n".to_string();
+        }
+        let mut out = String::new();
+        for d in &self.0 {
+            out.push_str(&format!(
+                "  {} [{}] {} @{} — {}\n",
+                d.file, d.status, d.platform, d.author, d.preview,
+            ));
+        }
+        out
+    }
+}
+
+/// Collect social drafts, optionally filtered by status, sorted by filename.
+pub fn collect_drafts(status_filter: Option<&str>) -> Result<SocialListReport> {
     let social_dir = resolve::social_dir();
     if !social_dir.exists() {
-        println!("No social drafts found.");
-        return Ok(());
+        return Ok(SocialListReport(vec![]));
     }
 
     let filter: Option<DraftStatus> = status_filter
@@ -252,7 +371,7 @@ pub fn run_list(status_filter: Option<&str>) -> Result<()> {
         .collect();
     entries.sort_by_key(|e| e.file_name());
 
-    let mut count = 0;
+    let mut drafts = Vec::new();
     for entry in entries {
         let content = std::fs::read_to_string(entry.path())?;
         if let Ok(draft) = SocialDraft::parse(&content) {
@@ -261,25 +380,23 @@ pub fn run_list(status_filter: Option<&str>) -> Result<()> {
                     continue;
                 }
             }
-            let name = entry.file_name();
-            let name_str = name.to_string_lossy();
-            let preview = util::truncate_preview(&draft.body, 60);
-            println!(
-                "  {} [{}] {} @{} — {}",
-                name_str,
-                draft.meta.status,
-                draft.meta.platform,
-                draft.meta.author,
-                preview,
-            );
-            count += 1;
+            drafts.push(SocialDraftEntry {
+                file: entry.file_name().to_string_lossy().to_string(),
+                preview: util::truncate_preview(&draft.body, 60),
+                status: draft.meta.status,
+                platform: draft.meta.platform,
+                author: draft.meta.author,
+                scheduled_at: draft.meta.scheduled_at,
+                post_url: draft.meta.post_url,
+            });
         }
     }
+    Ok(SocialListReport(drafts))
+}
 
-    if count == 0 {
-        println!("No social drafts found.");
-    }
-    Ok(())
+/// Run the `social list` command: list social drafts.
+pub fn run_list(status_filter: Option<&str>, format: OutputFormat) -> Result<()> {
+    emit(format, &collect_drafts(status_filter)?)
 }
 
 /// Run the `social rename-author` command.
@@ -47,7 +47,7 @@ impl Profile {
     }
 
     /// Returns all platform entries this profile has.
-    fn platform_entries(&self) -> Vec<(Platform, &PlatformEntry)> {
+    pub fn platform_entries(&self) -> Vec<(Platform, &PlatformEntry)> {
         let mut entries = Vec::new();
         if let Some(e) = &self.linkedin {
             entries.push((Platform::LinkedIn, e));
@@ -265,6 +265,32 @@ impl ProfilesFile {
         })
     }
 
+    /// Resolve an organization profile to its LinkedIn organization URN.
+    pub fn resolve_org_urn(&self, profile_name: &str) -> Result<String> {
+        let urn = self.resolve_urn(profile_name, Platform::LinkedIn)?;
+        if !urn.starts_with("urn:li:organization:") {
+            bail!(
+                "Profile '{}' is not a LinkedIn organization (URN: {}). \
+                 Expected urn:li:organization:<id>",
+                profile_name,
+                urn
+            );
+        }
+        Ok(urn)
+    }
+
+    /// Resolve a URN (or channel ID) to its profile name for a given platform.
+    pub fn resolve_by_urn(&self, urn: &str, platform: Platform) -> Option<String> {
+        for (name, profile) in &self.profiles {
+            if let Some(entry) = profile.get_platform(platform) {
+                if entry.urn.as_deref() == Some(urn) {
+                    return Some(name.clone());
+                }
+            }
+        }
+        None
+    }
+
     /// Resolve a handle to its profile name for a given platform.
     pub fn resolve_handle(&self, handle: &str, platform: Platform) -> Option<String> {
         for (name, profile) in &self.profiles {
@@ -4,6 +4,7 @@ use anyhow::{bail, Result};
 use chrono::Utc;
 use std::path::Path;
 
+use super::auth;
 use super::draft::{DraftStatus, SocialDraft};
 use super::linkedin;
 use super::youtube;
@@ -19,6 +20,7 @@ pub fn publish(path: &Path, dry_run: bool) -> Result<()> {
 
     // PB1: Check status
     // - Published → always reject (prevents double-publish)
+    // - Retracted → always reject (post was deleted from the platform)
     // - Draft + scheduled_at set → allowed (scheduling implies readiness)
     // - Draft + no scheduled_at + not dry-run → reject (manual publish requires ready)
     // - Ready → always allowed
@@ -30,6 +32,13 @@ pub fn publish(path: &Path, dry_run: bool) -> Result<()> {
             draft.meta.published_at.map(|t| t.to_string()).unwrap_or_default()
         );
     }
+    if draft.meta.status == DraftStatus::Retracted {
+        bail!(
+            "Draft was retracted and cannot be republished.\n\
+             Retracted at: {}",
+            draft.meta.retracted_at.map(|t| t.to_string()).unwrap_or_default()
+        );
+    }
     if !dry_run && draft.meta.status != DraftStatus::Ready && draft.meta.scheduled_at.is_none() {
         bail!(
             "Draft is not ready for publishing (status: draft).\n\
@@ -66,12 +75,32 @@ pub fn publish(path: &Path, dry_run: bool) -> Result<()> {
         }
     })?;
 
-    // Upload images if present (even in dry-run, to verify they work)
-    let image_urns = upload_images(path, &draft, &token.access_token, &urn, platform)?;
+    // Post as an organization (company page) when `organization` is set.
+    // The member's token is used; it must carry the organization posting scope.
+    let post_as = super::post_as_urn(&draft.meta, &profiles)?;
+    if draft.meta.organization.is_some()
+        && !token.scopes.iter().any(|s| s == auth::LINKEDIN_ORG_POST_SCOPE)
+    {
+        bail!(
+            "Token for {} ({}) lacks the {} scope needed to post as an organization.\n\
+             Run `corky linkedin auth --org --profile {}` to re-authenticate.",
+            author,
+            urn,
+            auth::LINKEDIN_ORG_POST_SCOPE,
+            author
+        );
+    }
+
+    // Upload images if present (even in dry-run, to verify they work).
+    // Images are owned by the post author (the organization for org posts).
+    let image_urns = upload_images(path, &draft, &token.access_token, &post_as, platform)?;
 
     if dry_run {
         println!("[dry-run] Validation passed. Would publish to {}.", platform);
         println!("[dry-run] Author: {} ({})", author, urn);
+        if let Some(ref org) = draft.meta.organization {
+            println!("[dry-run] Posting as organization: {} ({})", org, post_as);
+        }
         println!("[dry-run] Visibility: {}", draft.meta.visibility);
         if !image_urns.is_empty() {
             println!("[dry-run] Images uploaded: {}", image_urns.len());
@@ -98,7 +127,7 @@ pub fn publish(path: &Path, dry_run: bool) -> Result<()> {
         Platform::LinkedIn => {
             linkedin::create_post(
                 &token.access_token,
-                &urn,
+                &post_as,
                 &draft.body,
                 &draft.meta.visibility,
                 &image_urns,
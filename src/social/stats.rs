@@ -0,0 +1,191 @@
+//! Engagement metrics for published social drafts (`corky social stats`).
+//!
+//! Fetches reactions, comments, shares and impressions from the platform and
+//! writes them back into the draft's `metrics` frontmatter block.
+
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+use super::draft::{DraftStatus, PostMetrics, SocialDraft};
+use super::platform::Platform;
+use super::{linkedin, youtube};
+use crate::resolve;
+
+/// List published drafts (with a `post_id`) in `dir`, sorted by filename.
+pub fn published_drafts(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|x| x == "md").unwrap_or(false))
+        .filter(|p| {
+            std::fs::read_to_string(p)
+                .ok()
+                .and_then(|c| SocialDraft::parse(&c).ok())
+                .map(|d| d.meta.status == DraftStatus::Published && d.meta.post_id.is_some())
+                .unwrap_or(false)
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Fetch current metrics for a published draft from its platform.
+pub fn fetch_metrics(draft: &SocialDraft) -> Result<PostMetrics> {
+    let post_id = draft.meta.post_id.as_deref().ok_or_else(|| {
+        anyhow::anyhow!("Post has not been published yet — no post_id in frontmatter.")
+    })?;
+    let access_token = super::access_token_for(&draft.meta)?;
+
+    match draft.meta.platform {
+        Platform::LinkedIn => linkedin::get_post_metrics(&access_token, post_id),
+        Platform::Youtube => youtube::get_video_metrics(&access_token, post_id),
+        other => bail!("Metrics for {} posts are not yet supported", other),
+    }
+}
+
+/// Fetch metrics for a draft file and write them back into its frontmatter.
+pub fn refresh_file(path: &Path) -> Result<SocialDraft> {
+    let content = std::fs::read_to_string(path)?;
+    let mut draft = SocialDraft::parse(&content)?;
+    if draft.meta.status != DraftStatus::Published {
+        bail!("Draft is not published (status: {})", draft.meta.status);
+    }
+    draft.meta.metrics = Some(fetch_metrics(&draft)?);
+    std::fs::write(path, draft.render()?)?;
+    Ok(draft)
+}
+
+/// Refresh metrics for every published draft. Best-effort: failures are
+/// reported on stderr and skipped. Returns the number of drafts refreshed.
+pub fn refresh_all() -> Result<usize> {
+    let mut count = 0;
+    for path in published_drafts(&resolve::social_dir())? {
+        match refresh_file(&path) {
+            Ok(_) => count += 1,
+            Err(e) => eprintln!("  {}: {}", path.display(), e),
+        }
+    }
+    Ok(count)
+}
+
+/// Format an optional count for the summary table.
+fn fmt_count(n: Option<u64>) -> String {
+    n.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+/// Run `corky social stats [FILES]`: refresh metrics and print a summary table.
+/// With no files, all published drafts in `social/` are refreshed.
+pub fn run(files: &[PathBuf]) -> Result<()> {
+    let paths = if files.is_empty() {
+        published_drafts(&resolve::social_dir())?
+    } else {
+        files.to_vec()
+    };
+
+    if paths.is_empty() {
+        println!("No published social drafts found.");
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    let mut failed = 0;
+    for path in &paths {
+        match refresh_file(path) {
+            Ok(draft) => {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                rows.push((name, draft));
+            }
+            Err(e) => {
+                eprintln!("ERROR: {}: {}", path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    if !rows.is_empty() {
+        let name_w = rows.iter().map(|(n, _)| n.len()).max().unwrap_or(4).max(4);
+        println!(
+            "  {:<width$}  {:<8}  {:>9}  {:>8}  {:>6}  {:>11}",
+            "FILE",
+            "PLATFORM",
+            "REACTIONS",
+            "COMMENTS",
+            "SHARES",
+            "IMPRESSIONS",
+            width = name_w
+        );
+        for (name, draft) in &rows {
+            if let Some(m) = &draft.meta.metrics {
+                println!(
+                    "  {:<width$}  {:<8}  {:>9}  {:>8}  {:>6}  {:>11}",
+                    name,
+                    draft.meta.platform,
+                    m.reactions,
+                    m.comments,
+                    fmt_count(m.shares),
+                    fmt_count(m.impressions),
+                    width = name_w
+                );
+            }
+        }
+    }
+
+    if failed > 0 && rows.is_empty() {
+        bail!("Failed to fetch metrics for {} draft(s)", failed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_draft(dir: &Path, name: &str, status: &str, post_id: Option<&str>) {
+        let post_id_line = post_id
+            .map(|id| format!("post_id: \"{}\"\n", id))
+            .unwrap_or_default();
+        let content = format!(
+            "---\nplatform: linkedin\nauthor: btakita\nstatus: {}\n{}---\nBody\n",
+            status, post_id_line
+        );
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn published_drafts_filters_status_and_post_id() {
+        let tmp = TempDir::new().unwrap();
+        write_draft(tmp.path(), "b-published.md", "published", Some("urn:li:share:2"));
+        write_draft(tmp.path(), "a-published.md", "published", Some("urn:li:share:1"));
+        write_draft(tmp.path(), "ready.md", "ready", None);
+        write_draft(tmp.path(), "no-id.md", "published", None);
+        write_draft(tmp.path(), "retracted.md", "retracted", Some("urn:li:share:3"));
+        std::fs::write(tmp.path().join("notes.txt"), "ignored").unwrap();
+
+        let paths = published_drafts(tmp.path()).unwrap();
+        let names: Vec<_> = paths
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["a-published.md", "b-published.md"]);
+    }
+
+    #[test]
+    fn published_drafts_missing_dir() {
+        let tmp = TempDir::new().unwrap();
+        let paths = published_drafts(&tmp.path().join("nope")).unwrap();
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn fmt_count_none_is_dash() {
+        assert_eq!(fmt_count(None), "-");
+        assert_eq!(fmt_count(Some(42)), "42");
+    }
+}
@@ -0,0 +1,374 @@
+//! Pre-publish validation of social drafts against platform limits and media rules.
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use super::draft::{DraftStatus, SocialDraft};
+use super::linkedin;
+use super::platform::Platform;
+use super::profiles::ProfilesFile;
+use crate::resolve;
+
+/// `@handle` mentions (not preceded by a word char, so emails are skipped).
+static MENTION_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:^|[^\w@])@([A-Za-z0-9_][A-Za-z0-9_.-]*)").unwrap());
+
+/// Per-platform body and media limits.
+pub struct PlatformLimits {
+    /// Maximum body length in characters
+    pub max_body: usize,
+    /// Maximum number of attached images (0 = images not supported)
+    pub max_images: usize,
+    /// Maximum size of a single image in bytes
+    pub max_image_bytes: u64,
+    /// Accepted image file extensions (lowercase)
+    pub image_formats: &'static [&'static str],
+}
+
+/// Limits for a platform.
+pub fn limits(platform: Platform) -> PlatformLimits {
+    match platform {
+        Platform::LinkedIn => PlatformLimits {
+            max_body: linkedin::MAX_BODY_LENGTH,
+            max_images: linkedin::MAX_IMAGES,
+            max_image_bytes: 10 * 1024 * 1024,
+            image_formats: &["jpg", "jpeg", "png", "gif"],
+        },
+        Platform::Bluesky => PlatformLimits {
+            max_body: 300,
+            max_images: 4,
+            max_image_bytes: 1_000_000,
+            image_formats: &["jpg", "jpeg", "png", "webp", "gif"],
+        },
+        Platform::Mastodon => PlatformLimits {
+            max_body: 500,
+            max_images: 4,
+            max_image_bytes: 16 * 1024 * 1024,
+            image_formats: &["jpg", "jpeg", "png", "webp", "gif"],
+        },
+        Platform::Twitter => PlatformLimits {
+            max_body: 280,
+            max_images: 4,
+            max_image_bytes: 5 * 1024 * 1024,
+            image_formats: &["jpg", "jpeg", "png", "webp", "gif"],
+        },
+        Platform::Youtube => PlatformLimits {
+            max_body: YOUTUBE_MAX_DESCRIPTION,
+            max_images: 0,
+            max_image_bytes: 0,
+            image_formats: &[],
+        },
+    }
+}
+
+/// YouTube description limit (characters).
+const YOUTUBE_MAX_DESCRIPTION: usize = 5000;
+
+/// YouTube title limit (characters).
+const YOUTUBE_MAX_TITLE: usize = 100;
+
+/// YouTube maximum upload size (256 GB).
+const YOUTUBE_MAX_VIDEO_BYTES: u64 = 256 * 1024 * 1024 * 1024;
+
+/// Video containers YouTube accepts.
+const YOUTUBE_VIDEO_FORMATS: &[&str] = &[
+    "mp4", "mov", "m4v", "avi", "wmv", "flv", "webm", "mkv", "mpeg", "mpg", "3gp",
+];
+
+/// File extension, lowercased.
+fn extension(path: &Path) -> String {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// Extract `@handle` mentions from a post body (trailing dots stripped, deduped).
+pub fn extract_mentions(body: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    for cap in MENTION_RE.captures_iter(body) {
+        let handle = cap[1].trim_end_matches(['.', '-']).to_string();
+        if !handle.is_empty() && !mentions.contains(&handle) {
+            mentions.push(handle);
+        }
+    }
+    mentions
+}
+
+/// Check that an SRT file starts with a numbered cue and a timing line.
+fn looks_like_srt(text: &str) -> bool {
+    static TIMING_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^\d{2}:\d{2}:\d{2},\d{3} --> \d{2}:\d{2}:\d{2},\d{3}").unwrap()
+    });
+    let mut lines = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .skip_while(|l| l.trim().is_empty());
+    let index = lines.next().unwrap_or("");
+    let timing = lines.next().unwrap_or("");
+    index.trim().parse::<u32>().is_ok() && TIMING_RE.is_match(timing.trim())
+}
+
+/// Validate a social draft file. Returns list of issues (empty = valid).
+///
+/// Issues prefixed with `Warning:` don't block publishing. When `profiles` is
+/// `None`, @mentions are not checked.
+pub fn validate_social_draft(path: &Path, profiles: Option<&ProfilesFile>) -> Vec<String> {
+    if !path.exists() {
+        return vec![format!("File not found: {}", path.display())];
+    }
+
+    let text = match std::fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(e) => return vec![format!("Cannot read {}: {}", path.display(), e)],
+    };
+
+    let draft = match SocialDraft::parse(&text) {
+        Ok(d) => d,
+        Err(e) => return vec![format!("Invalid draft: {}", e)],
+    };
+
+    let mut issues = Vec::new();
+    let platform = draft.meta.platform;
+    let lim = limits(platform);
+    let draft_dir = path.parent().unwrap_or(Path::new("."));
+
+    match draft.meta.status {
+        DraftStatus::Published | DraftStatus::Retracted => issues.push(format!(
+            "Warning: draft is already {}",
+            draft.meta.status
+        )),
+        DraftStatus::Draft if draft.meta.scheduled_at.is_none() => issues.push(
+            "Warning: status is 'draft'. Set to 'ready' (or add scheduled_at) to publish"
+                .to_string(),
+        ),
+        _ => {}
+    }
+
+    // Body length
+    let body = draft.body.trim();
+    let char_count = body.chars().count();
+    if body.is_empty() {
+        issues.push("Warning: empty post body".to_string());
+    } else if char_count > lim.max_body {
+        issues.push(format!(
+            "Body exceeds {}'s {} character limit ({} characters)",
+            platform, lim.max_body, char_count
+        ));
+    }
+
+    // Images
+    if !draft.meta.images.is_empty() {
+        if lim.max_images == 0 {
+            issues.push(format!("Images are not supported for {} drafts", platform));
+        } else if draft.meta.images.len() > lim.max_images {
+            issues.push(format!(
+                "Too many images ({}) — {} allows up to {}",
+                draft.meta.images.len(),
+                platform,
+                lim.max_images
+            ));
+        }
+        if lim.max_images > 0 {
+            for image in &draft.meta.images {
+                let image_path = draft_dir.join(image);
+                let ext = extension(&image_path);
+                if !lim.image_formats.contains(&ext.as_str()) {
+                    issues.push(format!(
+                        "Unsupported image format '{}' for {}. Valid: {}",
+                        image,
+                        platform,
+                        lim.image_formats.join(", ")
+                    ));
+                }
+                match std::fs::metadata(&image_path) {
+                    Ok(m) if m.len() > lim.max_image_bytes => issues.push(format!(
+                        "Image too large: {} ({} bytes, limit {})",
+                        image,
+                        m.len(),
+                        lim.max_image_bytes
+                    )),
+                    Ok(_) => {}
+                    Err(_) => issues.push(format!(
+                        "Image file not found: {}",
+                        image_path.display()
+                    )),
+                }
+            }
+        }
+    }
+
+    // Video + captions
+    if platform == Platform::Youtube {
+        match draft.meta.video.as_deref() {
+            None => issues.push("Missing required field for YouTube: video".to_string()),
+            Some(video) => {
+                let video_path = draft_dir.join(video);
+                let ext = extension(&video_path);
+                if !YOUTUBE_VIDEO_FORMATS.contains(&ext.as_str()) {
+                    issues.push(format!(
+                        "Unsupported video container '{}'. Valid: {}",
+                        video,
+                        YOUTUBE_VIDEO_FORMATS.join(", ")
+                    ));
+                }
+                match std::fs::metadata(&video_path) {
+                    Ok(m) if m.len() == 0 => {
+                        issues.push(format!("Video file is empty: {}", video))
+                    }
+                    Ok(m) if m.len() > YOUTUBE_MAX_VIDEO_BYTES => issues.push(format!(
+                        "Video too large: {} ({} bytes, limit {})",
+                        video,
+                        m.len(),
+                        YOUTUBE_MAX_VIDEO_BYTES
+                    )),
+                    Ok(_) => {}
+                    Err(_) => issues.push(format!(
+                        "Video file not found: {}",
+                        video_path.display()
+                    )),
+                }
+            }
+        }
+
+        match draft.meta.title.as_deref() {
+            Some(title) if title.chars().count() > YOUTUBE_MAX_TITLE => issues.push(format!(
+                "Title exceeds YouTube's {} character limit ({} characters)",
+                YOUTUBE_MAX_TITLE,
+                title.chars().count()
+            )),
+            Some(title) if title.contains('<') || title.contains('>') => issues
+                .push("Title contains '<' or '>', which YouTube rejects".to_string()),
+            Some(_) => {}
+            None => issues.push(
+                "Warning: no title — the first body line will be used as the video title"
+                    .to_string(),
+            ),
+        }
+
+        if let Some(captions) = draft.meta.captions.as_deref() {
+            let captions_path = draft_dir.join(captions);
+            if extension(&captions_path) != "srt" {
+                issues.push(format!("Captions must be an SRT file: {}", captions));
+            }
+            match std::fs::read_to_string(&captions_path) {
+                Ok(t) if !looks_like_srt(&t) => issues.push(format!(
+                    "Captions file is not valid SRT (expected numbered cue + timing line): {}",
+                    captions
+                )),
+                Ok(_) => {}
+                Err(_) => issues.push(format!(
+                    "Captions file not found: {}",
+                    captions_path.display()
+                )),
+            }
+        }
+    } else {
+        if draft.meta.video.is_some() {
+            issues.push(format!("Video is only supported for YouTube drafts (platform: {})", platform));
+        }
+        if draft.meta.captions.is_some() {
+            issues.push(format!("Captions are only supported for YouTube drafts (platform: {})", platform));
+        }
+    }
+
+    // Organization (company page) posting
+    if let Some(org) = &draft.meta.organization {
+        if platform != Platform::LinkedIn {
+            issues.push(format!(
+                "Posting as an organization is only supported on LinkedIn (platform: {})",
+                platform
+            ));
+        } else if let Some(profiles) = profiles {
+            if let Err(e) = profiles.resolve_org_urn(org) {
+                issues.push(e.to_string());
+            }
+        }
+    }
+
+    // @mentions
+    if let Some(profiles) = profiles {
+        for handle in extract_mentions(&draft.body) {
+            let known = profiles.resolve_handle(&handle, platform).is_some()
+                || profiles.profiles.contains_key(&handle);
+            if !known {
+                issues.push(format!(
+                    "Warning: unresolved mention @{} (no {} handle in profiles)",
+                    handle, platform
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// Unpublished social drafts in `social/`, sorted by filename.
+fn unpublished_drafts() -> Result<Vec<PathBuf>> {
+    let dir = resolve::social_dir();
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|x| x == "md").unwrap_or(false))
+        .filter(|p| {
+            std::fs::read_to_string(p)
+                .ok()
+                .and_then(|c| SocialDraft::parse(&c).ok())
+                .map(|d| matches!(d.meta.status, DraftStatus::Draft | DraftStatus::Ready))
+                // Unparseable drafts are validated so the error gets reported
+                .unwrap_or(true)
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// corky social validate [FILES...] — default: all unpublished drafts in social/.
+pub fn run(files: &[PathBuf]) -> Result<()> {
+    let paths = if files.is_empty() {
+        unpublished_drafts()?
+    } else {
+        files.to_vec()
+    };
+
+    if paths.is_empty() {
+        println!("No social drafts found.");
+        return Ok(());
+    }
+
+    let profiles = ProfilesFile::load().ok();
+    let mut has_errors = false;
+
+    for path in &paths {
+        let issues = validate_social_draft(path, profiles.as_ref());
+        if !issues.is_empty() {
+            let errors: Vec<_> = issues
+                .iter()
+                .filter(|i| !i.starts_with("Warning:"))
+                .collect();
+            let warnings: Vec<_> = issues.iter().filter(|i| i.starts_with("Warning:")).collect();
+            if !errors.is_empty() {
+                has_errors = true;
+            }
+            println!("{}:", path.display());
+            for issue in errors {
+                println!("  ERROR: {}", issue);
+            }
+            for issue in warnings {
+                println!("  {}", issue);
+            }
+            println!();
+        } else {
+            println!("{}: OK", path.display());
+        }
+    }
+
+    if has_errors {
+        std::process::exit(1);
+    }
+    Ok(())
+}
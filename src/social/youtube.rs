@@ -1,11 +1,15 @@
 //! YouTube Data API v3 client (REST API).
 
 use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
 
+use super::comments::PostComment;
+use super::draft::PostMetrics;
+
 /// YouTube API base URL.
 const API_BASE: &str = "https://www.googleapis.com";
 
@@ -257,6 +261,197 @@ pub fn update_video_at(
     }
 }
 
+/// Delete a published video.
+///
+/// Uses the YouTube Data API v3 videos.delete endpoint (HTTP 204 on success).
+pub fn delete_video(access_token: &str, video_id: &str) -> Result<()> {
+    delete_video_at(API_BASE, access_token, video_id)
+}
+
+/// Delete a video with configurable API base URL (for testing).
+pub fn delete_video_at(api_base: &str, access_token: &str, video_id: &str) -> Result<()> {
+    let url = format!("{}/youtube/v3/videos?id={}", api_base, video_id);
+
+    let resp = ureq::delete(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .call();
+
+    match resp {
+        Ok(_) => Ok(()),
+        Err(ureq::Error::Status(status, resp)) => {
+            let err_body = resp.into_string().unwrap_or_default();
+            bail!("YouTube video delete failed (HTTP {}): {}", status, err_body);
+        }
+        Err(e) => bail!("YouTube video delete request failed: {}", e),
+    }
+}
+
+/// Fetch engagement metrics for a video (likes, comments, views).
+///
+/// YouTube has no share count; views are reported as `impressions`.
+pub fn get_video_metrics(access_token: &str, video_id: &str) -> Result<PostMetrics> {
+    get_video_metrics_at(API_BASE, access_token, video_id)
+}
+
+/// Fetch video metrics with configurable API base URL (for testing).
+pub fn get_video_metrics_at(
+    api_base: &str,
+    access_token: &str,
+    video_id: &str,
+) -> Result<PostMetrics> {
+    let url = format!(
+        "{}/youtube/v3/videos?part=statistics&id={}",
+        api_base, video_id
+    );
+    let resp = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .call();
+
+    let body: serde_json::Value = match resp {
+        Ok(r) => r.into_json()?,
+        Err(ureq::Error::Status(status, resp)) => {
+            let err_body = resp.into_string().unwrap_or_default();
+            bail!("YouTube statistics request failed (HTTP {}): {}", status, err_body);
+        }
+        Err(e) => bail!("YouTube statistics request failed: {}", e),
+    };
+
+    let stats = &body["items"][0]["statistics"];
+    if stats.is_null() {
+        bail!("YouTube video not found: {}", video_id);
+    }
+
+    // The API returns counts as strings
+    let count = |key: &str| -> Option<u64> {
+        stats[key]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .or_else(|| stats[key].as_u64())
+    };
+
+    Ok(PostMetrics {
+        reactions: count("likeCount").unwrap_or(0),
+        comments: count("commentCount").unwrap_or(0),
+        shares: None,
+        impressions: count("viewCount"),
+        fetched_at: Utc::now(),
+    })
+}
+
+/// Fetch all comments (top-level and replies) on a video, oldest first.
+pub fn get_comments(access_token: &str, video_id: &str) -> Result<Vec<PostComment>> {
+    get_comments_at(API_BASE, access_token, video_id)
+}
+
+/// Fetch video comments with configurable API base URL (for testing).
+pub fn get_comments_at(
+    api_base: &str,
+    access_token: &str,
+    video_id: &str,
+) -> Result<Vec<PostComment>> {
+    let mut comments = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut url = format!(
+            "{}/youtube/v3/commentThreads?part=snippet,replies&videoId={}&maxResults=100&textFormat=plainText",
+            api_base, video_id
+        );
+        if let Some(ref token) = page_token {
+            url.push_str(&format!("&pageToken={}", token));
+        }
+        let body = comments_request(&url, access_token)?;
+
+        for item in body["items"].as_array().into_iter().flatten() {
+            let top = &item["snippet"]["topLevelComment"];
+            comments.push(comment_from_json(top));
+            // commentThreads only inlines a few replies; fetch the rest by parent
+            let inlined = item["replies"]["comments"].as_array().map(Vec::as_slice).unwrap_or_default();
+            let total = item["snippet"]["totalReplyCount"].as_u64().unwrap_or(0);
+            if total > inlined.len() as u64 {
+                let parent_id = item["id"].as_str().or_else(|| top["id"].as_str()).unwrap_or_default();
+                comments.extend(get_replies_at(api_base, access_token, parent_id)?);
+            } else {
+                comments.extend(inlined.iter().map(comment_from_json));
+            }
+        }
+
+        page_token = body["nextPageToken"].as_str().map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    comments.sort_by_key(|c| c.created_at);
+    Ok(comments)
+}
+
+/// Every reply in a comment thread, via `comments.list?parentId=`.
+fn get_replies_at(api_base: &str, access_token: &str, parent_id: &str) -> Result<Vec<PostComment>> {
+    let mut replies = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut url = format!(
+            "{}/youtube/v3/comments?part=snippet&parentId={}&maxResults=100&textFormat=plainText",
+            api_base, parent_id
+        );
+        if let Some(ref token) = page_token {
+            url.push_str(&format!("&pageToken={}", token));
+        }
+        let body = comments_request(&url, access_token)?;
+        replies.extend(body["items"].as_array().into_iter().flatten().map(comment_from_json));
+
+        page_token = body["nextPageToken"].as_str().map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
+        }
+    }
+    Ok(replies)
+}
+
+/// GET a comments endpoint and parse the JSON body.
+fn comments_request(url: &str, access_token: &str) -> Result<serde_json::Value> {
+    let resp = ureq::get(url)
+        .set("Authorization", &format!("Bearer {}", access_token))
+        .call();
+
+    match resp {
+        Ok(r) => Ok(r.into_json()?),
+        Err(ureq::Error::Status(status, resp)) => {
+            let err_body = resp.into_string().unwrap_or_default();
+            bail!("YouTube comments request failed (HTTP {}): {}", status, err_body);
+        }
+        Err(e) => bail!("YouTube comments request failed: {}", e),
+    }
+}
+
+/// Convert a YouTube `comment` resource into a `PostComment`.
+fn comment_from_json(comment: &serde_json::Value) -> PostComment {
+    let snippet = &comment["snippet"];
+    let text = snippet["textOriginal"]
+        .as_str()
+        .or_else(|| snippet["textDisplay"].as_str())
+        .unwrap_or_default();
+    PostComment {
+        id: comment["id"].as_str().unwrap_or_default().to_string(),
+        author_id: snippet["authorChannelId"]["value"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        author_name: snippet["authorDisplayName"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        text: text.to_string(),
+        created_at: snippet["publishedAt"]
+            .as_str()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_default(),
+    }
+}
+
 /// Upload captions (subtitles) for a video.
 ///
 /// `caption_path` should be an SRT file.
@@ -0,0 +1,422 @@
+//! DiscordChatExporter JSON → corky conversations.
+//!
+//! Reads the per-channel JSON files written by DiscordChatExporter
+//! (`--format Json`) and, like `slack_import`, turns each reply chain into a
+//! thread keyed `discord:{channel_id}:{root_message_id}`. A message that
+//! replies to another message in the same export joins its thread; anything
+//! else starts a new one. Exports of Discord threads and forum posts become a
+//! single thread keyed `discord:{thread_channel_id}`.
+
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::imap_sync::{merge_message, MergeOutcome};
+use super::types::Message;
+use crate::config::owner;
+
+// ---------------------------------------------------------------------------
+// DiscordChatExporter JSON types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct DiscordExport {
+    #[serde(default)]
+    pub guild: Option<DiscordGuild>,
+    pub channel: DiscordChannel,
+    #[serde(default)]
+    pub messages: Vec<DiscordMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DiscordGuild {
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordChannel {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// "GuildTextChat", "DirectTextChat", "GuildPublicThread", ...
+    #[serde(default, rename = "type")]
+    pub channel_type: String,
+    /// Category name; for thread exports, the parent channel's name
+    #[serde(default)]
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordMessage {
+    pub id: String,
+    /// "Default", "Reply", "ThreadCreated", "GuildMemberJoin", ...
+    #[serde(default, rename = "type")]
+    pub msg_type: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub content: String,
+    pub author: DiscordUser,
+    #[serde(default)]
+    pub attachments: Vec<DiscordAttachment>,
+    #[serde(default)]
+    pub mentions: Vec<DiscordUser>,
+    #[serde(default)]
+    pub reference: Option<DiscordReference>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordUser {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub nickname: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordAttachment {
+    #[serde(default)]
+    pub file_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordReference {
+    #[serde(default)]
+    pub message_id: Option<String>,
+}
+
+/// Counts reported at the end of an import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportStats {
+    pub imported: usize,
+    pub duplicates: usize,
+    /// Joins, pins, thread-created notices and other non-chat entries
+    pub system: usize,
+}
+
+/// `<@123>` / `<@!123>` → `@Name`
+static USER_MENTION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<@!?(\d+)>").unwrap());
+
+/// `<:name:123>` / `<a:name:123>` → `:name:`
+static CUSTOM_EMOJI_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<a?:(\w+):\d+>").unwrap());
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Import DiscordChatExporter JSON into corky conversations.
+///
+/// - `path`: a channel export `.json`, or a directory of them
+/// - `label`: label prefix; threads are labeled `{label}:{channel}`
+/// - `out_dir`: directory to write conversation markdown files
+/// - `account_name`: account name for metadata
+pub fn run(path: &Path, label: &str, out_dir: &Path, account_name: &str) -> Result<ImportStats> {
+    let files: Vec<PathBuf> = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .with_context(|| format!("Cannot read directory {}", path.display()))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "json"))
+            .collect();
+        if files.is_empty() {
+            bail!("No .json exports found in {}", path.display());
+        }
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut stats = ImportStats::default();
+    for file in &files {
+        let text = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let export: DiscordExport = serde_json::from_str(&text)
+            .with_context(|| format!("Not a DiscordChatExporter JSON export: {}", file.display()))?;
+        import_export(&export, label, out_dir, account_name, &mut stats)?;
+    }
+
+    println!(
+        "Discord import complete: {} message(s) imported, {} duplicate(s) skipped, {} system message(s) skipped.",
+        stats.imported, stats.duplicates, stats.system
+    );
+    Ok(stats)
+}
+
+/// Import one channel export.
+pub fn import_export(
+    export: &DiscordExport,
+    label: &str,
+    out_dir: &Path,
+    account_name: &str,
+    stats: &mut ImportStats,
+) -> Result<()> {
+    let channel = &export.channel;
+    let is_thread = channel.channel_type.contains("Thread");
+    // Threads are filed under their parent channel
+    let channel_name = if is_thread {
+        channel.category.clone().unwrap_or_else(|| channel.name.clone())
+    } else {
+        channel.name.clone()
+    };
+
+    let messages: Vec<&DiscordMessage> = export
+        .messages
+        .iter()
+        .filter(|m| {
+            let keep = is_chat_message(m);
+            if !keep {
+                stats.system += 1;
+            }
+            keep
+        })
+        .collect();
+
+    match export.guild.as_ref().filter(|g| !g.name.is_empty()) {
+        Some(guild) => println!("  {} #{}: {} messages", guild.name, channel.name, messages.len()),
+        None => println!("  #{}: {} messages", channel.name, messages.len()),
+    }
+
+    let names = user_names(&export.messages);
+    let label_name = format!("{}:{}", label, channel_name);
+    // [owner] handles.discord may be a user ID or account name
+    let owner_id = owner::handle("discord");
+
+    for (root_id, thread_msgs) in group_into_threads(&messages) {
+        let (thread_key, subject) = if is_thread {
+            (format!("discord:{}", channel.id), channel.name.clone())
+        } else {
+            let root = thread_msgs[0];
+            (
+                format!("discord:{}:{}", channel.id, root_id),
+                derive_subject(&resolve_content(&root.content, &names), &channel_name, &root.timestamp),
+            )
+        };
+
+        for msg in thread_msgs {
+            let body = message_body(msg, &names);
+            if body.is_empty() {
+                continue;
+            }
+            let mine = owner_id
+                .as_deref()
+                .is_some_and(|id| id == msg.author.id || id.eq_ignore_ascii_case(&msg.author.name));
+            let from = if mine { owner::ME.to_string() } else { display_name(&msg.author) };
+            let message = Message {
+                id: msg.id.clone(),
+                thread_id: thread_key.clone(),
+                from,
+                to: String::new(),
+                cc: String::new(),
+                date: timestamp_to_rfc2822(&msg.timestamp),
+                subject: subject.clone(),
+                body,
+                raw: String::new(),
+            };
+            match merge_message(out_dir, &label_name, account_name, &message, &thread_key)? {
+                MergeOutcome::Written(_) => stats.imported += 1,
+                MergeOutcome::Duplicate(_) => stats.duplicates += 1,
+            }
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Regular messages and replies; everything else is a system notice.
+fn is_chat_message(msg: &DiscordMessage) -> bool {
+    matches!(msg.msg_type.as_str(), "" | "Default" | "Reply")
+}
+
+/// Server nickname if set, else the account name.
+fn display_name(user: &DiscordUser) -> String {
+    user.nickname
+        .as_deref()
+        .filter(|n| !n.is_empty())
+        .unwrap_or(&user.name)
+        .to_string()
+}
+
+/// User ID → display name, from authors and mentions.
+fn user_names(messages: &[DiscordMessage]) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+    for msg in messages {
+        for user in std::iter::once(&msg.author).chain(&msg.mentions) {
+            names.entry(user.id.clone()).or_insert_with(|| display_name(user));
+        }
+    }
+    names
+}
+
+/// Group messages by the root of their reply chain, in order of first appearance.
+fn group_into_threads<'a>(messages: &[&'a DiscordMessage]) -> Vec<(String, Vec<&'a DiscordMessage>)> {
+    let by_id: HashMap<&str, &DiscordMessage> = messages.iter().map(|m| (m.id.as_str(), *m)).collect();
+    let root_of = |msg: &DiscordMessage| -> String {
+        let mut current = msg;
+        // Bounded walk: guards against reference cycles in malformed exports
+        for _ in 0..messages.len() {
+            let parent = current
+                .reference
+                .as_ref()
+                .and_then(|r| r.message_id.as_deref())
+                .and_then(|id| by_id.get(id));
+            match parent {
+                Some(p) if p.id != current.id => current = *p,
+                _ => break,
+            }
+        }
+        current.id.clone()
+    };
+
+    let mut map: HashMap<String, Vec<&DiscordMessage>> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+    for &msg in messages {
+        let key = root_of(msg);
+        if !map.contains_key(&key) {
+            order.push(key.clone());
+        }
+        map.entry(key).or_default().push(msg);
+    }
+    order
+        .into_iter()
+        .filter_map(|key| {
+            let msgs = map.remove(&key)?;
+            Some((key, msgs))
+        })
+        .collect()
+}
+
+/// Resolve mention and custom emoji markup to plain text.
+fn resolve_content(content: &str, names: &HashMap<String, String>) -> String {
+    let result = USER_MENTION_RE.replace_all(content, |caps: &regex::Captures| {
+        let id = &caps[1];
+        format!("@{}", names.get(id).map(|n| n.as_str()).unwrap_or(id))
+    });
+    CUSTOM_EMOJI_RE.replace_all(&result, ":$1:").into_owned()
+}
+
+/// Message text followed by one `[Attachment: NAME]` line per attachment.
+fn message_body(msg: &DiscordMessage, names: &HashMap<String, String>) -> String {
+    let mut lines = vec![resolve_content(&msg.content, names)];
+    for attachment in &msg.attachments {
+        lines.push(format!("[Attachment: {}]", attachment.file_name));
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// First line of the root message (max 60 chars), or `#channel -- date`.
+fn derive_subject(text: &str, channel_name: &str, timestamp: &str) -> String {
+    let first_line = text.lines().next().unwrap_or("").trim();
+    if first_line.is_empty() {
+        let date = timestamp.get(..10).unwrap_or(timestamp);
+        return format!("#{} -- {}", channel_name, date);
+    }
+    match first_line.char_indices().nth(60) {
+        Some((end, _)) => first_line[..end].to_string(),
+        None => first_line.to_string(),
+    }
+}
+
+/// Convert an RFC 3339 timestamp ("2024-01-15T10:00:00.123+00:00") to RFC 2822.
+fn timestamp_to_rfc2822(ts: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(ts)
+        .map(|dt| dt.to_rfc2822())
+        .unwrap_or_else(|_| ts.to_string())
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(id: &str, reply_to: Option<&str>, msg_type: &str) -> DiscordMessage {
+        DiscordMessage {
+            id: id.into(),
+            msg_type: msg_type.into(),
+            timestamp: "2024-01-15T10:00:00+00:00".into(),
+            content: format!("message {}", id),
+            author: DiscordUser { id: "1".into(), name: "alice".into(), nickname: None },
+            attachments: vec![],
+            mentions: vec![],
+            reference: reply_to.map(|r| DiscordReference { message_id: Some(r.into()) }),
+        }
+    }
+
+    #[test]
+    fn test_group_reply_chains() {
+        let msgs = [
+            msg("1", None, "Default"),
+            msg("2", Some("1"), "Reply"),
+            msg("3", None, "Default"),
+            msg("4", Some("2"), "Reply"),
+            msg("5", Some("999"), "Reply"), // parent not in export
+        ];
+        let refs: Vec<&DiscordMessage> = msgs.iter().collect();
+        let threads = group_into_threads(&refs);
+        let shape: Vec<(&str, usize)> = threads.iter().map(|(k, m)| (k.as_str(), m.len())).collect();
+        assert_eq!(shape, vec![("1", 3), ("3", 1), ("5", 1)]);
+    }
+
+    #[test]
+    fn test_reference_cycle_terminates() {
+        let msgs = [msg("1", Some("2"), "Reply"), msg("2", Some("1"), "Reply")];
+        let refs: Vec<&DiscordMessage> = msgs.iter().collect();
+        assert!(!group_into_threads(&refs).is_empty());
+    }
+
+    #[test]
+    fn test_system_types() {
+        assert!(is_chat_message(&msg("1", None, "Default")));
+        assert!(is_chat_message(&msg("1", None, "Reply")));
+        assert!(!is_chat_message(&msg("1", None, "GuildMemberJoin")));
+        assert!(!is_chat_message(&msg("1", None, "ThreadCreated")));
+    }
+
+    #[test]
+    fn test_resolve_content() {
+        let mut names = HashMap::new();
+        names.insert("42".to_string(), "Bob".to_string());
+        assert_eq!(
+            resolve_content("hi <@42> and <@!7> <:party:123>", &names),
+            "hi @Bob and @7 :party:"
+        );
+    }
+
+    #[test]
+    fn test_display_name_prefers_nickname() {
+        let user = DiscordUser { id: "1".into(), name: "alice".into(), nickname: Some("Alice".into()) };
+        assert_eq!(display_name(&user), "Alice");
+        let user = DiscordUser { id: "1".into(), name: "alice".into(), nickname: Some(String::new()) };
+        assert_eq!(display_name(&user), "alice");
+    }
+
+    #[test]
+    fn test_derive_subject() {
+        assert_eq!(derive_subject("Hello\nmore", "general", "2024-01-15T10:00:00+00:00"), "Hello");
+        assert_eq!(derive_subject("", "general", "2024-01-15T10:00:00+00:00"), "#general -- 2024-01-15");
+        assert_eq!(derive_subject(&"é".repeat(100), "general", "").chars().count(), 60);
+    }
+
+    #[test]
+    fn test_timestamp_to_rfc2822() {
+        assert_eq!(
+            timestamp_to_rfc2822("2024-01-15T10:00:00.123+00:00"),
+            "Mon, 15 Jan 2024 10:00:00 +0000"
+        );
+    }
+}
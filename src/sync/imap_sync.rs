@@ -12,6 +12,7 @@ use std::path::{Path, PathBuf};
 
 use super::markdown::{parse_thread_markdown, thread_to_markdown};
 use super::types::{AccountSyncState, LabelState, Message, SyncState, Thread};
+use crate::cal::invite;
 use crate::config::corky_config;
 use crate::resolve;
 use crate::util::{slugify, thread_key_from_subject};
@@ -22,6 +23,9 @@ static THREAD_ID_RE: Lazy<Regex> =
 /// Extract text/plain body from a parsed email.
 fn extract_body(parsed: &mailparse::ParsedMail) -> String {
     if parsed.subparts.is_empty() {
+        if is_calendar(parsed) {
+            return String::new();
+        }
         if let Ok(body) = parsed.get_body() {
             return body;
         }
@@ -48,6 +52,91 @@ fn extract_body(parsed: &mailparse::ParsedMail) -> String {
     String::new()
 }
 
+/// Whether a part is an iCalendar object (inline invite or .ics attachment).
+fn is_calendar(part: &mailparse::ParsedMail) -> bool {
+    matches!(part.ctype.mimetype.as_str(), "text/calendar" | "application/ics")
+}
+
+/// Every iCalendar part of a parsed email, in order.
+fn extract_calendars(parsed: &mailparse::ParsedMail, out: &mut Vec<String>) {
+    if parsed.subparts.is_empty() {
+        if is_calendar(parsed) {
+            out.extend(parsed.get_body().ok());
+        }
+        return;
+    }
+    for part in &parsed.subparts {
+        extract_calendars(part, out);
+    }
+}
+
+/// Filenames of the parts sent as attachments, skipping iCalendar parts
+/// (those are rendered as invitation blocks instead).
+fn extract_attachments(parsed: &mailparse::ParsedMail, out: &mut Vec<String>) {
+    if parsed.subparts.is_empty() {
+        let disposition = parsed.get_content_disposition();
+        if matches!(disposition.disposition, mailparse::DispositionType::Attachment) && !is_calendar(parsed) {
+            let name = disposition
+                .params
+                .get("filename")
+                .or_else(|| parsed.ctype.params.get("name"))
+                .cloned()
+                .unwrap_or_else(|| parsed.ctype.mimetype.clone());
+            out.push(name);
+        }
+        return;
+    }
+    for part in &parsed.subparts {
+        extract_attachments(part, out);
+    }
+}
+
+/// Text body with a readable block appended for each meeting invitation and
+/// one `[Attachment: NAME]` line per attachment, as the chat importers write.
+fn render_body(parsed: &mailparse::ParsedMail) -> String {
+    let mut calendars = Vec::new();
+    extract_calendars(parsed, &mut calendars);
+    let body = invite::append_blocks(extract_body(parsed), &calendars);
+    let mut attachments = Vec::new();
+    extract_attachments(parsed, &mut attachments);
+    if attachments.is_empty() {
+        return body;
+    }
+    let lines: Vec<String> = attachments.iter().map(|name| format!("[Attachment: {}]", name)).collect();
+    let body = body.trim_end();
+    if body.is_empty() {
+        lines.join("\n")
+    } else {
+        format!("{}\n\n{}", body, lines.join("\n"))
+    }
+}
+
+/// First value of header `name` (case-insensitive), decoded.
+pub fn header_value(parsed: &mailparse::ParsedMail, name: &str) -> Option<String> {
+    parsed
+        .headers
+        .iter()
+        .find(|h| h.get_key_ref().eq_ignore_ascii_case(name))
+        .map(|h| h.get_value())
+}
+
+/// Build a [`Message`] from a parsed RFC822 email, threaded by subject.
+pub fn message_from_mail(parsed: &mailparse::ParsedMail, id: &str) -> Message {
+    let subject = header_value(parsed, "Subject").unwrap_or_else(|| "(no subject)".to_string());
+    let thread_key = thread_key_from_subject(&subject);
+    Message {
+        id: id.to_string(),
+        thread_id: thread_key,
+        from: header_value(parsed, "From").unwrap_or_default(),
+        to: header_value(parsed, "To").unwrap_or_default(),
+        cc: header_value(parsed, "Cc").unwrap_or_default(),
+        date: header_value(parsed, "Date").unwrap_or_default(),
+        subject,
+        body: render_body(parsed),
+        raw: String::new(),
+    }
+}
+
 /// Parse an RFC 2822 date string, falling back to epoch on failure.
 pub fn parse_msg_date(date_str: &str) -> DateTime<Utc> {
     DateTime::parse_from_rfc2822(date_str)
@@ -61,7 +150,7 @@ pub fn parse_msg_date(date_str: &str) -> DateTime<Utc> {
 
 /// Set file mtime to the parsed date.
 #[allow(unused_variables)]
-fn set_mtime(path: &Path, date_str: &str) -> Result<()> {
+pub(crate) fn set_mtime(path: &Path, date_str: &str) -> Result<()> {
     let dt = parse_msg_date(date_str);
     if dt.year() <= 1970 {
         return Ok(());
@@ -120,6 +209,15 @@ fn unique_slug(out_dir: &Path, slug: &str) -> String {
     format!("{}-{}", slug, n)
 }
 
+/// Result of merging one message into a thread file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeOutcome {
+    /// Message appended; path of the written file
+    Written(PathBuf),
+    /// Same (from, date) already in the thread; labels/accounts still updated
+    Duplicate(Option<PathBuf>),
+}
+
 /// Merge a single message into its thread file on disk.
 ///
 /// Returns the path of the written file, or None if only metadata updated.
@@ -130,6 +228,22 @@ pub fn merge_message_to_file(
     message: &Message,
     thread_key: &str,
 ) -> Result<Option<PathBuf>> {
+    Ok(
+        match merge_message(out_dir, label_name, account_name, message, thread_key)? {
+            MergeOutcome::Written(path) => Some(path),
+            MergeOutcome::Duplicate(path) => path,
+        },
+    )
+}
+
+/// Like [`merge_message_to_file`], but reports whether the message was a duplicate.
+pub fn merge_message(
+    out_dir: &Path,
+    label_name: &str,
+    account_name: &str,
+    message: &Message,
+    thread_key: &str,
+) -> Result<MergeOutcome> {
     std::fs::create_dir_all(out_dir)?;
 
     let existing_file = find_thread_file(out_dir, thread_key);
@@ -157,18 +271,22 @@ pub fn merge_message_to_file(
     }
 
     // Deduplicate by (from, date)
-    let seen: HashSet<(&str, &str)> = thread
+    if let Some(existing) = thread
         .messages
-        .iter()
-        .map(|m| (m.from.as_str(), m.date.as_str()))
-        .collect();
-    if seen.contains(&(message.from.as_str(), message.date.as_str())) {
+        .iter_mut()
+        .find(|m| m.from == message.from && m.date == message.date)
+    {
+        // Backfill the raw reference for messages synced before keep_raw
+        if existing.raw.is_empty() {
+            existing.raw = message.raw.clone();
+        }
         // Still update labels/accounts even if message is a dupe
         if let Some(ref ef) = existing_file {
             std::fs::write(ef, thread_to_markdown(&thread))?;
             let _ = set_mtime(ef, &thread.last_date);
+            crate::search::index::note_changed(ef);
         }
-        return Ok(existing_file);
+        return Ok(MergeOutcome::Duplicate(existing_file));
     }
 
     thread.messages.push(message.clone());
@@ -188,12 +306,13 @@ pub fn merge_message_to_file(
 
     std::fs::write(&file_path, thread_to_markdown(&thread))?;
     let _ = set_mtime(&file_path, &thread.last_date);
+    crate::search::index::note_changed(&file_path);
 
     println!(
         "  Wrote: {}",
         file_path.file_name().unwrap_or_default().to_string_lossy()
     );
-    Ok(Some(file_path))
+    Ok(MergeOutcome::Written(file_path))
 }
 
 /// Build label→output_dirs map from .corky.toml [routing].
@@ -296,6 +415,7 @@ pub fn sync_account(
         .or_default();
 
     let routes = build_label_routes(account_name);
+    let raw_store = super::raw_store::configured_store();
 
     // Merge shared labels into sync set (preserving order, no dupes)
     let mut all_labels: Vec<String> = Vec::new();
@@ -333,6 +453,7 @@ pub fn sync_account(
             full,
             sync_days,
             &out_dirs,
+            raw_store.as_deref(),
             &mut touched,
         )?;
     }
@@ -354,6 +475,7 @@ fn sync_label(
     full: bool,
     sync_days: u32,
     out_dirs: &[PathBuf],
+    raw_store: Option<&Path>,
     touched: &mut Option<&mut HashSet<PathBuf>>,
 ) -> Result<()> {
     println!("Syncing label: {}", label_name);
@@ -431,54 +553,11 @@ fn sync_label(
             }
         };
 
-        let subject = parsed
-            .headers
-            .iter()
-            .find(|h| h.get_key_ref().eq_ignore_ascii_case("Subject"))
-            .map(|h| h.get_value())
-            .unwrap_or_else(|| "(no subject)".to_string());
-
-        let from = parsed
-            .headers
-            .iter()
-            .find(|h| h.get_key_ref().eq_ignore_ascii_case("From"))
-            .map(|h| h.get_value())
-            .unwrap_or_default();
-
-        let to = parsed
-            .headers
-            .iter()
-            .find(|h| h.get_key_ref().eq_ignore_ascii_case("To"))
-            .map(|h| h.get_value())
-            .unwrap_or_default();
-
-        let cc = parsed
-            .headers
-            .iter()
-            .find(|h| h.get_key_ref().eq_ignore_ascii_case("Cc"))
-            .map(|h| h.get_value())
-            .unwrap_or_default();
-
-        let date = parsed
-            .headers
-            .iter()
-            .find(|h| h.get_key_ref().eq_ignore_ascii_case("Date"))
-            .map(|h| h.get_value())
-            .unwrap_or_default();
-
-        let thread_key = thread_key_from_subject(&subject);
-        let body = extract_body(&parsed);
-
-        let message = Message {
-            id: uid.to_string(),
-            thread_id: thread_key.clone(),
-            from,
-            to,
-            cc,
-            date,
-            subject,
-            body,
-        };
+        let mut message = message_from_mail(&parsed, &uid.to_string());
+        if let Some(store) = raw_store {
+            message.raw = super::raw_store::put(store, body_raw)?;
+        }
+        let thread_key = message.thread_id.clone();
 
         for out_dir in out_dirs {
             let file_path =
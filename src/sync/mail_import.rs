@@ -0,0 +1,333 @@
+//! mbox and Maildir archives → corky conversations.
+//!
+//! Messages are parsed with `mailparse` and merged one at a time with
+//! `merge_message()`, so memory use is bounded by the largest single message:
+//! mbox files are read line by line, Maildir files one file at a time.
+//!
+//! Threading follows `In-Reply-To`/`References` to messages seen earlier in the
+//! same import, falling back to the subject key IMAP sync uses, so imported
+//! mail joins threads that were already synced.
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use super::imap_sync::{header_value, merge_message, message_from_mail, MergeOutcome};
+use super::raw_store;
+
+// ---------------------------------------------------------------------------
+// mbox reader
+// ---------------------------------------------------------------------------
+
+/// Streams raw messages out of an mbox file.
+///
+/// A message starts at a `From ` line at the beginning of the file or after a
+/// blank line. `>From ` quoting (mboxrd) is undone on body lines.
+pub struct MboxReader<R: BufRead> {
+    reader: R,
+    /// The `From ` separator that starts the next message, once seen
+    pending_separator: bool,
+    done: bool,
+}
+
+impl<R: BufRead> MboxReader<R> {
+    pub fn new(reader: R) -> Self {
+        MboxReader {
+            reader,
+            pending_separator: false,
+            done: false,
+        }
+    }
+}
+
+fn is_separator(line: &[u8]) -> bool {
+    line.starts_with(b"From ")
+}
+
+/// Undo mboxrd quoting: `>From `, `>>From ` … lose one `>`.
+fn unquote(line: &[u8]) -> &[u8] {
+    let depth = line.iter().take_while(|&&b| b == b'>').count();
+    if depth > 0 && line[depth..].starts_with(b"From ") {
+        &line[1..]
+    } else {
+        line
+    }
+}
+
+impl<R: BufRead> Iterator for MboxReader<R> {
+    type Item = std::io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut message = Vec::new();
+        let mut started = self.pending_separator;
+        let mut prev_blank = true;
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match self.reader.read_until(b'\n', &mut line) {
+                Err(e) => return Some(Err(e)),
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(_) => {}
+            }
+            if is_separator(&line) && prev_blank {
+                if started {
+                    self.pending_separator = true;
+                    break;
+                }
+                started = true;
+                continue;
+            }
+            if !started {
+                // Junk before the first separator
+                continue;
+            }
+            let content = line.strip_suffix(b"\n").unwrap_or(&line);
+            let content = content.strip_suffix(b"\r").unwrap_or(content);
+            prev_blank = content.is_empty();
+            message.extend_from_slice(unquote(&line));
+        }
+        if !started {
+            return None;
+        }
+        // Drop the blank line that precedes the next separator
+        while message.ends_with(b"\n\n") || message.ends_with(b"\r\n\r\n") {
+            let cut = if message.ends_with(b"\r\n") { 2 } else { 1 };
+            message.truncate(message.len() - cut);
+        }
+        Some(Ok(message))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Maildir
+// ---------------------------------------------------------------------------
+
+/// Message files in a Maildir (`cur/` and `new/`) and its Maildir++
+/// subfolders (`.Folder/`), sorted by folder then file name.
+pub fn maildir_files(path: &Path) -> Result<Vec<PathBuf>> {
+    let is_maildir = |p: &Path| p.join("cur").is_dir() || p.join("new").is_dir();
+    if !is_maildir(path) {
+        anyhow::bail!(
+            "{} is not a Maildir (expected cur/ or new/ subdirectories)",
+            path.display()
+        );
+    }
+
+    let mut folders = vec![path.to_path_buf()];
+    let mut subfolders: Vec<PathBuf> = std::fs::read_dir(path)?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.') && n.len() > 1)
+                && is_maildir(p)
+        })
+        .collect();
+    subfolders.sort();
+    folders.extend(subfolders);
+
+    let mut files = Vec::new();
+    for folder in folders {
+        for sub in ["cur", "new"] {
+            let Ok(entries) = std::fs::read_dir(folder.join(sub)) else {
+                continue;
+            };
+            let mut names: Vec<PathBuf> = entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .collect();
+            names.sort();
+            files.extend(names);
+        }
+    }
+    Ok(files)
+}
+
+// ---------------------------------------------------------------------------
+// Import logic
+// ---------------------------------------------------------------------------
+
+/// Counts reported at the end of an import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportStats {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub failed: usize,
+}
+
+/// Message-ID without angle brackets or surrounding whitespace.
+fn clean_id(raw: &str) -> String {
+    raw.trim().trim_start_matches('<').trim_end_matches('>').to_string()
+}
+
+/// Merges raw messages into an output directory, tracking threads and dupes.
+pub struct Importer<'a> {
+    out_dir: &'a Path,
+    label: &'a str,
+    account: &'a str,
+    /// Message-IDs already imported in this run
+    seen: HashSet<String>,
+    /// Message-ID → thread key, for `In-Reply-To`/`References` threading
+    threads: HashMap<String, String>,
+    /// Raw store to keep each message's source in (`[sync] keep_raw`)
+    pub raw_store: Option<PathBuf>,
+    pub stats: ImportStats,
+}
+
+impl<'a> Importer<'a> {
+    pub fn new(out_dir: &'a Path, label: &'a str, account: &'a str) -> Self {
+        Importer {
+            out_dir,
+            label,
+            account,
+            seen: HashSet::new(),
+            threads: HashMap::new(),
+            raw_store: None,
+            stats: ImportStats::default(),
+        }
+    }
+
+    /// Parse and merge one raw RFC822 message. `source` names it in warnings.
+    pub fn import(&mut self, raw: &[u8], source: &str) -> Result<()> {
+        let parsed = match mailparse::parse_mail(raw) {
+            Ok(p) => p,
+            Err(e) => {
+                eprintln!("  Warning: failed to parse {}: {}", source, e);
+                self.stats.failed += 1;
+                return Ok(());
+            }
+        };
+
+        let message_id = header_value(&parsed, "Message-ID")
+            .map(|v| clean_id(&v))
+            .filter(|v| !v.is_empty());
+        if let Some(ref id) = message_id {
+            if !self.seen.insert(id.clone()) {
+                self.stats.duplicates += 1;
+                return Ok(());
+            }
+        }
+
+        let mut message = message_from_mail(&parsed, message_id.as_deref().unwrap_or(source));
+        if message.from.is_empty() && message.date.is_empty() && message.body.trim().is_empty() {
+            self.stats.failed += 1;
+            eprintln!("  Warning: {} has no From, Date or body; skipped", source);
+            return Ok(());
+        }
+
+        // Prefer the thread of a message this one replies to
+        let parents = header_value(&parsed, "In-Reply-To")
+            .into_iter()
+            .chain(header_value(&parsed, "References"))
+            .flat_map(|v| v.split_whitespace().map(clean_id).collect::<Vec<_>>());
+        if let Some(key) = parents.filter_map(|id| self.threads.get(&id)).next() {
+            message.thread_id = key.clone();
+        }
+        if let Some(id) = message_id {
+            self.threads.insert(id, message.thread_id.clone());
+        }
+
+        if let Some(ref store) = self.raw_store {
+            message.raw = raw_store::put(store, raw)?;
+        }
+
+        let key = message.thread_id.clone();
+        match merge_message(self.out_dir, self.label, self.account, &message, &key)? {
+            MergeOutcome::Written(_) => self.stats.imported += 1,
+            MergeOutcome::Duplicate(_) => self.stats.duplicates += 1,
+        }
+        Ok(())
+    }
+}
+
+fn report(kind: &str, stats: &ImportStats) {
+    println!(
+        "{} import complete: {} message(s) imported, {} duplicate(s) skipped{}.",
+        kind,
+        stats.imported,
+        stats.duplicates,
+        if stats.failed > 0 {
+            format!(", {} unparseable", stats.failed)
+        } else {
+            String::new()
+        }
+    );
+}
+
+/// Import every message in an mbox file.
+pub fn run_mbox(path: &Path, label: &str, out_dir: &Path, account: &str) -> Result<ImportStats> {
+    println!("mbox import: {}", path.display());
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+
+    let mut importer = Importer::new(out_dir, label, account);
+    importer.raw_store = raw_store::configured_store();
+    for (n, raw) in MboxReader::new(BufReader::new(file)).enumerate() {
+        let raw = raw.with_context(|| format!("Failed to read {}", path.display()))?;
+        importer.import(&raw, &format!("mbox:{}", n + 1))?;
+    }
+    report("mbox", &importer.stats);
+    Ok(importer.stats)
+}
+
+/// Import every message in a Maildir (including Maildir++ subfolders).
+pub fn run_maildir(path: &Path, label: &str, out_dir: &Path, account: &str) -> Result<ImportStats> {
+    println!("Maildir import: {}", path.display());
+    let files = maildir_files(path)?;
+
+    let mut importer = Importer::new(out_dir, label, account);
+    importer.raw_store = raw_store::configured_store();
+    for file in &files {
+        let raw = std::fs::read(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        importer.import(&raw, &format!("maildir:{}", name))?;
+    }
+    report("Maildir", &importer.stats);
+    Ok(importer.stats)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(mbox: &str) -> Vec<String> {
+        MboxReader::new(mbox.as_bytes())
+            .map(|m| String::from_utf8(m.unwrap()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn mbox_splits_on_separator_after_blank_line() {
+        let mbox = "From a@x Mon Jan  1 00:00:00 2024\nSubject: One\n\nBody one\nFrom here on, not a separator\n\nFrom b@x Mon Jan  1 00:00:00 2024\nSubject: Two\n\nBody two\n";
+        let msgs = split(mbox);
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(msgs[0], "Subject: One\n\nBody one\nFrom here on, not a separator\n");
+        assert_eq!(msgs[1], "Subject: Two\n\nBody two\n");
+    }
+
+    #[test]
+    fn mbox_unquotes_from_lines() {
+        let msgs = split("From a@x\nSubject: Q\n\n>From the start\n>>From deeper\n> From quoted reply\n");
+        assert_eq!(msgs[0], "Subject: Q\n\nFrom the start\n>From deeper\n> From quoted reply\n");
+    }
+
+    #[test]
+    fn mbox_empty_and_junk() {
+        assert!(split("").is_empty());
+        assert!(split("not an mbox\n").is_empty());
+    }
+}
@@ -1,15 +1,12 @@
 //! manifest.toml generation from conversation files + .corky.toml contacts.
 
 use anyhow::Result;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::collections::BTreeMap;
 use std::path::Path;
 
 use super::markdown::parse_thread_markdown;
 use crate::config::contact;
-
-static EMAIL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([^>]+)>").unwrap());
+use crate::contact::identity::ContactIndex;
 
 /// Generate manifest.toml from conversation files + .corky.toml contacts.
 pub fn generate_manifest(conversations_dir: &Path) -> Result<()> {
@@ -18,14 +15,7 @@ pub fn generate_manifest(conversations_dir: &Path) -> Result<()> {
     }
 
     let contacts = contact::load_contacts(None).unwrap_or_default();
-
-    // Build email→contact-name lookup
-    let mut email_to_contact: BTreeMap<String, String> = BTreeMap::new();
-    for (cname, c) in &contacts {
-        for addr in &c.emails {
-            email_to_contact.insert(addr.to_lowercase(), cname.clone());
-        }
-    }
+    let index = ContactIndex::new(&contacts);
 
     let mut threads: BTreeMap<String, toml::Value> = BTreeMap::new();
 
@@ -49,20 +39,8 @@ pub fn generate_manifest(conversations_dir: &Path) -> Result<()> {
             None => continue,
         };
 
-        // Match contacts by email addresses in from, to, and cc
-        let mut thread_contacts: Vec<String> = Vec::new();
-        for msg in &thread.messages {
-            for field in [&msg.from, &msg.to, &msg.cc] {
-                for cap in EMAIL_RE.captures_iter(field) {
-                    let addr = cap[1].to_lowercase();
-                    if let Some(cname) = email_to_contact.get(&addr) {
-                        if !thread_contacts.contains(cname) {
-                            thread_contacts.push(cname.clone());
-                        }
-                    }
-                }
-            }
-        }
+        // Match contacts by email, phone number and chat handle
+        let thread_contacts = index.thread_contacts(&thread);
 
         let slug = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
         let mut entry_map = toml::map::Map::new();
@@ -3,7 +3,7 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use super::types::{Message, Thread};
+use super::types::{Message, Thread, ThreadState, ThreadStatus};
 use crate::util::thread_key_from_subject;
 
 static META_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^\*\*(.+?)\*\*:\s*(.+)$").unwrap());
@@ -21,8 +21,9 @@ pub fn thread_to_markdown(thread: &Thread) -> String {
         format!("**Accounts**: {}", accounts_str),
         format!("**Thread ID**: {}", thread.id),
         format!("**Last updated**: {}", thread.last_date),
-        String::new(),
     ];
+    push_state(&mut lines, &thread.state);
+    lines.push(String::new());
     for msg in &thread.messages {
         lines.push("---".to_string());
         lines.push(String::new());
@@ -34,7 +35,10 @@ pub fn thread_to_markdown(thread: &Thread) -> String {
         if !msg.cc.is_empty() {
             lines.push(format!("**CC**: {}", msg.cc));
         }
-        if !msg.to.is_empty() || !msg.cc.is_empty() {
+        if !msg.raw.is_empty() {
+            lines.push(format!("**Raw**: {}", msg.raw));
+        }
+        if !msg.to.is_empty() || !msg.cc.is_empty() || !msg.raw.is_empty() {
             lines.push(String::new());
         }
         lines.push(msg.body.trim().to_string());
@@ -43,6 +47,42 @@ pub fn thread_to_markdown(thread: &Thread) -> String {
     lines.join("\n")
 }
 
+/// `**State**` and its companion lines; nothing for open threads.
+fn push_state(lines: &mut Vec<String>, state: &ThreadState) {
+    let status = match state.status {
+        ThreadStatus::Open => return,
+        ThreadStatus::Done => "done",
+        ThreadStatus::Snoozed => "snoozed",
+        ThreadStatus::Waiting => "waiting",
+    };
+    lines.push(format!("**State**: {}", status));
+    if !state.until.is_empty() {
+        lines.push(format!("**Snoozed until**: {}", state.until));
+    }
+    if !state.waiting_on.is_empty() {
+        lines.push(format!("**Waiting on**: {}", state.waiting_on));
+    }
+    if !state.since.is_empty() {
+        lines.push(format!("**State since**: {}", state.since));
+    }
+}
+
+fn parse_state(meta: &std::collections::HashMap<String, String>) -> ThreadState {
+    let status = match meta.get("State").map(|s| s.to_lowercase()).as_deref() {
+        Some("done") => ThreadStatus::Done,
+        Some("snoozed") => ThreadStatus::Snoozed,
+        Some("waiting") => ThreadStatus::Waiting,
+        _ => return ThreadState::default(),
+    };
+    let field = |key: &str| meta.get(key).cloned().unwrap_or_default();
+    ThreadState {
+        status,
+        until: field("Snoozed until"),
+        waiting_on: field("Waiting on"),
+        since: field("State since"),
+    }
+}
+
 /// Parse a conversation markdown file back into a Thread.
 pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
     let lines: Vec<&str> = text.split('\n').collect();
@@ -90,6 +130,7 @@ pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
     let mut current_date = String::new();
     let mut current_to = String::new();
     let mut current_cc = String::new();
+    let mut current_raw = String::new();
     let mut body_lines: Vec<&str> = Vec::new();
     let mut in_message = false;
     let mut in_msg_meta = false; // after header, before body
@@ -107,12 +148,14 @@ pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
                     date: current_date.clone(),
                     subject: subject.clone(),
                     body: body_lines.join("\n").trim().to_string(),
+                    raw: current_raw.clone(),
                 });
             }
             current_from = cap[1].to_string();
             current_date = cap[2].to_string();
             current_to = String::new();
             current_cc = String::new();
+            current_raw = String::new();
             body_lines.clear();
             in_message = true;
             in_msg_meta = true;
@@ -122,6 +165,7 @@ pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
                     match cap[1].to_string().as_str() {
                         "To" => current_to = cap[2].trim().to_string(),
                         "CC" => current_cc = cap[2].trim().to_string(),
+                        "Raw" => current_raw = cap[2].trim().to_string(),
                         _ => {} // ignore other per-message metadata
                     }
                 } else if line.trim().is_empty() {
@@ -150,6 +194,7 @@ pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
             date: current_date,
             subject: subject.clone(),
             body: body_lines.join("\n").trim().to_string(),
+            raw: current_raw,
         });
     }
 
@@ -160,6 +205,7 @@ pub fn parse_thread_markdown(text: &str) -> Option<Thread> {
         accounts,
         messages,
         last_date,
+        state: parse_state(&meta),
     })
 }
 
@@ -183,8 +229,10 @@ mod tests {
                 date: "Mon, 10 Feb 2025 10:00:00 +0000".to_string(),
                 subject: "Hello World".to_string(),
                 body: "Hello there!".to_string(),
+                raw: String::new(),
             }],
             last_date: "Mon, 10 Feb 2025 10:00:00 +0000".to_string(),
+            ..Default::default()
         };
 
         let md = thread_to_markdown(&thread);
@@ -215,8 +263,10 @@ mod tests {
                 date: "Mon, 10 Feb 2025 10:00:00 +0000".to_string(),
                 subject: "Hello World".to_string(),
                 body: "Hello there!".to_string(),
+                raw: String::new(),
             }],
             last_date: "Mon, 10 Feb 2025 10:00:00 +0000".to_string(),
+            ..Default::default()
         };
 
         let md = thread_to_markdown(&thread);
@@ -241,10 +291,34 @@ mod tests {
         assert_eq!(parsed.messages[0].body, "Hello there!");
     }
 
+    #[test]
+    fn test_roundtrip_raw_reference() {
+        let md = "# Subject\n\n**Labels**: inbox\n**Thread ID**: test\n**Last updated**: Mon, 1 Jan 2024 00:00:00 +0000\n\n---\n\n## Alice <alice@example.com> \u{2014} Mon, 1 Jan 2024 00:00:00 +0000\n\n**Raw**: sha256:abc123\n\nHello there!\n";
+        let parsed = parse_thread_markdown(md).unwrap();
+        assert_eq!(parsed.messages[0].raw, "sha256:abc123");
+        assert_eq!(parsed.messages[0].body, "Hello there!");
+        assert!(thread_to_markdown(&parsed).contains("\n**Raw**: sha256:abc123\n\nHello there!\n"));
+    }
+
     #[test]
     fn test_parse_multi_label() {
         let md = "# Subject\n\n**Labels**: label1, label2\n**Thread ID**: test\n**Last updated**: Mon, 1 Jan 2024 00:00:00 +0000\n";
         let parsed = parse_thread_markdown(md).unwrap();
         assert_eq!(parsed.labels, vec!["label1", "label2"]);
     }
+
+    #[test]
+    fn test_roundtrip_thread_state() {
+        let md = "# Subject\n\n**Labels**: inbox\n**Thread ID**: test\n**Last updated**: Mon, 1 Jan 2024 00:00:00 +0000\n**State**: snoozed\n**Snoozed until**: 2024-02-01\n**State since**: Tue, 2 Jan 2024 09:00:00 +0000\n\n---\n\n## Alice <alice@example.com> \u{2014} Mon, 1 Jan 2024 00:00:00 +0000\n\nHello there!\n";
+        let parsed = parse_thread_markdown(md).unwrap();
+        assert_eq!(parsed.state.status, ThreadStatus::Snoozed);
+        assert_eq!(parsed.state.until, "2024-02-01");
+        assert_eq!(parsed.state.since, "Tue, 2 Jan 2024 09:00:00 +0000");
+        assert!(thread_to_markdown(&parsed).contains("**Last updated**: Mon, 1 Jan 2024 00:00:00 +0000\n**State**: snoozed\n**Snoozed until**: 2024-02-01\n**State since**: Tue, 2 Jan 2024 09:00:00 +0000\n\n---"));
+
+        let mut open = parsed.clone();
+        open.state = ThreadState::default();
+        assert!(!thread_to_markdown(&open).contains("**State"));
+        assert_eq!(parse_thread_markdown(&thread_to_markdown(&open)).unwrap().state, ThreadState::default());
+    }
 }
@@ -1,15 +1,22 @@
 //! IMAP email sync — fetch threads from IMAP and write to Markdown.
 
 pub mod auth;
+pub mod discord_import;
 pub mod folders;
 pub mod imap_sync;
+pub mod mail_import;
 pub mod manifest;
 pub mod markdown;
+pub mod raw_store;
+pub mod reprocess;
 pub mod routes;
+pub mod signal_import;
 pub mod slack_import;
+pub mod slack_sync;
 pub mod sms_import;
 pub mod telegram_import;
 pub mod types;
+pub mod whatsapp_import;
 
 use anyhow::Result;
 use std::collections::HashSet;
@@ -0,0 +1,122 @@
+//! Content-addressed store of raw RFC822 messages.
+//!
+//! Enabled with `[sync] keep_raw = true`. Each fetched or imported message is
+//! gzip-compressed into `raw/XX/HASH.eml.gz` (HASH = SHA-256 of the raw bytes,
+//! XX = its first two hex digits) and its key, `sha256:HASH`, is written to
+//! the message's `**Raw**:` line in the conversation file. Identical messages
+//! (e.g. the same mail under two labels) are stored once.
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::corky_config;
+use crate::resolve;
+
+/// Store directory name, under the data directory.
+pub const RAW_DIR: &str = "raw";
+
+/// Key prefix naming the hash algorithm.
+const KEY_PREFIX: &str = "sha256:";
+
+/// The raw store of the current data directory.
+pub fn store_dir() -> PathBuf {
+    resolve::data_dir().join(RAW_DIR)
+}
+
+/// The raw store if `[sync] keep_raw` is enabled, else None.
+pub fn configured_store() -> Option<PathBuf> {
+    corky_config::try_load_config(None)
+        .and_then(|c| c.sync)
+        .filter(|s| s.keep_raw)
+        .map(|_| store_dir())
+}
+
+/// Store key for raw message bytes.
+pub fn key_for(raw: &[u8]) -> String {
+    let digest = Sha256::digest(raw);
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}{}", KEY_PREFIX, hex)
+}
+
+/// Path of `key` in `store`, or None if the key is malformed.
+pub fn path_for(store: &Path, key: &str) -> Option<PathBuf> {
+    let hex = key.strip_prefix(KEY_PREFIX)?;
+    if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(store.join(&hex[..2]).join(format!("{}.eml.gz", hex)))
+}
+
+/// Store `raw` (no-op if already present) and return its key.
+pub fn put(store: &Path, raw: &[u8]) -> Result<String> {
+    let key = key_for(raw);
+    let path = path_for(store, &key).expect("key_for produces valid keys");
+    if path.exists() {
+        return Ok(key);
+    }
+    let dir = path.parent().expect("store paths have a parent");
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    // Write to a temp file and rename, so a crash never leaves a truncated entry
+    let tmp = dir.join(format!(".{}.tmp", std::process::id()));
+    let mut encoder = GzEncoder::new(std::fs::File::create(&tmp)?, Compression::default());
+    encoder.write_all(raw)?;
+    encoder.finish()?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(key)
+}
+
+/// Load and verify the raw message for `key`.
+pub fn get(store: &Path, key: &str) -> Result<Vec<u8>> {
+    let Some(path) = path_for(store, key) else {
+        bail!("Malformed raw key '{}'", key);
+    };
+    let file = std::fs::File::open(&path)
+        .with_context(|| format!("Raw message {} not found in {}", key, store.display()))?;
+    let mut raw = Vec::new();
+    GzDecoder::new(file)
+        .read_to_end(&mut raw)
+        .with_context(|| format!("Failed to decompress {}", path.display()))?;
+    if key_for(&raw) != key {
+        bail!("Raw message {} is corrupt (hash mismatch)", key);
+    }
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn put_get_roundtrip_and_dedup() {
+        let tmp = tempfile::tempdir().unwrap();
+        let raw = b"Subject: Hi\r\n\r\nHello\r\n";
+        let key = put(tmp.path(), raw).unwrap();
+        assert!(key.starts_with("sha256:"));
+        assert_eq!(put(tmp.path(), raw).unwrap(), key);
+        assert_eq!(get(tmp.path(), &key).unwrap(), raw);
+
+        let path = path_for(tmp.path(), &key).unwrap();
+        assert!(path.ends_with(format!("{}/{}.eml.gz", &key[7..9], &key[7..])));
+    }
+
+    #[test]
+    fn corrupt_and_malformed_keys() {
+        let tmp = tempfile::tempdir().unwrap();
+        let key = put(tmp.path(), b"one").unwrap();
+        let path = path_for(tmp.path(), &key).unwrap();
+        let mut enc = GzEncoder::new(std::fs::File::create(&path).unwrap(), Compression::default());
+        enc.write_all(b"two").unwrap();
+        enc.finish().unwrap();
+        assert!(get(tmp.path(), &key).unwrap_err().to_string().contains("corrupt"));
+
+        assert!(path_for(tmp.path(), "sha256:../../etc").is_none());
+        assert!(get(tmp.path(), "md5:abc").is_err());
+    }
+}
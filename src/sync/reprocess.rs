@@ -0,0 +1,122 @@
+//! Regenerate conversation Markdown from the raw message store (`corky reprocess`).
+//!
+//! Every message with a `**Raw**:` reference is re-parsed from its stored
+//! RFC822 source with the current parser, replacing its From/To/CC/Date and
+//! body. Thread metadata (subject, labels, accounts, thread ID) is kept, and
+//! messages without a raw copy are left as they are.
+
+use anyhow::{bail, Result};
+use std::path::{Path, PathBuf};
+
+use super::imap_sync::{message_from_mail, parse_msg_date, set_mtime};
+use super::markdown::{parse_thread_markdown, thread_to_markdown};
+use super::raw_store;
+use crate::mailbox::find_unanswered::{resolve_dirs, Scope};
+
+/// Counts reported at the end of a reprocess run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReprocessStats {
+    /// Thread files whose Markdown changed
+    pub rewritten: usize,
+    /// Messages re-parsed from raw
+    pub reparsed: usize,
+    /// Messages with no raw reference (left unchanged)
+    pub no_raw: usize,
+    /// Messages whose raw reference could not be loaded (left unchanged)
+    pub missing: usize,
+}
+
+/// Reprocess one conversation file. Returns true if its Markdown changed
+/// (and, unless `dry_run`, was rewritten).
+pub fn reprocess_file(path: &Path, store: &Path, dry_run: bool, stats: &mut ReprocessStats) -> Result<bool> {
+    let text = std::fs::read_to_string(path)?;
+    let Some(mut thread) = parse_thread_markdown(&text) else {
+        return Ok(false);
+    };
+
+    for msg in &mut thread.messages {
+        if msg.raw.is_empty() {
+            stats.no_raw += 1;
+            continue;
+        }
+        let parsed_raw = raw_store::get(store, &msg.raw).and_then(|raw| {
+            let parsed = mailparse::parse_mail(&raw)?;
+            Ok(message_from_mail(&parsed, &msg.id))
+        });
+        match parsed_raw {
+            Ok(fresh) => {
+                msg.from = fresh.from;
+                msg.to = fresh.to;
+                msg.cc = fresh.cc;
+                msg.date = fresh.date;
+                msg.body = fresh.body;
+                stats.reparsed += 1;
+            }
+            Err(e) => {
+                eprintln!("  Warning: {}: {}", path.display(), e);
+                stats.missing += 1;
+            }
+        }
+    }
+
+    thread.messages.sort_by_key(|m| parse_msg_date(&m.date));
+    if let Some(last) = thread.messages.last() {
+        thread.last_date = last.date.clone();
+    }
+
+    let markdown = thread_to_markdown(&thread);
+    if markdown == text {
+        return Ok(false);
+    }
+    if !dry_run {
+        std::fs::write(path, &markdown)?;
+        let _ = set_mtime(path, &thread.last_date);
+        crate::search::index::note_changed(path);
+    }
+    Ok(true)
+}
+
+/// corky reprocess [SCOPE] [--dry-run]
+pub fn run(scope: Option<&str>, dry_run: bool) -> Result<()> {
+    let store = raw_store::store_dir();
+    if !store.is_dir() {
+        bail!(
+            "No raw message store at {}.\nSet keep_raw = true under [sync] in .corky.toml, then run 'corky sync full'.",
+            store.display()
+        );
+    }
+
+    let mut stats = ReprocessStats::default();
+    for (_, dir) in resolve_dirs(&Scope::from_arg(scope))? {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "md"))
+            .collect();
+        files.sort();
+        for path in files {
+            if reprocess_file(&path, &store, dry_run, &mut stats)? {
+                stats.rewritten += 1;
+                println!(
+                    "  {}: {}",
+                    if dry_run { "Would rewrite" } else { "Rewrote" },
+                    path.display()
+                );
+            }
+        }
+    }
+
+    println!(
+        "Reprocess {}: {} message(s) re-parsed, {} thread(s) {}, {} without raw, {} missing from store.",
+        if dry_run { "dry run" } else { "complete" },
+        stats.reparsed,
+        stats.rewritten,
+        if dry_run { "would change" } else { "rewritten" },
+        stats.no_raw,
+        stats.missing
+    );
+    Ok(())
+}
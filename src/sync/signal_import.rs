@@ -0,0 +1,540 @@
+//! Signal exports → corky conversations.
+//!
+//! Two input formats are accepted:
+//!
+//! - **signal-cli** JSON lines (`signal-cli -o json receive > signal.jsonl`):
+//!   one `{"envelope": {...}}` object per line. Incoming `dataMessage`s and
+//!   the account's own `syncMessage.sentMessage`s are imported; receipts,
+//!   typing indicators and reactions are skipped.
+//! - **Signal Desktop** JSON: `{"conversations": [...], "messages": [...]}`,
+//!   with the rows of Desktop's `conversations` and `messages` tables.
+//!
+//! Signal has no channels or threads, so each conversation (1:1 or group)
+//! becomes one thread, keyed `signal:{number-or-uuid}` or
+//! `signal:group:{group_id}`. Replies keep their context as a quoted first
+//! line (`> Alice: original text`). Sender names come from the export's
+//! profile names; messages sent from the account itself are from "Me".
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::imap_sync::{merge_message, MergeOutcome};
+use super::types::Message;
+
+// ---------------------------------------------------------------------------
+// signal-cli JSON types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct CliLine {
+    pub envelope: Envelope,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Envelope {
+    #[serde(default)]
+    pub source_number: Option<String>,
+    #[serde(default)]
+    pub source_uuid: Option<String>,
+    #[serde(default)]
+    pub source_name: Option<String>,
+    #[serde(default)]
+    pub data_message: Option<DataMessage>,
+    #[serde(default)]
+    pub sync_message: Option<SyncMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncMessage {
+    #[serde(default)]
+    pub sent_message: Option<SentMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SentMessage {
+    #[serde(default)]
+    pub destination_number: Option<String>,
+    #[serde(default)]
+    pub destination_uuid: Option<String>,
+    #[serde(flatten)]
+    pub data: DataMessage,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataMessage {
+    #[serde(default)]
+    pub timestamp: i64,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub group_info: Option<GroupInfo>,
+    #[serde(default)]
+    pub quote: Option<CliQuote>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInfo {
+    pub group_id: String,
+    #[serde(default)]
+    pub group_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliQuote {
+    #[serde(default)]
+    pub author_number: Option<String>,
+    #[serde(default)]
+    pub author_uuid: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(default, alias = "fileName")]
+    pub filename: Option<String>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Signal Desktop JSON types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Deserialize)]
+pub struct DesktopExport {
+    pub conversations: Vec<DesktopConversation>,
+    pub messages: Vec<DesktopMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DesktopConversation {
+    pub id: String,
+    /// "private" or "group"
+    #[serde(default, rename = "type")]
+    pub conv_type: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub profile_full_name: Option<String>,
+    #[serde(default)]
+    pub profile_name: Option<String>,
+    #[serde(default)]
+    pub e164: Option<String>,
+    #[serde(default, alias = "uuid")]
+    pub service_id: Option<String>,
+    #[serde(default)]
+    pub group_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DesktopMessage {
+    #[serde(default)]
+    pub conversation_id: String,
+    /// "incoming", "outgoing", or a notice type ("group-v2-change", ...)
+    #[serde(default, rename = "type")]
+    pub msg_type: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default, rename = "sent_at")]
+    pub sent_at: i64,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default, alias = "sourceUuid")]
+    pub source_service_id: Option<String>,
+    #[serde(default)]
+    pub quote: Option<DesktopQuote>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DesktopQuote {
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default, alias = "authorUuid")]
+    pub author_aci: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+/// Counts reported at the end of an import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportStats {
+    pub imported: usize,
+    pub duplicates: usize,
+    /// Receipts, reactions, group changes and other non-chat entries
+    pub system: usize,
+}
+
+/// A message normalized from either format, before merging.
+#[derive(Debug, Clone, PartialEq)]
+struct SignalMessage {
+    thread_key: String,
+    subject: String,
+    from: String,
+    timestamp_ms: i64,
+    body: String,
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Import a Signal export into corky conversations.
+///
+/// - `path`: signal-cli JSON lines, or a Signal Desktop JSON export
+/// - `label`: label to assign to imported threads (default: `"signal"`)
+/// - `out_dir`: directory to write conversation markdown files
+/// - `account_name`: account name for metadata
+pub fn run(path: &Path, label: &str, out_dir: &Path, account_name: &str) -> Result<ImportStats> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut stats = ImportStats::default();
+    let messages = match serde_json::from_str::<DesktopExport>(&text) {
+        Ok(export) => {
+            println!("Signal Desktop import: {}", path.display());
+            from_desktop(&export, &mut stats)
+        }
+        Err(_) => {
+            println!("signal-cli import: {}", path.display());
+            from_cli_lines(&text, &mut stats)?
+        }
+    };
+    if messages.is_empty() && stats.system == 0 {
+        bail!("No Signal messages found in {}", path.display());
+    }
+
+    // Signal timestamps are in milliseconds but conversation dates have
+    // second resolution: keep same-second messages from one sender apart.
+    let mut seen: HashMap<(String, String, i64), i64> = HashMap::new();
+    for msg in &messages {
+        let second = msg.timestamp_ms.div_euclid(1000);
+        let repeat = seen
+            .entry((msg.thread_key.clone(), msg.from.clone(), second))
+            .or_insert(0);
+        let date = ms_to_rfc2822((second + *repeat) * 1000);
+        *repeat += 1;
+
+        let message = Message {
+            id: format!("{}:{}", msg.thread_key, msg.timestamp_ms),
+            thread_id: msg.thread_key.clone(),
+            from: msg.from.clone(),
+            to: String::new(),
+            cc: String::new(),
+            date,
+            subject: msg.subject.clone(),
+            body: msg.body.clone(),
+            raw: String::new(),
+        };
+        match merge_message(out_dir, label, account_name, &message, &msg.thread_key)? {
+            MergeOutcome::Written(_) => stats.imported += 1,
+            MergeOutcome::Duplicate(_) => stats.duplicates += 1,
+        }
+    }
+
+    println!(
+        "Signal import complete: {} message(s) imported, {} duplicate(s) skipped, {} system message(s) skipped.",
+        stats.imported, stats.duplicates, stats.system
+    );
+    Ok(stats)
+}
+
+// ---------------------------------------------------------------------------
+// Internal helpers
+// ---------------------------------------------------------------------------
+
+/// Normalize signal-cli envelopes. Names are collected in a first pass so
+/// quotes and 1:1 subjects resolve even when the named message comes later.
+fn from_cli_lines(text: &str, stats: &mut ImportStats) -> Result<Vec<SignalMessage>> {
+    let mut envelopes = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let parsed: CliLine = serde_json::from_str(line)
+            .with_context(|| format!("Line {}: not signal-cli JSON or a Signal Desktop export", n + 1))?;
+        envelopes.push(parsed.envelope);
+    }
+
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut group_names: HashMap<String, String> = HashMap::new();
+    for env in &envelopes {
+        if let Some(name) = env.source_name.as_deref().filter(|n| !n.is_empty()) {
+            for id in [&env.source_number, &env.source_uuid].into_iter().flatten() {
+                names.insert(id.clone(), name.to_string());
+            }
+        }
+        let data = env
+            .data_message
+            .as_ref()
+            .or(env.sync_message.as_ref().and_then(|s| s.sent_message.as_ref()).map(|s| &s.data));
+        if let Some(group) = data.and_then(|d| d.group_info.as_ref()) {
+            if let Some(name) = group.group_name.as_deref().filter(|n| !n.is_empty()) {
+                group_names.insert(group.group_id.clone(), name.to_string());
+            }
+        }
+    }
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+    let mut messages = Vec::new();
+    for env in &envelopes {
+        let sent = env.sync_message.as_ref().and_then(|s| s.sent_message.as_ref());
+        let (data, from, peer) = match (&env.data_message, sent) {
+            (Some(data), _) => {
+                let source = env.source_number.as_ref().or(env.source_uuid.as_ref());
+                let Some(source) = source else {
+                    stats.system += 1;
+                    continue;
+                };
+                (data, name_of(source), source.clone())
+            }
+            (None, Some(sent)) => {
+                let peer = sent.destination_number.clone().or(sent.destination_uuid.clone());
+                (&sent.data, "Me".to_string(), peer.unwrap_or_default())
+            }
+            // Receipts, typing indicators, calls
+            (None, None) => {
+                stats.system += 1;
+                continue;
+            }
+        };
+
+        let quote = data.quote.as_ref().map(|q| {
+            let author = q.author_number.as_ref().or(q.author_uuid.as_ref());
+            (author.map(|a| name_of(a)).unwrap_or_default(), q.text.clone().unwrap_or_default())
+        });
+        let body = compose_body(data.message.as_deref(), &data.attachments, quote);
+        // Reactions, deletions and group updates carry no text
+        if body.is_empty() {
+            stats.system += 1;
+            continue;
+        }
+
+        let (thread_key, subject) = match &data.group_info {
+            Some(group) => (
+                format!("signal:group:{}", group.group_id),
+                group_names.get(&group.group_id).cloned().unwrap_or_else(|| "Signal group".to_string()),
+            ),
+            None if peer.is_empty() => {
+                stats.system += 1;
+                continue;
+            }
+            None => (format!("signal:{}", peer), name_of(&peer)),
+        };
+        messages.push(SignalMessage { thread_key, subject, from, timestamp_ms: data.timestamp, body });
+    }
+    messages.sort_by_key(|m| m.timestamp_ms);
+    Ok(messages)
+}
+
+/// Normalize a Signal Desktop export.
+fn from_desktop(export: &DesktopExport, stats: &mut ImportStats) -> Vec<SignalMessage> {
+    let conversations: HashMap<&str, &DesktopConversation> =
+        export.conversations.iter().map(|c| (c.id.as_str(), c)).collect();
+
+    // Phone number / service ID → contact name, from private conversations
+    let mut names: HashMap<String, String> = HashMap::new();
+    for conv in &export.conversations {
+        if conv.conv_type == "group" {
+            continue;
+        }
+        let name = conversation_name(conv);
+        for id in [&conv.e164, &conv.service_id].into_iter().flatten() {
+            names.insert(id.clone(), name.clone());
+        }
+    }
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+    let mut messages = Vec::new();
+    for msg in &export.messages {
+        let outgoing = match msg.msg_type.as_str() {
+            "outgoing" => true,
+            "incoming" => false,
+            _ => {
+                stats.system += 1;
+                continue;
+            }
+        };
+        let Some(conv) = conversations.get(msg.conversation_id.as_str()) else {
+            eprintln!("  Warning: message in unknown conversation '{}'; skipped", msg.conversation_id);
+            continue;
+        };
+        let from = if outgoing {
+            "Me".to_string()
+        } else {
+            let source = msg.source.as_ref().or(msg.source_service_id.as_ref());
+            match source {
+                Some(s) => name_of(s),
+                None => conversation_name(conv),
+            }
+        };
+        let quote = msg.quote.as_ref().map(|q| {
+            let author = q.author.as_ref().or(q.author_aci.as_ref());
+            (author.map(|a| name_of(a)).unwrap_or_default(), q.text.clone().unwrap_or_default())
+        });
+        let body = compose_body(msg.body.as_deref(), &msg.attachments, quote);
+        if body.is_empty() {
+            stats.system += 1;
+            continue;
+        }
+
+        let thread_key = match (&conv.group_id, &conv.e164, &conv.service_id) {
+            (Some(group), _, _) if conv.conv_type == "group" => format!("signal:group:{}", group),
+            (_, Some(number), _) => format!("signal:{}", number),
+            (_, _, Some(service_id)) => format!("signal:{}", service_id),
+            _ => format!("signal:{}", conv.id),
+        };
+        messages.push(SignalMessage {
+            thread_key,
+            subject: conversation_name(conv),
+            from,
+            timestamp_ms: msg.sent_at,
+            body,
+        });
+    }
+    messages.sort_by_key(|m| m.timestamp_ms);
+    messages
+}
+
+/// Display name of a Desktop conversation: saved name, then profile name,
+/// then number.
+fn conversation_name(conv: &DesktopConversation) -> String {
+    [&conv.name, &conv.profile_full_name, &conv.profile_name, &conv.e164, &conv.service_id]
+        .into_iter()
+        .flatten()
+        .find(|n| !n.is_empty())
+        .cloned()
+        .unwrap_or_else(|| if conv.conv_type == "group" { "Signal group".to_string() } else { conv.id.clone() })
+}
+
+/// Quote line, text, then one `[Attachment: NAME]` line per attachment.
+fn compose_body(text: Option<&str>, attachments: &[Attachment], quote: Option<(String, String)>) -> String {
+    let mut lines = Vec::new();
+    if let Some(text) = text.map(str::trim).filter(|t| !t.is_empty()) {
+        lines.push(text.to_string());
+    }
+    for attachment in attachments {
+        let name = attachment
+            .filename
+            .as_deref()
+            .or(attachment.content_type.as_deref())
+            .unwrap_or("file");
+        lines.push(format!("[Attachment: {}]", name));
+    }
+    if lines.is_empty() {
+        return String::new();
+    }
+    if let Some((author, quoted)) = quote {
+        let first = quoted.lines().next().unwrap_or("").trim();
+        let quote_line = match (author.is_empty(), first.is_empty()) {
+            (false, false) => format!("> {}: {}", author, first),
+            (false, true) => format!("> {}", author),
+            (true, _) => format!("> {}", first),
+        };
+        lines.insert(0, String::new());
+        lines.insert(0, quote_line);
+    }
+    lines.join("\n")
+}
+
+/// Convert epoch milliseconds to RFC 2822.
+fn ms_to_rfc2822(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .to_rfc2822()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLI: &str = r#"{"envelope":{"sourceNumber":"+15550001","sourceName":"Alice","timestamp":1705312800000,"dataMessage":{"timestamp":1705312800000,"message":"Lunch?","groupInfo":{"groupId":"g1==","groupName":"Team"}}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15559999","timestamp":1705312860000,"syncMessage":{"sentMessage":{"timestamp":1705312860000,"message":"Sure","groupInfo":{"groupId":"g1=="},"quote":{"authorNumber":"+15550001","text":"Lunch?"}}}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15550001","timestamp":1705312870000,"receiptMessage":{"isRead":true}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15550001","sourceName":"Alice","timestamp":1705312900000,"dataMessage":{"timestamp":1705312900000,"attachments":[{"contentType":"image/jpeg","filename":"menu.jpg"}]}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15559999","timestamp":1705313000000,"syncMessage":{"sentMessage":{"destinationNumber":"+15550001","timestamp":1705313000000,"message":"Hi Alice"}}},"account":"+15559999"}"#;
+
+    #[test]
+    fn test_cli_groups_quotes_and_receipts() {
+        let mut stats = ImportStats::default();
+        let msgs = from_cli_lines(CLI, &mut stats).unwrap();
+        assert_eq!(stats.system, 1);
+        assert_eq!(msgs.len(), 4);
+
+        assert_eq!(msgs[0].thread_key, "signal:group:g1==");
+        assert_eq!(msgs[0].subject, "Team");
+        assert_eq!(msgs[0].from, "Alice");
+
+        assert_eq!(msgs[1].from, "Me");
+        assert_eq!(msgs[1].subject, "Team");
+        assert_eq!(msgs[1].body, "> Alice: Lunch?\n\nSure");
+
+        assert_eq!(msgs[2].body, "[Attachment: menu.jpg]");
+
+        assert_eq!(msgs[3].thread_key, "signal:+15550001");
+        assert_eq!(msgs[3].subject, "Alice");
+    }
+
+    #[test]
+    fn test_desktop_names_and_notices() {
+        let json = r#"{
+            "conversations": [
+                {"id": "c1", "type": "private", "e164": "+15550001", "profileName": "Alice"},
+                {"id": "c2", "type": "group", "name": "Team", "groupId": "g2"}
+            ],
+            "messages": [
+                {"conversationId": "c2", "type": "incoming", "source": "+15550001", "body": "Standup?", "sent_at": 1705312800000},
+                {"conversationId": "c2", "type": "group-v2-change", "sent_at": 1705312810000},
+                {"conversationId": "c1", "type": "outgoing", "body": "On my way", "sent_at": 1705312900000,
+                 "quote": {"author": "+15550001", "text": "Where are you?"}}
+            ]
+        }"#;
+        let export: DesktopExport = serde_json::from_str(json).unwrap();
+        let mut stats = ImportStats::default();
+        let msgs = from_desktop(&export, &mut stats);
+        assert_eq!(stats.system, 1);
+        assert_eq!(msgs[0].thread_key, "signal:group:g2");
+        assert_eq!(msgs[0].subject, "Team");
+        assert_eq!(msgs[0].from, "Alice");
+        assert_eq!(msgs[1].thread_key, "signal:+15550001");
+        assert_eq!(msgs[1].subject, "Alice");
+        assert_eq!(msgs[1].body, "> Alice: Where are you?\n\nOn my way");
+    }
+
+    #[test]
+    fn test_compose_body() {
+        assert_eq!(compose_body(None, &[], None), "");
+        assert_eq!(
+            compose_body(None, &[], Some(("Alice".into(), "hi".into()))),
+            "",
+            "a quote alone (e.g. on a reaction) is not a message"
+        );
+        let attachment = Attachment { filename: None, content_type: Some("image/png".into()) };
+        assert_eq!(compose_body(Some(" ok "), &[attachment], None), "ok\n[Attachment: image/png]");
+    }
+
+    #[test]
+    fn test_ms_to_rfc2822() {
+        assert_eq!(ms_to_rfc2822(1705312800123), "Mon, 15 Jan 2024 10:00:00 +0000");
+    }
+}
@@ -10,6 +10,7 @@ use std::path::Path;
 
 use super::imap_sync::merge_message_to_file;
 use super::types::Message;
+use crate::config::owner;
 
 // ---------------------------------------------------------------------------
 // Slack export JSON types
@@ -113,6 +114,9 @@ pub fn run(zip_path: &Path, label: &str, out_dir: &Path, account_name: &str) ->
         channel_dirs.len()
     );
 
+    // Messages from [owner] handles.slack are filed as "Me"
+    let owner_id = owner::handle("slack");
+
     // 4. For each channel, parse date JSON files and group by thread
     for channel_name in &channel_dirs {
         let channel_id = channel_id_by_name
@@ -140,7 +144,11 @@ pub fn run(zip_path: &Path, label: &str, out_dir: &Path, account_name: &str) ->
             let label_name = format!("{}:{}", label, channel_name);
 
             for msg in thread_msgs {
-                let from = resolve_user_name(&users, msg.user.as_deref().unwrap_or("unknown"));
+                let from = if owner_id.is_some() && msg.user == owner_id {
+                    owner::ME.to_string()
+                } else {
+                    resolve_user_name(&users, msg.user.as_deref().unwrap_or("unknown"))
+                };
                 let date = ts_to_rfc2822(&msg.ts);
                 let body = resolve_mrkdwn(&msg.text, &users);
 
@@ -153,6 +161,7 @@ pub fn run(zip_path: &Path, label: &str, out_dir: &Path, account_name: &str) ->
                     date,
                     subject: subject.clone(),
                     body,
+                    raw: String::new(),
                 };
 
                 merge_message_to_file(out_dir, &label_name, account_name, &message, &thread_key)?;
@@ -170,14 +179,19 @@ pub fn run(zip_path: &Path, label: &str, out_dir: &Path, account_name: &str) ->
 
 /// Parse `users.json` from the archive into a user ID → display name map.
 fn parse_users(archive: &mut zip::ZipArchive<std::fs::File>) -> Result<HashMap<String, String>> {
-    let mut map = HashMap::new();
     let mut file = match archive.by_name("users.json") {
         Ok(f) => f,
-        Err(_) => return Ok(map), // no users.json — IDs won't resolve but import continues
+        Err(_) => return Ok(HashMap::new()), // no users.json — IDs won't resolve but import continues
     };
     let mut buf = String::new();
     file.read_to_string(&mut buf)?;
     let users: Vec<SlackUser> = serde_json::from_str(&buf)?;
+    Ok(user_display_names(users))
+}
+
+/// Build a user ID → display name map (display name > real name > handle).
+pub(crate) fn user_display_names(users: Vec<SlackUser>) -> HashMap<String, String> {
+    let mut map = HashMap::new();
     for u in users {
         let display = u
             .profile
@@ -189,7 +203,7 @@ fn parse_users(archive: &mut zip::ZipArchive<std::fs::File>) -> Result<HashMap<S
             .unwrap_or(u.name);
         map.insert(u.id, display);
     }
-    Ok(map)
+    map
 }
 
 /// Parse `channels.json` from the archive.
@@ -285,7 +299,7 @@ fn collect_channel_messages(
 }
 
 /// Group messages by thread_ts. Standalone messages (no thread_ts) use their own ts.
-fn group_into_threads(messages: &[SlackMessage]) -> Vec<(String, Vec<&SlackMessage>)> {
+pub(crate) fn group_into_threads(messages: &[SlackMessage]) -> Vec<(String, Vec<&SlackMessage>)> {
     let mut map: HashMap<String, Vec<&SlackMessage>> = HashMap::new();
     let mut order: Vec<String> = Vec::new();
 
@@ -312,7 +326,7 @@ fn group_into_threads(messages: &[SlackMessage]) -> Vec<(String, Vec<&SlackMessa
 
 /// Derive a subject from the parent message text.
 /// Falls back to `#channel_name -- date` if text is empty.
-fn derive_subject(parent_text: &str, channel_name: &str, thread_ts: &str) -> String {
+pub(crate) fn derive_subject(parent_text: &str, channel_name: &str, thread_ts: &str) -> String {
     let first_line = parent_text.lines().next().unwrap_or("").trim();
     if first_line.is_empty() {
         let date = ts_to_date(thread_ts);
@@ -330,7 +344,7 @@ fn derive_subject(parent_text: &str, channel_name: &str, thread_ts: &str) -> Str
 }
 
 /// Resolve a Slack user ID to a display name.
-fn resolve_user_name(users: &HashMap<String, String>, user_id: &str) -> String {
+pub(crate) fn resolve_user_name(users: &HashMap<String, String>, user_id: &str) -> String {
     users
         .get(user_id)
         .cloned()
@@ -338,7 +352,7 @@ fn resolve_user_name(users: &HashMap<String, String>, user_id: &str) -> String {
 }
 
 /// Convert a Slack `ts` (epoch seconds with microsecond fraction) to RFC 2822.
-fn ts_to_rfc2822(ts: &str) -> String {
+pub(crate) fn ts_to_rfc2822(ts: &str) -> String {
     let secs: f64 = ts.parse().unwrap_or(0.0);
     let dt = chrono::DateTime::from_timestamp(secs as i64, 0).unwrap_or_default();
     dt.to_rfc2822()
@@ -355,7 +369,7 @@ fn ts_to_date(ts: &str) -> String {
 ///
 /// Handles: `<@U1234>` → `@DisplayName`, `<#C1234|name>` → `#name`,
 /// `<url|text>` → `text (url)`, `<url>` → `url`.
-fn resolve_mrkdwn(text: &str, users: &HashMap<String, String>) -> String {
+pub(crate) fn resolve_mrkdwn(text: &str, users: &HashMap<String, String>) -> String {
     // Resolve user mentions
     let result = USER_MENTION_RE.replace_all(text, |caps: &regex::Captures| {
         let uid = &caps[1];
@@ -0,0 +1,355 @@
+//! Live Slack sync via the Web API (`corky sync slack`).
+//!
+//! Unlike `slack_import`, which reads a workspace-admin export ZIP once, this
+//! pulls `conversations.history` and `conversations.replies` with a user or
+//! bot token and only fetches what is new since the last run. Threads use the
+//! same `slack:{channel_id}:{thread_ts}` keys as the ZIP importer, so the two
+//! can be mixed.
+//!
+//! Cursors live in `SyncState.slack[account].channels[channel_id]`:
+//! `latest_ts` is the newest top-level message seen, and `threads` maps each
+//! open thread to its newest reply. Every top-level message starts out open,
+//! since replies can arrive after history has moved past it, and stays open
+//! (re-checked for replies on every run) until it has been quiet for
+//! `reply_window_days`.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::imap_sync::{merge_message, MergeOutcome};
+use super::slack_import::{
+    derive_subject, group_into_threads, resolve_mrkdwn, resolve_user_name, ts_to_rfc2822,
+    user_display_names, SlackMessage, SlackUser,
+};
+use super::types::{Message, SlackChannelState, SlackSyncState};
+use crate::config::corky_config::{self, SlackConfig};
+use crate::config::owner;
+
+/// Default Slack Web API base URL.
+const API_BASE: &str = "https://slack.com/api";
+
+/// Page size for paginated calls.
+const PAGE_LIMIT: &str = "200";
+
+/// Retries after HTTP 429 before giving up.
+const MAX_RETRIES: u32 = 5;
+
+/// A conversation from `conversations.list`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlackConversation {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub is_member: bool,
+    #[serde(default)]
+    pub is_im: bool,
+    /// Other party of a direct message
+    #[serde(default)]
+    pub user: Option<String>,
+}
+
+/// Counts reported at the end of a sync.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SlackSyncStats {
+    pub channels: usize,
+    pub imported: usize,
+    pub duplicates: usize,
+}
+
+/// Resolve the API token: inline > `token_cmd` > `CORKY_SLACK_TOKEN`.
+pub fn resolve_token(config: &SlackConfig) -> Result<String> {
+    crate::util::resolve_secret(
+        &config.token,
+        &config.token_cmd,
+        "[slack] has no token or token_cmd, and CORKY_SLACK_TOKEN is not set",
+    )
+    .or_else(|e| std::env::var("CORKY_SLACK_TOKEN").map_err(|_| e))
+}
+
+/// Call a Web API method, retrying on rate limits. Fails unless `"ok": true`.
+fn api_get(api_base: &str, token: &str, method: &str, params: &[(&str, &str)]) -> Result<serde_json::Value> {
+    let url = format!("{}/{}", api_base, method);
+    let mut retries = 0;
+    loop {
+        let mut req = ureq::get(&url).set("Authorization", &format!("Bearer {}", token));
+        for (key, value) in params {
+            req = req.query(key, value);
+        }
+        let body: serde_json::Value = match req.call() {
+            Ok(resp) => resp.into_json()?,
+            Err(ureq::Error::Status(429, resp)) if retries < MAX_RETRIES => {
+                let wait: u64 = resp
+                    .header("Retry-After")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(1);
+                eprintln!("  Slack rate limit on {}; retrying in {}s", method, wait);
+                std::thread::sleep(std::time::Duration::from_secs(wait));
+                retries += 1;
+                continue;
+            }
+            Err(ureq::Error::Status(status, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                bail!("Slack API error in {} (HTTP {}): {}", method, status, body);
+            }
+            Err(e) => bail!("Slack {} request failed: {}", method, e),
+        };
+        if body["ok"].as_bool() != Some(true) {
+            bail!(
+                "Slack API error in {}: {}",
+                method,
+                body["error"].as_str().unwrap_or("unknown error")
+            );
+        }
+        return Ok(body);
+    }
+}
+
+/// Call a cursor-paginated method and collect the `key` array of every page.
+fn paginate(
+    api_base: &str,
+    token: &str,
+    method: &str,
+    params: &[(&str, &str)],
+    key: &str,
+) -> Result<Vec<serde_json::Value>> {
+    let mut items = Vec::new();
+    let mut cursor = String::new();
+    loop {
+        let mut page_params = params.to_vec();
+        page_params.push(("limit", PAGE_LIMIT));
+        if !cursor.is_empty() {
+            page_params.push(("cursor", cursor.as_str()));
+        }
+        let body = api_get(api_base, token, method, &page_params)?;
+        if let Some(page) = body[key].as_array() {
+            items.extend(page.iter().cloned());
+        }
+        cursor = body["response_metadata"]["next_cursor"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string();
+        if cursor.is_empty() {
+            return Ok(items);
+        }
+    }
+}
+
+/// Fetch the workspace's user ID → display name map (`users.list`).
+pub fn list_users_at(api_base: &str, token: &str) -> Result<HashMap<String, String>> {
+    let users: Vec<SlackUser> = paginate(api_base, token, "users.list", &[], "members")?
+        .into_iter()
+        .filter_map(|v| serde_json::from_value(v).ok())
+        .collect();
+    Ok(user_display_names(users))
+}
+
+/// Fetch the conversations of the given types (`conversations.list`).
+pub fn list_conversations_at(api_base: &str, token: &str, types: &str) -> Result<Vec<SlackConversation>> {
+    let params = [("types", types), ("exclude_archived", "true")];
+    Ok(paginate(api_base, token, "conversations.list", &params, "channels")?
+        .into_iter()
+        .filter_map(|v| serde_json::from_value(v).ok())
+        .collect())
+}
+
+fn parse_messages(values: Vec<serde_json::Value>) -> Vec<SlackMessage> {
+    values
+        .into_iter()
+        .filter_map(|v| serde_json::from_value(v).ok())
+        .collect()
+}
+
+/// Slack `ts` as seconds, for comparisons.
+fn ts_secs(ts: &str) -> f64 {
+    ts.parse().unwrap_or(0.0)
+}
+
+/// Fetch what is new in one channel.
+///
+/// Returns the new messages (top-level and replies), sorted and without
+/// join/leave and other subtype entries, with the channel's advanced
+/// cursors. The caller stores the cursors once the messages are merged.
+fn fetch_channel(
+    api_base: &str,
+    token: &str,
+    channel_id: &str,
+    reply_window_days: u32,
+    state: &SlackChannelState,
+) -> Result<(Vec<SlackMessage>, SlackChannelState)> {
+    let mut params = vec![("channel", channel_id)];
+    if !state.latest_ts.is_empty() {
+        params.push(("oldest", state.latest_ts.as_str()));
+    }
+    let history = parse_messages(paginate(api_base, token, "conversations.history", &params, "messages")?);
+
+    let latest = history
+        .iter()
+        .map(|m| m.ts.as_str())
+        .max_by(|a, b| ts_secs(a).total_cmp(&ts_secs(b)))
+        .map(str::to_string);
+
+    // Every new top-level message may get replies later, after history has
+    // moved past it, so each one is tracked like an open thread
+    let mut threads = state.threads.clone();
+    for msg in &history {
+        let top_level = msg.thread_ts.as_deref().is_none_or(|t| t == msg.ts);
+        if top_level && msg.subtype.is_none() {
+            threads.entry(msg.ts.clone()).or_insert_with(|| msg.ts.clone());
+        }
+    }
+
+    let mut seen: HashSet<String> = history.iter().map(|m| m.ts.clone()).collect();
+    let mut messages = history;
+    for (thread_ts, last_reply) in threads.iter_mut() {
+        let params = [("channel", channel_id), ("ts", thread_ts.as_str()), ("oldest", last_reply.as_str())];
+        let replies = match paginate(api_base, token, "conversations.replies", &params, "messages") {
+            Ok(values) => parse_messages(values),
+            // The parent was deleted: stop tracking it
+            Err(e) if e.to_string().contains("thread_not_found") => {
+                *last_reply = String::new();
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        // The parent comes back with every call; it was merged when first seen
+        for reply in replies.into_iter().filter(|r| r.ts != *thread_ts) {
+            if ts_secs(&reply.ts) > ts_secs(last_reply) {
+                *last_reply = reply.ts.clone();
+            }
+            if seen.insert(reply.ts.clone()) {
+                messages.push(reply);
+            }
+        }
+    }
+
+    // Close threads that have been quiet for the reply window
+    let cutoff = chrono::Utc::now().timestamp() as f64 - f64::from(reply_window_days) * 86_400.0;
+    threads.retain(|_, last_reply| !last_reply.is_empty() && ts_secs(last_reply) >= cutoff);
+    let next = SlackChannelState {
+        latest_ts: latest.unwrap_or_else(|| state.latest_ts.clone()),
+        threads,
+    };
+
+    messages.retain(|m| m.subtype.is_none());
+    messages.sort_by(|a, b| ts_secs(&a.ts).total_cmp(&ts_secs(&b.ts)));
+    Ok((messages, next))
+}
+
+/// Whether a conversation is selected by `only` (names or IDs; empty = all).
+fn selected(conv: &SlackConversation, name: &str, only: &[String]) -> bool {
+    only.is_empty()
+        || only.iter().any(|o| {
+            let o = o.trim_start_matches('#');
+            o == conv.id || o == name
+        })
+}
+
+/// Sync the configured conversations into `out_dir`, updating `state`.
+///
+/// `only` (channel names or IDs) overrides `config.channels`. State is
+/// updated channel by channel, so a failure keeps the progress made so far.
+pub fn sync_at(
+    api_base: &str,
+    token: &str,
+    config: &SlackConfig,
+    only: &[String],
+    state: &mut SlackSyncState,
+    out_dir: &Path,
+) -> Result<SlackSyncStats> {
+    let users = list_users_at(api_base, token)?;
+    let conversations = list_conversations_at(api_base, token, &config.types)?;
+    let only = if only.is_empty() { config.channels.as_slice() } else { only };
+    let owner_id = owner::handle("slack");
+
+    let mut stats = SlackSyncStats::default();
+    for conv in &conversations {
+        let name = match (&conv.name, &conv.user) {
+            (Some(name), _) if !conv.is_im => name.clone(),
+            (_, Some(user)) => resolve_user_name(&users, user),
+            _ => conv.id.clone(),
+        };
+        if !selected(conv, &name, only) {
+            continue;
+        }
+        // Bots can only read channels they were invited to
+        if !conv.is_im && !conv.is_member {
+            if !only.is_empty() {
+                eprintln!("  Warning: not a member of #{}; skipped", name);
+            }
+            continue;
+        }
+        stats.channels += 1;
+
+        let channel_state = state.channels.get(&conv.id).cloned().unwrap_or_default();
+        let (messages, next_state) = fetch_channel(api_base, token, &conv.id, config.reply_window_days, &channel_state)
+            .with_context(|| format!("Failed to sync #{}", name))?;
+        if messages.is_empty() {
+            state.channels.insert(conv.id.clone(), next_state);
+            continue;
+        }
+        println!("  #{}: {} new message(s)", name, messages.len());
+
+        let label_name = format!("{}:{}", config.label, name);
+        for (thread_ts, thread_msgs) in group_into_threads(&messages) {
+            let thread_key = format!("slack:{}:{}", conv.id, thread_ts);
+            let subject = derive_subject(&thread_msgs[0].text, &name, &thread_ts);
+            for msg in thread_msgs {
+                let message = Message {
+                    id: msg.ts.clone(),
+                    thread_id: thread_key.clone(),
+                    from: if owner_id.is_some() && msg.user == owner_id {
+                        owner::ME.to_string()
+                    } else {
+                        resolve_user_name(&users, msg.user.as_deref().unwrap_or("unknown"))
+                    },
+                    to: String::new(),
+                    cc: String::new(),
+                    date: ts_to_rfc2822(&msg.ts),
+                    subject: subject.clone(),
+                    body: resolve_mrkdwn(&msg.text, &users),
+                    raw: String::new(),
+                };
+                match merge_message(out_dir, &label_name, &config.account, &message, &thread_key)? {
+                    MergeOutcome::Written(_) => stats.imported += 1,
+                    MergeOutcome::Duplicate(_) => stats.duplicates += 1,
+                }
+            }
+        }
+        // Only now: a failed merge leaves the old cursors, so the next run refetches
+        state.channels.insert(conv.id.clone(), next_state);
+    }
+    Ok(stats)
+}
+
+/// corky sync slack [--full] [--channel NAME]...
+pub fn run(full: bool, channels: &[String]) -> Result<()> {
+    let config = corky_config::load_config(None)?
+        .slack
+        .context("No [slack] section in .corky.toml.\nAdd one with a token or token_cmd.")?;
+    let token = resolve_token(&config)?;
+    let out_dir = crate::resolve::conversations_dir();
+
+    let mut state = super::load_state()?;
+    let mut slack_state = if full {
+        SlackSyncState::default()
+    } else {
+        state.slack.remove(&config.account).unwrap_or_default()
+    };
+
+    println!("Slack sync: account {}", config.account);
+    let result = sync_at(API_BASE, &token, &config, channels, &mut slack_state, &out_dir);
+
+    // Save cursors even on failure so finished channels are not refetched
+    state.slack.insert(config.account.clone(), slack_state);
+    super::save_state(&state)?;
+
+    let stats = result?;
+    println!(
+        "Slack sync complete: {} channel(s), {} message(s) imported, {} duplicate(s) skipped.",
+        stats.channels, stats.imported, stats.duplicates
+    );
+    super::manifest::generate_manifest(&out_dir)
+}
@@ -10,6 +10,7 @@ use std::path::Path;
 
 use super::imap_sync::merge_message_to_file;
 use super::types::Message;
+use crate::contact::identity::normalize_phone;
 
 // ---------------------------------------------------------------------------
 // XML types (SMS Backup & Restore format)
@@ -102,22 +103,6 @@ fn ms_to_rfc2822(ms_str: &str) -> String {
         .unwrap_or_else(|| ms_str.to_string())
 }
 
-// ---------------------------------------------------------------------------
-// Phone number normalization
-// ---------------------------------------------------------------------------
-
-/// Normalize a phone number to a consistent key for thread grouping.
-/// Strips everything except digits and leading +.
-fn normalize_phone(raw: &str) -> String {
-    let mut result = String::new();
-    for (i, c) in raw.chars().enumerate() {
-        if c.is_ascii_digit() || (i == 0 && c == '+') {
-            result.push(c);
-        }
-    }
-    result
-}
-
 // ---------------------------------------------------------------------------
 // Import logic
 // ---------------------------------------------------------------------------
@@ -173,6 +158,7 @@ pub fn run(path: &Path, label: &str, out_dir: &Path, account_name: &str) -> Resu
                         .cloned()
                         .unwrap_or_else(|| sms.address.clone()),
                     body: sms.body.clone(),
+                    raw: String::new(),
                 };
 
                 threads.entry(phone).or_default().push(message);
@@ -248,6 +234,7 @@ pub fn run(path: &Path, label: &str, out_dir: &Path, account_name: &str) -> Resu
                         .cloned()
                         .unwrap_or_else(|| phone.clone()),
                     body,
+                    raw: String::new(),
                 };
 
                 threads.entry(phone).or_default().push(message);
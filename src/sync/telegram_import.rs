@@ -13,6 +13,7 @@ use std::path::Path;
 
 use super::imap_sync::merge_message_to_file;
 use super::types::Message;
+use crate::config::owner;
 
 // ---------------------------------------------------------------------------
 // Telegram Desktop JSON types
@@ -127,6 +128,14 @@ fn telegram_date_to_rfc2822(iso: &str) -> String {
 // Import logic
 // ---------------------------------------------------------------------------
 
+/// Whether `from_id` is the owner's Telegram id (`user123` and `123` are the same).
+fn is_owner(from_id: Option<&str>, owner_id: Option<&str>) -> bool {
+    match (from_id, owner_id) {
+        (Some(from), Some(owner)) => from.trim_start_matches("user") == owner.trim_start_matches("user"),
+        _ => false,
+    }
+}
+
 /// Import a single chat into the output directory.
 fn import_chat(
     chat_name: &str,
@@ -139,6 +148,7 @@ fn import_chat(
     let thread_id = format!("tg:{}", chat_id);
     let subject = chat_name.to_string();
     let mut count = 0u32;
+    let owner_id = owner::handle("telegram");
 
     for msg in messages {
         // Only import regular messages
@@ -157,7 +167,11 @@ fn import_chat(
             continue;
         }
 
-        let from = msg.from.clone().unwrap_or_else(|| "Unknown".to_string());
+        let from = if is_owner(msg.from_id.as_deref(), owner_id.as_deref()) {
+            owner::ME.to_string()
+        } else {
+            msg.from.clone().unwrap_or_else(|| "Unknown".to_string())
+        };
         let date = telegram_date_to_rfc2822(&msg.date);
 
         let message = Message {
@@ -169,6 +183,7 @@ fn import_chat(
             date,
             subject: subject.clone(),
             body,
+            raw: String::new(),
         };
 
         merge_message_to_file(out_dir, label, account_name, &message, &thread_id)?;
@@ -364,6 +379,7 @@ fn import_html_file(
             date,
             subject: subject.clone(),
             body,
+            raw: String::new(),
         };
 
         merge_message_to_file(out_dir, label, account_name, &message, &thread_id)?;
@@ -565,6 +581,14 @@ mod tests {
         assert_eq!(html_decode("a &amp; b &lt; c"), "a & b < c");
     }
 
+    #[test]
+    fn test_is_owner_matches_with_or_without_user_prefix() {
+        assert!(is_owner(Some("user456"), Some("user456")));
+        assert!(is_owner(Some("user456"), Some("456")));
+        assert!(!is_owner(Some("user123"), Some("456")));
+        assert!(!is_owner(Some("user456"), None));
+    }
+
     #[test]
     fn test_chat_id_from_name_stable() {
         let id1 = chat_id_from_name("Eric Yang");
@@ -15,6 +15,36 @@ pub struct Message {
     pub date: String,
     pub subject: String,
     pub body: String,
+    /// Raw store key (`sha256:HEX`) of the original RFC822 source, if kept
+    #[serde(default)]
+    pub raw: String,
+}
+
+/// Triage status of a thread; open threads carry no `**State**` line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadStatus {
+    #[default]
+    Open,
+    Done,
+    Snoozed,
+    Waiting,
+}
+
+/// Per-thread triage state, kept in the thread file's header metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThreadState {
+    #[serde(default)]
+    pub status: ThreadStatus,
+    /// Snoozed: the day the thread comes back (YYYY-MM-DD)
+    #[serde(default)]
+    pub until: String,
+    /// Waiting: who or what the thread is waiting on
+    #[serde(default)]
+    pub waiting_on: String,
+    /// When the state was set (RFC 2822); a later message resurfaces the thread
+    #[serde(default)]
+    pub since: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -29,6 +59,8 @@ pub struct Thread {
     pub messages: Vec<Message>,
     #[serde(default)]
     pub last_date: String,
+    #[serde(default)]
+    pub state: ThreadState,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,12 +83,59 @@ pub struct ContactSyncState {
     pub mailboxes: HashMap<String, String>,
 }
 
+/// Per-channel cursors for live Slack sync.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SlackChannelState {
+    /// `ts` of the newest top-level message fetched (conversations.history `oldest`)
+    #[serde(default)]
+    pub latest_ts: String,
+    /// Open threads: parent `thread_ts` → `ts` of the newest reply fetched
+    #[serde(default)]
+    pub threads: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SlackSyncState {
+    /// Key = channel ID
+    #[serde(default)]
+    pub channels: HashMap<String, SlackChannelState>,
+}
+
+/// One address book card linked to a contact.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardDavCardState {
+    /// Contact name in [contacts]
+    pub contact: String,
+    /// Server ETag at last sync
+    #[serde(default)]
+    pub etag: String,
+    /// FNV-1a hash of the contact's rendered vCard at last sync
+    #[serde(default)]
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardDavSyncState {
+    /// Key = card href on the server
+    #[serde(default)]
+    pub cards: HashMap<String, CardDavCardState>,
+    /// Contacts whose card was deleted on the server; not uploaded again
+    #[serde(default)]
+    pub unlinked: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SyncState {
     #[serde(default)]
     pub accounts: HashMap<String, AccountSyncState>,
     #[serde(default)]
     pub contacts: HashMap<String, ContactSyncState>,
+    /// Key = Slack account name from [slack]
+    #[serde(default)]
+    pub slack: HashMap<String, SlackSyncState>,
+    /// Key = CardDAV address book URL from [carddav]
+    #[serde(default)]
+    pub carddav: HashMap<String, CardDavSyncState>,
 }
 
 pub fn load_state(data: &[u8]) -> anyhow::Result<SyncState> {
@@ -0,0 +1,492 @@
+//! WhatsApp "Export chat" → corky conversations.
+//!
+//! Reads the plain-text export (`WhatsApp Chat with NAME.txt`, or `_chat.txt`
+//! inside the iOS/Android .zip) and merges each message with
+//! `merge_message()` into one thread per chat, keyed `wa:{chat-slug}`.
+//!
+//! Line formats differ by platform and locale:
+//!
+//! - Android: `12/31/23, 9:41 PM - Alice: Hello` / `31.12.23, 21:41 - Alice: Hello`
+//! - iOS: `[31/12/2023, 21:41:05] Alice: Hello` / `[12/31/23, 9:41:05 PM] Alice: Hello`
+//!
+//! Day/month order is inferred from the whole file (a first field above 12
+//! means day-first, a second field above 12 means month-first; otherwise
+//! 12-hour clocks imply month-first) unless `--date-order` is given. Exports
+//! carry no time zone, so times are written as `+0000`. Android exports have
+//! minute resolution; repeated (sender, minute) pairs get one-second offsets so
+//! the (from, date) de-duplication in `merge_message()` keeps them apart.
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use clap::ValueEnum;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use super::imap_sync::{merge_message, MergeOutcome};
+use super::types::Message;
+use crate::util::slugify;
+
+/// A line that starts a new entry: timestamp, then the rest of the line.
+static ENTRY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^\[?(\d{1,4})[./-](\d{1,2})[./-](\d{1,4}),?\s+(\d{1,2})[:.](\d{2})(?:[:.](\d{2}))?\s*([AaPp]\.?\s?[Mm]\.?)?\]?\s*(?:-\s+)?(.*)$",
+    )
+    .unwrap()
+});
+
+/// iOS media placeholders: "image omitted", "GIF omitted", ...
+static OMITTED_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(image|video|audio|sticker|gif|document|contact card) omitted$").unwrap()
+});
+
+/// iOS attachment marker: "<attached: 00000012-PHOTO-2023-12-31-21-41-05.jpg>"
+static ATTACHED_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^<attached:\s*(.+?)>$").unwrap());
+
+/// Android attachment marker: "IMG-20231231-WA0001.jpg (file attached)"
+static FILE_ATTACHED_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(.+?) \(file attached\)$").unwrap());
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum DateOrder {
+    /// Infer from the file
+    #[default]
+    Auto,
+    /// Day/month/year
+    Dmy,
+    /// Month/day/year
+    Mdy,
+    /// Year/month/day
+    Ymd,
+}
+
+/// Counts reported at the end of an import.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChatStats {
+    pub imported: usize,
+    pub duplicates: usize,
+    /// System notices (encryption banner, joins, subject changes) skipped
+    pub system: usize,
+}
+
+/// One timestamped entry before date resolution.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    fields: [u32; 3],
+    year_digits: usize,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    meridiem: Option<bool>, // Some(true) = PM
+    /// None for system notices
+    sender: Option<String>,
+    text: String,
+}
+
+/// Strip leading bidi marks and normalize the odd spaces WhatsApp puts in
+/// timestamps. Marks inside the line are kept: iOS prefixes system notices
+/// with U+200E.
+fn clean_line(line: &str) -> String {
+    line.trim_end_matches(['\r', '\n'])
+        .trim_start_matches(['\u{200e}', '\u{200f}', '\u{feff}'])
+        .replace(['\u{202f}', '\u{a0}'], " ")
+}
+
+fn strip_marks(text: &str) -> String {
+    text.replace(['\u{200e}', '\u{200f}'], "")
+}
+
+/// A media placeholder or attachment marker line.
+fn is_media(line: &str) -> bool {
+    line == "<Media omitted>"
+        || OMITTED_RE.is_match(line)
+        || ATTACHED_RE.is_match(line)
+        || FILE_ATTACHED_RE.is_match(line)
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let caps = ENTRY_RE.captures(line)?;
+    let num = |i: usize| caps.get(i).and_then(|m| m.as_str().parse::<u32>().ok());
+    let fields = [num(1)?, num(2)?, num(3)?];
+    let year_digits = if caps[1].len() == 4 { 4 } else { caps[3].len() };
+    let meridiem = caps
+        .get(7)
+        .map(|m| m.as_str().to_ascii_lowercase().starts_with('p'));
+    let rest = caps.get(8).map(|m| m.as_str()).unwrap_or_default();
+
+    // "Name: text" is a message; anything else is a system notice
+    let named = |name: &str| !name.trim().is_empty() && !name.contains('"');
+    let (sender, text) = match rest.split_once(": ") {
+        Some((name, text)) if named(name) => {
+            // iOS group notices look like "Group: \u{200e}Alice added Bob"
+            let text_clean = strip_marks(text);
+            if text.starts_with('\u{200e}') && !is_media(text_clean.trim()) {
+                (None, text_clean)
+            } else {
+                (Some(strip_marks(name).trim().to_string()), text_clean)
+            }
+        }
+        _ => match rest.strip_suffix(':') {
+            // Sender with an empty first line ("Alice:" then text below)
+            Some(name) if named(name) => (Some(strip_marks(name).trim().to_string()), String::new()),
+            _ => (None, strip_marks(rest)),
+        },
+    };
+    Some(Entry {
+        fields,
+        year_digits,
+        hour: num(4)?,
+        minute: num(5)?,
+        second: num(6).unwrap_or(0),
+        meridiem,
+        sender,
+        text,
+    })
+}
+
+/// Split an export into entries; lines without a timestamp continue the
+/// previous entry (multi-line messages).
+fn parse_entries(text: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for raw in text.lines() {
+        let line = clean_line(raw);
+        match parse_entry(&line) {
+            Some(entry) => entries.push(entry),
+            None => {
+                if let Some(last) = entries.last_mut() {
+                    if !last.text.is_empty() {
+                        last.text.push('\n');
+                    }
+                    last.text.push_str(&strip_marks(&line));
+                }
+            }
+        }
+    }
+    entries
+}
+
+/// Resolve `Auto` against the entries of one file.
+fn infer_order(entries: &[Entry], order: DateOrder) -> DateOrder {
+    if order != DateOrder::Auto {
+        return order;
+    }
+    if entries.iter().any(|e| e.year_digits == 4 && e.fields[0] > 31) {
+        return DateOrder::Ymd;
+    }
+    if entries.iter().any(|e| e.fields[0] > 12) {
+        return DateOrder::Dmy;
+    }
+    if entries.iter().any(|e| e.fields[1] > 12) {
+        return DateOrder::Mdy;
+    }
+    if entries.iter().any(|e| e.meridiem.is_some()) {
+        DateOrder::Mdy
+    } else {
+        DateOrder::Dmy
+    }
+}
+
+/// Timestamp of an entry, or None if it is invalid.
+fn entry_date(entry: &Entry, order: DateOrder) -> Option<NaiveDateTime> {
+    let [a, b, c] = entry.fields;
+    let (year, month, day) = match order {
+        DateOrder::Ymd => (a, b, c),
+        DateOrder::Mdy => (c, a, b),
+        DateOrder::Dmy | DateOrder::Auto => (c, b, a),
+    };
+    let year = if year < 100 { 2000 + year } else { year };
+    let hour = match entry.meridiem {
+        Some(true) if entry.hour < 12 => entry.hour + 12,
+        Some(false) if entry.hour == 12 => 0,
+        _ => entry.hour,
+    };
+    NaiveDate::from_ymd_opt(year as i32, month, day)?.and_hms_opt(hour, entry.minute, entry.second)
+}
+
+fn format_date(dt: NaiveDateTime) -> String {
+    dt.format("%a, %d %b %Y %H:%M:%S +0000").to_string()
+}
+
+/// Replace media placeholders and attachment markers with readable text.
+fn clean_body(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.replace(" <This message was edited>", "");
+            let trimmed = line.trim();
+            if trimmed == "<Media omitted>" {
+                return "[Media omitted]".to_string();
+            }
+            if let Some(c) = OMITTED_RE.captures(trimmed) {
+                let kind = &c[1];
+                let mut chars = kind.chars();
+                let kind = match chars.next() {
+                    Some(f) if kind != "GIF" => f.to_uppercase().collect::<String>() + chars.as_str(),
+                    _ => kind.to_string(),
+                };
+                return format!("[{} omitted]", kind);
+            }
+            if let Some(c) = ATTACHED_RE.captures(trimmed) {
+                return format!("[Attachment: {}]", &c[1]);
+            }
+            if let Some(c) = FILE_ATTACHED_RE.captures(trimmed) {
+                return format!("[Attachment: {}]", &c[1]);
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Chat name from an export file name ("WhatsApp Chat with Alice.txt").
+fn chat_name_from_path(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    for prefix in ["WhatsApp Chat with ", "WhatsApp Chat - ", "WhatsApp Chat "] {
+        if let Some(name) = stem.strip_prefix(prefix) {
+            return name.trim().to_string();
+        }
+    }
+    stem
+}
+
+/// Stable thread ID for a chat.
+pub fn thread_id_for_chat(chat: &str) -> String {
+    format!("wa:{}", slugify(chat))
+}
+
+/// Import one chat export's text into `out_dir`.
+pub fn import_chat(
+    text: &str,
+    chat: &str,
+    order: DateOrder,
+    label: &str,
+    out_dir: &Path,
+    account_name: &str,
+    stats: &mut ChatStats,
+) -> Result<()> {
+    let entries = parse_entries(text);
+    if entries.is_empty() {
+        bail!("No WhatsApp messages found in chat '{}'", chat);
+    }
+    let order = infer_order(&entries, order);
+    let thread_id = thread_id_for_chat(chat);
+    let mut seen: HashMap<(String, NaiveDateTime), i64> = HashMap::new();
+
+    for (n, entry) in entries.iter().enumerate() {
+        let Some(ref sender) = entry.sender else {
+            stats.system += 1;
+            continue;
+        };
+        let Some(date) = entry_date(entry, order) else {
+            eprintln!("  Warning: invalid timestamp in '{}' entry {}; skipped", chat, n + 1);
+            continue;
+        };
+        let body = clean_body(&entry.text);
+        if body.is_empty() {
+            continue;
+        }
+        let repeat = seen.entry((sender.clone(), date)).or_insert(0);
+        let date = format_date(date + Duration::seconds(*repeat));
+        *repeat += 1;
+        let message = Message {
+            id: format!("{}:{}", thread_id, n + 1),
+            thread_id: thread_id.clone(),
+            from: sender.clone(),
+            to: String::new(),
+            cc: String::new(),
+            date,
+            subject: chat.to_string(),
+            body,
+            raw: String::new(),
+        };
+        match merge_message(out_dir, label, account_name, &message, &thread_id)? {
+            MergeOutcome::Written(_) => stats.imported += 1,
+            MergeOutcome::Duplicate(_) => stats.duplicates += 1,
+        }
+    }
+    Ok(())
+}
+
+/// Read the chat text out of an export .zip (`_chat.txt`, else the first .txt).
+fn read_zip_chat(path: &Path) -> Result<String> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut archive = zip::ZipArchive::new(file)
+        .with_context(|| format!("Invalid ZIP: {}", path.display()))?;
+    let names: Vec<String> = archive.file_names().map(|n| n.to_string()).collect();
+    let Some(name) = names
+        .iter()
+        .find(|n| n.rsplit('/').next() == Some("_chat.txt"))
+        .or_else(|| names.iter().find(|n| n.ends_with(".txt")))
+    else {
+        bail!("No chat .txt found in {}", path.display());
+    };
+    let mut text = String::new();
+    archive
+        .by_name(name)?
+        .read_to_string(&mut text)
+        .with_context(|| format!("Failed to read {} in {}", name, path.display()))?;
+    Ok(text)
+}
+
+/// Import a single .txt or .zip export.
+fn import_file(
+    path: &Path,
+    chat: Option<&str>,
+    order: DateOrder,
+    label: &str,
+    out_dir: &Path,
+    account_name: &str,
+    stats: &mut ChatStats,
+) -> Result<()> {
+    let is_zip = path
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("zip"));
+    let text = if is_zip {
+        read_zip_chat(path)?
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    };
+    let name = chat
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| chat_name_from_path(path));
+    let before = stats.imported;
+    import_chat(&text, &name, order, label, out_dir, account_name, stats)?;
+    println!("  {} — {} message(s)", name, stats.imported - before);
+    Ok(())
+}
+
+/// Entry point: import WhatsApp chat export(s).
+///
+/// `path` can be a .txt export, a .zip export, or a directory of them.
+/// `chat` overrides the chat name (and so the thread ID) for a single file.
+pub fn run(
+    path: &Path,
+    label: &str,
+    out_dir: &Path,
+    account_name: &str,
+    chat: Option<&str>,
+    order: DateOrder,
+) -> Result<ChatStats> {
+    println!("WhatsApp import: {}", path.display());
+    let mut stats = ChatStats::default();
+
+    if path.is_dir() {
+        if chat.is_some() {
+            bail!("--chat applies to a single export file, not a directory");
+        }
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .with_context(|| format!("Cannot read directory {}", path.display()))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| e.eq_ignore_ascii_case("txt") || e.eq_ignore_ascii_case("zip"))
+            })
+            .collect();
+        if files.is_empty() {
+            bail!("No .txt or .zip exports found in {}", path.display());
+        }
+        files.sort();
+        for file in files {
+            import_file(&file, None, order, label, out_dir, account_name, &mut stats)?;
+        }
+    } else {
+        import_file(path, chat, order, label, out_dir, account_name, &mut stats)?;
+    }
+
+    println!(
+        "WhatsApp import complete: {} message(s) imported, {} duplicate(s) skipped, {} system message(s) skipped.",
+        stats.imported, stats.duplicates, stats.system
+    );
+    Ok(stats)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates(text: &str, order: DateOrder) -> Vec<String> {
+        let entries = parse_entries(text);
+        let order = infer_order(&entries, order);
+        entries.iter().filter_map(|e| entry_date(e, order)).map(format_date).collect()
+    }
+
+    #[test]
+    fn android_us_12_hour() {
+        let text = "1/2/24, 9:41\u{202f}PM - Alice: Hi\n1/2/24, 12:05 AM - Bob: Late";
+        assert_eq!(
+            dates(text, DateOrder::Auto),
+            vec!["Tue, 02 Jan 2024 21:41:00 +0000", "Tue, 02 Jan 2024 00:05:00 +0000"]
+        );
+    }
+
+    #[test]
+    fn ios_day_first_24_hour() {
+        let text = "[31/12/2023, 21:41:05] Alice: Hi\n[01/01/2024, 09:00:00] Bob: Happy new year";
+        assert_eq!(
+            dates(text, DateOrder::Auto),
+            vec!["Sun, 31 Dec 2023 21:41:05 +0000", "Mon, 01 Jan 2024 09:00:00 +0000"]
+        );
+    }
+
+    #[test]
+    fn dotted_and_ambiguous_dates() {
+        // Ambiguous 24-hour dates default to day-first
+        assert_eq!(dates("03.04.24, 10:00 - A: x", DateOrder::Auto), vec!["Wed, 03 Apr 2024 10:00:00 +0000"]);
+        assert_eq!(dates("03.04.24, 10:00 - A: x", DateOrder::Mdy), vec!["Mon, 04 Mar 2024 10:00:00 +0000"]);
+        assert_eq!(dates("2024-04-03 10:00 - A: x", DateOrder::Auto), vec!["Wed, 03 Apr 2024 10:00:00 +0000"]);
+    }
+
+    #[test]
+    fn multiline_and_system_messages() {
+        let text = "12/31/23, 9:00 PM - Messages and calls are end-to-end encrypted.\n12/31/23, 9:01 PM - Alice: line one\nline two\n\nline four\n12/31/23, 9:02 PM - Alice added Bob";
+        let entries = parse_entries(text);
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].sender.is_none());
+        assert_eq!(entries[1].sender.as_deref(), Some("Alice"));
+        assert_eq!(entries[1].text, "line one\nline two\n\nline four");
+        assert!(entries[2].sender.is_none());
+    }
+
+    #[test]
+    fn ios_group_notices_are_system() {
+        let text = "[31/12/2023, 21:41:05] Team: \u{200e}Alice added Bob\n[31/12/2023, 21:42:00] Alice: \u{200e}image omitted";
+        let entries = parse_entries(text);
+        assert!(entries[0].sender.is_none());
+        assert_eq!(entries[1].sender.as_deref(), Some("Alice"));
+        assert_eq!(entries[1].text, "image omitted");
+    }
+
+    #[test]
+    fn media_placeholders() {
+        assert_eq!(clean_body("<Media omitted>"), "[Media omitted]");
+        assert_eq!(clean_body("image omitted"), "[Image omitted]");
+        assert_eq!(clean_body("GIF omitted"), "[GIF omitted]");
+        assert_eq!(clean_body("<attached: 00000012-PHOTO.jpg>"), "[Attachment: 00000012-PHOTO.jpg]");
+        assert_eq!(
+            clean_body("IMG-20231231-WA0001.jpg (file attached)\nLook!"),
+            "[Attachment: IMG-20231231-WA0001.jpg]\nLook!"
+        );
+        assert_eq!(clean_body("Fixed it <This message was edited>"), "Fixed it");
+    }
+
+    #[test]
+    fn chat_names_and_ids() {
+        assert_eq!(chat_name_from_path(Path::new("WhatsApp Chat with Alice Smith.txt")), "Alice Smith");
+        assert_eq!(chat_name_from_path(Path::new("WhatsApp Chat - Team.zip")), "Team");
+        assert_eq!(thread_id_for_chat("Alice Smith"), "wa:alice-smith");
+    }
+}
@@ -1,45 +1,75 @@
 //! `corky topics info` — show topic details and matching threads.
 
 use anyhow::Result;
+use serde::Serialize;
 
+use super::list::TopicEntry;
 use crate::config::topic;
+use crate::output::{emit, OutputFormat, Report};
 use crate::resolve;
 use crate::util;
 
-pub fn run(name: &str) -> Result<()> {
+/// Result of `corky topics info` (JSON: object).
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicInfo {
+    #[serde(flatten)]
+    pub topic: TopicEntry,
+    /// Conversation slugs whose subject matches a keyword
+    pub matching_conversations: Vec<String>,
+}
+
+impl Report for TopicInfo {
+    fn render_table(&self) -> String {
+        let t = &self.topic;
+        let mut out = format!("Topic: {}\n", t.name);
+        if let Some(ref desc) = t.description {
+            out.push_str(&format!("Description: {}\n", desc));
+        }
+        if !t.keywords.is_empty() {
+            out.push_str(&format!("Keywords: {}\n", t.keywords.join(", ")));
+        }
+        if !t.contacts.is_empty() {
+            out.push_str(&format!("Contacts: {}\n", t.contacts.join(", ")));
+        }
+        if !t.mailboxes.is_empty() {
+            out.push_str(&format!("Mailboxes: {}\n", t.mailboxes.join(", ")));
+        }
+
+        if !t.keywords.is_empty() {
+            if self.matching_conversations.is_empty() {
+                out.push_str("\nNo matching conversations found.\n");
+            } else {
+                out.push_str(&format!(
+                    "\nMatching conversations ({}):\n",
+                    self.matching_conversations.len()
+                ));
+                for m in &self.matching_conversations {
+                    out.push_str(&format!("  {}\n", m));
+                }
+            }
+        }
+        out
+    }
+}
+
+pub fn run(name: &str, format: OutputFormat) -> Result<()> {
     let topics = topic::load_topics(None)?;
     let config = topics
         .get(name)
         .ok_or_else(|| anyhow::anyhow!("Topic '{}' not found in .corky.toml.", name))?;
 
-    println!("Topic: {}", name);
-    if let Some(ref desc) = config.description {
-        println!("Description: {}", desc);
-    }
-    if !config.keywords.is_empty() {
-        println!("Keywords: {}", config.keywords.join(", "));
-    }
-    if !config.contacts.is_empty() {
-        println!("Contacts: {}", config.contacts.join(", "));
-    }
-    if !config.mailboxes.is_empty() {
-        println!("Mailboxes: {}", config.mailboxes.join(", "));
-    }
-
     // Find matching conversations
-    if !config.keywords.is_empty() {
-        let matches = find_matching_conversations(config)?;
-        if matches.is_empty() {
-            println!("\nNo matching conversations found.");
-        } else {
-            println!("\nMatching conversations ({}):", matches.len());
-            for m in &matches {
-                println!("  {}", m);
-            }
-        }
-    }
+    let matching_conversations = if config.keywords.is_empty() {
+        Vec::new()
+    } else {
+        find_matching_conversations(config)?
+    };
 
-    Ok(())
+    let info = TopicInfo {
+        topic: TopicEntry::new(name, config),
+        matching_conversations,
+    };
+    emit(format, &info)
 }
 
 /// Scan conversations directory for threads whose subject matches any keyword.
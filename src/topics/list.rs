@@ -1,41 +1,82 @@
 //! `corky topics list` — show configured topics.
 
 use anyhow::Result;
+use serde::Serialize;
 
 use crate::config::topic;
+use crate::output::{emit, OutputFormat, Report};
 
-pub fn run(verbose: bool) -> Result<()> {
-    let topics = topic::load_topics(None)?;
-    if topics.is_empty() {
-        println!("No topics configured. Use 'corky topics add' to create one.");
-        return Ok(());
+/// A configured topic.
+#[derive(Debug, Clone, Serialize)]
+pub struct TopicEntry {
+    pub name: String,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub contacts: Vec<String>,
+    pub mailboxes: Vec<String>,
+}
+
+impl TopicEntry {
+    pub fn new(name: &str, config: &topic::TopicConfig) -> Self {
+        TopicEntry {
+            name: name.to_string(),
+            description: config.description.clone(),
+            keywords: config.keywords.clone(),
+            contacts: config.contacts.clone(),
+            mailboxes: config.mailboxes.clone(),
+        }
     }
-    for (name, config) in &topics {
-        if verbose {
-            println!("{}:", name);
-            if let Some(ref desc) = config.description {
-                println!("  description: {}", desc);
-            }
-            if !config.keywords.is_empty() {
-                println!("  keywords: {}", config.keywords.join(", "));
-            }
-            if !config.contacts.is_empty() {
-                println!("  contacts: {}", config.contacts.join(", "));
-            }
-            if !config.mailboxes.is_empty() {
-                println!("  mailboxes: {}", config.mailboxes.join(", "));
-            }
-        } else {
-            let desc = config
-                .description
-                .as_deref()
-                .unwrap_or("");
-            if desc.is_empty() {
-                println!("{}", name);
+}
+
+/// Result of `corky topics list` (JSON: array of [`TopicEntry`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct TopicListReport {
+    pub topics: Vec<TopicEntry>,
+    #[serde(skip)]
+    pub verbose: bool,
+}
+
+impl Report for TopicListReport {
+    fn render_table(&self) -> String {
+        if self.topics.is_empty() {
+            return "No topics configured. Use 'corky topics add' to create one.\n".to_string();
+        }
+        let mut out = String::new();
+        for t in &self.topics {
+            if self.verbose {
+                out.push_str(&format!("{}:\n", t.name));
+                if let Some(ref desc) = t.description {
+                    out.push_str(&format!("  description: {}\n", desc));
+                }
+                if !t.keywords.is_empty() {
+                    out.push_str(&format!("  keywords: {}\n", t.keywords.join(", ")));
+                }
+                if !t.contacts.is_empty() {
+                    out.push_str(&format!("  contacts: {}\n", t.contacts.join(", ")));
+                }
+                if !t.mailboxes.is_empty() {
+                    out.push_str(&format!("  mailboxes: {}\n", t.mailboxes.join(", ")));
+                }
             } else {
-                println!("{} — {}", name, desc);
+                match t.description.as_deref().filter(|d| !d.is_empty()) {
+                    Some(desc) => out.push_str(&format!("{} — {}\n", t.name, desc)),
+                    None => out.push_str(&format!("{}\n", t.name)),
+                }
             }
         }
+        out
     }
-    Ok(())
+}
+
+pub fn run(verbose: bool, format: OutputFormat) -> Result<()> {
+    let topics = topic::load_topics(None)?;
+    let report = TopicListReport {
+        topics: topics
+            .iter()
+            .map(|(name, config)| TopicEntry::new(name, config))
+            .collect(),
+        verbose,
+    };
+    emit(format, &report)
 }
@@ -7,16 +7,18 @@ pub mod suggest;
 
 use anyhow::Result;
 
-pub fn run_list(verbose: bool) -> Result<()> {
-    list::run(verbose)
+use crate::output::OutputFormat;
+
+pub fn run_list(verbose: bool, format: OutputFormat) -> Result<()> {
+    list::run(verbose, format)
 }
 
 pub fn run_add(name: &str, keywords: &[String], description: Option<&str>) -> Result<()> {
     add::run(name, keywords, description)
 }
 
-pub fn run_info(name: &str) -> Result<()> {
-    info::run(name)
+pub fn run_info(name: &str, format: OutputFormat) -> Result<()> {
+    info::run(name, format)
 }
 
 pub fn run_suggest(limit: usize, mailbox: Option<&str>) -> Result<()> {
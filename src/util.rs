@@ -102,10 +102,37 @@ pub fn truncate_preview(s: &str, max: usize) -> String {
     }
 }
 
+/// Parse a span like "36h", "5d" or "2w" (hours, days or weeks).
+pub fn parse_span(s: &str) -> Option<chrono::Duration> {
+    let s = s.trim();
+    let unit = s.chars().last()?;
+    let n: i64 = s[..s.len() - unit.len_utf8()].parse().ok()?;
+    if n < 0 {
+        return None;
+    }
+    match unit {
+        'h' => Some(chrono::Duration::hours(n)),
+        'd' => Some(chrono::Duration::days(n)),
+        'w' => Some(chrono::Duration::weeks(n)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_span() {
+        assert_eq!(parse_span("36h"), Some(chrono::Duration::hours(36)));
+        assert_eq!(parse_span(" 5d"), Some(chrono::Duration::days(5)));
+        assert_eq!(parse_span("2w"), Some(chrono::Duration::days(14)));
+        assert_eq!(parse_span("5"), None);
+        assert_eq!(parse_span("-1d"), None);
+        assert_eq!(parse_span("d"), None);
+        assert_eq!(parse_span(""), None);
+    }
+
     #[test]
     fn test_slugify_basic() {
         assert_eq!(slugify("Hello World"), "hello-world");
@@ -1,13 +1,17 @@
 //! IMAP polling daemon — syncs email and pushes to shared repos on an interval.
 
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use crate::accounts::{load_accounts, load_watch_config, resolve_password};
 use crate::config::corky_config;
+use crate::config::owner::Identity;
+use crate::mailbox::find_unanswered::Scope;
+use crate::mailbox::{followups, triage};
 use crate::resolve;
+use crate::serve::events::{self, SyncEvent};
 use crate::sync::imap_sync::sync_account;
 use crate::sync::types::SyncState;
 
@@ -116,6 +120,73 @@ fn schedule_tick() {
     }
 }
 
+/// Refresh engagement metrics on published social drafts (best-effort).
+fn social_stats_tick() {
+    match crate::social::stats::refresh_all() {
+        Ok(0) => {}
+        Ok(n) => println!("corky watch: refreshed metrics for {} social post(s)", n),
+        Err(e) => eprintln!("corky watch: social stats failed: {}", e),
+    }
+}
+
+/// Reopen snoozed/done/waiting threads whose snooze expired or that got a
+/// new message, and report them (best-effort).
+fn triage_tick(notify_enabled: bool) {
+    let resurfaced = match triage::resurface(&Scope::All, &Identity::load(None)) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("corky watch: triage check failed: {}", e);
+            return;
+        }
+    };
+    if !resurfaced.is_empty() {
+        println!("corky watch: {} thread(s) resurfaced", resurfaced.len());
+        for t in &resurfaced {
+            println!("  {} ({}, {})", t.subject, t.reason, t.scope);
+        }
+        if notify_enabled {
+            notify("corky", &format!("{} thread(s) resurfaced", resurfaced.len()));
+        }
+    }
+}
+
+/// Report threads that newly crossed their follow-up threshold (best-effort).
+///
+/// `seen` holds the threads already reported (`scope/file`); threads that
+/// stop being due (a reply arrived) drop out, so they are reported again if
+/// they fall due later.
+fn followups_tick(notify_enabled: bool, mut seen: HashSet<String>) -> HashSet<String> {
+    let me = Identity::load(None);
+    if !me.is_configured() {
+        eprintln!("corky watch: followups need [owner] name or accounts in .corky.toml");
+        return seen;
+    }
+    let report = match followups::collect(&Scope::All, &me, None) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("corky watch: followups check failed: {}", e);
+            return seen;
+        }
+    };
+    let due: HashSet<String> = report.threads.iter().map(|t| format!("{}/{}", t.scope, t.file)).collect();
+    seen.retain(|key| due.contains(key));
+    let fresh: Vec<_> = report
+        .threads
+        .iter()
+        .filter(|t| seen.insert(format!("{}/{}", t.scope, t.file)))
+        .collect();
+    if !fresh.is_empty() {
+        println!("corky watch: {} thread(s) due for a follow-up", fresh.len());
+        for t in &fresh {
+            println!("  {} ({}d, {})", t.subject, t.days_waiting, t.scope);
+        }
+        if notify_enabled {
+            notify("corky", &format!("{} thread(s) due for a follow-up", fresh.len()));
+        }
+    }
+    seen
+}
+
 /// Check for upgrade and self-restart if a newer version is available.
 /// Returns true if the process should restart (exec failed as fallback).
 fn try_auto_upgrade() -> bool {
@@ -174,7 +245,11 @@ fn check_filter_drift() {
 }
 
 /// One sync + mailbox sync cycle. Returns count of labels with new messages.
-fn poll_once(notify_enabled: bool) -> usize {
+///
+/// Progress is appended to the sync event log (tagged with `source`) for
+/// `corky serve` subscribers.
+pub fn poll_once(notify_enabled: bool, source: &str) -> usize {
+    let data_dir = resolve::data_dir();
     let accounts = match load_accounts(None) {
         Ok(a) => a,
         Err(e) => {
@@ -182,6 +257,7 @@ fn poll_once(notify_enabled: bool) -> usize {
             return 0;
         }
     };
+    events::record(&data_dir, source, SyncEvent::SyncStarted { accounts: accounts.len() });
 
     let mut state = load_state();
     let before = snapshot_uids(&state);
@@ -192,6 +268,8 @@ fn poll_once(notify_enabled: bool) -> usize {
             Ok(p) => p,
             Err(e) => {
                 eprintln!("  Error resolving password for {}: {}", acct_name, e);
+                let error = e.to_string();
+                events::record(&data_dir, source, SyncEvent::AccountFailed { account: acct_name.clone(), error });
                 continue;
             }
         };
@@ -210,8 +288,11 @@ fn poll_once(notify_enabled: bool) -> usize {
             None,
         ) {
             eprintln!("  Error syncing {}: {}", acct_name, e);
+            let error = e.to_string();
+            events::record(&data_dir, source, SyncEvent::AccountFailed { account: acct_name.clone(), error });
             continue;
         }
+        events::record(&data_dir, source, SyncEvent::AccountSynced { account: acct_name.clone() });
     }
 
     save_state(&state);
@@ -231,6 +312,7 @@ fn poll_once(notify_enabled: bool) -> usize {
     } else {
         println!("\nNo new messages");
     }
+    events::record(&data_dir, source, SyncEvent::SyncFinished { new_labels: new_count });
 
     new_count
 }
@@ -266,6 +348,15 @@ pub async fn run(interval_override: Option<u64>) -> Result<()> {
     let upgrade_check_every = (3600 / interval).max(1);
     // Check filter drift every N cycles (roughly once per hour)
     let filter_check_every = upgrade_check_every;
+    // Refresh social metrics every N cycles (0 = disabled)
+    let mut cycles_since_stats: u64 = 0;
+    let stats_every = if config.social_stats_interval > 0 {
+        (config.social_stats_interval / interval).max(1)
+    } else {
+        0
+    };
+    // Threads already reported as due for a follow-up
+    let mut followups_seen: HashSet<String> = HashSet::new();
 
     loop {
         if shutdown.load(Ordering::Relaxed) {
@@ -275,7 +366,7 @@ pub async fn run(interval_override: Option<u64>) -> Result<()> {
         // Run sync in a blocking context
         let notify_enabled = config.notify;
         tokio::task::spawn_blocking(move || {
-            poll_once(notify_enabled);
+            poll_once(notify_enabled, "watch");
         })
         .await?;
 
@@ -290,6 +381,25 @@ pub async fn run(interval_override: Option<u64>) -> Result<()> {
             break;
         }
 
+        // Resurface snoozed/done/waiting threads
+        let notify_enabled = config.notify;
+        tokio::task::spawn_blocking(move || triage_tick(notify_enabled)).await?;
+
+        if shutdown.load(Ordering::Relaxed) {
+            break;
+        }
+
+        // Follow-up reminders (opt-in via [watch] followups)
+        if config.followups {
+            let notify_enabled = config.notify;
+            let seen = std::mem::take(&mut followups_seen);
+            followups_seen = tokio::task::spawn_blocking(move || followups_tick(notify_enabled, seen)).await?;
+        }
+
+        if shutdown.load(Ordering::Relaxed) {
+            break;
+        }
+
         // Auto-upgrade check (once per hour)
         if auto_upgrade {
             cycles_since_upgrade_check += 1;
@@ -315,6 +425,19 @@ pub async fn run(interval_override: Option<u64>) -> Result<()> {
             break;
         }
 
+        // Social metrics refresh (opt-in via social_stats_interval)
+        if stats_every > 0 {
+            cycles_since_stats += 1;
+            if cycles_since_stats >= stats_every {
+                cycles_since_stats = 0;
+                tokio::task::spawn_blocking(social_stats_tick).await?;
+            }
+        }
+
+        if shutdown.load(Ordering::Relaxed) {
+            break;
+        }
+
         // Sleep interruptibly — wake immediately on Ctrl-C
         tokio::select! {
             _ = tokio::time::sleep(tokio::time::Duration::from_secs(interval)) => {}
@@ -0,0 +1,129 @@
+//! Meeting invitation rendering and `corky cal rsvp` tests (IV1–IV3).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::mail_import::run_mbox;
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+fn calendar(method: &str, sequence: u32) -> String {
+    format!(
+        "BEGIN:VCALENDAR\r\nPRODID:-//Example//Calendar//EN\r\nVERSION:2.0\r\nMETHOD:{method}\r\nBEGIN:VEVENT\r\nUID:budget-42@example.com\r\nSEQUENCE:{sequence}\r\nSUMMARY:Budget review\r\nDTSTART:20260310T140000Z\r\nDTEND:20260310T150000Z\r\nLOCATION:Room 4\r\nORGANIZER;CN=Alice:mailto:alice@example.com\r\nATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:me@example.com\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n"
+    )
+}
+
+/// An Outlook-style invite: text, inline text/calendar and the same .ics attached.
+fn invite_message(id: &str, date: &str, method: &str, sequence: u32) -> String {
+    let ics = calendar(method, sequence);
+    format!(
+        "From MAILER-DAEMON Mon Mar  2 10:00:00 2026\n\
+         From: Alice <alice@example.com>\nTo: me@example.com\nSubject: Budget review\nDate: {date}\n\
+         Message-ID: <{id}>\nMIME-Version: 1.0\nContent-Type: multipart/mixed; boundary=\"outer\"\n\n\
+         --outer\nContent-Type: multipart/alternative; boundary=\"inner\"\n\n\
+         --inner\nContent-Type: text/plain; charset=utf-8\n\nLet's go over the numbers.\n\n\
+         --inner\nContent-Type: text/calendar; charset=utf-8; method={method}\n\n{ics}\n\
+         --inner--\n\n\
+         --outer\nContent-Type: application/ics; name=\"invite.ics\"\nContent-Disposition: attachment; filename=\"invite.ics\"\n\n{ics}\n\
+         --outer--\n\n"
+    )
+}
+
+fn setup(messages: &[String]) -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let mbox = data.join("invites.mbox");
+    std::fs::write(&mbox, messages.concat()).unwrap();
+    run_mbox(&mbox, "inbox", &data.join("conversations"), "work").unwrap();
+    (tmp, data)
+}
+
+// IV1: a text/calendar part becomes one readable event block after the text
+#[test]
+fn iv1_invite_rendered_in_conversation() {
+    let (_tmp, data) = setup(&[invite_message("1@x", "Mon, 2 Mar 2026 10:00:00 +0000", "REQUEST", 0)]);
+    let text = std::fs::read_to_string(data.join("conversations/budget-review.md")).unwrap();
+    assert!(text.contains(
+        "Let's go over the numbers.\n\n\
+         [Invitation] Budget review\n\
+         When: 2026-03-10T14:00:00+00:00 to 2026-03-10T15:00:00+00:00\n\
+         Where: Room 4\n\
+         Organizer: Alice <alice@example.com>\n\
+         UID: budget-42@example.com\n"
+    ));
+    assert_eq!(text.matches("[Invitation]").count(), 1);
+    assert!(!text.contains("BEGIN:VCALENDAR"));
+}
+
+// IV2: rsvp writes a REPLY draft to the organizer with the iTIP calendar attached
+#[test]
+fn iv2_rsvp_scaffolds_reply_draft() {
+    let (_tmp, data) = setup(&[
+        invite_message("1@x", "Mon, 2 Mar 2026 10:00:00 +0000", "REQUEST", 0),
+        invite_message("2@x", "Tue, 3 Mar 2026 10:00:00 +0000", "REQUEST", 1),
+    ]);
+    let text = std::fs::read_to_string(data.join("conversations/budget-review.md")).unwrap();
+    assert!(text.contains("[Update] Budget review\n"));
+
+    let output = corky(&data).args(["cal", "rsvp", "budget-review", "tentative"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let draft = data.join(String::from_utf8(output.stdout).unwrap().trim());
+    assert!(draft.to_string_lossy().ends_with("-tentative-budget-review.md"), "{}", draft.display());
+
+    let content = std::fs::read_to_string(&draft).unwrap();
+    assert!(content.contains("to: Alice <alice@example.com>\n"));
+    assert!(content.contains("account: default\n"));
+    assert!(content.contains("status: review\n"));
+    assert!(content.contains("# Tentative: Budget review\n"));
+    assert!(content.contains("Test User has tentatively accepted this invitation."));
+    assert!(content.contains("rsvp-budget-42-example-com.ics"));
+
+    let ics = std::fs::read_to_string(data.join("drafts/rsvp-budget-42-example-com.ics")).unwrap();
+    assert!(ics.contains("METHOD:REPLY\r\n"));
+    assert!(ics.contains("UID:budget-42@example.com\r\nSEQUENCE:1\r\n"));
+    assert!(ics.contains("ATTENDEE;PARTSTAT=TENTATIVE;CN=Test User:mailto:me@example.com\r\n"));
+    assert!(ics.contains("ORGANIZER;CN=Alice:mailto:alice@example.com\r\n"));
+
+    corky(&data)
+        .args(["draft", "validate"])
+        .arg(&draft)
+        .assert()
+        .success();
+}
+
+// IV3: cancelled events, threads without invites and --add with decline are refused
+#[test]
+fn iv3_rsvp_errors() {
+    let (_tmp, data) = setup(&[
+        invite_message("1@x", "Mon, 2 Mar 2026 10:00:00 +0000", "REQUEST", 0),
+        invite_message("2@x", "Tue, 3 Mar 2026 10:00:00 +0000", "CANCEL", 1),
+    ]);
+    corky(&data)
+        .args(["cal", "rsvp", "budget-review", "accept"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'Budget review' was cancelled by the organizer"));
+
+    std::fs::write(
+        data.join("conversations/lunch.md"),
+        "# Lunch\n\n**Labels**: inbox\n**Accounts**: work\n**Thread ID**: lunch\n**Last updated**: Mon, 10 Feb 2025 10:00:00 +0000\n\n---\n\n## Bob <bob@example.com> \u{2014} Mon, 10 Feb 2025 10:00:00 +0000\n\nLunch friday?\n",
+    )
+    .unwrap();
+    corky(&data)
+        .args(["cal", "rsvp", "lunch", "accept"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No meeting invitation found"));
+    corky(&data)
+        .args(["cal", "rsvp", "lunch", "decline", "--add"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--add only applies to accept or tentative"));
+}
@@ -0,0 +1,234 @@
+//! CLI integration tests for `corky sync discord-import` and
+//! `corky sync signal-import` (CI1–CI6).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::markdown::parse_thread_markdown;
+use corky::sync::types::Thread;
+
+const DISCORD: &str = r#"{
+  "guild": {"id": "1", "name": "Makers"},
+  "channel": {"id": "100", "type": "GuildTextChat", "category": "Text Channels", "name": "general"},
+  "messages": [
+    {"id": "1001", "type": "Default", "timestamp": "2024-01-15T10:00:00.000+00:00", "content": "Who has the venue contract?",
+     "author": {"id": "7", "name": "alice", "nickname": "Alice"}, "attachments": [], "mentions": []},
+    {"id": "1002", "type": "GuildMemberJoin", "timestamp": "2024-01-15T10:01:00.000+00:00", "content": "",
+     "author": {"id": "9", "name": "carol"}},
+    {"id": "1003", "type": "Reply", "timestamp": "2024-01-15T10:02:00.000+00:00", "content": "<@7> I do, attaching it",
+     "author": {"id": "8", "name": "bob", "nickname": null},
+     "attachments": [{"id": "5", "url": "https://cdn.example/contract.pdf", "fileName": "contract.pdf"}],
+     "mentions": [{"id": "7", "name": "alice", "nickname": "Alice"}],
+     "reference": {"messageId": "1001", "channelId": "100", "guildId": "1"}},
+    {"id": "1004", "type": "Default", "timestamp": "2024-01-15T11:00:00.000+00:00", "content": "Unrelated: lunch?",
+     "author": {"id": "8", "name": "bob"}}
+  ]
+}"#;
+
+const SIGNAL_CLI: &str = r#"{"envelope":{"sourceNumber":"+15550001","sourceName":"Alice","timestamp":1705312800000,"dataMessage":{"timestamp":1705312800000,"message":"Lunch?","groupInfo":{"groupId":"g1==","groupName":"Team"}}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15559999","timestamp":1705312860000,"syncMessage":{"sentMessage":{"timestamp":1705312860000,"message":"Sure","groupInfo":{"groupId":"g1=="},"quote":{"authorNumber":"+15550001","text":"Lunch?"}}}},"account":"+15559999"}
+{"envelope":{"sourceNumber":"+15550001","timestamp":1705312870000,"typingMessage":{"action":"STARTED"}},"account":"+15559999"}
+"#;
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+/// All conversation threads, sorted by thread ID.
+fn threads(data: &Path) -> Vec<Thread> {
+    let mut threads: Vec<Thread> = std::fs::read_dir(data.join("conversations"))
+        .unwrap()
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|x| x == "md"))
+        .filter_map(|e| parse_thread_markdown(&std::fs::read_to_string(e.path()).unwrap()))
+        .collect();
+    threads.sort_by(|a, b| a.id.cmp(&b.id));
+    threads
+}
+
+// CI1: Discord replies join their parent's thread; system messages are skipped
+#[test]
+fn ci1_discord_reply_grouping() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("Makers - general [100].json");
+    std::fs::write(&path, DISCORD).unwrap();
+
+    corky(&data)
+        .args(["sync", "discord-import", &path.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "3 message(s) imported, 0 duplicate(s) skipped, 1 system message(s) skipped",
+        ));
+
+    let threads = threads(&data);
+    assert_eq!(threads.len(), 2);
+    let contract = &threads[0];
+    assert_eq!(contract.id, "discord:100:1001");
+    assert_eq!(contract.subject, "Who has the venue contract?");
+    assert_eq!(contract.labels, vec!["discord:general"]);
+    assert_eq!(contract.messages.len(), 2);
+    assert_eq!(contract.messages[0].from, "Alice");
+    assert_eq!(contract.messages[1].from, "bob");
+    assert_eq!(contract.messages[1].body, "@Alice I do, attaching it\n[Attachment: contract.pdf]");
+    assert_eq!(threads[1].id, "discord:100:1004");
+}
+
+// CI2: Discord thread exports become one thread; re-import is a no-op
+#[test]
+fn ci2_discord_thread_export_and_reimport() {
+    let (_tmp, data) = common::temp_data_dir();
+    let dir = data.join("discord");
+    std::fs::create_dir_all(&dir).unwrap();
+    let thread_export = DISCORD
+        .replace(r#""type": "GuildTextChat", "category": "Text Channels", "name": "general""#,
+                 r#""type": "GuildPublicThread", "category": "general", "name": "Venue planning""#);
+    std::fs::write(dir.join("thread.json"), thread_export).unwrap();
+
+    corky(&data)
+        .args(["sync", "discord-import", &dir.to_string_lossy()])
+        .assert()
+        .success();
+    let threads_after = threads(&data);
+    assert_eq!(threads_after.len(), 1);
+    assert_eq!(threads_after[0].id, "discord:100");
+    assert_eq!(threads_after[0].subject, "Venue planning");
+    assert_eq!(threads_after[0].labels, vec!["discord:general"]);
+    assert_eq!(threads_after[0].messages.len(), 3);
+
+    corky(&data)
+        .args(["sync", "discord-import", &dir.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 message(s) imported, 3 duplicate(s) skipped"));
+}
+
+// CI3: signal-cli JSON lines → one thread per group, with sender names and quotes
+#[test]
+fn ci3_signal_cli() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("signal.jsonl");
+    std::fs::write(&path, SIGNAL_CLI).unwrap();
+
+    corky(&data)
+        .args(["sync", "signal-import", &path.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 message(s) imported, 0 duplicate(s) skipped, 1 system message(s) skipped",
+        ));
+
+    let threads = threads(&data);
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].id, "signal:group:g1==");
+    assert_eq!(threads[0].subject, "Team");
+    assert_eq!(threads[0].labels, vec!["signal"]);
+    assert_eq!(threads[0].messages[0].from, "Alice");
+    assert_eq!(threads[0].messages[1].from, "Me");
+    assert_eq!(threads[0].messages[1].body, "> Alice: Lunch?\n\nSure");
+}
+
+// CI4: Signal Desktop JSON; same-second messages are kept apart
+#[test]
+fn ci4_signal_desktop() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("signal-desktop.json");
+    std::fs::write(
+        &path,
+        r#"{
+            "conversations": [{"id": "c1", "type": "private", "e164": "+15550001", "name": "Alice Smith"}],
+            "messages": [
+                {"conversationId": "c1", "type": "incoming", "source": "+15550001", "body": "one", "sent_at": 1705312800100},
+                {"conversationId": "c1", "type": "incoming", "source": "+15550001", "body": "two", "sent_at": 1705312800900},
+                {"conversationId": "c1", "type": "keychange", "sent_at": 1705312801000}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    corky(&data)
+        .args(["sync", "signal-import", &path.to_string_lossy(), "--account", "phone"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 message(s) imported"));
+
+    let threads = threads(&data);
+    assert_eq!(threads[0].id, "signal:+15550001");
+    assert_eq!(threads[0].subject, "Alice Smith");
+    assert_eq!(threads[0].accounts, vec!["phone"]);
+    assert_eq!(threads[0].messages.len(), 2);
+    assert_eq!(threads[0].messages[0].from, "Alice Smith");
+    assert_eq!(threads[0].messages[1].body, "two");
+}
+
+// CI5: Unreadable or unrecognized inputs fail
+#[test]
+fn ci5_errors() {
+    let (_tmp, data) = common::temp_data_dir();
+    corky(&data)
+        .args(["sync", "discord-import", "/tmp/nonexistent-discord.json"])
+        .assert()
+        .failure();
+
+    let path = data.join("other.json");
+    std::fs::write(&path, r#"{"hello": "world"}"#).unwrap();
+    corky(&data)
+        .args(["sync", "discord-import", &path.to_string_lossy()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Not a DiscordChatExporter JSON export"));
+    corky(&data)
+        .args(["sync", "signal-import", &path.to_string_lossy()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not signal-cli JSON or a Signal Desktop export"));
+}
+
+// CI6: Imports regenerate the manifest, linking threads by phone number and chat handle
+#[test]
+fn ci6_manifest_links_phones_and_handles() {
+    let (_tmp, data) = common::temp_data_dir();
+    std::fs::write(
+        data.join(".corky.toml"),
+        "[contacts.alice]\nphones = [\"555-0001\"]\n\n[contacts.bob]\nemails = [\"bob@example.com\"]\nhandles = { discord = \"@Bob\" }\n",
+    )
+    .unwrap();
+    let signal = data.join("signal-desktop.json");
+    std::fs::write(
+        &signal,
+        r#"{
+            "conversations": [{"id": "c1", "type": "private", "e164": "+15550001", "name": "Ally"}],
+            "messages": [{"conversationId": "c1", "type": "incoming", "source": "+15550001", "body": "hi", "sent_at": 1705312800100}]
+        }"#,
+    )
+    .unwrap();
+    let discord = data.join("discord.json");
+    std::fs::write(&discord, DISCORD).unwrap();
+
+    corky(&data).args(["sync", "signal-import", &signal.to_string_lossy()]).assert().success();
+    corky(&data)
+        .args(["sync", "discord-import", &discord.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Generated"));
+
+    let manifest: toml::Value = toml::from_str(&std::fs::read_to_string(data.join("manifest.toml")).unwrap()).unwrap();
+    let contacts_of = |thread_id: &str| {
+        let thread = manifest["threads"]
+            .as_table()
+            .unwrap()
+            .values()
+            .find(|t| t["thread_id"].as_str() == Some(thread_id))
+            .unwrap();
+        thread["contacts"].as_array().unwrap().iter().map(|c| c.as_str().unwrap().to_string()).collect::<Vec<_>>()
+    };
+    // Signal knows alice by a display name she has no alias for, but her number matches
+    assert_eq!(contacts_of("signal:+15550001"), vec!["alice"]);
+    assert_eq!(contacts_of("discord:100:1001"), vec!["bob"]);
+    assert_eq!(contacts_of("discord:100:1004"), vec!["bob"]);
+}
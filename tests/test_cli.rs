@@ -209,3 +209,127 @@ labels = ["inbox"]
     cmd.args(["sync", "account", "nonexistent"]);
     cmd.assert().failure();
 }
+
+/// Create `mail/` with one unanswered thread and one topic under `project_dir`.
+fn write_format_fixture(project_dir: &std::path::Path) {
+    let data_dir = project_dir.join("mail");
+    std::fs::create_dir_all(data_dir.join("conversations")).unwrap();
+    std::fs::write(
+        data_dir.join("conversations/hello.md"),
+        "# Hello\n\n\
+         **Labels**: inbox, work\n\
+         **Accounts**: personal\n\
+         **Thread ID**: hello\n\
+         **Last updated**: Mon, 10 Feb 2025 10:00:00 +0000\n\n\
+         ---\n\n\
+         ## Alice <alice@example.com> \u{2014} Mon, 10 Feb 2025 10:00:00 +0000\n\n\
+         Hello there!\n",
+    )
+    .unwrap();
+    std::fs::write(
+        data_dir.join(".corky.toml"),
+        "[owner]\ngithub_user = \"testuser\"\nname = \"Test User\"\n\n\
+         [topics.launch]\n\
+         description = \"Product launch\"\n\
+         keywords = [\"launch\"]\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cli_format_json_unanswered() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    write_format_fixture(tmp.path());
+
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["unanswered", ".", "--format", "json"]);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        v,
+        serde_json::json!([{
+            "scope": "Root",
+            "file": "hello.md",
+            "labels": ["inbox", "work"],
+            "last_from": "Alice <alice@example.com>",
+            "last_updated": "Mon, 10 Feb 2025 10:00:00 +0000"
+        }])
+    );
+}
+
+#[test]
+fn test_cli_format_jsonl_topics_list() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    write_format_fixture(tmp.path());
+
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["--format", "jsonl", "topics", "list"]);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let v: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(v["name"], "launch");
+    assert_eq!(v["description"], "Product launch");
+    assert_eq!(v["keywords"], serde_json::json!(["launch"]));
+}
+
+#[test]
+fn test_cli_format_table_is_default() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    write_format_fixture(tmp.path());
+
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["topics", "list"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("launch — Product launch"));
+}
+
+#[test]
+fn test_cli_format_rejects_unknown() {
+    let mut cmd = corky_cmd();
+    cmd.args(["unanswered", "--format", "xml"]);
+    cmd.assert().failure();
+}
+
+#[test]
+fn test_cli_format_json_search() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    write_format_fixture(tmp.path());
+
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["search", "hello", "--format", "json"]);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let v: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(v.as_array().unwrap().len(), 1);
+    assert_eq!(v[0]["path"], "conversations/hello.md");
+    assert_eq!(v[0]["kind"], "conversation");
+    assert_eq!(v[0]["subject"], "Hello");
+}
+
+#[test]
+fn test_cli_doc_build_type_is_to() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["doc", "build", "missing.md", "--to", "docx"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Input file not found: missing.md"));
+
+    // --format stays the global output format
+    let mut cmd = corky_cmd();
+    cmd.current_dir(tmp.path());
+    cmd.args(["doc", "build", "missing.md", "--format", "docx"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'docx' for '--format <FORMAT>'"));
+}
@@ -178,6 +178,41 @@ fn test_save_and_reload_contacts() {
     assert_eq!(bob.emails, vec!["bob@work.com"]);
 }
 
+#[test]
+fn test_save_and_reload_typed_fields() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join(".corky.toml");
+    std::fs::write(&path, "").unwrap();
+
+    let mut kenji = Contact {
+        emails: vec!["kenji@example.jp".to_string()],
+        title: "Head of Design".to_string(),
+        timezone: "Asia/Tokyo".to_string(),
+        language: "ja".to_string(),
+        profile: "kenji".to_string(),
+        ..Default::default()
+    };
+    kenji.handles.insert("github".to_string(), "kenji".to_string());
+    kenji.dates.insert("birthday".to_string(), "04-12".to_string());
+
+    contact::save_contact("kenji", &kenji, Some(&path)).unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("handles = { github = \"kenji\" }"));
+    assert!(content.contains("dates = { birthday = \"04-12\" }"));
+
+    let reloaded = contact::load_contacts(Some(&path)).unwrap();
+    assert_eq!(reloaded["kenji"], kenji);
+    assert_eq!(reloaded["kenji"].tz(), Some(chrono_tz::Asia::Tokyo));
+    assert!(reloaded["kenji"].field_errors().is_empty());
+
+    let bad = Contact {
+        timezone: "Tokyo".to_string(),
+        dates: [("birthday".to_string(), "April 12".to_string())].into(),
+        ..Default::default()
+    };
+    assert_eq!(bad.field_errors().len(), 2);
+}
+
 #[test]
 fn test_load_contacts_no_contacts_section() {
     let tmp = TempDir::new().unwrap();
@@ -0,0 +1,170 @@
+//! CLI integration tests for `corky contact discover` (CD1–CD4).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use chrono::{Duration, Utc};
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::config::contact::load_contacts;
+use corky::sync::markdown::thread_to_markdown;
+use corky::sync::types::{Message, Thread};
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+/// (from, to, cc, days ago)
+type Msg<'a> = (&'a str, &'a str, &'a str, i64);
+
+fn write_thread(dir: &Path, id: &str, subject: &str, messages: &[Msg]) {
+    let messages: Vec<Message> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, (from, to, cc, days))| Message {
+            id: format!("{}-{}", id, i),
+            thread_id: id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            cc: cc.to_string(),
+            date: (Utc::now() - Duration::days(*days) + Duration::minutes(i as i64)).to_rfc2822(),
+            subject: subject.to_string(),
+            body: "Hello".to_string(),
+            raw: String::new(),
+        })
+        .collect();
+    let thread = Thread {
+        id: id.to_string(),
+        subject: subject.to_string(),
+        labels: vec!["inbox".to_string()],
+        accounts: vec!["personal".to_string()],
+        last_date: messages.last().unwrap().date.clone(),
+        messages,
+        ..Default::default()
+    };
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(dir.join(format!("{}.md", id)), thread_to_markdown(&thread)).unwrap();
+}
+
+const ME: &str = "Me <me@example.com>";
+const ALICE: &str = "Alice Smith <alice@example.com>";
+
+/// Mail with alice (2 two-way threads), bob (CC'd, one message), eve (old,
+/// never answered), dave (one message), a newsletter and a known contact.
+fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let mut config = std::fs::read_to_string(data.join(".corky.toml")).unwrap();
+    config.push_str("\n[contacts.carol]\nemails = [\"carol@example.com\"]\n");
+    std::fs::write(data.join(".corky.toml"), config).unwrap();
+
+    let conv = data.join("conversations");
+    write_thread(&conv, "venue-contract", "Venue contract", &[
+        (ALICE, ME, "", 3),
+        (ME, ALICE, "Bob <bob@example.com>", 3),
+        ("Bob <bob@example.com>", "me@example.com, alice@example.com", "", 2),
+    ]);
+    write_thread(&conv, "budget", "Budget", &[(ALICE, ME, "", 1), (ME, ALICE, "", 1)]);
+    // A routed copy in a mailbox is not counted twice
+    write_thread(&data.join("mailboxes/team/conversations"), "budget", "Budget", &[(ALICE, ME, "", 1), (ME, ALICE, "", 1)]);
+    write_thread(&conv, "old-news", "Old news", &[
+        ("Eve <eve@example.com>", ME, "", 400),
+        ("Eve <eve@example.com>", ME, "", 400),
+        ("Eve <eve@example.com>", ME, "", 400),
+    ]);
+    write_thread(&conv, "hello", "Hello", &[("Dave <dave@example.com>", ME, "", 1)]);
+    write_thread(&conv, "weekly", "Weekly digest", &[
+        ("News <noreply@news.example>", ME, "", 1),
+        ("News <noreply@news.example>", ME, "", 2),
+    ]);
+    write_thread(&conv, "carol", "Catch up", &[("Carol <carol@example.com>", ME, "", 1), (ME, "carol@example.com", "", 1)]);
+    (tmp, data)
+}
+
+// CD1: Candidates are ranked by volume, reply ratio and recency; known, automated and rare senders are left out
+#[test]
+fn cd1_ranking_json() {
+    let (_tmp, data) = setup();
+    let output = corky(&data)
+        .args(["contact", "discover", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let candidates: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let names: Vec<&str> = candidates.iter().map(|c| c["suggested_name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["alice-smith", "bob", "eve"]);
+
+    let alice = &candidates[0];
+    assert_eq!(alice["rank"], 1);
+    assert_eq!(alice["display_name"], "Alice Smith");
+    assert_eq!(alice["emails"], serde_json::json!(["alice@example.com"]));
+    assert_eq!(alice["messages_from"], 2);
+    assert_eq!(alice["messages_to"], 2);
+    assert_eq!(alice["threads"], 2);
+    assert_eq!(alice["reply_ratio"], 1.0);
+    assert!(candidates[2]["score"].as_f64().unwrap() < 0.5, "old mail decays");
+}
+
+// CD2: Table output numbers candidates and honors --limit
+#[test]
+fn cd2_table_and_limit() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "discover", "--limit", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  1. alice-smith  Alice Smith <alice@example.com>"))
+        .stdout(predicate::str::contains("4 message(s) (2 from, 2 to), 2 thread(s), 100% replied"))
+        .stdout(predicate::str::contains("bob").not())
+        .stdout(predicate::str::contains("Create with: corky contact discover --create"));
+}
+
+// CD3: Accepted candidates are created with enriched AGENTS.md and drop out of the ranking
+#[test]
+fn cd3_create_selected() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "discover", "--create", "1", "--create", "bob@example.com=bob-jones"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created 2 contact(s)."));
+
+    let contacts = load_contacts(Some(&data.join(".corky.toml"))).unwrap();
+    assert_eq!(contacts["alice-smith"].emails, vec!["alice@example.com"]);
+    assert_eq!(contacts["bob-jones"].emails, vec!["bob@example.com"]);
+    let agents_md = std::fs::read_to_string(data.join("contacts/alice-smith/AGENTS.md")).unwrap();
+    assert!(agents_md.contains("- Budget (from conversation)"));
+    assert!(agents_md.contains("- Venue contract (from conversation)"));
+    assert!(agents_md.contains("Shared threads with: bob"));
+    assert!(agents_md.contains("Email domain: example.com"));
+
+    corky(&data)
+        .args(["contact", "discover"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("eve"))
+        .stdout(predicate::str::contains("alice").not());
+}
+
+// CD4: --all creates the listed candidates; unknown selectors fail
+#[test]
+fn cd4_all_and_unknown_selector() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "discover", "--create", "zed@example.com"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No discovered correspondent matches 'zed@example.com'"));
+
+    corky(&data)
+        .args(["contact", "discover", "--all", "--limit", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created 2 contact(s)."));
+    let contacts = load_contacts(Some(&data.join(".corky.toml"))).unwrap();
+    assert!(contacts.contains_key("alice-smith") && contacts.contains_key("bob"));
+    assert!(!contacts.contains_key("eve"));
+}
@@ -0,0 +1,112 @@
+//! CLI integration tests for `corky contact info` relationship stats (CI1–CI2).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use chrono::{Duration, Utc};
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::markdown::thread_to_markdown;
+use corky::sync::types::{Message, Thread};
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+const ME: &str = "Me <me@example.com>";
+const ALICE: &str = "Alice <alice@example.com>";
+
+/// (from, to, hours ago)
+fn write_thread(dir: &Path, id: &str, subject: &str, messages: &[(&str, &str, i64)]) {
+    let messages: Vec<Message> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, (from, to, hours))| Message {
+            id: format!("{}-{}", id, i),
+            thread_id: id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            cc: String::new(),
+            date: (Utc::now() - Duration::hours(*hours)).to_rfc2822(),
+            subject: subject.to_string(),
+            body: "Hello".to_string(),
+            raw: String::new(),
+        })
+        .collect();
+    let thread = Thread {
+        id: id.to_string(),
+        subject: subject.to_string(),
+        labels: vec!["inbox".to_string()],
+        accounts: vec!["personal".to_string()],
+        last_date: messages.last().unwrap().date.clone(),
+        messages,
+        ..Default::default()
+    };
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(dir.join(format!("{}.md", id)), thread_to_markdown(&thread)).unwrap();
+}
+
+/// alice: one answered thread (her reply after 10h, mine after 2h) and one
+/// awaiting my reply (hers after 30h), with a routed mailbox copy of the latter.
+fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let mut config = std::fs::read_to_string(data.join(".corky.toml")).unwrap();
+    config.push_str("\n[contacts.alice]\nemails = [\"alice@example.com\"]\n");
+    config.push_str("\n[topics.finance]\nkeywords = [\"budget\"]\n");
+    std::fs::write(data.join(".corky.toml"), config).unwrap();
+
+    let conv = data.join("conversations");
+    write_thread(&conv, "budget", "Budget", &[(ME, "alice@example.com", 102), (ALICE, "me@example.com", 92), (ME, "alice@example.com", 90)]);
+    write_thread(&conv, "venue", "Venue", &[(ME, "alice@example.com", 80), (ALICE, "me@example.com", 50)]);
+    write_thread(&data.join("mailboxes/events/conversations"), "venue", "Venue", &[(ME, "alice@example.com", 80), (ALICE, "me@example.com", 50)]);
+
+    let manifest = "[threads.budget]\nsubject = \"Budget\"\ncontacts = [\"alice\"]\nlast_updated = \"\"\n\n\
+                    [threads.venue]\nsubject = \"Venue\"\ncontacts = [\"alice\"]\nlast_updated = \"\"\n";
+    std::fs::write(data.join("manifest.toml"), manifest).unwrap();
+    std::fs::write(data.join("mailboxes/events/manifest.toml"), manifest.split("\n\n").nth(1).unwrap()).unwrap();
+    (tmp, data)
+}
+
+// CI1: JSON stats count each thread once and report reply times, owed threads and topics
+#[test]
+fn ci1_stats_json() {
+    let (_tmp, data) = setup();
+    let output = corky(&data)
+        .args(["contact", "info", "alice", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(info["threads"].as_array().unwrap().len(), 3);
+    let stats = &info["stats"];
+    assert_eq!(stats["messages_sent"], 3);
+    assert_eq!(stats["messages_received"], 2);
+    assert_eq!(stats["my_median_reply_hours"], 2.0);
+    assert_eq!(stats["their_median_reply_hours"], 20.0);
+    assert!(stats["first_contact"].is_string());
+    let total: u64 = stats["timeline"].as_array().unwrap().iter().map(|m| m["received"].as_u64().unwrap()).sum();
+    assert_eq!(total, 2);
+    let owed = stats["owed_replies"].as_array().unwrap();
+    assert_eq!(owed.len(), 1);
+    assert_eq!(owed[0]["slug"], "venue");
+    assert_eq!(owed[0]["days_waiting"], 2);
+    assert_eq!(stats["topics"], serde_json::json!([{"name": "finance", "linked": false, "threads": 1}]));
+}
+
+// CI2: The table report shows the stats after the thread list
+#[test]
+fn ci2_stats_table() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "info", "alice"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Messages: 3 sent, 2 received"))
+        .stdout(predicate::str::contains("Median reply time: you 2.0h, them 20.0h"))
+        .stdout(predicate::str::contains("--- Awaiting your reply (1) ---"))
+        .stdout(predicate::str::contains("Topics: finance (1 thread(s))"));
+}
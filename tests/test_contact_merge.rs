@@ -0,0 +1,180 @@
+//! CLI integration tests for `corky contact rename` and `corky contact merge` (CM1–CM4).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::config::contact::{load_contacts, save_contact};
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+/// Same FNV-1a hash contact sync records in .sync-state.json.
+fn fnv1a(content: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x00000100000001B3);
+    }
+    format!("{:016x}", hash)
+}
+
+fn read(path: impl AsRef<Path>) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn state(data: &Path) -> serde_json::Value {
+    serde_json::from_str(&read(data.join(".sync-state.json"))).unwrap()
+}
+
+fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    corky(&data).args(["contact", "add", "alice", "--email", "alice@example.com"]).assert().success();
+    corky(&data).args(["contact", "add", "alice-work", "--email", "alice@work.com"]).assert().success();
+    (tmp, data)
+}
+
+// CM1: Rename moves config, topic references, root and mailbox directories, and carries sync state
+#[test]
+fn cm1_rename() {
+    let (_tmp, data) = setup();
+    let config_path = data.join(".corky.toml");
+    let mut config = read(&config_path);
+    config.push_str("\n[topics.conference]\nkeywords = [\"rustconf\"]\ncontacts = [\"alice\", \"bob\"]\n");
+    std::fs::write(&config_path, config).unwrap();
+    let mut alice = load_contacts(Some(&config_path)).unwrap()["alice"].clone();
+    alice.shared_with = vec!["team".to_string()];
+    save_contact("alice", &alice, Some(&config_path)).unwrap();
+
+    // Last sync left both sides equal; since then only the mailbox copy was
+    // edited, but the root file has the newer mtime.
+    let root_md = data.join("contacts/alice/AGENTS.md");
+    let base = read(&root_md);
+    let mb_dir = data.join("mailboxes/team/contacts/alice");
+    std::fs::create_dir_all(&mb_dir).unwrap();
+    std::fs::write(mb_dir.join("CLAUDE.md"), format!("{}\n- Prefers calls (from team)\n", base)).unwrap();
+    let old = filetime::FileTime::from_unix_time(1_600_000_000, 0);
+    filetime::set_file_mtime(mb_dir.join("CLAUDE.md"), old).unwrap();
+    let state_json = serde_json::json!({"contacts": {"alice": {"mailboxes": {"team": fnv1a(&base)}}}});
+    std::fs::write(data.join(".sync-state.json"), state_json.to_string()).unwrap();
+
+    corky(&data)
+        .args(["contact", "rename", "alice", "alice-smith"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Done. Contact 'alice' renamed to 'alice-smith'."));
+
+    let contacts = load_contacts(Some(&config_path)).unwrap();
+    assert!(!contacts.contains_key("alice"));
+    assert_eq!(contacts["alice-smith"].emails, vec!["alice@example.com"]);
+    assert_eq!(contacts["alice-smith"].aliases, vec!["alice"]);
+    assert_eq!(contacts["alice-smith"].shared_with, vec!["team"]);
+    assert!(read(&config_path).contains("contacts = [\"alice-smith\", \"bob\"]"));
+
+    assert!(!data.join("contacts/alice").exists());
+    assert!(!data.join("mailboxes/team/contacts/alice").exists());
+    let root = read(data.join("contacts/alice-smith/CLAUDE.md"));
+    assert!(root.starts_with("# Contact: alice-smith\n"));
+    let mb = read(data.join("mailboxes/team/contacts/alice-smith/CLAUDE.md"));
+    assert!(mb.starts_with("# Contact: alice-smith\n"));
+    assert!(state(&data)["contacts"].get("alice").is_none());
+
+    // The carried base still says "root unchanged", so the mailbox edit wins
+    corky(&data).args(["contact", "sync"]).assert().success();
+    assert!(read(data.join("contacts/alice-smith/AGENTS.md")).contains("- Prefers calls (from team)"));
+}
+
+// CM2: Rename refuses unknown contacts and existing targets
+#[test]
+fn cm2_rename_errors() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "rename", "zed", "zed-2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Contact 'zed' not found"));
+    corky(&data)
+        .args(["contact", "rename", "alice", "alice-work"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use 'corky contact merge alice alice-work'"));
+    assert!(data.join("contacts/alice").is_dir());
+}
+
+// CM3: Merge unions config fields, merges AGENTS.md sections and folds directories together
+#[test]
+fn cm3_merge() {
+    let (_tmp, data) = setup();
+    let config_path = data.join(".corky.toml");
+    let mut work = load_contacts(Some(&config_path)).unwrap()["alice-work"].clone();
+    work.aliases = vec!["Alice at Acme".to_string()];
+    work.full_name = "Alice Smith".to_string();
+    work.phones = vec!["+1 555 0100".to_string()];
+    save_contact("alice-work", &work, Some(&config_path)).unwrap();
+
+    let work_md = data.join("contacts/alice-work/AGENTS.md");
+    let content = read(&work_md).replace("## Topics\n", "## Topics\n\n- Acme contract\n");
+    std::fs::write(&work_md, content).unwrap();
+    std::fs::write(data.join("contacts/alice-work/notes.txt"), "call notes").unwrap();
+
+    // "team" has both contacts, "ops" only the one being merged away
+    let team = data.join("mailboxes/team/contacts");
+    let ops = data.join("mailboxes/ops/contacts");
+    for (dir, body) in [(team.join("alice"), "# Contact: alice\n\n## Topics\n\n- Budget\n"), (team.join("alice-work"), "# Contact: alice-work\n\n## Topics\n\n- Acme contract\n"), (ops.join("alice-work"), "# Contact: alice-work\n\n## Topics\n\n- Acme contract\n")] {
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("CLAUDE.md"), body).unwrap();
+    }
+    let state_json = serde_json::json!({"contacts": {"alice-work": {"mailboxes": {"ops": "0000000000000000"}}}});
+    std::fs::write(data.join(".sync-state.json"), state_json.to_string()).unwrap();
+
+    corky(&data)
+        .args(["contact", "merge", "alice-work", "alice"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Done. Contact 'alice-work' merged into 'alice'."));
+
+    let contacts = load_contacts(Some(&config_path)).unwrap();
+    assert!(!contacts.contains_key("alice-work"));
+    let alice = &contacts["alice"];
+    assert_eq!(alice.emails, vec!["alice@example.com", "alice@work.com"]);
+    assert_eq!(alice.aliases, vec!["Alice at Acme", "alice-work"]);
+    assert_eq!(alice.full_name, "Alice Smith");
+    assert_eq!(alice.phones, vec!["+1 555 0100"]);
+
+    assert!(!data.join("contacts/alice-work").exists());
+    let root = read(data.join("contacts/alice/AGENTS.md"));
+    assert!(root.starts_with("# Contact: alice\n"));
+    assert!(root.contains("- Acme contract"));
+    assert_eq!(read(data.join("contacts/alice/notes.txt")), "call notes");
+
+    assert_eq!(read(team.join("alice/CLAUDE.md")), "# Contact: alice\n\n## Topics\n\n- Budget\n- Acme contract\n");
+    assert!(!team.join("alice-work").exists());
+    assert!(read(ops.join("alice/CLAUDE.md")).starts_with("# Contact: alice\n"));
+
+    let state = state(&data);
+    assert!(state["contacts"].get("alice-work").is_none());
+    assert_eq!(state["contacts"]["alice"]["mailboxes"]["ops"], "0000000000000000");
+}
+
+// CM4: Merge refuses a contact into itself and unknown contacts
+#[test]
+fn cm4_merge_errors() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["contact", "merge", "alice", "alice"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot merge a contact into itself"));
+    corky(&data)
+        .args(["contact", "merge", "alice", "zed"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Contact 'zed' not found"));
+    assert!(load_contacts(Some(&data.join(".corky.toml"))).unwrap().contains_key("alice"));
+}
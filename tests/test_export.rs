@@ -0,0 +1,178 @@
+//! Conversation export tests (EX1–EX6).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::Path;
+
+use corky::sync::mail_import::run_mbox;
+
+fn write_thread(dir: &Path, slug: &str, subject: &str, labels: &str, messages: &[(&str, &str, &str)]) {
+    let last = messages.last().map(|m| m.1).unwrap_or_default();
+    let mut md = format!(
+        "# {subject}\n\n**Labels**: {labels}\n**Accounts**: personal\n**Thread ID**: {}\n**Last updated**: {last}\n\n",
+        subject.to_lowercase()
+    );
+    for (from, date, body) in messages {
+        md.push_str(&format!("---\n\n## {from} \u{2014} {date}\n\n{body}\n\n"));
+    }
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(dir.join(format!("{slug}.md")), md).unwrap();
+}
+
+fn fixture() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    let conv = data.join("conversations");
+    write_thread(
+        &conv,
+        "budget",
+        "Budget",
+        "work",
+        &[
+            ("Alice <alice@example.com>", "Mon, 10 Feb 2025 10:00:00 +0000", "Here is the budget.\nFrom now on, use v2."),
+            ("Test User <me@example.com>", "Wed, 12 Mar 2025 09:00:00 +0000", "Thanks!"),
+        ],
+    );
+    write_thread(
+        &conv,
+        "lunch",
+        "Lunch",
+        "personal",
+        &[("Bob <bob@example.com>", "Tue, 11 Feb 2025 12:00:00 +0000", "Friday?")],
+    );
+    write_thread(
+        &data.join("mailboxes").join("acme").join("conversations"),
+        "contract",
+        "Contract",
+        "work",
+        &[("Carol <carol@acme.com>", "Tue, 04 Mar 2025 09:00:00 +0000", "Please sign.")],
+    );
+    (tmp, data)
+}
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+fn manifest(path: &Path) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+// EX1: mbox export of everything, with a manifest and mboxrd quoting
+#[test]
+fn ex1_mbox_all() {
+    let (_tmp, data) = fixture();
+    let out = data.join("export").join("all.mbox");
+    corky(&data)
+        .args(["export", &out.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 4 message(s) from 3 thread(s)"));
+
+    let mbox = std::fs::read_to_string(&out).unwrap();
+    assert_eq!(mbox.matches("\n\nFrom ").count() + 1, 4);
+    assert!(mbox.starts_with("From alice@example.com Mon Feb 10 10:00:00 2025\n"));
+    assert!(mbox.contains("\n>From now on, use v2.\n"));
+    assert!(mbox.contains("X-Corky-Labels: work\n"));
+
+    let m = manifest(&data.join("export").join("all.manifest.json"));
+    assert_eq!(m["format"], "mbox");
+    assert_eq!(m["messages"], 4);
+    assert_eq!(m["items"][0]["source"], "conversations/budget.md");
+    assert_eq!(m["items"][3]["source"], "mailboxes/acme/conversations/contract.md");
+}
+
+// EX2: Exported mbox re-imports into the same threads
+#[test]
+fn ex2_round_trip() {
+    let (_tmp, data) = fixture();
+    let out = data.join("all.mbox");
+    corky(&data).args(["export", &out.to_string_lossy(), "--scope", "."]).assert().success();
+
+    let reimport = data.join("reimport");
+    let stats = run_mbox(&out, "work", &reimport, "personal").unwrap();
+    assert_eq!(stats.imported, 3);
+    let budget = std::fs::read_to_string(reimport.join("budget.md")).unwrap();
+    assert!(budget.contains("Thanks!"));
+    assert!(budget.contains("From now on, use v2."));
+}
+
+// EX3: .eml directory with CRLF messages threaded by In-Reply-To
+#[test]
+fn ex3_eml_with_label() {
+    let (_tmp, data) = fixture();
+    let out = data.join("eml");
+    corky(&data)
+        .args(["export", &out.to_string_lossy(), "--type", "eml", "--label", "WORK", "--scope", "."])
+        .assert()
+        .success();
+
+    let m = manifest(&out.join("manifest.json"));
+    assert_eq!(m["messages"], 2);
+    assert_eq!(m["selection"]["labels"][0], "WORK");
+    let first = m["items"][0]["message_id"].as_str().unwrap();
+    let second = std::fs::read_to_string(out.join("00002-budget.eml")).unwrap();
+    assert!(second.contains(&format!("In-Reply-To: {}\r\n", first)));
+    assert!(second.contains("\r\n\r\nThanks!\r\n"));
+}
+
+// EX4: Date range filters messages; contact and search filter threads
+#[test]
+fn ex4_filters() {
+    let (_tmp, data) = fixture();
+    std::fs::write(
+        data.join(".corky.toml"),
+        "[contacts.bob]\nemails = [\"bob@example.com\"]\n",
+    )
+    .unwrap();
+
+    corky(&data)
+        .args(["export", "march.mbox", "--since", "2025-03-01", "--until", "2025-03-12"])
+        .assert()
+        .success();
+    let m = manifest(&data.join("march.manifest.json"));
+    let dates: Vec<&str> = m["items"].as_array().unwrap().iter().map(|i| i["date"].as_str().unwrap()).collect();
+    assert_eq!(dates, vec!["Wed, 12 Mar 2025 09:00:00 +0000", "Tue, 04 Mar 2025 09:00:00 +0000"]);
+
+    corky(&data).args(["export", "bob.mbox", "--contact", "bob"]).assert().success();
+    assert_eq!(manifest(&data.join("bob.manifest.json"))["items"][0]["source"], "conversations/lunch.md");
+
+    corky(&data).args(["export", "sign.mbox", "--search", "sign"]).assert().success();
+    let m = manifest(&data.join("sign.manifest.json"));
+    assert_eq!(m["messages"], 1);
+    assert_eq!(m["items"][0]["from"], "Carol <carol@acme.com>");
+}
+
+// EX5: Existing output is never overwritten
+#[test]
+fn ex5_refuses_overwrite() {
+    let (_tmp, data) = fixture();
+    let out = data.join("x.mbox");
+    std::fs::write(&out, "keep").unwrap();
+    corky(&data)
+        .args(["export", &out.to_string_lossy()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+    assert_eq!(std::fs::read_to_string(&out).unwrap(), "keep");
+}
+
+// EX6: Bad selections are errors
+#[test]
+fn ex6_bad_selection() {
+    let (_tmp, data) = fixture();
+    corky(&data)
+        .args(["export", "a.mbox", "--contact", "nobody"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+    corky(&data)
+        .args(["export", "b.mbox", "--since", "March"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected YYYY-MM-DD"));
+}
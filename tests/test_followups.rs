@@ -0,0 +1,132 @@
+//! CLI integration tests for `corky followups` (FU1–FU3).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use chrono::{Duration, Utc};
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::markdown::thread_to_markdown;
+use corky::sync::types::{Message, Thread};
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+const ME: &str = "Test User <me@example.com>";
+
+/// (from, to, hours ago)
+fn write_thread(dir: &Path, id: &str, label: &str, messages: &[(&str, &str, i64)]) {
+    let subject = format!("{}{}", id[..1].to_uppercase(), &id[1..]);
+    let messages: Vec<Message> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, (from, to, hours))| Message {
+            id: format!("{}-{}", id, i),
+            thread_id: id.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            cc: String::new(),
+            date: (Utc::now() - Duration::hours(*hours)).to_rfc2822(),
+            subject: subject.clone(),
+            body: format!("About {}", id),
+            raw: String::new(),
+        })
+        .collect();
+    let thread = Thread {
+        id: id.to_string(),
+        subject,
+        labels: vec![label.to_string()],
+        accounts: vec!["default".to_string()],
+        last_date: messages.last().unwrap().date.clone(),
+        messages,
+        ..Default::default()
+    };
+    std::fs::write(dir.join(format!("{}.md", id)), thread_to_markdown(&thread)).unwrap();
+}
+
+fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let mut config = std::fs::read_to_string(data.join(".corky.toml")).unwrap();
+    config.push_str("\n[followups]\nlabels = { clients = \"1d\" }\n");
+    config.push_str("\n[contacts.bob]\nemails = [\"bob@example.com\"]\nfollowup_after = \"1w\"\n");
+    std::fs::write(data.join(".corky.toml"), config).unwrap();
+
+    let conv = data.join("conversations");
+    write_thread(&conv, "budget", "inbox", &[("Alice <alice@example.com>", "me@example.com", 200), (ME, "alice@example.com", 150)]);
+    write_thread(&conv, "venue", "clients", &[(ME, "carol@example.com", 48)]);
+    write_thread(&conv, "lunch", "inbox", &[(ME, "alice@example.com", 300), ("Alice <alice@example.com>", "me@example.com", 200)]);
+    write_thread(&conv, "recent", "inbox", &[(ME, "dave@example.com", 30)]);
+    write_thread(&conv, "quote", "inbox", &[(ME, "bob@example.com", 150)]);
+    (tmp, data)
+}
+
+// FU1: Only threads ending with my message past their threshold are listed, oldest first
+#[test]
+fn fu1_thresholds() {
+    let (_tmp, data) = setup();
+    let output = corky(&data).args(["followups", "--format", "json"]).output().unwrap();
+    assert!(output.status.success());
+    let threads: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let threads = threads.as_array().unwrap();
+    let files: Vec<&str> = threads.iter().map(|t| t["file"].as_str().unwrap()).collect();
+    // lunch: their reply came last; recent: under 5d; quote: bob waits 1w
+    assert_eq!(files, vec!["budget.md", "venue.md"]);
+    assert_eq!(threads[0]["after"], "5d");
+    assert_eq!(threads[0]["days_waiting"], 6);
+    assert_eq!(threads[0]["to"], "alice@example.com");
+    assert_eq!(threads[1]["after"], "1d");
+    assert!(threads[1].get("draft").is_none());
+
+    corky(&data)
+        .args(["followups", "--after", "1d"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Awaiting their reply (3):"))
+        .stdout(predicate::str::contains("recent.md"))
+        .stdout(predicate::str::contains("quote.md").not());
+}
+
+// FU2: Invalid spans are rejected
+#[test]
+fn fu2_invalid_span() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["followups", "--after", "soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --after 'soon'"));
+}
+
+// FU3: --draft scaffolds one nudge per thread and reuses it on the next run
+#[test]
+fn fu3_nudge_drafts() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["followups", "--draft"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nudge draft:"));
+
+    let drafts = || {
+        let mut files: Vec<_> = std::fs::read_dir(data.join("drafts")).unwrap().flatten().map(|e| e.path()).collect();
+        files.sort();
+        files
+    };
+    let first = drafts();
+    assert_eq!(first.len(), 2);
+    let budget = first.iter().find(|p| p.to_string_lossy().ends_with("re-budget.md")).unwrap();
+    let content = std::fs::read_to_string(budget).unwrap();
+    assert!(content.contains("to: alice@example.com\n"));
+    assert!(content.contains("account: default\n"));
+    assert!(content.contains("author: Test User\n"));
+    assert!(content.contains("# Re: Budget\n"));
+    assert!(content.contains("> About budget\n"));
+
+    corky(&data).args(["followups", "--draft"]).assert().success();
+    assert_eq!(drafts(), first);
+}
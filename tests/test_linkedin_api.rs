@@ -230,6 +230,236 @@ fn upload_image_init_failure() {
         .contains("image init failed"));
 }
 
+// --- delete_post ---
+
+#[test]
+fn delete_post_success() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("DELETE", "/rest/posts/urn%3Ali%3Ashare%3A12345")
+        .match_header("X-RestLi-Method", "DELETE")
+        .with_status(204)
+        .create();
+
+    let result = linkedin::delete_post_at(&server.url(), "test-token", "urn:li:share:12345");
+    mock.assert();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn delete_post_not_found() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("DELETE", "/rest/posts/urn%3Ali%3Ashare%3A404")
+        .with_status(404)
+        .with_body(r#"{"message":"Not Found"}"#)
+        .create();
+
+    let result = linkedin::delete_post_at(&server.url(), "test-token", "urn:li:share:404");
+    mock.assert();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("HTTP 404"));
+}
+
+// --- get_post_metrics ---
+
+#[test]
+fn get_post_metrics_social_actions() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/socialActions/urn%3Ali%3Ashare%3A12345")
+        .with_status(200)
+        .with_body(
+            r#"{"likesSummary":{"totalLikes":17},"commentsSummary":{"aggregatedTotalComments":4,"totalFirstLevelComments":3}}"#,
+        )
+        .create();
+
+    // No analytics mock → shares/impressions are best-effort None
+    let metrics =
+        linkedin::get_post_metrics_at(&server.url(), "test-token", "urn:li:share:12345").unwrap();
+    mock.assert();
+    assert_eq!(metrics.reactions, 17);
+    assert_eq!(metrics.comments, 4);
+    assert_eq!(metrics.shares, None);
+    assert_eq!(metrics.impressions, None);
+}
+
+#[test]
+fn get_post_metrics_with_analytics() {
+    let mut server = mockito::Server::new();
+    let _actions = server
+        .mock("GET", "/rest/socialActions/urn%3Ali%3Ashare%3A12345")
+        .with_status(200)
+        .with_body(r#"{"likesSummary":{"totalLikes":2},"commentsSummary":{"totalFirstLevelComments":1}}"#)
+        .create();
+    let _impressions = server
+        .mock(
+            "GET",
+            "/rest/memberCreatorPostAnalytics?q=entity&entity=(share:urn%3Ali%3Ashare%3A12345)&queryType=IMPRESSION&aggregation=TOTAL",
+        )
+        .with_status(200)
+        .with_body(r#"{"elements":[{"count":1200}]}"#)
+        .create();
+    let _reshares = server
+        .mock(
+            "GET",
+            "/rest/memberCreatorPostAnalytics?q=entity&entity=(share:urn%3Ali%3Ashare%3A12345)&queryType=RESHARE&aggregation=TOTAL",
+        )
+        .with_status(200)
+        .with_body(r#"{"elements":[{"count":5}]}"#)
+        .create();
+
+    let metrics =
+        linkedin::get_post_metrics_at(&server.url(), "test-token", "urn:li:share:12345").unwrap();
+    assert_eq!(metrics.reactions, 2);
+    assert_eq!(metrics.comments, 1);
+    assert_eq!(metrics.shares, Some(5));
+    assert_eq!(metrics.impressions, Some(1200));
+}
+
+#[test]
+fn get_post_metrics_api_error() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/rest/socialActions/urn%3Ali%3Ashare%3A1")
+        .with_status(403)
+        .with_body(r#"{"message":"Forbidden"}"#)
+        .create();
+
+    let result = linkedin::get_post_metrics_at(&server.url(), "test-token", "urn:li:share:1");
+    mock.assert();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("HTTP 403"));
+}
+
+// --- get_comments ---
+
+#[test]
+fn get_comments_parses_elements() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            "/rest/socialActions/urn%3Ali%3Ashare%3A12345/comments?start=0&count=100",
+        )
+        .with_status(200)
+        .with_body(
+            r#"{"elements":[
+                {"id":"702","actor":"urn:li:person:bob","created":{"time":1700000600000},"message":{"text":"Second"}},
+                {"id":"701","actor":"urn:li:person:alice","created":{"time":1700000000000},"message":{"text":"First!"}}
+            ],"paging":{"start":0,"count":100,"total":2}}"#,
+        )
+        .create();
+
+    let comments =
+        linkedin::get_comments_at(&server.url(), "test-token", "urn:li:share:12345").unwrap();
+    mock.assert();
+    assert_eq!(comments.len(), 2);
+    // Sorted oldest first
+    assert_eq!(comments[0].id, "701");
+    assert_eq!(comments[0].author_id, "urn:li:person:alice");
+    assert_eq!(comments[0].text, "First!");
+    assert_eq!(comments[0].created_at.timestamp(), 1_700_000_000);
+    assert_eq!(comments[1].id, "702");
+}
+
+#[test]
+fn get_comments_api_error() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            "/rest/socialActions/urn%3Ali%3Ashare%3A1/comments?start=0&count=100",
+        )
+        .with_status(401)
+        .with_body(r#"{"message":"Unauthorized"}"#)
+        .create();
+
+    let result = linkedin::get_comments_at(&server.url(), "bad-token", "urn:li:share:1");
+    mock.assert();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("HTTP 401"));
+}
+
+// --- get_admin_orgs ---
+
+#[test]
+fn get_admin_orgs_lists_organizations() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock(
+            "GET",
+            "/rest/organizationAcls?q=roleAssignee&role=ADMINISTRATOR&state=APPROVED",
+        )
+        .with_status(200)
+        .with_body(
+            r#"{"elements":[
+                {"organization":"urn:li:organization:42","role":"ADMINISTRATOR","state":"APPROVED"},
+                {"organization":"urn:li:organization:7","role":"ADMINISTRATOR","state":"APPROVED"}
+            ]}"#,
+        )
+        .create();
+
+    let orgs = linkedin::get_admin_orgs_at(&server.url(), "test-token").unwrap();
+    mock.assert();
+    assert_eq!(orgs, vec!["urn:li:organization:42", "urn:li:organization:7"]);
+}
+
+#[test]
+fn create_post_as_organization() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/rest/posts")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"author":"urn:li:organization:42"}"#.to_string(),
+        ))
+        .with_status(201)
+        .with_header("x-restli-id", "urn:li:share:555")
+        .create();
+
+    let (post_id, _) = linkedin::create_post_at(
+        &server.url(),
+        "test-token",
+        "urn:li:organization:42",
+        "Company update",
+        "public",
+        &[],
+    )
+    .unwrap();
+    mock.assert();
+    assert_eq!(post_id, "urn:li:share:555");
+}
+
+#[test]
+fn upload_image_owned_by_organization() {
+    let mut server = mockito::Server::new();
+    let upload_path = "/upload/org-image";
+    let init_mock = server
+        .mock("POST", "/rest/images?action=initializeUpload")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"initializeUploadRequest":{"owner":"urn:li:organization:42"}}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"value":{{"uploadUrl":"{}{}","image":"urn:li:image:org1"}}}}"#,
+            server.url(),
+            upload_path
+        ))
+        .create();
+    let upload_mock = server.mock("PUT", upload_path).with_status(201).create();
+
+    let urn = linkedin::upload_image_at(
+        &server.url(),
+        "test-token",
+        "urn:li:organization:42",
+        b"img",
+    )
+    .unwrap();
+    init_mock.assert();
+    upload_mock.assert();
+    assert_eq!(urn, "urn:li:image:org1");
+}
+
 // --- map_visibility ---
 
 #[test]
@@ -0,0 +1,155 @@
+//! mbox and Maildir import tests (MI1–MI6).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+use tempfile::TempDir;
+
+use corky::sync::mail_import::{maildir_files, run_maildir, run_mbox, ImportStats};
+
+fn message(id: &str, from: &str, subject: &str, date: &str, extra: &str, body: &str) -> String {
+    format!(
+        "From: {from}\nTo: me@example.com\nSubject: {subject}\nDate: {date}\nMessage-ID: <{id}>\n{extra}\n{body}\n"
+    )
+}
+
+fn archive() -> Vec<String> {
+    vec![
+        message("1@x", "Alice <alice@example.com>", "Budget", "Mon, 10 Feb 2025 10:00:00 +0000", "", "Here is the budget."),
+        message(
+            "2@x",
+            "Me <me@example.com>",
+            "Changed subject entirely",
+            "Mon, 10 Feb 2025 11:00:00 +0000",
+            "In-Reply-To: <1@x>\n",
+            "Thanks!\n>From the original: looks fine.",
+        ),
+        message("3@x", "Bob <bob@example.com>", "Lunch", "Tue, 11 Feb 2025 12:00:00 +0000", "", "Lunch friday?"),
+    ]
+}
+
+fn write_mbox(path: &Path, messages: &[String]) {
+    let mut out = String::new();
+    for m in messages {
+        out.push_str("From MAILER-DAEMON Mon Feb 10 10:00:00 2025\n");
+        out.push_str(m);
+        out.push('\n');
+    }
+    std::fs::write(path, out).unwrap();
+}
+
+fn md_files(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|n| n.ends_with(".md"))
+        .collect();
+    files.sort();
+    files
+}
+
+// MI1: mbox messages are threaded (In-Reply-To beats subject) and merged
+#[test]
+fn mi1_mbox_threads_and_merges() {
+    let tmp = TempDir::new().unwrap();
+    let mbox = tmp.path().join("archive.mbox");
+    write_mbox(&mbox, &archive());
+    let out = tmp.path().join("conversations");
+
+    let stats = run_mbox(&mbox, "archive", &out, "takeout").unwrap();
+    assert_eq!(stats, ImportStats { imported: 3, duplicates: 0, failed: 0 });
+    assert_eq!(md_files(&out), vec!["budget.md", "lunch.md"]);
+
+    let budget = std::fs::read_to_string(out.join("budget.md")).unwrap();
+    assert!(budget.contains("**Labels**: archive"));
+    assert!(budget.contains("**Accounts**: takeout"));
+    assert!(budget.contains("Here is the budget."));
+    assert!(budget.contains("Thanks!"));
+    // mboxrd quoting undone
+    assert!(budget.contains("\nFrom the original: looks fine."));
+}
+
+// MI2: Re-importing and in-file repeats are reported as duplicates
+#[test]
+fn mi2_duplicates_reported() {
+    let tmp = TempDir::new().unwrap();
+    let mut messages = archive();
+    messages.push(messages[0].clone());
+    let mbox = tmp.path().join("archive.mbox");
+    write_mbox(&mbox, &messages);
+    let out = tmp.path().join("conversations");
+
+    let first = run_mbox(&mbox, "archive", &out, "takeout").unwrap();
+    assert_eq!(first.imported, 3);
+    assert_eq!(first.duplicates, 1);
+
+    let again = run_mbox(&mbox, "archive", &out, "takeout").unwrap();
+    assert_eq!(again.imported, 0);
+    assert_eq!(again.duplicates, 4);
+}
+
+// MI3: Unparseable entries are counted, not fatal
+#[test]
+fn mi3_garbage_counted() {
+    let tmp = TempDir::new().unwrap();
+    let mbox = tmp.path().join("archive.mbox");
+    write_mbox(&mbox, &[archive()[2].clone(), "\n".to_string()]);
+    let stats = run_mbox(&mbox, "archive", &tmp.path().join("c"), "takeout").unwrap();
+    assert_eq!(stats.imported, 1);
+    assert_eq!(stats.failed, 1);
+}
+
+fn write_maildir(root: &Path) {
+    let msgs = archive();
+    for sub in ["cur", "new", "tmp"] {
+        std::fs::create_dir_all(root.join(sub)).unwrap();
+    }
+    std::fs::write(root.join("cur").join("1700000000.1.host:2,S"), &msgs[0]).unwrap();
+    std::fs::write(root.join("new").join("1700000001.2.host"), &msgs[1]).unwrap();
+    std::fs::write(root.join("tmp").join("partial"), "garbage").unwrap();
+    let sub = root.join(".Archive");
+    std::fs::create_dir_all(sub.join("cur")).unwrap();
+    std::fs::write(sub.join("cur").join("1700000002.3.host:2,S"), &msgs[2]).unwrap();
+}
+
+// MI4: Maildir walks cur/, new/ and Maildir++ subfolders, skipping tmp/
+#[test]
+fn mi4_maildir_import() {
+    let tmp = TempDir::new().unwrap();
+    let md = tmp.path().join("Maildir");
+    write_maildir(&md);
+    assert_eq!(maildir_files(&md).unwrap().len(), 3);
+
+    let out = tmp.path().join("conversations");
+    let stats = run_maildir(&md, "maildir", &out, "maildir").unwrap();
+    assert_eq!(stats, ImportStats { imported: 3, duplicates: 0, failed: 0 });
+    assert_eq!(md_files(&out), vec!["budget.md", "lunch.md"]);
+}
+
+// MI5: A directory without cur/ or new/ is rejected
+#[test]
+fn mi5_not_a_maildir() {
+    let tmp = TempDir::new().unwrap();
+    let err = run_maildir(tmp.path(), "maildir", &tmp.path().join("c"), "maildir").unwrap_err();
+    assert!(err.to_string().contains("is not a Maildir"));
+}
+
+// MI6: CLI wiring writes into the data dir's conversations/
+#[test]
+fn mi6_cli_mbox_import() {
+    let (tmp, data_dir) = common::temp_data_dir();
+    let mbox = tmp.path().join("archive.mbox");
+    write_mbox(&mbox, &archive());
+
+    cargo_bin_cmd!("corky")
+        .current_dir(tmp.path())
+        .env("CORKY_DATA", data_dir.to_string_lossy().as_ref())
+        .args(["sync", "mbox-import", &mbox.to_string_lossy(), "--label", "old"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("3 message(s) imported, 0 duplicate(s) skipped"));
+    assert_eq!(md_files(&data_dir.join("conversations")), vec!["budget.md", "lunch.md"]);
+}
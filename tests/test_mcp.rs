@@ -0,0 +1,237 @@
+//! MCP server protocol, tool and permission tests (MC1–MC8).
+
+mod common;
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+use corky::config::corky_config::{MailboxConfig, MailboxPermissions};
+use corky::mcp::access::Access;
+use corky::mcp::Server;
+
+const THREAD: &str = "# Budget review\n\n**Labels**: work\n**Accounts**: personal\n**Thread ID**: budget\n**Last updated**: Mon, 10 Feb 2025 10:00:00 +0000\n\n---\n\n## Alice <alice@example.com> \u{2014} Mon, 10 Feb 2025 10:00:00 +0000\n\nHere is the quarterly budget plan.\n";
+
+fn fixture() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    std::fs::write(data.join("conversations").join("budget-review.md"), THREAD).unwrap();
+    std::fs::create_dir_all(data.join("contacts").join("alice")).unwrap();
+    std::fs::write(data.join("contacts").join("alice").join("AGENTS.md"), "# Alice\n\nPrefers email.\n").unwrap();
+
+    let mb = data.join("mailboxes").join("acme");
+    for sub in ["conversations", "drafts", "contacts"] {
+        std::fs::create_dir_all(mb.join(sub)).unwrap();
+    }
+    std::fs::write(
+        mb.join("conversations").join("contract.md"),
+        THREAD.replace("Budget review", "Contract renewal").replace("quarterly budget plan", "renewal contract"),
+    )
+    .unwrap();
+    (tmp, data)
+}
+
+fn request(server: &Server, method: &str, params: Value) -> Value {
+    server
+        .handle(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+        .expect("request should get a reply")
+}
+
+/// Call a tool; returns (text, isError).
+fn call(server: &Server, name: &str, args: Value) -> (String, bool) {
+    let reply = request(server, "tools/call", json!({ "name": name, "arguments": args }));
+    let result = &reply["result"];
+    (
+        result["content"][0]["text"].as_str().unwrap().to_string(),
+        result["isError"].as_bool().unwrap(),
+    )
+}
+
+fn owner(data: &Path) -> Server {
+    Server::new(data.to_path_buf(), Access::owner(), Some("Test User".into())).unwrap()
+}
+
+fn scoped(data: &Path, read: &[&str], write: &[&str]) -> Server {
+    let mut permissions = HashMap::new();
+    permissions.insert(
+        "agent".to_string(),
+        MailboxPermissions {
+            read: read.iter().map(|s| s.to_string()).collect(),
+            write: write.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        },
+    );
+    let config = MailboxConfig {
+        permissions,
+        ..Default::default()
+    };
+    let access = Access::for_mailbox("acme", Some(&config), "agent");
+    Server::new(data.to_path_buf(), access, Some("Test User".into())).unwrap()
+}
+
+// MC1: initialize, ping and notifications
+#[test]
+fn mc1_initialize() {
+    let (_tmp, data) = fixture();
+    let server = owner(&data);
+    let reply = request(&server, "initialize", json!({}));
+    assert_eq!(reply["id"], 1);
+    assert_eq!(reply["result"]["protocolVersion"], corky::mcp::PROTOCOL_VERSION);
+    assert_eq!(reply["result"]["serverInfo"]["name"], "corky");
+    assert!(reply["result"]["capabilities"]["tools"].is_object());
+
+    assert_eq!(request(&server, "ping", json!({}))["result"], json!({}));
+    assert!(server
+        .handle(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .is_none());
+    assert_eq!(request(&server, "bogus/method", json!({}))["error"]["code"], -32601);
+}
+
+// MC2: tools/list advertises every tool with an input schema
+#[test]
+fn mc2_tools_list() {
+    let (_tmp, data) = fixture();
+    let reply = request(&owner(&data), "tools/list", json!({}));
+    let tools = reply["result"]["tools"].as_array().unwrap();
+    let names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
+    for expected in [
+        "search",
+        "read_conversation",
+        "list_unanswered",
+        "create_draft",
+        "validate_draft",
+        "contact_info",
+        "list_schedule",
+    ] {
+        assert!(names.contains(&expected), "missing tool {}", expected);
+    }
+    assert!(tools.iter().all(|t| t["inputSchema"]["type"] == "object"));
+}
+
+// MC3: search and read_conversation (owner sees everything)
+#[test]
+fn mc3_search_and_read() {
+    let (_tmp, data) = fixture();
+    let server = owner(&data);
+
+    let (text, err) = call(&server, "search", json!({ "query": "renewal" }));
+    assert!(!err, "{}", text);
+    let hits: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(hits[0]["path"], "mailboxes/acme/conversations/contract.md");
+
+    let (text, err) = call(&server, "read_conversation", json!({ "path": "budget-review" }));
+    assert!(!err);
+    assert!(text.contains("quarterly budget plan"));
+
+    let (text, err) = call(&server, "read_conversation", json!({ "path": "../secret.md" }));
+    assert!(err);
+    assert!(text.contains("Invalid path"));
+}
+
+// MC4: scoped search only sees the mailbox, with mailbox-relative paths
+#[test]
+fn mc4_scoped_search() {
+    let (_tmp, data) = fixture();
+    let server = scoped(&data, &["*"], &["drafts"]);
+    let (text, _) = call(&server, "search", json!({ "query": "budget" }));
+    assert_eq!(serde_json::from_str::<Value>(&text).unwrap(), json!([]));
+
+    let (text, _) = call(&server, "search", json!({ "query": "renewal" }));
+    let hits: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(hits[0]["path"], "conversations/contract.md");
+
+    let (text, err) = call(&server, "read_conversation", json!({ "path": "contract" }));
+    assert!(!err);
+    assert!(text.contains("renewal contract"));
+}
+
+// MC5: create_draft writes into the scope's drafts/ and validates
+#[test]
+fn mc5_create_and_validate_draft() {
+    let (_tmp, data) = fixture();
+    let server = scoped(&data, &["conversations"], &["drafts"]);
+    let (text, err) = call(
+        &server,
+        "create_draft",
+        json!({ "subject": "Re: Contract", "to": "carol@acme.com", "body": "Signed copy attached." }),
+    );
+    assert!(!err, "{}", text);
+    let path = serde_json::from_str::<Value>(&text).unwrap()["path"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(path.starts_with("drafts/"));
+    let content = std::fs::read_to_string(data.join("mailboxes").join("acme").join(&path)).unwrap();
+    assert!(content.contains("author: Test User"));
+    assert!(content.ends_with("Signed copy attached.\n"));
+
+    let (text, err) = call(&server, "validate_draft", json!({ "path": path }));
+    assert!(!err);
+    let result: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(result["valid"], true, "{}", text);
+}
+
+// MC6: permission denials are tool errors
+#[test]
+fn mc6_permission_denied() {
+    let (_tmp, data) = fixture();
+    let server = scoped(&data, &["conversations"], &[]);
+    let (text, err) = call(&server, "create_draft", json!({ "subject": "x", "to": "a@b.com" }));
+    assert!(err);
+    assert_eq!(text, "Permission denied: 'agent' cannot write drafts in mailbox 'acme'");
+    assert!(!data.join("mailboxes").join("acme").join("drafts").read_dir().unwrap().any(|_| true));
+
+    let (text, err) = call(&server, "contact_info", json!({ "name": "alice" }));
+    assert!(err);
+    assert!(text.contains("cannot read contacts"));
+
+    let reply = request(&server, "tools/call", json!({ "name": "sync", "arguments": {} }));
+    assert_eq!(reply["error"]["code"], -32602);
+}
+
+// MC7: resources list and read conversations and contacts
+#[test]
+fn mc7_resources() {
+    let (_tmp, data) = fixture();
+    let server = owner(&data);
+    let reply = request(&server, "resources/list", json!({}));
+    let uris: Vec<&str> = reply["result"]["resources"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["uri"].as_str().unwrap())
+        .collect();
+    assert_eq!(uris, vec!["corky://conversations/budget-review.md", "corky://contacts/alice"]);
+
+    let reply = request(&server, "resources/read", json!({ "uri": "corky://contacts/alice" }));
+    assert!(reply["result"]["contents"][0]["text"].as_str().unwrap().contains("Prefers email."));
+
+    let reply = request(&server, "resources/read", json!({ "uri": "corky://../.corky.toml" }));
+    assert_eq!(reply["error"]["code"], -32602);
+}
+
+// MC8: scoped resources respect read permissions; serve speaks JSON lines
+#[test]
+fn mc8_scoped_resources_and_serve() {
+    let (_tmp, data) = fixture();
+    let server = scoped(&data, &["conversations"], &[]);
+    let reply = request(&server, "resources/list", json!({}));
+    assert_eq!(
+        reply["result"]["resources"][0]["uri"],
+        "corky://conversations/contract.md"
+    );
+    assert_eq!(reply["result"]["resources"].as_array().unwrap().len(), 1);
+
+    let input = "{\"jsonrpc\":\"2.0\",\"id\":7,\"method\":\"ping\"}\nnot json\n\n{\"jsonrpc\":\"2.0\",\"method\":\"notifications/initialized\"}\n";
+    let mut out = Vec::new();
+    server.serve(input.as_bytes(), &mut out).unwrap();
+    let lines: Vec<Value> = String::from_utf8(out)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["id"], 7);
+    assert_eq!(lines[1]["error"]["code"], -32700);
+
+    assert!(Server::new(data.clone(), Access::for_mailbox("missing", None, "agent"), None).is_err());
+}
@@ -0,0 +1,129 @@
+//! Raw message store and reprocess tests (RP1–RP5).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::markdown::parse_thread_markdown;
+use corky::sync::raw_store;
+
+const RAW: &str = "From: Alice <alice@example.com>\r\nTo: me@example.com\r\nSubject: Budget\r\nDate: Mon, 10 Feb 2025 10:00:00 +0000\r\nMessage-ID: <budget-1@example.com>\r\nX-Original: kept\r\n\r\nHere is the budget.\r\n";
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+/// A data dir with keep_raw on, RAW in the store, and a thread whose
+/// Markdown body is stale (as if written by an older parser).
+fn fixture() -> (tempfile::TempDir, std::path::PathBuf, String) {
+    let (tmp, data) = common::temp_data_dir();
+    std::fs::write(data.join(".corky.toml"), "[sync]\nkeep_raw = true\n").unwrap();
+    let key = raw_store::put(&data.join("raw"), RAW.as_bytes()).unwrap();
+    std::fs::write(
+        data.join("conversations").join("budget.md"),
+        format!(
+            "# Budget\n\n**Labels**: work\n**Accounts**: personal\n**Thread ID**: budget\n**Last updated**: Mon, 10 Feb 2025 10:00:00 +0000\n\n---\n\n## alice@example.com \u{2014} Mon, 10 Feb 2025 10:00:00 +0000\n\n**Raw**: {key}\n\n(garbled)\n"
+        ),
+    )
+    .unwrap();
+    (tmp, data, key)
+}
+
+// RP1: reprocess regenerates messages from raw, keeping thread metadata
+#[test]
+fn rp1_reprocess_rewrites_from_raw() {
+    let (_tmp, data, key) = fixture();
+    corky(&data)
+        .arg("reprocess")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 message(s) re-parsed, 1 thread(s) rewritten"));
+
+    let text = std::fs::read_to_string(data.join("conversations").join("budget.md")).unwrap();
+    let thread = parse_thread_markdown(&text).unwrap();
+    assert_eq!(thread.labels, vec!["work"]);
+    assert_eq!(thread.messages[0].from, "Alice <alice@example.com>");
+    assert_eq!(thread.messages[0].to, "me@example.com");
+    assert_eq!(thread.messages[0].body, "Here is the budget.");
+    assert_eq!(thread.messages[0].raw, key);
+
+    // Second run is a no-op
+    corky(&data)
+        .arg("reprocess")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 thread(s) rewritten"));
+}
+
+// RP2: --dry-run reports without writing
+#[test]
+fn rp2_dry_run() {
+    let (_tmp, data, _) = fixture();
+    let path = data.join("conversations").join("budget.md");
+    let before = std::fs::read_to_string(&path).unwrap();
+    corky(&data)
+        .args(["reprocess", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would rewrite"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+}
+
+// RP3: Missing store is an error; missing entries are skipped
+#[test]
+fn rp3_missing_store_and_entries() {
+    let (_tmp, data, key) = fixture();
+    let entry = raw_store::path_for(&data.join("raw"), &key).unwrap();
+    std::fs::remove_file(entry).unwrap();
+    corky(&data)
+        .arg("reprocess")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 missing from store"));
+
+    std::fs::remove_dir_all(data.join("raw")).unwrap();
+    corky(&data)
+        .arg("reprocess")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("keep_raw"));
+}
+
+// RP4: Imports with keep_raw store the source and reference it
+#[test]
+fn rp4_import_keeps_raw() {
+    let (_tmp, data) = common::temp_data_dir();
+    std::fs::write(data.join(".corky.toml"), "[sync]\nkeep_raw = true\n").unwrap();
+    let mbox = data.join("in.mbox");
+    std::fs::write(&mbox, format!("From x Mon Feb 10 10:00:00 2025\n{}\n", RAW.replace("\r\n", "\n"))).unwrap();
+
+    corky(&data)
+        .args(["sync", "mbox-import", &mbox.to_string_lossy()])
+        .assert()
+        .success();
+    let text = std::fs::read_to_string(data.join("conversations").join("budget.md")).unwrap();
+    let raw_key = parse_thread_markdown(&text).unwrap().messages[0].raw.clone();
+    assert!(raw_key.starts_with("sha256:"));
+    let raw = raw_store::get(&data.join("raw"), &raw_key).unwrap();
+    assert!(String::from_utf8(raw).unwrap().contains("X-Original: kept"));
+}
+
+// RP5: Export uses the retained source, original headers included
+#[test]
+fn rp5_export_prefers_raw() {
+    let (_tmp, data, _) = fixture();
+    corky(&data)
+        .args(["export", "out", "--type", "eml"])
+        .assert()
+        .success();
+    let eml = std::fs::read_to_string(data.join("out").join("00001-budget.eml")).unwrap();
+    assert_eq!(eml, RAW);
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(data.join("out").join("manifest.json")).unwrap()).unwrap();
+    assert_eq!(manifest["items"][0]["raw_headers"], true);
+    assert_eq!(manifest["items"][0]["message_id"], "<budget-1@example.com>");
+}
@@ -0,0 +1,269 @@
+//! Full-text search index and query tests (SR1–SR9).
+
+mod common;
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use corky::search::index::{SearchIndex, INDEX_FILE, PENDING_FILE};
+use corky::search::query::Query;
+use corky::search::search;
+use corky::sync::imap_sync::merge_message_to_file;
+use corky::sync::mail_import::run_mbox;
+use corky::sync::types::Message;
+
+fn write_thread(dir: &Path, slug: &str, subject: &str, labels: &str, accounts: &str, date: &str, messages: &[(&str, &str, &str)]) {
+    let mut text = format!(
+        "# {}\n\n**Labels**: {}\n**Accounts**: {}\n**Thread ID**: {}\n**Last updated**: {}\n\n",
+        subject, labels, accounts, subject, date
+    );
+    for (from, to, body) in messages {
+        text.push_str(&format!("---\n\n## {} \u{2014} {}\n\n", from, date));
+        if !to.is_empty() {
+            text.push_str(&format!("**To**: {}\n\n", to));
+        }
+        text.push_str(body);
+        text.push_str("\n\n");
+    }
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(dir.join(format!("{}.md", slug)), text).unwrap();
+}
+
+fn fixture() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    let conv = data.join("conversations");
+    write_thread(
+        &conv,
+        "budget-review",
+        "Budget review",
+        "work",
+        "personal",
+        "Mon, 10 Feb 2025 10:00:00 +0000",
+        &[
+            ("Alice Smith <alice@example.com>", "me@example.com", "Here is the quarterly budget plan for review."),
+            ("Test User <me@example.com>", "alice@example.com", "Thanks, the budget looks good."),
+        ],
+    );
+    write_thread(
+        &conv,
+        "lunch",
+        "Lunch on Friday",
+        "personal",
+        "personal",
+        "Wed, 15 Jan 2025 12:00:00 +0000",
+        &[("Bob <bob@example.com>", "", "Lunch friday? We could talk budget briefly.")],
+    );
+    write_thread(
+        &data.join("mailboxes").join("acme").join("conversations"),
+        "contract",
+        "Contract renewal",
+        "for-acme",
+        "work",
+        "Tue, 04 Mar 2025 09:00:00 +0000",
+        &[("Carol <carol@acme.com>", "me@example.com", "Please sign the renewal contract by Friday.")],
+    );
+    std::fs::write(
+        data.join("drafts").join("reply.md"),
+        "---\nto: alice@example.com\nsubject: \"Re: Budget review\"\nstatus: draft\nattachments:\n  - /tmp/budget.xlsx\n---\n\nAttached the revised budget spreadsheet.\n",
+    )
+    .unwrap();
+    (tmp, data)
+}
+
+fn paths(index: &SearchIndex, data: &Path, q: &str) -> Vec<String> {
+    search(index, data, &Query::parse(q).unwrap(), 20)
+        .into_iter()
+        .map(|h| h.path)
+        .collect()
+}
+
+// SR1: Index covers root conversations, mailbox conversations and drafts
+#[test]
+fn sr1_index_covers_all_roots() {
+    let (_tmp, data) = fixture();
+    let (index, stats) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(stats.indexed, 4);
+    assert!(data.join(INDEX_FILE).exists());
+    let ids: Vec<_> = index.docs.keys().cloned().collect();
+    assert_eq!(
+        ids,
+        vec![
+            "conversations/budget-review.md",
+            "conversations/lunch.md",
+            "drafts/reply.md",
+            "mailboxes/acme/conversations/contract.md",
+        ]
+    );
+}
+
+// SR2: Terms are ANDed and ranked (subject match ranks higher)
+#[test]
+fn sr2_ranked_terms() {
+    let (_tmp, data) = fixture();
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+
+    let hits = paths(&index, &data, "budget");
+    assert_eq!(hits.len(), 3);
+    assert_eq!(hits[0], "conversations/budget-review.md");
+    assert!(hits.contains(&"conversations/lunch.md".to_string()));
+
+    assert_eq!(paths(&index, &data, "budget friday"), vec!["conversations/lunch.md"]);
+    assert!(paths(&index, &data, "nonexistentword").is_empty());
+}
+
+// SR3: Phrase queries require adjacent words
+#[test]
+fn sr3_phrase() {
+    let (_tmp, data) = fixture();
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(
+        paths(&index, &data, "\"quarterly budget\""),
+        vec!["conversations/budget-review.md"]
+    );
+    assert!(paths(&index, &data, "\"budget quarterly\"").is_empty());
+}
+
+// SR4: from:/to:/label:/account: filters
+#[test]
+fn sr4_field_filters() {
+    let (_tmp, data) = fixture();
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(paths(&index, &data, "budget from:bob"), vec!["conversations/lunch.md"]);
+    assert_eq!(paths(&index, &data, "from:carol"), vec!["mailboxes/acme/conversations/contract.md"]);
+    let mut to_alice = paths(&index, &data, "to:alice@example.com");
+    to_alice.sort();
+    assert_eq!(to_alice, vec!["conversations/budget-review.md", "drafts/reply.md"]);
+    assert_eq!(paths(&index, &data, "label:work"), vec!["conversations/budget-review.md"]);
+    assert_eq!(paths(&index, &data, "account:work"), vec!["mailboxes/acme/conversations/contract.md"]);
+}
+
+// SR5: before:/after: use last activity; has:attachment matches drafts with attachments
+#[test]
+fn sr5_dates_and_attachments() {
+    let (_tmp, data) = fixture();
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(
+        paths(&index, &data, "after:2025-02-01 before:2025-03-01"),
+        vec!["conversations/budget-review.md"]
+    );
+    assert_eq!(paths(&index, &data, "before:2025-02-01"), vec!["conversations/lunch.md"]);
+    assert_eq!(paths(&index, &data, "has:attachment"), vec!["drafts/reply.md"]);
+}
+
+// SR6: Snippets come from message content around the match
+#[test]
+fn sr6_snippets() {
+    let (_tmp, data) = fixture();
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    let hits = search(&index, &data, &Query::parse("renewal").unwrap(), 5);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].subject, "Contract renewal");
+    assert_eq!(hits[0].snippet, "Please sign the renewal contract by Friday.");
+}
+
+// SR7: Incremental refresh picks up new, changed and deleted files
+#[test]
+fn sr7_incremental_refresh() {
+    let (_tmp, data) = fixture();
+    SearchIndex::open(&data, false).unwrap();
+
+    let (_, stats) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(stats.indexed, 0);
+    assert_eq!(stats.removed, 0);
+
+    std::fs::remove_file(data.join("conversations").join("lunch.md")).unwrap();
+    write_thread(
+        &data.join("conversations"),
+        "offsite",
+        "Team offsite",
+        "work",
+        "personal",
+        "Thu, 20 Feb 2025 10:00:00 +0000",
+        &[("Dana <dana@example.com>", "", "Offsite venue options attached below.")],
+    );
+    let (index, stats) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(stats.indexed, 1);
+    assert_eq!(stats.removed, 1);
+    assert_eq!(paths(&index, &data, "venue"), vec!["conversations/offsite.md"]);
+    assert!(paths(&index, &data, "lunch").is_empty());
+    assert!(!index.postings.contains_key("lunch"));
+}
+
+// SR8: merge_message_to_file records changes for the next search
+#[test]
+fn sr8_merge_marks_pending() {
+    let (_tmp, data) = fixture();
+    let conv = data.join("conversations");
+
+    // No index yet → nothing recorded
+    let msg = Message {
+        id: "1".into(),
+        thread_id: "kickoff".into(),
+        from: "Eve <eve@example.com>".into(),
+        to: String::new(),
+        cc: String::new(),
+        date: "Mon, 03 Mar 2025 10:00:00 +0000".into(),
+        subject: "Project kickoff".into(),
+        body: "Kickoff agenda draft".into(),
+        raw: String::new(),
+    };
+    merge_message_to_file(&conv, "work", "personal", &msg, "kickoff").unwrap();
+    assert!(!data.join(PENDING_FILE).exists());
+
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    assert_eq!(paths(&index, &data, "label:work kickoff"), vec!["conversations/project-kickoff.md"]);
+
+    // Dedup path still updates labels; pending log forces a re-index
+    merge_message_to_file(&conv, "urgent", "personal", &msg, "kickoff").unwrap();
+    let pending = std::fs::read_to_string(data.join(PENDING_FILE)).unwrap();
+    assert_eq!(pending.trim(), "conversations/project-kickoff.md");
+
+    let mut index = SearchIndex::load(&data);
+    let forced: HashSet<String> = pending.lines().map(|l| l.to_string()).collect();
+    index.refresh(&data, &forced);
+    assert_eq!(paths(&index, &data, "label:urgent"), vec!["conversations/project-kickoff.md"]);
+
+    SearchIndex::open(&data, false).unwrap();
+    assert!(!data.join(PENDING_FILE).exists());
+}
+
+// SR9: has:attachment matches conversations with [Attachment: …] lines (chat imports, mail sync)
+#[test]
+fn sr9_conversation_attachments() {
+    let (_tmp, data) = fixture();
+    let conv = data.join("conversations");
+    write_thread(
+        &conv,
+        "photos",
+        "Photos",
+        "whatsapp",
+        "whatsapp",
+        "Sat, 01 Mar 2025 18:00:00 +0000",
+        &[("Bob <bob@example.com>", "", "From the hike\n[Attachment: 00000012-PHOTO.jpg]")],
+    );
+    let mbox = data.join("contract.mbox");
+    std::fs::write(
+        &mbox,
+        "From MAILER-DAEMON Mon Mar  3 10:00:00 2025\n\
+         From: Carol <carol@acme.com>\nTo: me@example.com\nSubject: Signed contract\n\
+         Date: Mon, 03 Mar 2025 10:00:00 +0000\nMessage-ID: <signed@acme.com>\nMIME-Version: 1.0\n\
+         Content-Type: multipart/mixed; boundary=\"b\"\n\n\
+         --b\nContent-Type: text/plain; charset=utf-8\n\nSigned copy attached.\n\n\
+         --b\nContent-Type: application/pdf; name=\"contract.pdf\"\n\
+         Content-Disposition: attachment; filename=\"contract.pdf\"\nContent-Transfer-Encoding: base64\n\nJVBERi0xLjQK\n\
+         --b--\n\n",
+    )
+    .unwrap();
+    run_mbox(&mbox, "inbox", &conv, "work").unwrap();
+    let text = std::fs::read_to_string(conv.join("signed-contract.md")).unwrap();
+    assert!(text.contains("Signed copy attached.\n\n[Attachment: contract.pdf]"), "{}", text);
+
+    let (index, _) = SearchIndex::open(&data, false).unwrap();
+    let mut hits = paths(&index, &data, "has:attachment");
+    hits.sort();
+    assert_eq!(
+        hits,
+        vec!["conversations/photos.md", "conversations/signed-contract.md", "drafts/reply.md"]
+    );
+    assert!(!index.docs["conversations/budget-review.md"].has_attachment);
+}
@@ -0,0 +1,232 @@
+//! Local HTTP/JSON API tests (SV1–SV8).
+
+mod common;
+
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use corky::serve::api::{Api, ApiRequest};
+use corky::serve::events::{self, SyncEvent};
+
+const TOKEN: &str = "test-token";
+
+fn thread(subject: &str, from: &str, date: &str, body: &str) -> String {
+    format!(
+        "# {subject}\n\n**Labels**: work\n**Accounts**: personal\n**Thread ID**: {subject}\n**Last updated**: {date}\n\n---\n\n## {from} \u{2014} {date}\n\n{body}\n"
+    )
+}
+
+fn fixture() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    let conv = data.join("conversations");
+    std::fs::write(
+        conv.join("budget-review.md"),
+        thread("Budget review", "Alice <alice@example.com>", "Mon, 10 Feb 2025 10:00:00 +0000", "Quarterly budget attached."),
+    )
+    .unwrap();
+    std::fs::write(
+        conv.join("lunch.md"),
+        thread("Lunch", "Test User <me@example.com>", "Wed, 15 Jan 2025 12:00:00 +0000", "Friday works."),
+    )
+    .unwrap();
+    let mb = data.join("mailboxes").join("acme").join("conversations");
+    std::fs::create_dir_all(&mb).unwrap();
+    std::fs::write(
+        mb.join("contract.md"),
+        thread("Contract", "Carol <carol@acme.com>", "Tue, 04 Mar 2025 09:00:00 +0000", "Please sign."),
+    )
+    .unwrap();
+    (tmp, data)
+}
+
+fn api(data: &Path) -> Api {
+    Api {
+        data_dir: data.to_path_buf(),
+        token: TOKEN.to_string(),
+        owner_name: Some("Test User".to_string()),
+        sync_running: Arc::new(AtomicBool::new(false)),
+    }
+}
+
+fn call(api: &Api, method: &str, url: &str, body: Value) -> (u16, Value) {
+    let body = if body.is_null() { String::new() } else { body.to_string() };
+    let req = ApiRequest::new(method, url, Some(format!("Bearer {}", TOKEN)), body);
+    let resp = api.handle(&req);
+    (resp.status, resp.body)
+}
+
+// SV1: Every route except /api/health requires the token
+#[test]
+fn sv1_token_auth() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    let resp = api.handle(&ApiRequest::new("GET", "/api/threads", None, String::new()));
+    assert_eq!(resp.status, 401);
+    let resp = api.handle(&ApiRequest::new("GET", "/api/threads", Some("Bearer wrong".into()), String::new()));
+    assert_eq!(resp.status, 401);
+    let resp = api.handle(&ApiRequest::new("GET", "/api/threads?token=test-token", None, String::new()));
+    assert_eq!(resp.status, 200);
+    let resp = api.handle(&ApiRequest::new("GET", "/api/health", None, String::new()));
+    assert_eq!(resp.status, 200);
+    assert_eq!(resp.body["status"], "ok");
+}
+
+// SV2: Thread listing (newest first, per mailbox) and detail
+#[test]
+fn sv2_threads() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    let (status, body) = call(&api, "GET", "/api/threads", Value::Null);
+    assert_eq!(status, 200);
+    let slugs: Vec<&str> = body.as_array().unwrap().iter().map(|t| t["slug"].as_str().unwrap()).collect();
+    assert_eq!(slugs, vec!["budget-review", "lunch"]);
+    assert_eq!(body[0]["labels"], json!(["work"]));
+
+    let (_, body) = call(&api, "GET", "/api/threads?mailbox=acme", Value::Null);
+    assert_eq!(body[0]["slug"], "contract");
+
+    let (status, body) = call(&api, "GET", "/api/threads/budget-review", Value::Null);
+    assert_eq!(status, 200);
+    assert_eq!(body["subject"], "Budget review");
+    assert_eq!(body["messages"][0]["from"], "Alice <alice@example.com>");
+    assert!(body["markdown"].as_str().unwrap().starts_with("# Budget review"));
+
+    assert_eq!(call(&api, "GET", "/api/threads/missing", Value::Null).0, 404);
+    assert_eq!(call(&api, "GET", "/api/threads?mailbox=nope", Value::Null).0, 404);
+}
+
+// SV3: Path traversal is forbidden
+#[test]
+fn sv3_traversal() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    assert_eq!(call(&api, "GET", "/api/threads/..%2F.corky", Value::Null).0, 403);
+    assert_eq!(call(&api, "GET", "/api/threads?mailbox=..", Value::Null).0, 403);
+    assert_eq!(call(&api, "GET", "/api/drafts/.hidden", Value::Null).0, 403);
+}
+
+// SV4: Unanswered uses the owner name across root and mailboxes
+#[test]
+fn sv4_unanswered() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    let (status, body) = call(&api, "GET", "/api/unanswered", Value::Null);
+    assert_eq!(status, 200);
+    let files: Vec<&str> = body.as_array().unwrap().iter().map(|t| t["file"].as_str().unwrap()).collect();
+    assert_eq!(files.len(), 2);
+    assert!(files.iter().any(|f| f.contains("contract")));
+    assert!(!files.iter().any(|f| f.contains("lunch")));
+}
+
+// SV5: Queue a draft, list it, read it back and validate it
+#[test]
+fn sv5_drafts() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    let (status, body) = call(
+        &api,
+        "POST",
+        "/api/drafts",
+        json!({ "subject": "Re: Budget review", "to": "alice@example.com", "body": "Looks good." }),
+    );
+    assert_eq!(status, 201, "{}", body);
+    let file = body["file"].as_str().unwrap().to_string();
+    assert!(file.ends_with("-re-budget-review.md"));
+
+    let (_, list) = call(&api, "GET", "/api/drafts", Value::Null);
+    assert_eq!(list[0]["file"], file.as_str());
+    assert_eq!(list[0]["status"], "draft");
+    assert_eq!(list[0]["author"], "Test User");
+
+    let (_, draft) = call(&api, "GET", &format!("/api/drafts/{}", file), Value::Null);
+    assert!(draft["markdown"].as_str().unwrap().ends_with("Looks good.\n"));
+
+    let (_, result) = call(&api, "POST", &format!("/api/drafts/{}/validate", file), Value::Null);
+    assert_eq!(result["valid"], true);
+    assert!(!result["warnings"].as_array().unwrap().is_empty());
+
+    assert_eq!(call(&api, "POST", "/api/drafts", json!({ "subject": "x" })).0, 400);
+    let (status, body) = call(&api, "GET", "/api/drafts/missing.md", Value::Null);
+    assert_eq!(status, 404);
+    assert_eq!(body["error"], "Draft 'missing.md' not found");
+}
+
+// SV6: Search returns ranked hits
+#[test]
+fn sv6_search() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    let (status, body) = call(&api, "GET", "/api/search?q=quarterly+budget", Value::Null);
+    assert_eq!(status, 200);
+    assert_eq!(body[0]["path"], "conversations/budget-review.md");
+    assert_eq!(call(&api, "GET", "/api/search", Value::Null).0, 400);
+    assert_eq!(call(&api, "GET", "/api/search?q=budget&limit=many", Value::Null).0, 400);
+    assert_eq!(call(&api, "GET", "/api/search?q=has:nothing", Value::Null).0, 400);
+}
+
+// SV7: A second sync trigger while one runs is rejected; unknown routes 404
+#[test]
+fn sv7_sync_conflict_and_routes() {
+    let (_tmp, data) = fixture();
+    let api = api(&data);
+    api.sync_running.store(true, Ordering::SeqCst);
+    assert_eq!(call(&api, "POST", "/api/sync", Value::Null).0, 409);
+    assert_eq!(call(&api, "DELETE", "/api/threads", Value::Null).0, 404);
+    assert_eq!(call(&api, "GET", "/nope", Value::Null).0, 404);
+}
+
+// SV8: Over HTTP: bearer auth, JSON responses and the SSE event stream
+#[test]
+fn sv8_http_and_sse() {
+    let (_tmp, data) = fixture();
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let port = server.server_addr().to_ip().unwrap().port();
+    let api = Arc::new(api(&data));
+    std::thread::spawn(move || corky::serve::serve(server, api));
+    let base = format!("http://127.0.0.1:{}", port);
+
+    match ureq::get(&format!("{}/api/threads", base)).call() {
+        Err(ureq::Error::Status(code, _)) => assert_eq!(code, 401),
+        other => panic!("expected 401, got {:?}", other.map(|r| r.status())),
+    }
+    let resp = ureq::get(&format!("{}/api/threads", base))
+        .set("Authorization", &format!("Bearer {}", TOKEN))
+        .call()
+        .unwrap();
+    assert_eq!(resp.content_type(), "application/json");
+    let body: Value = resp.into_json().unwrap();
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    // SSE: subscribe, then an event written by `watch` arrives on the stream
+    let mut stream = std::net::TcpStream::connect(("127.0.0.1", port)).unwrap();
+    write!(
+        stream,
+        "GET /api/events?token={} HTTP/1.1\r\nHost: localhost\r\nAccept: text/event-stream\r\n\r\n",
+        TOKEN
+    )
+    .unwrap();
+    stream
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut status = String::new();
+    reader.read_line(&mut status).unwrap();
+    assert!(status.contains("200"), "{}", status);
+
+    // Give the stream a moment to record its starting offset
+    std::thread::sleep(std::time::Duration::from_millis(200));
+    events::append(&data, "watch", SyncEvent::SyncFinished { new_labels: 3 }).unwrap();
+
+    let mut seen = String::new();
+    let mut buf = [0u8; 256];
+    while !seen.contains("\"new_labels\":3") {
+        let n = reader.read(&mut buf).unwrap();
+        assert!(n > 0, "stream closed: {}", seen);
+        seen.push_str(&String::from_utf8_lossy(&buf[..n]));
+    }
+    assert!(seen.contains("text/event-stream"));
+    assert!(seen.contains("event: sync_finished"));
+}
@@ -0,0 +1,315 @@
+//! Live Slack sync tests against a mock Web API (SS1–SS7).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use mockito::{Matcher, Server};
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::config::corky_config::SlackConfig;
+use corky::sync::markdown::parse_thread_markdown;
+use corky::sync::slack_sync;
+use corky::sync::types::{SlackSyncState, Thread};
+
+/// Slack `ts` for `secs_ago` seconds before now (recent, so threads stay open).
+fn ts(secs_ago: i64) -> String {
+    format!("{}.000100", chrono::Utc::now().timestamp() - secs_ago)
+}
+
+fn ok(body: &str) -> String {
+    format!(r#"{{"ok": true, {}}}"#, body)
+}
+
+/// users.list and conversations.list, shared by every run.
+fn mock_workspace(server: &mut Server) {
+    server
+        .mock("GET", "/users.list")
+        .match_header("Authorization", "Bearer xoxb-test")
+        .match_query(Matcher::Any)
+        .with_body(ok(r#""members": [
+            {"id": "U1", "name": "alice", "profile": {"display_name": "Alice"}},
+            {"id": "U2", "name": "bob", "real_name": "Bob Jones"}
+        ]"#))
+        .create();
+    server
+        .mock("GET", "/conversations.list")
+        .match_query(Matcher::UrlEncoded("types".into(), "public_channel,private_channel".into()))
+        .with_body(ok(r#""channels": [
+            {"id": "C1", "name": "general", "is_member": true},
+            {"id": "C2", "name": "random", "is_member": false}
+        ]"#))
+        .create();
+}
+
+fn threads(out_dir: &Path) -> Vec<Thread> {
+    let mut threads: Vec<Thread> = std::fs::read_dir(out_dir)
+        .unwrap()
+        .flatten()
+        .filter_map(|e| parse_thread_markdown(&std::fs::read_to_string(e.path()).unwrap()))
+        .collect();
+    threads.sort_by(|a, b| a.id.cmp(&b.id));
+    threads
+}
+
+// SS1: First sync pages through history, fetches replies, records cursors
+#[test]
+fn ss1_initial_sync() {
+    let (parent, reply, standalone) = (ts(7200), ts(7000), ts(3600));
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::Regex("^channel=C1&limit=200$".into()))
+        .with_body(ok(&format!(
+            r#""messages": [
+                {{"user": "U2", "text": "Unrelated <@U1>", "ts": "{standalone}"}},
+                {{"subtype": "channel_join", "user": "U2", "text": "joined", "ts": "{standalone}9"}}
+            ],
+            "response_metadata": {{"next_cursor": "page2"}}"#
+        )))
+        .create();
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::UrlEncoded("cursor".into(), "page2".into()))
+        .with_body(ok(&format!(
+            r#""messages": [{{"user": "U1", "text": "Kickoff at 3?", "ts": "{parent}", "thread_ts": "{parent}", "reply_count": 1}}],
+            "response_metadata": {{"next_cursor": ""}}"#
+        )))
+        .create();
+    let replies = server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("channel".into(), "C1".into()),
+            Matcher::UrlEncoded("ts".into(), parent.clone()),
+            Matcher::UrlEncoded("oldest".into(), parent.clone()),
+        ]))
+        .with_body(ok(&format!(
+            r#""messages": [
+                {{"user": "U1", "text": "Kickoff at 3?", "ts": "{parent}", "thread_ts": "{parent}"}},
+                {{"user": "U2", "text": "Works for me", "ts": "{reply}", "thread_ts": "{parent}"}}
+            ]"#
+        )))
+        .create();
+    // The standalone message is tracked too, in case replies come later
+    server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::UrlEncoded("ts".into(), standalone.clone()))
+        .with_body(ok(&format!(r#""messages": [{{"user": "U2", "text": "Unrelated <@U1>", "ts": "{standalone}"}}]"#)))
+        .create();
+
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("conversations");
+    let mut state = SlackSyncState::default();
+    let stats = slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, &out_dir)
+        .unwrap();
+    replies.assert();
+
+    assert_eq!(stats.channels, 1, "non-member #random is skipped");
+    assert_eq!(stats.imported, 3);
+    let threads = threads(&out_dir);
+    assert_eq!(threads.len(), 2);
+    let kickoff = threads.iter().find(|t| t.id == format!("slack:C1:{}", parent)).unwrap();
+    assert_eq!(kickoff.subject, "Kickoff at 3?");
+    assert_eq!(kickoff.labels, vec!["slack:general"]);
+    assert_eq!(kickoff.accounts, vec!["slack"]);
+    assert_eq!(kickoff.messages[0].from, "Alice");
+    assert_eq!(kickoff.messages[1].from, "Bob Jones");
+    let unrelated = threads.iter().find(|t| t.id == format!("slack:C1:{}", standalone)).unwrap();
+    assert_eq!(unrelated.messages[0].body, "Unrelated @Alice");
+
+    let channel = &state.channels["C1"];
+    assert_eq!(channel.latest_ts, format!("{}9", standalone));
+    assert_eq!(channel.threads[&parent], reply);
+    assert_eq!(channel.threads[&standalone], standalone);
+}
+
+// SS2: Next sync asks only for newer history and new replies on open threads
+#[test]
+fn ss2_incremental_sync() {
+    let (parent, reply, new_reply, latest) = (ts(7200), ts(7000), ts(60), ts(3600));
+    let mut state = SlackSyncState::default();
+    let channel = state.channels.entry("C1".into()).or_default();
+    channel.latest_ts = latest.clone();
+    channel.threads.insert(parent.clone(), reply.clone());
+
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    let history = server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::UrlEncoded("oldest".into(), latest.clone()))
+        .with_body(ok(r#""messages": []"#))
+        .create();
+    server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::UrlEncoded("oldest".into(), reply.clone()))
+        .with_body(ok(&format!(
+            r#""messages": [
+                {{"user": "U1", "text": "Kickoff at 3?", "ts": "{parent}", "thread_ts": "{parent}"}},
+                {{"user": "U1", "text": "Moved to 4", "ts": "{new_reply}", "thread_ts": "{parent}"}}
+            ]"#
+        )))
+        .create();
+
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("conversations");
+    let stats = slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, &out_dir)
+        .unwrap();
+    history.assert();
+
+    assert_eq!(stats.imported, 1, "only the new reply; the parent is not re-merged");
+    assert_eq!(stats.duplicates, 0);
+    let threads = threads(&out_dir);
+    assert_eq!(threads[0].id, format!("slack:C1:{}", parent));
+    assert_eq!(threads[0].messages[0].body, "Moved to 4");
+    assert_eq!(state.channels["C1"].threads[&parent], new_reply);
+    assert_eq!(state.channels["C1"].latest_ts, latest);
+}
+
+// SS3: Threads quiet for longer than the reply window are closed
+#[test]
+fn ss3_quiet_threads_close() {
+    let old = "1600000000.000100".to_string();
+    let mut state = SlackSyncState::default();
+    let channel = state.channels.entry("C1".into()).or_default();
+    channel.latest_ts = old.clone();
+    channel.threads.insert(old.clone(), old.clone());
+
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::Any)
+        .with_body(ok(r#""messages": []"#))
+        .create();
+    server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::Any)
+        .with_body(ok(&format!(r#""messages": [{{"user": "U1", "text": "old", "ts": "{old}", "thread_ts": "{old}"}}]"#)))
+        .create();
+
+    let tmp = tempfile::tempdir().unwrap();
+    slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, tmp.path()).unwrap();
+    assert!(state.channels["C1"].threads.is_empty());
+}
+
+// SS6: A reply to a message that had none when it was synced is fetched next run
+#[test]
+fn ss6_first_reply_after_sync() {
+    let (parent, reply) = (ts(3600), ts(60));
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("conversations");
+    let mut state = SlackSyncState::default();
+
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::Any)
+        .with_body(ok(&format!(r#""messages": [{{"user": "U1", "text": "Anyone for lunch?", "ts": "{parent}"}}]"#)))
+        .create();
+    let parent_only = format!(r#""messages": [{{"user": "U1", "text": "Anyone for lunch?", "ts": "{parent}"}}]"#);
+    server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::UrlEncoded("ts".into(), parent.clone()))
+        .with_body(ok(&parent_only))
+        .create();
+    let stats = slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, &out_dir)
+        .unwrap();
+    assert_eq!(stats.imported, 1);
+
+    // Second run: history has nothing newer, but the parent now has a reply
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::UrlEncoded("oldest".into(), parent.clone()))
+        .with_body(ok(r#""messages": []"#))
+        .create();
+    let replies = server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("ts".into(), parent.clone()),
+            Matcher::UrlEncoded("oldest".into(), parent.clone()),
+        ]))
+        .with_body(ok(&format!(
+            r#""messages": [
+                {{"user": "U1", "text": "Anyone for lunch?", "ts": "{parent}", "thread_ts": "{parent}", "reply_count": 1}},
+                {{"user": "U2", "text": "Me!", "ts": "{reply}", "thread_ts": "{parent}"}}
+            ]"#
+        )))
+        .create();
+    let stats = slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, &out_dir)
+        .unwrap();
+    replies.assert();
+
+    assert_eq!(stats.imported, 1);
+    let threads = threads(&out_dir);
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].id, format!("slack:C1:{}", parent));
+    assert_eq!(threads[0].messages.len(), 2);
+    assert_eq!(threads[0].messages[1].body, "Me!");
+    assert_eq!(state.channels["C1"].threads[&parent], reply);
+}
+
+// SS7: A failed merge keeps the channel's old cursors so the messages are refetched
+#[test]
+fn ss7_failed_merge_keeps_cursors() {
+    let (old, new) = (ts(7200), ts(60));
+    let mut state = SlackSyncState::default();
+    state.channels.entry("C1".into()).or_default().latest_ts = old.clone();
+
+    let mut server = Server::new();
+    mock_workspace(&mut server);
+    server
+        .mock("GET", "/conversations.history")
+        .match_query(Matcher::Any)
+        .with_body(ok(&format!(r#""messages": [{{"user": "U1", "text": "New", "ts": "{new}"}}]"#)))
+        .create();
+    server
+        .mock("GET", "/conversations.replies")
+        .match_query(Matcher::Any)
+        .with_body(ok(&format!(r#""messages": [{{"user": "U1", "text": "New", "ts": "{new}"}}]"#)))
+        .create();
+
+    // A file where the conversations directory should be makes the merge fail
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("conversations");
+    std::fs::write(&out_dir, "not a directory").unwrap();
+    assert!(slack_sync::sync_at(&server.url(), "xoxb-test", &SlackConfig::default(), &[], &mut state, &out_dir).is_err());
+
+    let channel = &state.channels["C1"];
+    assert_eq!(channel.latest_ts, old);
+    assert!(channel.threads.is_empty());
+}
+
+// SS4: Slack API errors surface with the method name
+#[test]
+fn ss4_api_error() {
+    let mut server = Server::new();
+    server
+        .mock("GET", "/users.list")
+        .match_query(Matcher::Any)
+        .with_body(r#"{"ok": false, "error": "invalid_auth"}"#)
+        .create();
+
+    let tmp = tempfile::tempdir().unwrap();
+    let mut state = SlackSyncState::default();
+    let err = slack_sync::sync_at(&server.url(), "bad", &SlackConfig::default(), &[], &mut state, tmp.path())
+        .unwrap_err();
+    assert!(err.to_string().contains("users.list: invalid_auth"));
+}
+
+// SS5: `corky sync slack` needs a [slack] section
+#[test]
+fn ss5_cli_requires_config() {
+    let (_tmp, data) = common::temp_data_dir();
+    std::fs::write(data.join(".corky.toml"), "[sync]\nkeep_raw = false\n").unwrap();
+    cargo_bin_cmd!("corky")
+        .current_dir(&data)
+        .env("CORKY_DATA", data.to_string_lossy().as_ref())
+        .args(["sync", "slack"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No [slack] section"));
+}
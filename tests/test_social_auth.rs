@@ -1,4 +1,4 @@
-//! OAuth auth flow edge cases (A1–A5).
+//! OAuth auth flow edge cases (A1–A6).
 
 mod common;
 
@@ -87,3 +87,19 @@ fn platform_display() {
     assert_eq!(Platform::Mastodon.to_string(), "mastodon");
     assert_eq!(Platform::Twitter.to_string(), "twitter");
 }
+
+// A6: --org adds LinkedIn organization scopes (member scopes kept)
+#[test]
+fn a6_org_scopes() {
+    let member = auth::scopes(Platform::LinkedIn, false);
+    assert!(member.contains(&"w_member_social"));
+    assert!(!member.contains(&auth::LINKEDIN_ORG_POST_SCOPE));
+
+    let org = auth::scopes(Platform::LinkedIn, true);
+    assert!(org.contains(&"w_member_social"));
+    assert!(org.contains(&auth::LINKEDIN_ORG_POST_SCOPE));
+    assert!(org.contains(&"rw_organization_admin"));
+
+    // YouTube ignores the org flag
+    assert_eq!(auth::scopes(Platform::Youtube, true), auth::scopes(Platform::Youtube, false));
+}
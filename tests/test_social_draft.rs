@@ -142,17 +142,20 @@ fn d8_render_parse_roundtrip() {
     let meta = SocialDraftMeta {
         platform: Platform::LinkedIn,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Ready,
         tags: vec!["rust".to_string(), "ai".to_string()],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let original = SocialDraft::new(meta, "Test body content.\n".to_string());
@@ -240,17 +243,20 @@ fn im4_roundtrip_with_images() {
     let meta = SocialDraftMeta {
         platform: Platform::LinkedIn,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Ready,
         tags: vec![],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec!["assets/screenshot.png".to_string(), "assets/diagram.png".to_string()],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let original = SocialDraft::new(meta, "Post with images.\n".to_string());
@@ -268,17 +274,20 @@ fn im6_empty_images_omitted_in_yaml() {
     let meta = SocialDraftMeta {
         platform: Platform::LinkedIn,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Draft,
         tags: vec![],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let draft = SocialDraft::new(meta, "Body.\n".to_string());
@@ -310,17 +319,20 @@ fn yt1_roundtrip_with_video_fields() {
     let meta = SocialDraftMeta {
         platform: Platform::Youtube,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Ready,
         tags: vec!["rust".to_string(), "tutorial".to_string()],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: Some("assets/demo.mp4".to_string()),
         captions: Some("assets/demo.srt".to_string()),
         title: Some("Rust Tutorial: Getting Started".to_string()),
+        metrics: None,
     };
 
     let original = SocialDraft::new(meta, "Video description goes here.\n".to_string());
@@ -383,17 +395,20 @@ fn li2_roundtrip_with_post_id() {
     let meta = SocialDraftMeta {
         platform: Platform::LinkedIn,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Published,
         tags: vec![],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: Some("urn:li:share:98765".to_string()),
         post_url: Some("https://www.linkedin.com/feed/update/urn:li:share:98765".to_string()),
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let original = SocialDraft::new(meta, "Edited content.\n".to_string());
@@ -413,17 +428,20 @@ fn yt3_none_video_fields_omitted() {
     let meta = SocialDraftMeta {
         platform: Platform::Youtube,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Draft,
         tags: vec![],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     };
 
     let draft = SocialDraft::new(meta, "Body.\n".to_string());
@@ -432,3 +450,55 @@ fn yt3_none_video_fields_omitted() {
     assert!(!rendered.contains("captions"), "None captions should not appear in YAML: {}", rendered);
     assert!(!rendered.contains("title"), "None title should not appear in YAML: {}", rendered);
 }
+
+// ST1: metrics block round-trips through frontmatter
+#[test]
+fn st1_metrics_roundtrip() {
+    let content = r#"---
+platform: linkedin
+author: btakita
+status: published
+post_id: "urn:li:share:12345"
+metrics:
+  reactions: 17
+  comments: 4
+  impressions: 1200
+  fetched_at: 2026-10-01T12:00:00Z
+---
+Published post.
+"#;
+
+    let draft = SocialDraft::parse(content).unwrap();
+    let metrics = draft.meta.metrics.clone().unwrap();
+    assert_eq!(metrics.reactions, 17);
+    assert_eq!(metrics.comments, 4);
+    assert_eq!(metrics.shares, None);
+    assert_eq!(metrics.impressions, Some(1200));
+
+    let rendered = draft.render().unwrap();
+    assert!(!rendered.contains("shares"), "None shares should be omitted: {}", rendered);
+    let parsed = SocialDraft::parse(&rendered).unwrap();
+    assert_eq!(parsed.meta.metrics, Some(metrics));
+}
+
+// ORG1: organization field parses and is omitted when unset
+#[test]
+fn org1_organization_field() {
+    let content = r#"---
+platform: linkedin
+author: btakita
+organization: acme
+status: ready
+---
+Company news.
+"#;
+
+    let draft = SocialDraft::parse(content).unwrap();
+    assert_eq!(draft.meta.organization, Some("acme".to_string()));
+    let rendered = draft.render().unwrap();
+    assert!(rendered.contains("organization: acme"));
+
+    let personal = SocialDraft::parse("---\nplatform: linkedin\nauthor: btakita\n---\nHi\n").unwrap();
+    assert_eq!(personal.meta.organization, None);
+    assert!(!personal.render().unwrap().contains("organization"));
+}
@@ -257,3 +257,44 @@ handle = "brian-takita"
     let name = profiles.resolve_handle("brian-takita", Platform::LinkedIn);
     assert_eq!(name, Some("btakita".to_string()));
 }
+
+// resolve_org_urn
+#[test]
+fn resolve_org_urn_requires_organization_urn() {
+    let profiles = ProfilesFile::parse(
+        r#"
+[btakita.linkedin]
+handle = "brian-takita"
+urn = "urn:li:person:abc123"
+
+[acme.linkedin]
+handle = "acme-inc"
+urn = "urn:li:organization:42"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(profiles.resolve_org_urn("acme").unwrap(), "urn:li:organization:42");
+    let err = profiles.resolve_org_urn("btakita").unwrap_err().to_string();
+    assert!(err.contains("not a LinkedIn organization"), "got: {}", err);
+    assert!(profiles.resolve_org_urn("nobody").is_err());
+}
+
+// resolve_by_urn
+#[test]
+fn resolve_by_urn_reverse_lookup() {
+    let profiles = ProfilesFile::parse(
+        r#"
+[acme.linkedin]
+handle = "acme-inc"
+urn = "urn:li:organization:42"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        profiles.resolve_by_urn("urn:li:organization:42", Platform::LinkedIn),
+        Some("acme".to_string())
+    );
+    assert_eq!(profiles.resolve_by_urn("urn:li:organization:42", Platform::Youtube), None);
+}
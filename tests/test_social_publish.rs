@@ -1,4 +1,4 @@
-//! Publish flow edge cases (PB1–PB10).
+//! Publish flow edge cases (PB1–PB11).
 //!
 //! Most tests here verify draft validation logic without making actual API calls.
 
@@ -16,17 +16,20 @@ fn ready_meta() -> SocialDraftMeta {
     SocialDraftMeta {
         platform: Platform::LinkedIn,
         author: "btakita".to_string(),
+        organization: None,
         visibility: "public".to_string(),
         status: DraftStatus::Ready,
         tags: vec![],
         scheduled_at: None,
         published_at: None,
+        retracted_at: None,
         post_id: None,
         post_url: None,
         images: vec![],
         video: None,
         captions: None,
         title: None,
+        metrics: None,
     }
 }
 
@@ -181,10 +184,12 @@ fn draft_status_roundtrip() {
     assert_eq!(DraftStatus::Draft.as_str(), "draft");
     assert_eq!(DraftStatus::Ready.as_str(), "ready");
     assert_eq!(DraftStatus::Published.as_str(), "published");
+    assert_eq!(DraftStatus::Retracted.as_str(), "retracted");
 
     assert_eq!("draft".parse::<DraftStatus>().unwrap(), DraftStatus::Draft);
     assert_eq!("ready".parse::<DraftStatus>().unwrap(), DraftStatus::Ready);
     assert_eq!("published".parse::<DraftStatus>().unwrap(), DraftStatus::Published);
+    assert_eq!("retracted".parse::<DraftStatus>().unwrap(), DraftStatus::Retracted);
     assert!("invalid".parse::<DraftStatus>().is_err());
 }
 
@@ -273,3 +278,23 @@ fn publish_file_roundtrip_with_images() {
     let parsed = SocialDraft::parse(&loaded).unwrap();
     assert_eq!(parsed.meta.images, vec!["assets/screenshot.png"]);
 }
+
+// PB11: Retracted draft → publish rejected before any API call
+#[test]
+fn pb11_retracted_rejected() {
+    let tmp = TempDir::new().unwrap();
+    let file = tmp.path().join("retracted.md");
+
+    let mut meta = published_meta();
+    meta.status = DraftStatus::Retracted;
+    meta.retracted_at = Some(Utc::now());
+    let draft = SocialDraft::new(meta, "Hello world".to_string());
+    std::fs::write(&file, draft.render().unwrap()).unwrap();
+
+    let parsed = SocialDraft::parse(&std::fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(parsed.meta.status, DraftStatus::Retracted);
+    assert!(parsed.meta.retracted_at.is_some());
+
+    let err = corky::social::publish::publish(&file, false).unwrap_err();
+    assert!(err.to_string().contains("retracted"), "got: {}", err);
+}
@@ -0,0 +1,240 @@
+//! Social draft validation against platform limits and media rules (V1–V11).
+
+mod common;
+
+use corky::social::profiles::ProfilesFile;
+use corky::social::validate::{extract_mentions, validate_social_draft};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn write_draft(dir: &Path, frontmatter: &str, body: &str) -> PathBuf {
+    let path = dir.join("post.md");
+    std::fs::write(&path, format!("---\n{}---\n{}", frontmatter, body)).unwrap();
+    path
+}
+
+fn errors(issues: &[String]) -> Vec<&String> {
+    issues.iter().filter(|i| !i.starts_with("Warning:")).collect()
+}
+
+// V1: Valid ready LinkedIn draft → no issues
+#[test]
+fn v1_valid_linkedin_draft() {
+    let tmp = TempDir::new().unwrap();
+    let path = write_draft(
+        tmp.path(),
+        "platform: linkedin\nauthor: btakita\nstatus: ready\n",
+        "Hello world\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.is_empty(), "unexpected issues: {:?}", issues);
+}
+
+// V2: Body over per-platform limit
+#[test]
+fn v2_body_too_long_per_platform() {
+    let tmp = TempDir::new().unwrap();
+    let body = "x".repeat(400);
+
+    let path = write_draft(tmp.path(), "platform: linkedin\nauthor: a\nstatus: ready\n", &body);
+    assert!(errors(&validate_social_draft(&path, None)).is_empty());
+
+    let path = write_draft(tmp.path(), "platform: bluesky\nauthor: a\nstatus: ready\n", &body);
+    let issues = validate_social_draft(&path, None);
+    assert!(
+        issues.iter().any(|i| i.contains("300 character limit")),
+        "got: {:?}",
+        issues
+    );
+}
+
+// V3: Image missing, wrong format, too many
+#[test]
+fn v3_image_checks() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(tmp.path().join("ok.png"), b"png").unwrap();
+    std::fs::write(tmp.path().join("doc.pdf"), b"pdf").unwrap();
+    let path = write_draft(
+        tmp.path(),
+        "platform: twitter\nauthor: a\nstatus: ready\nimages:\n  - ok.png\n  - doc.pdf\n  - missing.jpg\n  - ok.png\n  - ok.png\n",
+        "Hi\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("Too many images (5)")), "got: {:?}", issues);
+    assert!(issues.iter().any(|i| i.contains("Unsupported image format 'doc.pdf'")));
+    assert!(issues.iter().any(|i| i.contains("Image file not found") && i.contains("missing.jpg")));
+}
+
+// V4: Images on YouTube are rejected
+#[test]
+fn v4_images_not_supported_on_youtube() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(tmp.path().join("v.mp4"), b"video").unwrap();
+    let path = write_draft(
+        tmp.path(),
+        "platform: youtube\nauthor: a\nstatus: ready\ntitle: T\nvideo: v.mp4\nimages:\n  - a.png\n",
+        "Description\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("Images are not supported for youtube")));
+}
+
+// V5: YouTube video required, container checked
+#[test]
+fn v5_youtube_video_checks() {
+    let tmp = TempDir::new().unwrap();
+    let path = write_draft(tmp.path(), "platform: youtube\nauthor: a\nstatus: ready\ntitle: T\n", "D\n");
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("Missing required field for YouTube: video")));
+
+    std::fs::write(tmp.path().join("clip.gif"), b"gif").unwrap();
+    let path = write_draft(
+        tmp.path(),
+        "platform: youtube\nauthor: a\nstatus: ready\ntitle: T\nvideo: clip.gif\n",
+        "D\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("Unsupported video container")), "got: {:?}", issues);
+}
+
+// V6: Captions must be SRT with a valid first cue
+#[test]
+fn v6_captions_format() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(tmp.path().join("v.mp4"), b"video").unwrap();
+    std::fs::write(
+        tmp.path().join("good.srt"),
+        "1\n00:00:01,000 --> 00:00:02,500\nHello\n",
+    )
+    .unwrap();
+    std::fs::write(tmp.path().join("bad.srt"), "WEBVTT\n\n00:01.000 --> 00:02.000\nHi\n").unwrap();
+    std::fs::write(tmp.path().join("subs.vtt"), "WEBVTT\n").unwrap();
+
+    let fm = |captions: &str| {
+        format!(
+            "platform: youtube\nauthor: a\nstatus: ready\ntitle: T\nvideo: v.mp4\ncaptions: {}\n",
+            captions
+        )
+    };
+
+    let path = write_draft(tmp.path(), &fm("good.srt"), "D\n");
+    assert!(validate_social_draft(&path, None).is_empty());
+
+    let path = write_draft(tmp.path(), &fm("bad.srt"), "D\n");
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("not valid SRT")), "got: {:?}", issues);
+
+    let path = write_draft(tmp.path(), &fm("subs.vtt"), "D\n");
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("Captions must be an SRT file")));
+}
+
+// V7: YouTube title length
+#[test]
+fn v7_youtube_title_too_long() {
+    let tmp = TempDir::new().unwrap();
+    std::fs::write(tmp.path().join("v.mp4"), b"video").unwrap();
+    let title = "t".repeat(101);
+    let path = write_draft(
+        tmp.path(),
+        &format!("platform: youtube\nauthor: a\nstatus: ready\ntitle: {}\nvideo: v.mp4\n", title),
+        "D\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("100 character limit")));
+}
+
+// V8: Unresolved @mentions are warnings; known handles/profile names pass
+#[test]
+fn v8_mentions_against_profiles() {
+    let tmp = TempDir::new().unwrap();
+    let profiles = ProfilesFile::parse(
+        r#"
+[alice.linkedin]
+handle = "alice-smith"
+"#,
+    )
+    .unwrap();
+    let path = write_draft(
+        tmp.path(),
+        "platform: linkedin\nauthor: a\nstatus: ready\n",
+        "Thanks @alice-smith and @alice and @stranger. Mail me@example.com\n",
+    );
+    let issues = validate_social_draft(&path, Some(&profiles));
+    assert_eq!(issues.len(), 1, "got: {:?}", issues);
+    assert!(issues[0].starts_with("Warning:"));
+    assert!(issues[0].contains("@stranger"));
+}
+
+// V9: Malformed draft is a single error
+#[test]
+fn v9_malformed_draft() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("bad.md");
+    std::fs::write(&path, "no frontmatter").unwrap();
+    let issues = validate_social_draft(&path, None);
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].starts_with("Invalid draft"));
+}
+
+// V10: Status warnings (draft without schedule, already published)
+#[test]
+fn v10_status_warnings() {
+    let tmp = TempDir::new().unwrap();
+    let path = write_draft(tmp.path(), "platform: linkedin\nauthor: a\n", "Hi\n");
+    let issues = validate_social_draft(&path, None);
+    assert!(errors(&issues).is_empty());
+    assert!(issues.iter().any(|i| i.contains("status is 'draft'")));
+
+    let path = write_draft(tmp.path(), "platform: linkedin\nauthor: a\nstatus: published\n", "Hi\n");
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("already published")));
+}
+
+// V11: organization must resolve to a LinkedIn organization URN
+#[test]
+fn v11_organization_profile() {
+    let tmp = TempDir::new().unwrap();
+    let profiles = ProfilesFile::parse(
+        r#"
+[btakita.linkedin]
+handle = "brian-takita"
+urn = "urn:li:person:abc"
+
+[acme.linkedin]
+handle = "acme-inc"
+urn = "urn:li:organization:42"
+"#,
+    )
+    .unwrap();
+
+    let path = write_draft(
+        tmp.path(),
+        "platform: linkedin\nauthor: btakita\norganization: acme\nstatus: ready\n",
+        "Company news\n",
+    );
+    assert!(validate_social_draft(&path, Some(&profiles)).is_empty());
+
+    let path = write_draft(
+        tmp.path(),
+        "platform: linkedin\nauthor: btakita\norganization: btakita\nstatus: ready\n",
+        "Company news\n",
+    );
+    let issues = validate_social_draft(&path, Some(&profiles));
+    assert!(issues.iter().any(|i| i.contains("not a LinkedIn organization")), "got: {:?}", issues);
+
+    let path = write_draft(
+        tmp.path(),
+        "platform: youtube\nauthor: btakita\norganization: acme\nstatus: ready\ntitle: T\n",
+        "D\n",
+    );
+    let issues = validate_social_draft(&path, None);
+    assert!(issues.iter().any(|i| i.contains("only supported on LinkedIn")));
+}
+
+// Additional: mention extraction
+#[test]
+fn extract_mentions_dedup_and_trailing_punct() {
+    let mentions = extract_mentions("@bob, hi @bob. cc @carol_x.\nemail a@b.com");
+    assert_eq!(mentions, vec!["bob", "carol_x"]);
+}
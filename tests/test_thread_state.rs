@@ -0,0 +1,138 @@
+//! CLI integration tests for `corky thread` triage state (TS1–TS3).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use chrono::{Duration, Utc};
+use predicates::prelude::*;
+use std::path::Path;
+
+use corky::sync::markdown::{parse_thread_markdown, thread_to_markdown};
+use corky::sync::types::{Message, Thread, ThreadState, ThreadStatus};
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+/// One message from Alice, `hours` ago.
+fn write_thread(dir: &Path, id: &str, hours: i64, state: ThreadState) {
+    let date = (Utc::now() - Duration::hours(hours)).to_rfc2822();
+    let thread = Thread {
+        id: id.to_string(),
+        subject: id.to_string(),
+        labels: vec!["inbox".to_string()],
+        accounts: vec!["default".to_string()],
+        messages: vec![Message {
+            id: format!("{}-0", id),
+            thread_id: id.to_string(),
+            from: "Alice <alice@example.com>".to_string(),
+            to: "me@example.com".to_string(),
+            cc: String::new(),
+            date: date.clone(),
+            subject: id.to_string(),
+            body: format!("About {}", id),
+            raw: String::new(),
+        }],
+        last_date: date,
+        state,
+    };
+    std::fs::write(dir.join(format!("{}.md", id)), thread_to_markdown(&thread)).unwrap();
+}
+
+fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let conv = data.join("conversations");
+    for id in ["budget", "venue", "lunch", "quote"] {
+        write_thread(&conv, id, 48, ThreadState::default());
+    }
+    (tmp, data)
+}
+
+fn unanswered(data: &Path) -> Vec<String> {
+    let output = corky(data).args(["unanswered", "--from", "Test User", "--format", "json"]).output().unwrap();
+    assert!(output.status.success());
+    let threads: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut files: Vec<String> = threads.as_array().unwrap().iter().map(|t| t["file"].as_str().unwrap().to_string()).collect();
+    files.sort();
+    files
+}
+
+// TS1: done, snoozed and waiting threads drop out of unanswered; reopen brings them back
+#[test]
+fn ts1_triage_hides_from_unanswered() {
+    let (_tmp, data) = setup();
+    corky(&data).args(["thread", "done", "budget"]).assert().success().stdout(predicate::str::contains("Marked budget.md done\n"));
+    corky(&data)
+        .args(["thread", "snooze", "venue", "--until", "2099-01-01"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Snoozed venue.md until 2099-01-01\n"));
+    corky(&data)
+        .args(["thread", "wait", "conversations/lunch.md", "--on", "alice"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Marked lunch.md waiting on alice\n"));
+    assert_eq!(unanswered(&data), vec!["quote.md"]);
+
+    let text = std::fs::read_to_string(data.join("conversations/lunch.md")).unwrap();
+    assert!(text.contains("**State**: waiting\n**Waiting on**: alice\n**State since**: "));
+    assert!(text.contains("About lunch"));
+
+    corky(&data)
+        .args(["thread", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[done] budget.md"))
+        .stdout(predicate::str::contains("[snoozed until 2099-01-01] venue.md"))
+        .stdout(predicate::str::contains("[waiting on alice] lunch.md"))
+        .stdout(predicate::str::contains("quote.md").not());
+
+    corky(&data).args(["thread", "reopen", "budget"]).assert().success();
+    assert_eq!(unanswered(&data), vec!["budget.md", "quote.md"]);
+    let text = std::fs::read_to_string(data.join("conversations/budget.md")).unwrap();
+    assert!(!text.contains("**State"));
+}
+
+// TS2: an expired snooze or a message newer than the state resurfaces the thread
+#[test]
+fn ts2_resurfaced_threads_show_again() {
+    let (_tmp, data) = setup();
+    let conv = data.join("conversations");
+    let since = (Utc::now() - Duration::hours(72)).to_rfc2822();
+    write_thread(
+        &conv,
+        "budget",
+        48,
+        ThreadState { status: ThreadStatus::Done, since: since.clone(), ..Default::default() },
+    );
+    write_thread(
+        &conv,
+        "venue",
+        96,
+        ThreadState { status: ThreadStatus::Snoozed, until: "2000-01-01".to_string(), since, ..Default::default() },
+    );
+    corky(&data).args(["thread", "done", "lunch"]).assert().success();
+    assert_eq!(unanswered(&data), vec!["budget.md", "quote.md", "venue.md"]);
+
+    let state = parse_thread_markdown(&std::fs::read_to_string(conv.join("budget.md")).unwrap()).unwrap().state;
+    assert_eq!(state.status, ThreadStatus::Done);
+}
+
+// TS3: bad --until values and unknown conversations are rejected
+#[test]
+fn ts3_errors() {
+    let (_tmp, data) = setup();
+    corky(&data)
+        .args(["thread", "snooze", "budget", "--until", "soon"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --until 'soon'"));
+    corky(&data)
+        .args(["thread", "done", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Conversation 'missing' not found"));
+}
@@ -0,0 +1,95 @@
+//! CLI integration tests for "is it me" detection in `corky unanswered` (UA1–UA2).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use std::path::Path;
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+fn thread(subject: &str, from: &str) -> String {
+    format!(
+        "# {subject}\n\n**Labels**: inbox\n**Accounts**: default\n**Thread ID**: {subject}\n**Last updated**: Mon, 10 Feb 2025 10:00:00 +0000\n\n---\n\n## {from} \u{2014} Mon, 10 Feb 2025 10:00:00 +0000\n\nHello\n"
+    )
+}
+
+fn setup(extra_config: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+    let (tmp, data) = common::temp_data_dir();
+    common::write_corky_toml(&data, "me@example.com");
+    let mut config = std::fs::read_to_string(data.join(".corky.toml")).unwrap();
+    config = config.replace("name = \"Test User\"\n", &format!("name = \"Test User\"\n{}", extra_config));
+    config.push_str("\n[accounts.work]\nprovider = \"imap\"\nuser = \"test.user@work.example\"\n");
+    std::fs::write(data.join(".corky.toml"), config).unwrap();
+    (tmp, data)
+}
+
+fn unanswered(data: &Path) -> Vec<String> {
+    let output = corky(data).args(["unanswered", "--format", "json"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let threads: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut files: Vec<String> =
+        threads.as_array().unwrap().iter().map(|t| t["file"].as_str().unwrap().to_string()).collect();
+    files.sort();
+    files
+}
+
+// UA1: Any account address, alias or "Me" is you; your name with someone else's address is not
+#[test]
+fn ua1_accounts_and_aliases() {
+    let (_tmp, data) = setup("aliases = [\"old@example.org\"]\n");
+    let conv = data.join("conversations");
+    let fixtures = [
+        ("work", "Test User <TEST.USER@work.example>"),
+        ("old", "T. User <old@example.org>"),
+        ("sms", "Me"),
+        ("spoof", "Test User <test.user@elsewhere.example>"),
+        ("alice", "Alice <alice@example.com>"),
+    ];
+    for (slug, from) in fixtures {
+        std::fs::write(conv.join(format!("{}.md", slug)), thread(slug, from)).unwrap();
+    }
+    assert_eq!(unanswered(&data), vec!["alice.md", "spoof.md"]);
+
+    // --from adds a name or address rather than replacing the configured ones
+    let output = corky(&data)
+        .args(["unanswered", "--from", "alice@example.com", "--format", "json"])
+        .output()
+        .unwrap();
+    let threads: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(threads.as_array().unwrap().len(), 1);
+    assert_eq!(threads[0]["file"], "spoof.md");
+}
+
+// UA2: Telegram messages from [owner] handles.telegram are imported as "Me"
+#[test]
+fn ua2_telegram_owner_handle() {
+    let (_tmp, data) = setup("handles = { telegram = \"user456\" }\n");
+    let export = r#"{
+        "name": "Alice",
+        "type": "personal_chat",
+        "id": 12345,
+        "messages": [
+            {"id": 1, "type": "message", "date": "2024-10-09T19:32:23", "from": "Alice", "from_id": "user123", "text": "Lunch?"},
+            {"id": 2, "type": "message", "date": "2024-10-09T19:33:00", "from": "Tester", "from_id": "user456", "text": "Sure"}
+        ]
+    }"#;
+    let path = data.join("result.json");
+    std::fs::write(&path, export).unwrap();
+    corky(&data).args(["sync", "telegram-import"]).arg(&path).assert().success();
+
+    let file = std::fs::read_dir(data.join("conversations"))
+        .unwrap()
+        .flatten()
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|x| x == "md"))
+        .unwrap();
+    let text = std::fs::read_to_string(file).unwrap();
+    assert!(text.contains("## Alice \u{2014}"));
+    assert!(text.contains("## Me \u{2014}"));
+    assert!(!text.contains("Tester"));
+    assert!(unanswered(&data).is_empty());
+}
@@ -0,0 +1,337 @@
+//! vCard import/export and CardDAV sync tests (VC1–VC5).
+//!
+//! CardDAV runs against an in-process stand-in server that implements the
+//! PROPFIND / GET / PUT / DELETE subset corky uses, with ETags and
+//! If-Match / If-None-Match preconditions.
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tiny_http::{Header, Response, Server};
+
+use corky::config::contact::{load_contacts, save_contact, Contact};
+
+const COLLECTION: &str = "/ab/";
+/// Basic auth for me:secret
+const AUTH: &str = "Basic bWU6c2VjcmV0";
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+fn contacts(data: &Path) -> BTreeMap<String, Contact> {
+    load_contacts(Some(&data.join(".corky.toml"))).unwrap()
+}
+
+fn card(uid: &str, body: &str) -> String {
+    format!("BEGIN:VCARD\r\nVERSION:3.0\r\nUID:{}\r\n{}END:VCARD\r\n", uid, body)
+}
+
+/// Cards by path → (ETag, vCard body)
+type Store = Arc<Mutex<BTreeMap<String, (String, String)>>>;
+
+/// Stand-in CardDAV server. Returns the collection URL and its card store.
+fn dav_server() -> (String, Store) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}{}", server.server_addr().to_ip().unwrap(), COLLECTION);
+    let store: Store = Arc::default();
+    let shared = store.clone();
+    std::thread::spawn(move || {
+        let mut next_etag = 100;
+        for mut req in server.incoming_requests() {
+            let header = |name: &'static str| {
+                req.headers()
+                    .iter()
+                    .find(|h| h.field.equiv(name))
+                    .map(|h| h.value.as_str().to_string())
+            };
+            if header("Authorization").as_deref() != Some(AUTH) {
+                req.respond(Response::empty(401)).unwrap();
+                continue;
+            }
+            let (if_match, if_none_match) = (header("If-Match"), header("If-None-Match"));
+            let mut body = String::new();
+            req.as_reader().read_to_string(&mut body).unwrap();
+            let path = req.url().to_string();
+            let mut store = shared.lock().unwrap();
+            let response = match req.method().as_str() {
+                "PROPFIND" => {
+                    let mut xml = format!(
+                        r#"<?xml version="1.0"?><d:multistatus xmlns:d="DAV:"><d:response><d:href>{}</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><card:addressbook xmlns:card="urn:ietf:params:xml:ns:carddav"/></d:resourcetype></d:prop></d:propstat></d:response>"#,
+                        COLLECTION
+                    );
+                    for (href, (etag, _)) in store.iter() {
+                        xml.push_str(&format!(
+                            "<d:response><d:href>{}</d:href><d:propstat><d:prop><d:getetag>{}</d:getetag><d:resourcetype/></d:prop></d:propstat></d:response>",
+                            href,
+                            etag.replace('"', "&quot;")
+                        ));
+                    }
+                    xml.push_str("</d:multistatus>");
+                    Response::from_string(xml).with_status_code(207)
+                }
+                "GET" => match store.get(&path) {
+                    Some((_, vcf)) => Response::from_string(vcf.clone()),
+                    None => Response::from_string("").with_status_code(404),
+                },
+                "PUT" => {
+                    let current = store.get(&path).map(|(etag, _)| etag.clone());
+                    let precondition_ok = match (&if_match, &if_none_match) {
+                        (Some(tag), _) => current.as_ref() == Some(tag),
+                        (None, Some(_)) => current.is_none(),
+                        (None, None) => true,
+                    };
+                    if precondition_ok {
+                        next_etag += 1;
+                        store.insert(path, (format!("\"{}\"", next_etag), body));
+                        Response::from_string("").with_status_code(if current.is_some() { 204 } else { 201 })
+                    } else {
+                        Response::from_string("").with_status_code(412)
+                    }
+                }
+                "DELETE" => match store.get(&path) {
+                    Some((etag, _)) if if_match.as_ref().is_none_or(|tag| tag == etag) => {
+                        store.remove(&path);
+                        Response::from_string("").with_status_code(204)
+                    }
+                    Some(_) => Response::from_string("").with_status_code(412),
+                    None => Response::from_string("").with_status_code(404),
+                },
+                _ => Response::from_string("").with_status_code(405),
+            };
+            drop(store);
+            req.respond(response.with_header("DAV: 1, 3, addressbook".parse::<Header>().unwrap())).unwrap();
+        }
+    });
+    (url, store)
+}
+
+fn put_card(store: &Store, name: &str, etag: &str, vcf: String) {
+    store.lock().unwrap().insert(format!("{}{}", COLLECTION, name), (etag.to_string(), vcf));
+}
+
+// VC1: Import merges into matching contacts and creates the rest; re-import is a no-op
+#[test]
+fn vc1_import() {
+    let (_tmp, data) = common::temp_data_dir();
+    std::fs::write(
+        data.join(".corky.toml"),
+        "[contacts.alice]\nemails = [\"alice@example.com\"]\norganization = \"Local Org\"\n",
+    )
+    .unwrap();
+    let vcf = data.join("export.vcf");
+    std::fs::write(
+        &vcf,
+        format!(
+            "{}{}{}",
+            card("a1", "FN:Alice Smith\r\nEMAIL:ALICE@example.com\r\nTEL;TYPE=CELL:+1 555 0001\r\nORG:Remote Org\r\n"),
+            card("b1", "N:Jones;Bob;;;\r\nEMAIL;TYPE=WORK:bob@example.com\r\nNICKNAME:Bobby\r\n"),
+            "BEGIN:VCARD\r\nVERSION:3.0\r\nNOTE:nothing useful\r\nEND:VCARD\r\n"
+        ),
+    )
+    .unwrap();
+
+    corky(&data)
+        .args(["contact", "import", &vcf.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated alice"))
+        .stdout(predicate::str::contains("Created bob-jones"))
+        .stdout(predicate::str::contains("1 created, 1 updated, 0 unchanged, 1 skipped"));
+
+    let contacts = contacts(&data);
+    let alice = &contacts["alice"];
+    assert_eq!(alice.full_name, "Alice Smith");
+    assert_eq!(alice.organization, "Local Org", "existing values win");
+    assert_eq!(alice.phones, vec!["+1 555 0001"]);
+    assert_eq!(alice.uid, "a1");
+    let bob = &contacts["bob-jones"];
+    assert_eq!(bob.emails, vec!["bob@example.com"]);
+    assert_eq!(bob.aliases, vec!["Bobby"]);
+    assert!(data.join("contacts/bob-jones/AGENTS.md").exists());
+
+    corky(&data)
+        .args(["contact", "import", &vcf.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 created, 0 updated, 2 unchanged, 1 skipped"));
+}
+
+// VC2: Export writes vCard 3.0 that imports back without duplicates
+#[test]
+fn vc2_export_roundtrip() {
+    let (_tmp, data) = common::temp_data_dir();
+    std::fs::write(
+        data.join(".corky.toml"),
+        "[contacts.alice]\nfull_name = \"Alice Smith\"\nemails = [\"alice@example.com\"]\nphones = [\"+15550001\"]\norganization = \"Acme, Inc.\"\n\n[contacts.bob]\nemails = [\"bob@example.com\"]\n",
+    )
+    .unwrap();
+
+    corky(&data)
+        .args(["contact", "export", "--contact", "alice"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("BEGIN:VCARD\r\nVERSION:3.0\r\nUID:corky-alice\r\nFN:Alice Smith\r\n"))
+        .stdout(predicate::str::contains("ORG:Acme\\, Inc.\r\n"))
+        .stdout(predicate::str::contains("bob@example.com").not());
+
+    let out = data.join("all.vcf");
+    corky(&data)
+        .args(["contact", "export", &out.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 2 contact(s)"));
+    corky(&data)
+        .args(["contact", "import", &out.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 created"));
+
+    corky(&data)
+        .args(["contact", "export", "--contact", "nobody"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown contact: nobody"));
+}
+
+fn write_carddav_config(data: &Path, url: &str) {
+    std::fs::write(
+        data.join(".corky.toml"),
+        format!(
+            "[carddav]\nurl = \"{url}\"\nuser = \"me\"\npassword = \"secret\"\n\n[contacts.alice]\nemails = [\"alice@example.com\"]\n\n[contacts.carol]\nfull_name = \"Carol White\"\nemails = [\"carol@example.com\"]\n"
+        ),
+    )
+    .unwrap();
+}
+
+// VC3: First CardDAV sync links, creates and uploads
+#[test]
+fn vc3_carddav_first_sync() {
+    let (_tmp, data) = common::temp_data_dir();
+    let (url, store) = dav_server();
+    write_carddav_config(&data, &url);
+    put_card(&store, "alice.vcf", "\"1\"", card("alice-uid", "FN:Alice Smith\r\nEMAIL:alice@example.com\r\nTEL:+15550001\r\n"));
+    put_card(&store, "bob.vcf", "\"2\"", card("bob-uid", "FN:Bob Jones\r\nEMAIL:bob@example.com\r\nORG:Acme\r\n"));
+
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Linked alice"))
+        .stdout(predicate::str::contains("Created bob-jones"))
+        .stdout(predicate::str::contains("Uploading carol"))
+        .stdout(predicate::str::contains(
+            "0 pulled, 0 pushed, 0 merged, 1 created locally, 1 created on server, 0 deleted on server, 0 unlinked",
+        ));
+
+    let contacts = contacts(&data);
+    assert_eq!(contacts["alice"].phones, vec!["+15550001"]);
+    assert_eq!(contacts["alice"].uid, "alice-uid");
+    assert_eq!(contacts["bob-jones"].organization, "Acme");
+    assert_eq!(contacts["carol"].uid, "corky-carol");
+    assert!(data.join("contacts/bob-jones/AGENTS.md").exists());
+
+    let store = store.lock().unwrap();
+    let (_, carol) = &store["/ab/corky-carol.vcf"];
+    assert!(carol.contains("FN:Carol White\r\n"));
+    assert_eq!(store["/ab/alice.vcf"].0, "\"1\"", "alice's card is untouched");
+}
+
+// VC4: Later syncs move one-sided changes, delete and unlink
+#[test]
+fn vc4_carddav_incremental() {
+    let (_tmp, data) = common::temp_data_dir();
+    let (url, store) = dav_server();
+    write_carddav_config(&data, &url);
+    put_card(&store, "alice.vcf", "\"1\"", card("alice-uid", "FN:Alice Smith\r\nEMAIL:alice@example.com\r\n"));
+    put_card(&store, "bob.vcf", "\"2\"", card("bob-uid", "FN:Bob Jones\r\nEMAIL:bob@example.com\r\nORG:Acme\r\n"));
+    corky(&data).args(["contact", "carddav"]).assert().success();
+
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 pulled, 0 pushed, 0 merged, 0 created locally, 0 created on server"));
+
+    // Server: bob's org changes, alice's card goes away. Local: carol gets a phone.
+    put_card(&store, "bob.vcf", "\"50\"", card("bob-uid", "FN:Bob Jones\r\nEMAIL:bob@example.com\r\nORG:Initech\r\n"));
+    store.lock().unwrap().remove("/ab/alice.vcf");
+    let toml = data.join(".corky.toml");
+    let mut carol = contacts(&data)["carol"].clone();
+    carol.phones.push("+15550003".into());
+    save_contact("carol", &carol, Some(&toml)).unwrap();
+
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("alice: card deleted on the server"))
+        .stdout(predicate::str::contains("1 pulled, 1 pushed, 0 merged, 0 created locally, 0 created on server, 0 deleted on server, 1 unlinked"));
+    assert_eq!(contacts(&data)["bob-jones"].organization, "Initech");
+    assert!(store.lock().unwrap()["/ab/corky-carol.vcf"].1.contains("TEL:+15550003\r\n"));
+    assert!(contacts(&data).contains_key("alice"), "unlinked contact is kept");
+
+    // Removing carol locally deletes her card; alice is not uploaded again
+    let content = std::fs::read_to_string(&toml).unwrap();
+    let mut doc = content.parse::<toml_edit::DocumentMut>().unwrap();
+    doc["contacts"].as_table_mut().unwrap().remove("carol");
+    std::fs::write(&toml, doc.to_string()).unwrap();
+
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 created on server, 1 deleted on server"));
+    let store = store.lock().unwrap();
+    assert_eq!(store.keys().collect::<Vec<_>>(), vec!["/ab/bob.vcf"]);
+}
+
+// VC5: Both sides changed → merged; --dry-run writes nothing; config is required
+#[test]
+fn vc5_carddav_merge_dry_run_and_config() {
+    let (_tmp, data) = common::temp_data_dir();
+    let (url, store) = dav_server();
+    write_carddav_config(&data, &url);
+    put_card(&store, "alice.vcf", "\"1\"", card("alice-uid", "FN:Alice Smith\r\nEMAIL:alice@example.com\r\n"));
+    corky(&data).args(["contact", "carddav"]).assert().success();
+
+    put_card(&store, "alice.vcf", "\"60\"", card("alice-uid", "FN:Alice Smith\r\nEMAIL:alice@example.com\r\nTEL:+15550001\r\n"));
+    let toml = data.join(".corky.toml");
+    let mut alice = contacts(&data)["alice"].clone();
+    alice.emails.push("alice@work.com".into());
+    save_contact("alice", &alice, Some(&toml)).unwrap();
+    let before = std::fs::read_to_string(&toml).unwrap();
+
+    corky(&data)
+        .args(["contact", "carddav", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Merged alice"))
+        .stdout(predicate::str::contains("CardDAV sync dry run"));
+    assert_eq!(std::fs::read_to_string(&toml).unwrap(), before);
+    assert_eq!(store.lock().unwrap()["/ab/alice.vcf"].0, "\"60\"");
+
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1 merged"));
+    let alice = &contacts(&data)["alice"];
+    assert_eq!(alice.emails, vec!["alice@example.com", "alice@work.com"]);
+    assert_eq!(alice.phones, vec!["+15550001"]);
+    let vcf = store.lock().unwrap()["/ab/alice.vcf"].1.clone();
+    assert!(vcf.contains("alice@work.com") && vcf.contains("TEL:+15550001"));
+
+    std::fs::write(&toml, "[contacts.alice]\nemails = [\"alice@example.com\"]\n").unwrap();
+    corky(&data)
+        .args(["contact", "carddav"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No [carddav] section"));
+}
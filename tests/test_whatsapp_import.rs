@@ -0,0 +1,141 @@
+//! CLI integration tests for `corky sync whatsapp-import` (WA1–WA5).
+
+mod common;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::io::Write;
+use std::path::Path;
+
+use corky::sync::markdown::parse_thread_markdown;
+
+const ANDROID: &str = "12/31/23, 9:00 PM - Messages and calls are end-to-end encrypted. No one outside of this chat can read them.\n12/31/23, 9:41 PM - Alice Smith: Are we still on for tonight?\nI can bring snacks\n12/31/23, 9:41 PM - Alice Smith: <Media omitted>\n12/31/23, 9:45 PM - Me: Yes! See you at 10\n";
+
+const IOS: &str = "\u{200e}[31/12/2023, 21:41:05] Team: \u{200e}Messages and calls are end-to-end encrypted.\n[31/12/2023, 21:41:05] Team: \u{200e}Alice added Bob\n[31/12/2023, 21:42:10] Bob: \u{200e}<attached: 00000012-PHOTO-2023-12-31-21-42-10.jpg>\n[01/01/2024, 00:00:01] Alice: Happy new year!\n";
+
+fn corky(data: &Path) -> assert_cmd::Command {
+    let mut cmd = cargo_bin_cmd!("corky");
+    cmd.current_dir(data).env("CORKY_DATA", data.to_string_lossy().as_ref());
+    cmd
+}
+
+fn read_thread(data: &Path, file: &str) -> corky::sync::types::Thread {
+    let text = std::fs::read_to_string(data.join("conversations").join(file)).unwrap();
+    parse_thread_markdown(&text).unwrap()
+}
+
+// WA1: Android .txt export → one thread keyed by chat name
+#[test]
+fn wa1_android_txt() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("WhatsApp Chat with Alice Smith.txt");
+    std::fs::write(&path, ANDROID).unwrap();
+
+    corky(&data)
+        .args(["sync", "whatsapp-import", &path.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "3 message(s) imported, 0 duplicate(s) skipped, 1 system message(s) skipped",
+        ));
+
+    let thread = read_thread(&data, "alice-smith.md");
+    assert_eq!(thread.id, "wa:alice-smith");
+    assert_eq!(thread.labels, vec!["whatsapp"]);
+    assert_eq!(thread.messages.len(), 3);
+    assert_eq!(thread.messages[0].from, "Alice Smith");
+    assert_eq!(thread.messages[0].date, "Sun, 31 Dec 2023 21:41:00 +0000");
+    assert_eq!(thread.messages[0].body, "Are we still on for tonight?\nI can bring snacks");
+    // Same sender, same minute: kept apart by a one-second offset
+    assert_eq!(thread.messages[1].date, "Sun, 31 Dec 2023 21:41:01 +0000");
+    assert_eq!(thread.messages[1].body, "[Media omitted]");
+}
+
+// WA2: iOS .zip export with _chat.txt
+#[test]
+fn wa2_ios_zip() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("WhatsApp Chat - Team.zip");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+    let options = zip::write::SimpleFileOptions::default();
+    zip.start_file("_chat.txt", options).unwrap();
+    zip.write_all(IOS.as_bytes()).unwrap();
+    zip.start_file("00000012-PHOTO-2023-12-31-21-42-10.jpg", options).unwrap();
+    zip.write_all(b"\xff\xd8").unwrap();
+    zip.finish().unwrap();
+
+    corky(&data)
+        .args(["sync", "whatsapp-import", &path.to_string_lossy(), "--label", "family"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 message(s) imported").and(predicate::str::contains(
+            "2 system message(s) skipped",
+        )));
+
+    let thread = read_thread(&data, "team.md");
+    assert_eq!(thread.id, "wa:team");
+    assert_eq!(thread.labels, vec!["family"]);
+    assert_eq!(thread.messages[0].body, "[Attachment: 00000012-PHOTO-2023-12-31-21-42-10.jpg]");
+    assert_eq!(thread.messages[1].date, "Mon, 01 Jan 2024 00:00:01 +0000");
+}
+
+// WA3: Re-importing reports duplicates and adds nothing
+#[test]
+fn wa3_reimport_is_idempotent() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("WhatsApp Chat with Alice Smith.txt");
+    std::fs::write(&path, ANDROID).unwrap();
+    corky(&data)
+        .args(["sync", "whatsapp-import", &path.to_string_lossy()])
+        .assert()
+        .success();
+    corky(&data)
+        .args(["sync", "whatsapp-import", &path.to_string_lossy()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 message(s) imported, 3 duplicate(s) skipped"));
+    assert_eq!(read_thread(&data, "alice-smith.md").messages.len(), 3);
+}
+
+// WA4: --chat and --date-order overrides
+#[test]
+fn wa4_chat_and_date_order() {
+    let (_tmp, data) = common::temp_data_dir();
+    let path = data.join("_chat.txt");
+    std::fs::write(&path, "03.04.24, 10:00 - Bob: Hi\n").unwrap();
+
+    corky(&data)
+        .args([
+            "sync",
+            "whatsapp-import",
+            &path.to_string_lossy(),
+            "--chat",
+            "Bob Jones",
+            "--date-order",
+            "mdy",
+        ])
+        .assert()
+        .success();
+
+    let thread = read_thread(&data, "bob-jones.md");
+    assert_eq!(thread.id, "wa:bob-jones");
+    assert_eq!(thread.messages[0].date, "Mon, 04 Mar 2024 10:00:00 +0000");
+}
+
+// WA5: Missing files and exports without messages fail
+#[test]
+fn wa5_errors() {
+    let (_tmp, data) = common::temp_data_dir();
+    corky(&data)
+        .args(["sync", "whatsapp-import", "/tmp/nonexistent-wa-export.txt"])
+        .assert()
+        .failure();
+
+    let path = data.join("notes.txt");
+    std::fs::write(&path, "just some notes\n").unwrap();
+    corky(&data)
+        .args(["sync", "whatsapp-import", &path.to_string_lossy()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No WhatsApp messages found"));
+}
@@ -1,11 +1,11 @@
-//! YouTube upload and update tests (YT-U1–YT-U8).
+//! YouTube upload, update, delete, metrics, and comments tests (YT-U1–YT-U14).
 //!
 //! Uses a mock HTTP server to simulate YouTube API responses.
 
 mod common;
 
 use corky::social::youtube::{
-    get_channel_id_at, map_visibility, update_video_at, upload_video_at,
+    delete_video_at, get_channel_id_at, get_comments_at, get_video_metrics_at, map_visibility, update_video_at, upload_video_at,
     VideoMetadata,
 };
 use std::io::Write;
@@ -242,3 +242,180 @@ fn yt_u8_upload_missing_file() {
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("not found"));
 }
+
+// YT-U9: delete_video sends DELETE with the video ID
+#[test]
+fn yt_u9_delete_video() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("/youtube/v3/videos"));
+        assert!(req.url().contains("id=VIDEO_ID_123"));
+        assert_eq!(req.method().as_str(), "DELETE");
+        req.respond(Response::empty(204)).unwrap();
+    });
+
+    let result = delete_video_at(&api_base, "fake_token", "VIDEO_ID_123");
+    handle.join().unwrap();
+    assert!(result.is_ok());
+}
+
+// YT-U10: delete_video returns error on 404
+#[test]
+fn yt_u10_delete_video_not_found() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        req.respond(
+            Response::from_string(r#"{"error":{"message":"Video not found"}}"#)
+                .with_status_code(404),
+        )
+        .unwrap();
+    });
+
+    let result = delete_video_at(&api_base, "fake_token", "MISSING");
+    handle.join().unwrap();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("404"));
+}
+
+// YT-U11: get_video_metrics parses string counts from statistics
+#[test]
+fn yt_u11_video_metrics() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("part=statistics"));
+        assert!(req.url().contains("id=VIDEO_ID_123"));
+        req.respond(
+            Response::from_string(
+                r#"{"items":[{"id":"VIDEO_ID_123","statistics":{"viewCount":"1500","likeCount":"42","commentCount":"7"}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+    });
+
+    let metrics = get_video_metrics_at(&api_base, "fake_token", "VIDEO_ID_123").unwrap();
+    handle.join().unwrap();
+    assert_eq!(metrics.reactions, 42);
+    assert_eq!(metrics.comments, 7);
+    assert_eq!(metrics.impressions, Some(1500));
+    assert_eq!(metrics.shares, None);
+}
+
+// YT-U12: get_video_metrics errors when the video is missing
+#[test]
+fn yt_u12_video_metrics_not_found() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        req.respond(Response::from_string(r#"{"items":[]}"#).with_status_code(200))
+            .unwrap();
+    });
+
+    let result = get_video_metrics_at(&api_base, "fake_token", "MISSING");
+    handle.join().unwrap();
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("not found"));
+}
+
+// YT-U13: get_comments flattens top-level comments and replies, following pageToken
+#[test]
+fn yt_u13_comments_paged() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("/youtube/v3/commentThreads"));
+        assert!(req.url().contains("videoId=VIDEO_ID_123"));
+        assert!(!req.url().contains("pageToken"));
+        req.respond(
+            Response::from_string(
+                r#"{"nextPageToken":"P2","items":[{"snippet":{"topLevelComment":{"id":"c1","snippet":{"authorDisplayName":"Alice","authorChannelId":{"value":"UCalice"},"textOriginal":"Loved it","publishedAt":"2026-01-01T10:00:00Z"}}},"replies":{"comments":[{"id":"c1.r1","snippet":{"authorDisplayName":"Me","authorChannelId":{"value":"UCme"},"textOriginal":"Thanks!","publishedAt":"2026-01-01T11:00:00Z"}}]}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("pageToken=P2"));
+        req.respond(
+            Response::from_string(
+                r#"{"items":[{"snippet":{"topLevelComment":{"id":"c0","snippet":{"authorDisplayName":"Bob","authorChannelId":{"value":"UCbob"},"textOriginal":"Early","publishedAt":"2025-12-31T09:00:00Z"}}}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+    });
+
+    let comments = get_comments_at(&api_base, "fake_token", "VIDEO_ID_123").unwrap();
+    handle.join().unwrap();
+    let ids: Vec<_> = comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["c0", "c1", "c1.r1"]);
+    assert_eq!(comments[1].author_name, "Alice");
+    assert_eq!(comments[1].author_id, "UCalice");
+    assert_eq!(comments[2].text, "Thanks!");
+}
+
+// YT-U14: threads with more replies than commentThreads inlines page through comments.list
+#[test]
+fn yt_u14_comment_replies_by_parent() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    let api_base = format!("http://{}", addr);
+
+    let handle = std::thread::spawn(move || {
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("/youtube/v3/commentThreads"));
+        req.respond(
+            Response::from_string(
+                r#"{"items":[{"id":"t1","snippet":{"totalReplyCount":3,"topLevelComment":{"id":"t1","snippet":{"authorDisplayName":"Alice","textOriginal":"Question?","publishedAt":"2026-01-01T10:00:00Z"}}},"replies":{"comments":[{"id":"t1.r1","snippet":{"authorDisplayName":"Me","textOriginal":"Answer","publishedAt":"2026-01-01T11:00:00Z"}}]}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("/youtube/v3/comments?"));
+        assert!(req.url().contains("parentId=t1"));
+        assert!(!req.url().contains("pageToken"));
+        req.respond(
+            Response::from_string(
+                r#"{"nextPageToken":"R2","items":[{"id":"t1.r1","snippet":{"authorDisplayName":"Me","textOriginal":"Answer","publishedAt":"2026-01-01T11:00:00Z"}},{"id":"t1.r2","snippet":{"authorDisplayName":"Bob","textOriginal":"Same here","publishedAt":"2026-01-01T12:00:00Z"}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+
+        let req = server.recv().unwrap();
+        assert!(req.url().contains("parentId=t1"));
+        assert!(req.url().contains("pageToken=R2"));
+        req.respond(
+            Response::from_string(
+                r#"{"items":[{"id":"t1.r3","snippet":{"authorDisplayName":"Alice","textOriginal":"Thanks","publishedAt":"2026-01-01T13:00:00Z"}}]}"#,
+            )
+            .with_status_code(200),
+        )
+        .unwrap();
+    });
+
+    let comments = get_comments_at(&api_base, "fake_token", "VIDEO_ID_123").unwrap();
+    handle.join().unwrap();
+    let ids: Vec<_> = comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["t1", "t1.r1", "t1.r2", "t1.r3"]);
+}